@@ -564,6 +564,7 @@ func New(
 	wasmOpts := []wasmkeeper.Option{
 		wasmkeeper.WithMessageEncoders(wasmcustomhandler.NewCoreumMsgHandler()),
 		wasmkeeper.WithQueryPlugins(wasmcustomhandler.NewCoreumQueryHandler(
+			app.BankKeeper,
 			assetftkeeper.NewQueryService(app.AssetFTKeeper, app.BankKeeper),
 			assetnftkeeper.NewQueryService(app.AssetNFTKeeper),
 			app.NFTKeeper,
@@ -0,0 +1,46 @@
+fn main() {
+    // contracts using the prost messages of cosmos-sdk-proto build without the protos
+    #[cfg(feature = "protos")]
+    generate_protos();
+}
+
+// Generates the rust-protobuf messages of every proto under proto/ into $OUT_DIR/protos, the
+// crate includes them from there.
+#[cfg(feature = "protos")]
+fn generate_protos() {
+    use std::fs;
+    use std::path::Path;
+
+    println!("cargo:rerun-if-changed=proto");
+
+    let mut protos: Vec<_> = fs::read_dir("proto")
+        .expect("proto directory must exist")
+        .map(|entry| entry.expect("proto directory must be readable").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "proto"))
+        .collect();
+    protos.sort();
+
+    let out_dir = Path::new(&std::env::var("OUT_DIR").unwrap()).join("protos");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    // the protos have no package, so each one is generated on its own to keep messages of the
+    // same name, like Coin, from resolving to another file
+    for proto in &protos {
+        protobuf_codegen::Codegen::new()
+            .pure()
+            .include("proto")
+            .input(proto)
+            .out_dir(&out_dir)
+            .run_from_script();
+    }
+
+    // the generated files still allow lints removed from rustc, which warns about it
+    let mod_rs: String = protos
+        .iter()
+        .map(|proto| {
+            let name = proto.file_stem().unwrap().to_str().unwrap();
+            format!("#[allow(renamed_and_removed_lints)]\npub mod {name};\n")
+        })
+        .collect();
+    fs::write(out_dir.join("mod.rs"), mod_rs).unwrap();
+}
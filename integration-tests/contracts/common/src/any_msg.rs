@@ -0,0 +1,88 @@
+use cosmwasm_std::{Binary, CosmosMsg};
+use protobuf::well_known_types::any::Any;
+use protobuf::{Error, Message};
+
+use crate::protos::{
+    AssetFTTx, AssetNFTTx, CosmosAuthz, CosmosAuthzQuery, CosmosBankParams, CosmosBankSend,
+    CosmosDistribution, CosmosFeegrant, CosmosGov, CosmosStaking, CosmosVesting, CosmosWasm,
+    IbcTransfer,
+};
+use crate::typeurl;
+
+/// Protobuf message with a cosmos type URL, so it can be packed into an Any or dispatched as a
+/// stargate message.
+pub trait AnyMsg: Message {
+    const TYPE_URL: &'static str;
+
+    fn type_url(&self) -> &'static str {
+        Self::TYPE_URL
+    }
+
+    fn to_any(&self) -> Result<Any, Error> {
+        Ok(Any {
+            type_url: Self::TYPE_URL.to_string(),
+            value: self.write_to_bytes()?,
+            special_fields: Default::default(),
+        })
+    }
+
+    fn to_stargate<T>(&self) -> Result<CosmosMsg<T>, Error> {
+        Ok(CosmosMsg::Stargate {
+            type_url: Self::TYPE_URL.to_string(),
+            value: Binary::from(self.write_to_bytes()?),
+        })
+    }
+}
+
+macro_rules! impl_any_msg {
+    ($($msg:ty => $type_url:path),* $(,)?) => {
+        $(
+            impl AnyMsg for $msg {
+                const TYPE_URL: &'static str = $type_url;
+            }
+        )*
+    };
+}
+
+impl_any_msg! {
+    AssetFTTx::MsgIssue => typeurl::MSG_ISSUE,
+    AssetFTTx::MsgMint => typeurl::MSG_MINT,
+    AssetFTTx::MsgBurn => typeurl::MSG_BURN,
+    AssetFTTx::MsgFreeze => typeurl::MSG_FREEZE,
+    AssetFTTx::MsgUnfreeze => typeurl::MSG_UNFREEZE,
+    AssetFTTx::MsgSetFrozen => typeurl::MSG_SET_FROZEN,
+    AssetFTTx::MsgGloballyFreeze => typeurl::MSG_GLOBALLY_FREEZE,
+    AssetFTTx::MsgGloballyUnfreeze => typeurl::MSG_GLOBALLY_UNFREEZE,
+    AssetFTTx::MsgSetWhitelistedLimit => typeurl::MSG_SET_WHITELISTED_LIMIT,
+    AssetFTTx::MsgUpgradeTokenV1 => typeurl::MSG_UPGRADE_TOKEN_V1,
+    AssetNFTTx::MsgAddToClassWhitelist => typeurl::MSG_ADD_TO_CLASS_WHITELIST,
+    AssetNFTTx::MsgRemoveFromClassWhitelist => typeurl::MSG_REMOVE_FROM_CLASS_WHITELIST,
+    CosmosAuthz::MsgExec => typeurl::MSG_EXEC,
+    CosmosAuthz::MsgGrant => typeurl::MSG_GRANT,
+    CosmosAuthz::MsgRevoke => typeurl::MSG_REVOKE,
+    CosmosAuthzQuery::GenericAuthorization => typeurl::GENERIC_AUTHORIZATION,
+    CosmosAuthzQuery::SendAuthorization => typeurl::SEND_AUTHORIZATION,
+    CosmosAuthzQuery::StakeAuthorization => typeurl::STAKE_AUTHORIZATION,
+    CosmosBankParams::MsgSetSendEnabled => typeurl::MSG_SET_SEND_ENABLED,
+    CosmosBankParams::MsgUpdateParams => typeurl::MSG_UPDATE_PARAMS,
+    CosmosBankSend::MsgSend => typeurl::MSG_SEND,
+    CosmosBankSend::MsgMultiSend => typeurl::MSG_MULTI_SEND,
+    CosmosDistribution::MsgSetWithdrawAddress => typeurl::MSG_SET_WITHDRAW_ADDRESS,
+    CosmosDistribution::MsgWithdrawDelegatorReward => typeurl::MSG_WITHDRAW_DELEGATOR_REWARD,
+    CosmosFeegrant::BasicAllowance => typeurl::BASIC_ALLOWANCE,
+    CosmosFeegrant::PeriodicAllowance => typeurl::PERIODIC_ALLOWANCE,
+    CosmosFeegrant::AllowedMsgAllowance => typeurl::ALLOWED_MSG_ALLOWANCE,
+    CosmosFeegrant::MsgGrantAllowance => typeurl::MSG_GRANT_ALLOWANCE,
+    CosmosFeegrant::MsgRevokeAllowance => typeurl::MSG_REVOKE_ALLOWANCE,
+    CosmosGov::MsgSubmitProposal => typeurl::MSG_SUBMIT_PROPOSAL,
+    CosmosGov::MsgDeposit => typeurl::MSG_DEPOSIT,
+    CosmosGov::MsgVote => typeurl::MSG_VOTE,
+    CosmosGov::MsgVoteWeighted => typeurl::MSG_VOTE_WEIGHTED,
+    CosmosStaking::MsgDelegate => typeurl::MSG_DELEGATE,
+    CosmosStaking::MsgUndelegate => typeurl::MSG_UNDELEGATE,
+    CosmosStaking::MsgBeginRedelegate => typeurl::MSG_BEGIN_REDELEGATE,
+    CosmosVesting::MsgCreateVestingAccount => typeurl::MSG_CREATE_VESTING_ACCOUNT,
+    CosmosWasm::MsgClearAdmin => typeurl::MSG_CLEAR_ADMIN,
+    CosmosWasm::MsgUpdateAdmin => typeurl::MSG_UPDATE_ADMIN,
+    IbcTransfer::MsgTransfer => typeurl::MSG_TRANSFER,
+}
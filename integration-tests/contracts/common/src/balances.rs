@@ -0,0 +1,239 @@
+use std::str::FromStr;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, CustomQuery, QuerierWrapper, StdResult, Uint128};
+use protobuf::MessageField;
+
+use crate::protos::CosmosBankQuery::{
+    PageRequest, QueryAllBalancesRequest, QueryAllBalancesResponse,
+};
+use crate::stargate::query_stargate;
+
+pub const ALL_BALANCES_PATH: &str = "/cosmos.bank.v1beta1.Query/AllBalances";
+pub const BALANCES_PAGE_LIMIT: u64 = 50;
+pub const MAX_BALANCES_PAGES: usize = 10;
+
+#[cw_serde]
+pub struct BalancesMatching {
+    pub balances: Vec<Coin>,
+    // set if the account holds more denoms than the pages read, the balances are then incomplete
+    pub cap_reached: bool,
+}
+
+/// Pages through the balances of the address and keeps the coins whose denom starts with the
+/// prefix and, if given, ends with the suffix. Smart tokens of an issuer share the `-{issuer}`
+/// suffix. An empty prefix matches every denom. At most MAX_BALANCES_PAGES pages of
+/// BALANCES_PAGE_LIMIT coins are read.
+pub fn balances_matching<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    address: &str,
+    denom_prefix: &str,
+    denom_suffix: Option<&str>,
+) -> StdResult<BalancesMatching> {
+    let mut balances = vec![];
+    let mut key = vec![];
+    for _ in 0..MAX_BALANCES_PAGES {
+        let mut pagination = PageRequest::new();
+        pagination.key = key;
+        pagination.limit = BALANCES_PAGE_LIMIT;
+        let mut request = QueryAllBalancesRequest::new();
+        request.address = address.to_string();
+        request.pagination = MessageField::some(pagination);
+        let res: QueryAllBalancesResponse = query_stargate(querier, ALL_BALANCES_PATH, &request)?;
+
+        for coin in res.balances {
+            if coin.denom.starts_with(denom_prefix)
+                && denom_suffix.is_none_or(|suffix| coin.denom.ends_with(suffix))
+            {
+                balances.push(Coin {
+                    amount: Uint128::from_str(&coin.amount)?,
+                    denom: coin.denom,
+                });
+            }
+        }
+
+        key = res.pagination.into_option().unwrap_or_default().next_key;
+        if key.is_empty() {
+            return Ok(BalancesMatching {
+                balances,
+                cap_reached: false,
+            });
+        }
+    }
+
+    Ok(BalancesMatching {
+        balances,
+        cap_reached: true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use cosmwasm_std::testing::MockQuerier;
+    use cosmwasm_std::{coin, Binary, ContractResult, Empty};
+    use protobuf::Message;
+
+    use super::*;
+    use crate::protos::CosmosBankQuery::{Coin as ProtoCoin, PageResponse};
+    use crate::stargate_mock::StargateMockQuerier;
+
+    const ADDRESS: &str = "core1qyqszqgpqyqszqgpqyqszqgpqyqszqgppae928";
+    const ISSUER: &str = "core1zgqszqgpqyqszqgpqyqszqgpqyqszqgp0f2xnw";
+
+    // first page of AllBalances with uabc-{ADDRESS} 100 and ucore 5, continued at key 0x016e657874
+    const FIRST_PAGE: &str = "CjcKMHVhYmMtY29yZTFxeXFzenFncHF5cXN6cWdwcXlxc3pxZ3BxeXFzenFncHBhZTkyOBIDMTAwCgoKBXVjb3JlEgE1EgkKBQFuZXh0EAM=";
+
+    // a bank holding the denoms in order, paged like the chain with the index of the next coin as
+    // the key. Counts the pages read.
+    fn bank(denoms: Vec<String>, pages: Rc<Cell<usize>>) -> StargateMockQuerier {
+        StargateMockQuerier::new(MockQuerier::new(&[])).with_handler(
+            ALL_BALANCES_PATH,
+            move |data| {
+                let request = QueryAllBalancesRequest::parse_from_bytes(data).unwrap();
+                assert_eq!(request.address, ADDRESS);
+                let pagination = request.pagination.unwrap();
+                assert_eq!(pagination.limit, BALANCES_PAGE_LIMIT);
+                pages.set(pages.get() + 1);
+
+                let start = match pagination.key.as_slice() {
+                    [] => 0,
+                    key => String::from_utf8(key.to_vec()).unwrap().parse().unwrap(),
+                };
+                let end = denoms.len().min(start + BALANCES_PAGE_LIMIT as usize);
+                let mut res = QueryAllBalancesResponse::new();
+                res.balances = denoms[start..end]
+                    .iter()
+                    .map(|denom| {
+                        let mut coin = ProtoCoin::new();
+                        coin.denom = denom.clone();
+                        coin.amount = "1".to_string();
+                        coin
+                    })
+                    .collect();
+                let mut page = PageResponse::new();
+                if end < denoms.len() {
+                    page.next_key = end.to_string().into_bytes();
+                }
+                res.pagination = MessageField::some(page);
+                ContractResult::Ok(res.write_to_bytes().unwrap().into())
+            },
+        )
+    }
+
+    fn matching(
+        denoms: Vec<String>,
+        prefix: &str,
+        suffix: Option<&str>,
+    ) -> (BalancesMatching, usize) {
+        let pages = Rc::new(Cell::new(0));
+        let querier = bank(denoms, pages.clone());
+        let res = balances_matching(
+            &QuerierWrapper::<Empty>::new(&querier),
+            ADDRESS,
+            prefix,
+            suffix,
+        )
+        .unwrap();
+        (res, pages.get())
+    }
+
+    // 60 smart tokens of the issuer interleaved with 60 other denoms, three pages
+    fn mixed_denoms() -> Vec<String> {
+        (0..60)
+            .flat_map(|i| [format!("tok{i:02}-{ISSUER}"), format!("ibc/{i:064X}")])
+            .collect()
+    }
+
+    fn denoms(balances: &[Coin]) -> Vec<&str> {
+        balances.iter().map(|coin| coin.denom.as_str()).collect()
+    }
+
+    #[test]
+    fn decodes_the_fixture_pages() {
+        let querier: StargateMockQuerier = StargateMockQuerier::new(MockQuerier::new(&[]))
+            .with_handler(ALL_BALANCES_PATH, |data| {
+                let request = QueryAllBalancesRequest::parse_from_bytes(data).unwrap();
+                match request.pagination.key.as_slice() {
+                    [] => ContractResult::Ok(Binary::from_base64(FIRST_PAGE).unwrap()),
+                    b"\x01next" => ContractResult::Ok(Binary::default()),
+                    key => panic!("unexpected key {key:?}"),
+                }
+            });
+        let res =
+            balances_matching(&QuerierWrapper::<Empty>::new(&querier), ADDRESS, "", None).unwrap();
+        assert_eq!(
+            res,
+            BalancesMatching {
+                balances: vec![coin(100, format!("uabc-{ADDRESS}")), coin(5, "ucore")],
+                cap_reached: false,
+            }
+        );
+    }
+
+    #[test]
+    fn filters_across_pages() {
+        let (res, pages) = matching(mixed_denoms(), "", Some(&format!("-{ISSUER}")));
+        assert_eq!(pages, 3);
+        assert!(!res.cap_reached);
+        assert_eq!(res.balances.len(), 60);
+        assert_eq!(res.balances[59], coin(1, format!("tok59-{ISSUER}")));
+
+        let (res, _) = matching(mixed_denoms(), "tok5", Some(&format!("-{ISSUER}")));
+        assert_eq!(
+            denoms(&res.balances),
+            (50..60)
+                .map(|i| format!("tok{i}-{ISSUER}"))
+                .collect::<Vec<_>>()
+        );
+
+        let (res, _) = matching(mixed_denoms(), "ibc/", None);
+        assert_eq!(res.balances.len(), 60);
+    }
+
+    #[test]
+    fn empty_prefix_matches_everything() {
+        let (res, pages) = matching(mixed_denoms(), "", None);
+        assert_eq!(pages, 3);
+        assert_eq!(denoms(&res.balances), mixed_denoms());
+    }
+
+    #[test]
+    fn no_matches() {
+        let (res, pages) = matching(mixed_denoms(), "ucore", None);
+        assert_eq!(pages, 3);
+        assert_eq!(
+            res,
+            BalancesMatching {
+                balances: vec![],
+                cap_reached: false,
+            }
+        );
+
+        // the suffix has to match as well as the prefix
+        let (res, _) = matching(mixed_denoms(), "tok", Some(&format!("-{ADDRESS}")));
+        assert!(res.balances.is_empty());
+
+        let (res, pages) = matching(vec![], "", None);
+        assert_eq!(pages, 1);
+        assert!(res.balances.is_empty());
+    }
+
+    #[test]
+    fn stops_at_the_page_cap() {
+        let all = |count: usize| (0..count).map(|i| format!("u{i:04}")).collect();
+
+        // the last page fits into the cap
+        let (res, pages) = matching(all(500), "", None);
+        assert_eq!(pages, MAX_BALANCES_PAGES);
+        assert_eq!(res.balances.len(), 500);
+        assert!(!res.cap_reached);
+
+        let (res, pages) = matching(all(501), "", None);
+        assert_eq!(pages, MAX_BALANCES_PAGES);
+        assert_eq!(res.balances.len(), 500);
+        assert!(res.cap_reached);
+    }
+}
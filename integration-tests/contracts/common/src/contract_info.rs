@@ -0,0 +1,158 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{ContractResult, CustomQuery, QuerierWrapper, StdError, StdResult};
+
+use crate::protos::CosmosWasmQuery::{QueryContractInfoRequest, QueryContractInfoResponse};
+use crate::stargate::{decode, query_stargate_raw};
+
+pub const CONTRACT_INFO_PATH: &str = "/cosmwasm.wasm.v1.Query/ContractInfo";
+
+#[cw_serde]
+pub struct ContractInfo {
+    pub code_id: u64,
+    pub creator: String,
+    // None for contracts without an admin, which the chain encodes as an empty, omitted, field
+    pub admin: Option<String>,
+    pub label: String,
+}
+
+impl From<QueryContractInfoResponse> for ContractInfo {
+    fn from(res: QueryContractInfoResponse) -> Self {
+        let info = res.contract_info.unwrap_or_default();
+        Self {
+            code_id: info.code_id,
+            creator: info.creator,
+            admin: Some(info.admin).filter(|admin| !admin.is_empty()),
+            label: info.label,
+        }
+    }
+}
+
+/// Queries the wasm module for the contract at the address, None if there is no contract at it.
+/// Other errors of the query are returned as is.
+pub fn query_contract_info<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    address: &str,
+) -> StdResult<Option<ContractInfo>> {
+    let mut request = QueryContractInfoRequest::new();
+    request.address = address.to_string();
+    match query_stargate_raw(querier, CONTRACT_INFO_PATH, &request)? {
+        ContractResult::Ok(data) => {
+            let res: QueryContractInfoResponse = decode(CONTRACT_INFO_PATH, &data)?;
+            Ok(Some(res.into()))
+        }
+        // wasmd reports an unknown address as not found or, since v0.45, as no such contract
+        ContractResult::Err(err) if is_not_found(&err) => Ok(None),
+        ContractResult::Err(err) => Err(StdError::generic_err(format!(
+            "Querier contract error: {err}"
+        ))),
+    }
+}
+
+/// Tells whether the contract at the address was instantiated by the creator, false if there is
+/// no contract at the address.
+pub fn is_instantiated_by<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    address: &str,
+    creator: &str,
+) -> StdResult<bool> {
+    Ok(query_contract_info(querier, address)?.is_some_and(|info| info.creator == creator))
+}
+
+fn is_not_found(err: &str) -> bool {
+    err.contains("not found") || err.contains("no such contract")
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockQuerier;
+    use cosmwasm_std::{Binary, Empty};
+    use protobuf::Message;
+
+    use super::*;
+    use crate::stargate_mock::StargateMockQuerier;
+
+    const CONTRACT: &str = "core14hj2tavq8fpesdwxxcu44rty3hh90vhujrvcmstl4zr3txmfvw9sgnpr7u";
+    const CREATOR: &str = "core1qyqszqgpqyqszqgpqyqszqgpqyqszqgppae928";
+    const ADMIN: &str = "core1zgqszqgpqyqszqgpqyqszqgpqyqszqgp0f2xnw";
+
+    // QueryContractInfoResponse of code 7 labeled escrow, as wasmd encodes it with the created
+    // position the subset doesn't define
+    const WITH_ADMIN: &str = "Cj9jb3JlMTRoajJ0YXZxOGZwZXNkd3h4Y3U0NHJ0eTNoaDkwdmh1anJ2Y21zdGw0enIzdHhtZnZ3OXNnbnByN3USaAgHEitjb3JlMXF5cXN6cWdwcXlxc3pxZ3BxeXFzenFncHF5cXN6cWdwcGFlOTI4Gitjb3JlMXpncXN6cWdwcXlxc3pxZ3BxeXFzenFncHF5cXN6cWdwMGYyeG53IgZlc2Nyb3cqAggq";
+    const WITHOUT_ADMIN: &str = "Cj9jb3JlMTRoajJ0YXZxOGZwZXNkd3h4Y3U0NHJ0eTNoaDkwdmh1anJ2Y21zdGw0enIzdHhtZnZ3OXNnbnByN3USOwgHEitjb3JlMXF5cXN6cWdwcXlxc3pxZ3BxeXFzenFncHF5cXN6cWdwcGFlOTI4IgZlc2Nyb3cqAggq";
+
+    // answers the query of CONTRACT with the fixture and fails with the error for other addresses
+    fn querier(fixture: &'static str, error: &'static str) -> StargateMockQuerier {
+        StargateMockQuerier::new(MockQuerier::new(&[])).with_handler(
+            CONTRACT_INFO_PATH,
+            move |data| {
+                let request = QueryContractInfoRequest::parse_from_bytes(data).unwrap();
+                if request.address == CONTRACT {
+                    ContractResult::Ok(Binary::from_base64(fixture).unwrap())
+                } else {
+                    ContractResult::Err(error.to_string())
+                }
+            },
+        )
+    }
+
+    fn contract_info(
+        querier: &StargateMockQuerier,
+        address: &str,
+    ) -> StdResult<Option<ContractInfo>> {
+        query_contract_info(&QuerierWrapper::<Empty>::new(querier), address)
+    }
+
+    #[test]
+    fn decodes_the_admin() {
+        let querier = querier(WITH_ADMIN, "not found");
+        assert_eq!(
+            contract_info(&querier, CONTRACT).unwrap(),
+            Some(ContractInfo {
+                code_id: 7,
+                creator: CREATOR.to_string(),
+                admin: Some(ADMIN.to_string()),
+                label: "escrow".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn omitted_admin_is_none() {
+        let querier = querier(WITHOUT_ADMIN, "not found");
+        let info = contract_info(&querier, CONTRACT).unwrap().unwrap();
+        assert_eq!(info.admin, None);
+        assert_eq!(info.creator, CREATOR);
+
+        // an admin sent as an explicitly empty string is the same absent admin
+        let mut res = QueryContractInfoResponse::new();
+        res.contract_info.mut_or_insert_default().admin = String::new();
+        let mut encoded = res.write_to_bytes().unwrap();
+        encoded.extend_from_slice(&[0x12, 0x02, 0x1a, 0x00]);
+        let res = QueryContractInfoResponse::parse_from_bytes(&encoded).unwrap();
+        assert_eq!(ContractInfo::from(res).admin, None);
+    }
+
+    #[test]
+    fn unknown_address_is_none() {
+        for error in [
+            "not found",
+            "codespace: wasm, code: 22: no such contract: address core1unknown",
+        ] {
+            let querier = querier(WITH_ADMIN, error);
+            assert_eq!(contract_info(&querier, "core1unknown").unwrap(), None);
+        }
+
+        let querier = querier(WITH_ADMIN, "decoding bech32 failed");
+        let err = contract_info(&querier, "unknown").unwrap_err();
+        assert!(err.to_string().contains("decoding bech32 failed"));
+    }
+
+    #[test]
+    fn instantiated_by_the_creator() {
+        let querier = querier(WITHOUT_ADMIN, "not found");
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+        assert!(is_instantiated_by(&querier, CONTRACT, CREATOR).unwrap());
+        assert!(!is_instantiated_by(&querier, CONTRACT, ADMIN).unwrap());
+        assert!(!is_instantiated_by(&querier, "core1unknown", CREATOR).unwrap());
+    }
+}
@@ -0,0 +1,145 @@
+use cosmwasm_std::Addr;
+
+const NATIVE_DENOMS: [&str; 3] = ["ucore", "utestcore", "udevcore"];
+const IBC_PREFIX: &str = "ibc/";
+const IBC_HASH_LEN: usize = 64;
+const MAX_SUBUNIT_LEN: usize = 51;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DenomKind {
+    // the chain staking denom
+    Native,
+    // assetft token, denom is `{subunit}-{issuer}`
+    SmartToken { subunit: String, issuer: Addr },
+    // IBC voucher, denom is `ibc/{HASH}`
+    Ibc { hash: String },
+    Unknown,
+}
+
+/// Classifies a denom by its format. Smart token issuers must be valid lowercase bech32 and IBC
+/// hashes 64 uppercase hex characters, anything malformed is reported as unknown.
+pub fn classify_denom(denom: &str) -> DenomKind {
+    if NATIVE_DENOMS.contains(&denom) {
+        return DenomKind::Native;
+    }
+
+    if let Some(hash) = denom.strip_prefix(IBC_PREFIX) {
+        let valid = hash.len() == IBC_HASH_LEN
+            && hash
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('A'..='F').contains(&c));
+        return if valid {
+            DenomKind::Ibc {
+                hash: hash.to_string(),
+            }
+        } else {
+            DenomKind::Unknown
+        };
+    }
+
+    if let Some((subunit, issuer)) = denom.split_once('-') {
+        if is_valid_subunit(subunit) && is_valid_bech32(issuer) {
+            return DenomKind::SmartToken {
+                subunit: subunit.to_string(),
+                issuer: Addr::unchecked(issuer),
+            };
+        }
+    }
+
+    DenomKind::Unknown
+}
+
+/// Returns the issuer of a smart token denom.
+pub fn issuer_of(denom: &str) -> Option<Addr> {
+    match classify_denom(denom) {
+        DenomKind::SmartToken { issuer, .. } => Some(issuer),
+        _ => None,
+    }
+}
+
+// is_valid_subunit mirrors the `^[a-z][a-z0-9]{0,50}$` rule of the assetft module.
+fn is_valid_subunit(subunit: &str) -> bool {
+    let mut chars = subunit.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_lowercase())
+        && subunit.len() <= MAX_SUBUNIT_LEN
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+}
+
+fn is_valid_bech32(address: &str) -> bool {
+    address == address.to_lowercase() && bech32::decode(address).is_ok()
+}
+
+/// Builds the denom the chain gives a token issued by the issuer, `{subunit}-{issuer}` with the
+/// subunit lowercased like BuildDenom of x/asset/ft, so it is known before the issuance.
+pub fn build_denom(subunit: &str, issuer: &Addr) -> String {
+    format!("{}-{}", subunit.to_lowercase(), issuer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: &str = "core1qyqszqgpqyqszqgpqyqszqgpqyqszqgppae928";
+    const HASH: &str = "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+    fn smart_token(subunit: &str) -> DenomKind {
+        DenomKind::SmartToken {
+            subunit: subunit.to_string(),
+            issuer: Addr::unchecked(ISSUER),
+        }
+    }
+
+    #[test]
+    fn subunit_is_lowercased() {
+        let issuer = Addr::unchecked("devcore172rc5sz2uclpsy3vvx3y79ah5dk450z5ruq2r5");
+        assert_eq!(
+            build_denom("uABC", &issuer),
+            "uabc-devcore172rc5sz2uclpsy3vvx3y79ah5dk450z5ruq2r5"
+        );
+    }
+
+    #[test]
+    fn classify() {
+        let cases = [
+            ("ucore".to_string(), DenomKind::Native),
+            ("utestcore".to_string(), DenomKind::Native),
+            ("udevcore".to_string(), DenomKind::Native),
+            (format!("abc-{ISSUER}"), smart_token("abc")),
+            (format!("a1b2-{ISSUER}"), smart_token("a1b2")),
+            (
+                format!("ibc/{HASH}"),
+                DenomKind::Ibc {
+                    hash: HASH.to_string(),
+                },
+            ),
+            // native denoms are matched exactly
+            ("UCORE".to_string(), DenomKind::Unknown),
+            ("core".to_string(), DenomKind::Unknown),
+            (String::new(), DenomKind::Unknown),
+            // malformed smart tokens
+            (format!("-{ISSUER}"), DenomKind::Unknown),
+            (format!("Abc-{ISSUER}"), DenomKind::Unknown),
+            (format!("1abc-{ISSUER}"), DenomKind::Unknown),
+            (format!("ab_c-{ISSUER}"), DenomKind::Unknown),
+            (format!("{}-{ISSUER}", "a".repeat(52)), DenomKind::Unknown),
+            (format!("abc-{}", ISSUER.to_uppercase()), DenomKind::Unknown),
+            (
+                "abc-core1qyqszqgpqyqszqgpqyqszqgpqyqszqgppae929".to_string(),
+                DenomKind::Unknown,
+            ),
+            ("abc-".to_string(), DenomKind::Unknown),
+            ("abc".to_string(), DenomKind::Unknown),
+            // malformed ibc vouchers
+            ("ibc/".to_string(), DenomKind::Unknown),
+            (format!("ibc/{}", HASH.to_lowercase()), DenomKind::Unknown),
+            (format!("ibc/{}", &HASH[1..]), DenomKind::Unknown),
+            (format!("ibc/{HASH}0"), DenomKind::Unknown),
+            (format!("ibc/{}G", &HASH[1..]), DenomKind::Unknown),
+            (format!("IBC/{HASH}"), DenomKind::Unknown),
+        ];
+
+        for (denom, expected) in cases {
+            assert_eq!(classify_denom(&denom), expected, "{denom}");
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{StdError, Storage};
+use cw_storage_plus::Item;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GuardError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Operation waiting for reply {reply_id} is in progress")]
+    OperationInProgress { reply_id: u64 },
+}
+
+// Operation waiting for its reply
+#[cw_serde]
+pub struct PendingOp {
+    pub reply_id: u64,
+}
+
+/// Blocks execute entry points while a submessage scheduled by the contract waits for its reply,
+/// so a callee re-entering the contract can't observe or change the pending state.
+pub struct ReentrancyGuard<'a>(Item<'a, Option<PendingOp>>);
+
+impl<'a> ReentrancyGuard<'a> {
+    pub const fn new(key: &'a str) -> Self {
+        Self(Item::new(key))
+    }
+
+    /// Fails if an operation is waiting for its reply.
+    pub fn ensure_idle(&self, storage: &dyn Storage) -> Result<(), GuardError> {
+        match self.0.may_load(storage)?.flatten() {
+            Some(op) => Err(GuardError::OperationInProgress {
+                reply_id: op.reply_id,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Marks the operation replied to with the id as pending.
+    pub fn enter(&self, storage: &mut dyn Storage, reply_id: u64) -> Result<(), GuardError> {
+        self.ensure_idle(storage)?;
+        Ok(self.0.save(storage, &Some(PendingOp { reply_id }))?)
+    }
+
+    /// Clears the pending operation, called by the reply.
+    pub fn exit(&self, storage: &mut dyn Storage) -> Result<(), GuardError> {
+        Ok(self.0.save(storage, &None)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    const GUARD: ReentrancyGuard = ReentrancyGuard::new("guard");
+
+    #[test]
+    fn idle_without_pending_operation() {
+        let mut storage = MockStorage::new();
+        GUARD.ensure_idle(&storage).unwrap();
+
+        // a reply without a pending operation leaves the guard idle
+        GUARD.exit(&mut storage).unwrap();
+        GUARD.ensure_idle(&storage).unwrap();
+    }
+
+    #[test]
+    fn blocks_until_reply() {
+        let mut storage = MockStorage::new();
+        GUARD.enter(&mut storage, 7).unwrap();
+
+        let in_progress = GuardError::OperationInProgress { reply_id: 7 };
+        assert_eq!(GUARD.ensure_idle(&storage).unwrap_err(), in_progress);
+        assert_eq!(GUARD.enter(&mut storage, 8).unwrap_err(), in_progress);
+
+        GUARD.exit(&mut storage).unwrap();
+        GUARD.ensure_idle(&storage).unwrap();
+        GUARD.enter(&mut storage, 8).unwrap();
+    }
+
+    #[test]
+    fn guards_are_independent() {
+        let mut storage = MockStorage::new();
+        let other = ReentrancyGuard::new("other_guard");
+        GUARD.enter(&mut storage, 1).unwrap();
+
+        other.ensure_idle(&storage).unwrap();
+    }
+}
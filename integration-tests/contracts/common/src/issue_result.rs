@@ -0,0 +1,144 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_json, Binary, Reply, Uint128};
+use cw_utils::{parse_execute_response_data, parse_instantiate_response_data};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum IssueResultError {
+    #[error("Invalid issue result: {reason}")]
+    Invalid { reason: String },
+}
+
+// set as response data of the issue and mint_and_send handlers of the ft contract, so calling
+// contracts learn the denom without a follow-up query
+#[cw_serde]
+pub struct IssueResult {
+    pub denom: String,
+    pub amount_minted: Uint128,
+}
+
+/// Extracts the IssueResult set as response data by the ft contract from a SubMsg reply. Older
+/// wasmd versions forward the raw JSON while newer ones wrap it into a protobuf encoded
+/// MsgExecuteContractResponse or MsgInstantiateContractResponse, so every layout is tried.
+pub fn parse_issue_result(reply: &Reply) -> Result<IssueResult, IssueResultError> {
+    let data = reply
+        .result
+        .clone()
+        .into_result()
+        .map_err(|err| IssueResultError::Invalid { reason: err })?
+        .data
+        .ok_or_else(|| invalid("reply has no data"))?;
+
+    if let Ok(result) = from_json::<IssueResult>(&data) {
+        return Ok(result);
+    }
+
+    // an instantiate response also decodes as an execute response, its address taking the place
+    // of the data, so the execute layout must hold an issue result to be accepted
+    let executed = parse_execute_response_data(data.as_slice())
+        .ok()
+        .and_then(|res| res.data);
+    let instantiated = parse_instantiate_response_data(data.as_slice())
+        .ok()
+        .and_then(|res| res.data);
+
+    [executed, instantiated]
+        .iter()
+        .flatten()
+        .find_map(|inner: &Binary| from_json::<IssueResult>(inner).ok())
+        .ok_or_else(|| invalid("data does not contain an issue result"))
+}
+
+fn invalid(reason: &str) -> IssueResultError {
+    IssueResultError::Invalid {
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{to_json_binary, SubMsgResponse, SubMsgResult};
+
+    use super::*;
+
+    fn issue_result() -> IssueResult {
+        IssueResult {
+            denom: "abc-core1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqdcyqx".to_string(),
+            amount_minted: Uint128::new(1000),
+        }
+    }
+
+    fn reply(data: Option<Vec<u8>>) -> Reply {
+        Reply {
+            id: 1,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: data.map(Binary::from),
+            }),
+        }
+    }
+
+    // protobuf length delimited field, the lengths used here fit in a single varint byte
+    fn field(number: u8, value: &[u8]) -> Vec<u8> {
+        let mut out = vec![number << 3 | 2, value.len() as u8];
+        out.extend_from_slice(value);
+        out
+    }
+
+    #[test]
+    fn raw_json() {
+        let data = to_json_binary(&issue_result()).unwrap().to_vec();
+        assert_eq!(parse_issue_result(&reply(Some(data))), Ok(issue_result()));
+    }
+
+    #[test]
+    fn wrapped_in_execute_response() {
+        // MsgExecuteContractResponse { data = 1 }
+        let data = field(1, to_json_binary(&issue_result()).unwrap().as_slice());
+        assert_eq!(parse_issue_result(&reply(Some(data))), Ok(issue_result()));
+    }
+
+    #[test]
+    fn wrapped_in_instantiate_response() {
+        // MsgInstantiateContractResponse { address = 1, data = 2 }
+        let mut data = field(1, b"core1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqdcyqx");
+        data.extend(field(
+            2,
+            to_json_binary(&issue_result()).unwrap().as_slice(),
+        ));
+        assert_eq!(parse_issue_result(&reply(Some(data))), Ok(issue_result()));
+    }
+
+    #[test]
+    fn missing_or_foreign_data() {
+        assert_eq!(
+            parse_issue_result(&reply(None)),
+            Err(invalid("reply has no data"))
+        );
+        for data in [
+            b"{\"denom\":\"abc\"}".to_vec(),
+            field(1, b"{\"other\":true}"),
+            field(1, b""),
+            vec![0xff, 0xff],
+        ] {
+            assert_eq!(
+                parse_issue_result(&reply(Some(data))),
+                Err(invalid("data does not contain an issue result"))
+            );
+        }
+    }
+
+    #[test]
+    fn failed_submessage() {
+        let reply = Reply {
+            id: 1,
+            result: SubMsgResult::Err("codespace: assetft, code: 6".to_string()),
+        };
+        assert_eq!(
+            parse_issue_result(&reply),
+            Err(IssueResultError::Invalid {
+                reason: "codespace: assetft, code: 6".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,20 @@
+#[cfg(feature = "protos")]
+pub mod any_msg;
+#[cfg(feature = "protos")]
+pub mod balances;
+#[cfg(feature = "protos")]
+pub mod contract_info;
+pub mod denom;
+pub mod guard;
+pub mod issue_result;
+#[cfg(feature = "protos")]
+pub mod protos {
+    include!(concat!(env!("OUT_DIR"), "/protos/mod.rs"));
+}
+pub mod raw_range;
+pub mod split;
+#[cfg(feature = "protos")]
+pub mod stargate;
+pub mod stargate_mock;
+pub mod test_exhaustive;
+pub mod typeurl;
@@ -0,0 +1,161 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, Order, StdResult, Storage};
+
+const MAX_RAW_RANGE_LIMIT: u32 = 30;
+
+#[cw_serde]
+pub struct RawEntry {
+    pub key: Binary,
+    pub value: Binary,
+}
+
+#[cw_serde]
+pub struct RawRangeResponse {
+    pub entries: Vec<RawEntry>,
+    // first key of the next page, pass it as start to continue
+    pub next_key: Option<Binary>,
+}
+
+/// Walks the contract storage in ascending key order, so state written through any Item/Map
+/// layout can be compared before and after a migration. The start is inclusive, the end exclusive
+/// and at most 30 entries are returned per page.
+pub fn raw_range(
+    storage: &dyn Storage,
+    start: Option<Binary>,
+    end: Option<Binary>,
+    limit: u32,
+) -> StdResult<RawRangeResponse> {
+    let limit = limit.min(MAX_RAW_RANGE_LIMIT) as usize;
+
+    let mut entries: Vec<RawEntry> = storage
+        .range(
+            start.as_ref().map(|k| k.as_slice()),
+            end.as_ref().map(|k| k.as_slice()),
+            Order::Ascending,
+        )
+        .take(limit + 1)
+        .map(|(key, value)| RawEntry {
+            key: key.into(),
+            value: value.into(),
+        })
+        .collect();
+
+    let next_key = if entries.len() > limit {
+        entries.pop().map(|entry| entry.key)
+    } else {
+        None
+    };
+
+    Ok(RawRangeResponse { entries, next_key })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+    use cw_storage_plus::{Item, Map};
+
+    use super::*;
+
+    const CONFIG: Item<String> = Item::new("config");
+    const BALANCES: Map<u32, u64> = Map::new("balances");
+    const OWNER: Item<String> = Item::new("owner");
+
+    // namespace prefix of the BALANCES entries, a length prefixed "balances"
+    const BALANCES_PREFIX: &[u8] = b"\x00\x08balances";
+    // first key after every BALANCES entry
+    const BALANCES_END: &[u8] = b"\x00\x08balancet";
+
+    fn storage() -> MockStorage {
+        let mut storage = MockStorage::new();
+        CONFIG.save(&mut storage, &"config".to_string()).unwrap();
+        OWNER.save(&mut storage, &"owner".to_string()).unwrap();
+        for i in 0..40 {
+            BALANCES.save(&mut storage, i, &u64::from(i)).unwrap();
+        }
+        storage
+    }
+
+    fn keys(res: &RawRangeResponse) -> Vec<Vec<u8>> {
+        res.entries.iter().map(|entry| entry.key.to_vec()).collect()
+    }
+
+    #[test]
+    fn pages_through_everything_in_order() {
+        let storage = storage();
+
+        let mut walked = Vec::new();
+        let mut start = None;
+        loop {
+            let res = raw_range(&storage, start, None, 30).unwrap();
+            assert!(res.entries.len() <= 30);
+            walked.extend(keys(&res));
+            match res.next_key {
+                Some(next_key) => start = Some(next_key),
+                None => break,
+            }
+        }
+
+        assert_eq!(walked.len(), 42);
+        assert!(walked.windows(2).all(|pair| pair[0] < pair[1]));
+        // map entries are namespaced with a length prefix, so they sort before the items
+        assert_eq!(walked[40], b"config");
+        assert_eq!(walked[41], b"owner");
+    }
+
+    #[test]
+    fn limit_is_capped() {
+        let storage = storage();
+
+        let res = raw_range(&storage, None, None, 100).unwrap();
+        assert_eq!(res.entries.len(), 30);
+        assert_eq!(res.next_key.unwrap().to_vec(), BALANCES.key(30).to_vec());
+
+        let res = raw_range(&storage, None, None, 0).unwrap();
+        assert!(res.entries.is_empty());
+        assert_eq!(res.next_key.unwrap().to_vec(), BALANCES.key(0).to_vec());
+    }
+
+    #[test]
+    fn stays_within_a_prefix() {
+        let storage = storage();
+        let start = Some(Binary::from(BALANCES_PREFIX));
+        let end = Some(Binary::from(BALANCES_END));
+
+        let first = raw_range(&storage, start, end.clone(), 30).unwrap();
+        let second = raw_range(&storage, first.next_key.clone(), end, 30).unwrap();
+        assert_eq!(first.entries.len(), 30);
+        assert_eq!(second.entries.len(), 10);
+        assert_eq!(second.next_key, None);
+        assert!(keys(&first)
+            .iter()
+            .chain(keys(&second).iter())
+            .all(|key| key.starts_with(BALANCES_PREFIX)));
+        assert_eq!(
+            second.entries[9].value.as_slice(),
+            b"39".as_slice(),
+            "values are the raw JSON"
+        );
+    }
+
+    #[test]
+    fn start_is_inclusive_and_end_exclusive() {
+        let storage = storage();
+        let res = raw_range(
+            &storage,
+            Some(BALANCES.key(5).to_vec().into()),
+            Some(BALANCES.key(8).to_vec().into()),
+            30,
+        )
+        .unwrap();
+
+        assert_eq!(
+            keys(&res),
+            vec![
+                BALANCES.key(5).to_vec(),
+                BALANCES.key(6).to_vec(),
+                BALANCES.key(7).to_vec(),
+            ]
+        );
+        assert_eq!(res.next_key, None);
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashSet;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Api, StdError, Uint128};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SplitError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("No recipients")]
+    EmptyRecipients {},
+
+    #[error("Recipient {address} has zero weight")]
+    ZeroWeight { address: String },
+
+    #[error("Recipient {address} is listed more than once")]
+    DuplicateRecipient { address: String },
+}
+
+#[cw_serde]
+pub struct SplitRecipient {
+    pub address: String,
+    pub weight: u32,
+}
+
+/// Divides the amount across the recipients proportionally to their weights. The rounding
+/// remainder goes to the first recipient and recipients whose share rounds down to zero are left
+/// out, since a bank send of zero coins is rejected.
+pub fn split_amount(
+    api: &dyn Api,
+    amount: Uint128,
+    recipients: &[SplitRecipient],
+) -> Result<Vec<(Addr, Uint128)>, SplitError> {
+    if recipients.is_empty() {
+        return Err(SplitError::EmptyRecipients {});
+    }
+
+    let mut seen = HashSet::with_capacity(recipients.len());
+    let mut total_weight = 0u64;
+    for recipient in recipients {
+        let address = api.addr_validate(&recipient.address)?;
+        if recipient.weight == 0 {
+            return Err(SplitError::ZeroWeight {
+                address: recipient.address.clone(),
+            });
+        }
+        if !seen.insert(address) {
+            return Err(SplitError::DuplicateRecipient {
+                address: recipient.address.clone(),
+            });
+        }
+        total_weight += u64::from(recipient.weight);
+    }
+
+    let mut shares: Vec<(Addr, Uint128)> = recipients
+        .iter()
+        .map(|recipient| {
+            (
+                Addr::unchecked(&recipient.address),
+                amount.multiply_ratio(recipient.weight, total_weight),
+            )
+        })
+        .collect();
+    let distributed: Uint128 = shares.iter().map(|(_, share)| share).sum();
+    shares[0].1 += amount - distributed;
+    shares.retain(|(_, share)| !share.is_zero());
+
+    Ok(shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockApi;
+
+    use super::*;
+
+    fn recipient(address: &str, weight: u32) -> SplitRecipient {
+        SplitRecipient {
+            address: address.to_string(),
+            weight,
+        }
+    }
+
+    fn split(amount: u128, recipients: &[SplitRecipient]) -> Result<Vec<u128>, SplitError> {
+        let shares = split_amount(&MockApi::default(), amount.into(), recipients)?;
+        Ok(shares.into_iter().map(|(_, share)| share.u128()).collect())
+    }
+
+    #[test]
+    fn remainder_goes_to_first_recipient() {
+        let recipients = [
+            recipient("alice", 1),
+            recipient("bob", 1),
+            recipient("carol", 1),
+        ];
+        assert_eq!(split(100, &recipients).unwrap(), vec![34, 33, 33]);
+    }
+
+    #[test]
+    fn single_recipient_gets_everything() {
+        assert_eq!(split(7, &[recipient("alice", 3)]).unwrap(), vec![7]);
+    }
+
+    #[test]
+    fn hundred_recipients() {
+        let recipients: Vec<_> = (0..100)
+            .map(|i| recipient(&format!("recipient{i}"), i + 1))
+            .collect();
+        let shares =
+            split_amount(&MockApi::default(), Uint128::new(1_000_003), &recipients).unwrap();
+
+        assert_eq!(shares.len(), 100);
+        let total: Uint128 = shares.iter().map(|(_, share)| share).sum();
+        assert_eq!(total, Uint128::new(1_000_003));
+        // the total weight is 5050, the last recipient holds 100 of it
+        assert_eq!(
+            shares[99],
+            (Addr::unchecked("recipient99"), Uint128::new(19_802))
+        );
+    }
+
+    #[test]
+    fn zero_shares_are_dropped() {
+        let recipients = [recipient("alice", 1000), recipient("bob", 1)];
+        assert_eq!(split(10, &recipients).unwrap(), vec![10]);
+    }
+
+    #[test]
+    fn duplicate_addresses_are_rejected() {
+        let recipients = [
+            recipient("alice", 1),
+            recipient("bob", 1),
+            recipient("alice", 2),
+        ];
+        assert_eq!(
+            split(100, &recipients).unwrap_err(),
+            SplitError::DuplicateRecipient {
+                address: "alice".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_recipients_are_rejected() {
+        assert_eq!(split(100, &[]).unwrap_err(), SplitError::EmptyRecipients {});
+        assert_eq!(
+            split(100, &[recipient("alice", 1), recipient("bob", 0)]).unwrap_err(),
+            SplitError::ZeroWeight {
+                address: "bob".to_string()
+            }
+        );
+        assert!(matches!(
+            split(100, &[recipient("Alice", 1)]).unwrap_err(),
+            SplitError::Std(_)
+        ));
+    }
+}
@@ -0,0 +1,46 @@
+use cosmwasm_std::{
+    to_json_vec, Binary, ContractResult, CustomQuery, QuerierWrapper, QueryRequest, StdError,
+    StdResult, SystemResult,
+};
+use protobuf::Message;
+
+/// Runs a protobuf encoded query through the chain's stargate querier, the path has to be on its
+/// accept list. An error of the queried module is returned as ContractResult::Err so callers can
+/// tell a typed failure, like an unknown address, from a broken query.
+pub fn query_stargate_raw<C: CustomQuery>(
+    querier: &QuerierWrapper<C>,
+    path: &str,
+    request: &impl Message,
+) -> StdResult<ContractResult<Binary>> {
+    let data = request
+        .write_to_bytes()
+        .map_err(|err| StdError::serialize_err(path, err.to_string()))?;
+    let request: QueryRequest<C> = QueryRequest::Stargate {
+        path: path.to_string(),
+        data: data.into(),
+    };
+    match querier.raw_query(&to_json_vec(&request)?) {
+        SystemResult::Ok(result) => Ok(result),
+        SystemResult::Err(err) => Err(StdError::generic_err(format!(
+            "Querier system error: {err}"
+        ))),
+    }
+}
+
+/// Runs a protobuf encoded query through the chain's stargate querier and decodes the response.
+pub fn query_stargate<C: CustomQuery, R: Message>(
+    querier: &QuerierWrapper<C>,
+    path: &str,
+    request: &impl Message,
+) -> StdResult<R> {
+    match query_stargate_raw(querier, path, request)? {
+        ContractResult::Ok(data) => decode(path, &data),
+        ContractResult::Err(err) => Err(StdError::generic_err(format!(
+            "Querier contract error: {err}"
+        ))),
+    }
+}
+
+pub(crate) fn decode<R: Message>(path: &str, data: &[u8]) -> StdResult<R> {
+    R::parse_from_bytes(data).map_err(|err| StdError::parse_err(path, err.to_string()))
+}
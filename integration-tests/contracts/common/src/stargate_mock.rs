@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_std::testing::MockQuerier;
+use cosmwasm_std::{
+    from_json, Binary, ContractResult, CustomQuery, Empty, Querier, QuerierResult, QueryRequest,
+    SystemError, SystemResult,
+};
+
+type Handler = Box<dyn Fn(&[u8]) -> ContractResult<Binary>>;
+
+/// Querier answering stargate queries from a handler registered for their path, like the chain
+/// does for the paths on its accept list. Every other request goes to the MockQuerier, so unit
+/// tests of contracts running stargate queries don't need a chain.
+pub struct StargateMockQuerier<C: CustomQuery + DeserializeOwned = Empty> {
+    pub base: MockQuerier<C>,
+    handlers: BTreeMap<String, Handler>,
+}
+
+impl<C: CustomQuery + DeserializeOwned> StargateMockQuerier<C> {
+    pub fn new(base: MockQuerier<C>) -> Self {
+        Self {
+            base,
+            handlers: BTreeMap::new(),
+        }
+    }
+
+    /// Answers the queries of the path with the handler, which gets the protobuf encoded request.
+    pub fn with_handler(
+        mut self,
+        path: &str,
+        handler: impl Fn(&[u8]) -> ContractResult<Binary> + 'static,
+    ) -> Self {
+        self.handlers.insert(path.to_string(), Box::new(handler));
+        self
+    }
+}
+
+impl<C: CustomQuery + DeserializeOwned> Querier for StargateMockQuerier<C> {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        match from_json::<QueryRequest<C>>(bin_request) {
+            Ok(QueryRequest::Stargate { path, data }) => match self.handlers.get(&path) {
+                Some(handler) => SystemResult::Ok(handler(data.as_slice())),
+                None => SystemResult::Err(SystemError::UnsupportedRequest { kind: path }),
+            },
+            _ => self.base.raw_query(bin_request),
+        }
+    }
+}
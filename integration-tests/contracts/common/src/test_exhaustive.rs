@@ -0,0 +1,125 @@
+use std::fmt::Debug;
+
+/// Expected result of dispatching the message of a case.
+pub enum Outcome<E> {
+    Ok,
+    /// Fails with an error the predicate accepts.
+    Err(fn(&E) -> bool),
+}
+
+/// Representative instance of one message variant and what dispatching it must result in.
+pub struct Case<M, E> {
+    pub variant: &'static str,
+    pub msg: M,
+    pub outcome: Outcome<E>,
+}
+
+/// Dispatches the message of every case with run, which is expected to build fresh state for each
+/// one, and panics naming the first variant whose result differs from its expected outcome.
+pub fn run_cases<M, R, E: Debug>(cases: Vec<Case<M, E>>, mut run: impl FnMut(M) -> Result<R, E>) {
+    for case in cases {
+        match (run(case.msg), case.outcome) {
+            (Ok(_), Outcome::Ok) => {}
+            (Err(err), Outcome::Err(expected)) if expected(&err) => {}
+            (Ok(_), Outcome::Err(_)) => panic!("{}: expected an error, got Ok", case.variant),
+            (Err(err), _) => panic!("{}: unexpected error {err:?}", case.variant),
+        }
+    }
+}
+
+/// Lists a Case for every variant of a message enum. The variants are also matched exhaustively,
+/// so adding a variant to the enum without listing it here fails to compile.
+///
+/// ```ignore
+/// let cases = exhaustive_cases!(ExecuteMsg {
+///     Mint { amount: 100 } => Outcome::Ok,
+///     Burn { amount: 0 } => Outcome::Err(|err| matches!(err, ContractError::ZeroAmount {})),
+/// });
+/// ```
+#[macro_export]
+macro_rules! exhaustive_cases {
+    ($msg:ident { $( $(#[$meta:meta])* $variant:ident $fields:tt => $outcome:expr ),+ $(,)? }) => {{
+        #[allow(dead_code)]
+        fn covered(msg: &$msg) {
+            match msg {
+                $( $(#[$meta])* $msg::$variant { .. } => {} )+
+            }
+        }
+
+        vec![$(
+            $(#[$meta])*
+            $crate::test_exhaustive::Case {
+                variant: stringify!($variant),
+                msg: $msg::$variant $fields,
+                outcome: $outcome,
+            }
+        ),+]
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    enum Msg {
+        Add { amount: u32 },
+        Clear {},
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Zero,
+    }
+
+    fn dispatch(msg: Msg) -> Result<u32, Error> {
+        match msg {
+            Msg::Add { amount: 0 } => Err(Error::Zero),
+            Msg::Add { amount } => Ok(amount),
+            Msg::Clear {} => Ok(0),
+        }
+    }
+
+    #[test]
+    fn every_case_is_dispatched() {
+        let cases = exhaustive_cases!(Msg {
+            Add { amount: 0 } => Outcome::Err(|err| *err == Error::Zero),
+            Clear {} => Outcome::Ok,
+        });
+        assert_eq!(
+            cases.iter().map(|case| case.variant).collect::<Vec<_>>(),
+            vec!["Add", "Clear"]
+        );
+
+        let mut dispatched = 0;
+        run_cases(cases, |msg| {
+            dispatched += 1;
+            dispatch(msg)
+        });
+        assert_eq!(dispatched, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Add: expected an error, got Ok")]
+    fn unexpected_success_names_the_variant() {
+        run_cases(
+            exhaustive_cases!(Msg {
+                Add { amount: 1 } => Outcome::Err(|_| true),
+                Clear {} => Outcome::Ok,
+            }),
+            dispatch,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Add: unexpected error Zero")]
+    fn unexpected_error_names_the_variant() {
+        run_cases(
+            exhaustive_cases!(Msg {
+                Add { amount: 0 } => Outcome::Ok,
+                Clear {} => Outcome::Ok,
+            }),
+            dispatch,
+        );
+    }
+}
@@ -0,0 +1,66 @@
+// Type URLs of the proto messages the contracts pack into an Any or send as stargate messages,
+// bound to their messages in any_msg.
+
+// coreum.asset.ft.v1
+pub const MSG_ISSUE: &str = "/coreum.asset.ft.v1.MsgIssue";
+pub const MSG_MINT: &str = "/coreum.asset.ft.v1.MsgMint";
+pub const MSG_BURN: &str = "/coreum.asset.ft.v1.MsgBurn";
+pub const MSG_FREEZE: &str = "/coreum.asset.ft.v1.MsgFreeze";
+pub const MSG_UNFREEZE: &str = "/coreum.asset.ft.v1.MsgUnfreeze";
+pub const MSG_SET_FROZEN: &str = "/coreum.asset.ft.v1.MsgSetFrozen";
+pub const MSG_GLOBALLY_FREEZE: &str = "/coreum.asset.ft.v1.MsgGloballyFreeze";
+pub const MSG_GLOBALLY_UNFREEZE: &str = "/coreum.asset.ft.v1.MsgGloballyUnfreeze";
+pub const MSG_SET_WHITELISTED_LIMIT: &str = "/coreum.asset.ft.v1.MsgSetWhitelistedLimit";
+pub const MSG_UPGRADE_TOKEN_V1: &str = "/coreum.asset.ft.v1.MsgUpgradeTokenV1";
+
+// coreum.asset.nft.v1
+pub const MSG_ADD_TO_CLASS_WHITELIST: &str = "/coreum.asset.nft.v1.MsgAddToClassWhitelist";
+pub const MSG_REMOVE_FROM_CLASS_WHITELIST: &str =
+    "/coreum.asset.nft.v1.MsgRemoveFromClassWhitelist";
+
+// cosmos.authz.v1beta1
+pub const MSG_EXEC: &str = "/cosmos.authz.v1beta1.MsgExec";
+pub const MSG_GRANT: &str = "/cosmos.authz.v1beta1.MsgGrant";
+pub const MSG_REVOKE: &str = "/cosmos.authz.v1beta1.MsgRevoke";
+pub const GENERIC_AUTHORIZATION: &str = "/cosmos.authz.v1beta1.GenericAuthorization";
+
+// cosmos.bank.v1beta1
+pub const MSG_SEND: &str = "/cosmos.bank.v1beta1.MsgSend";
+pub const MSG_MULTI_SEND: &str = "/cosmos.bank.v1beta1.MsgMultiSend";
+pub const MSG_SET_SEND_ENABLED: &str = "/cosmos.bank.v1beta1.MsgSetSendEnabled";
+pub const MSG_UPDATE_PARAMS: &str = "/cosmos.bank.v1beta1.MsgUpdateParams";
+pub const SEND_AUTHORIZATION: &str = "/cosmos.bank.v1beta1.SendAuthorization";
+
+// cosmos.distribution.v1beta1
+pub const MSG_SET_WITHDRAW_ADDRESS: &str = "/cosmos.distribution.v1beta1.MsgSetWithdrawAddress";
+pub const MSG_WITHDRAW_DELEGATOR_REWARD: &str =
+    "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward";
+
+// cosmos.feegrant.v1beta1
+pub const BASIC_ALLOWANCE: &str = "/cosmos.feegrant.v1beta1.BasicAllowance";
+pub const PERIODIC_ALLOWANCE: &str = "/cosmos.feegrant.v1beta1.PeriodicAllowance";
+pub const ALLOWED_MSG_ALLOWANCE: &str = "/cosmos.feegrant.v1beta1.AllowedMsgAllowance";
+pub const MSG_GRANT_ALLOWANCE: &str = "/cosmos.feegrant.v1beta1.MsgGrantAllowance";
+pub const MSG_REVOKE_ALLOWANCE: &str = "/cosmos.feegrant.v1beta1.MsgRevokeAllowance";
+
+// cosmos.gov.v1
+pub const MSG_SUBMIT_PROPOSAL: &str = "/cosmos.gov.v1.MsgSubmitProposal";
+pub const MSG_DEPOSIT: &str = "/cosmos.gov.v1.MsgDeposit";
+pub const MSG_VOTE: &str = "/cosmos.gov.v1.MsgVote";
+pub const MSG_VOTE_WEIGHTED: &str = "/cosmos.gov.v1.MsgVoteWeighted";
+
+// cosmos.staking.v1beta1
+pub const MSG_DELEGATE: &str = "/cosmos.staking.v1beta1.MsgDelegate";
+pub const MSG_UNDELEGATE: &str = "/cosmos.staking.v1beta1.MsgUndelegate";
+pub const MSG_BEGIN_REDELEGATE: &str = "/cosmos.staking.v1beta1.MsgBeginRedelegate";
+pub const STAKE_AUTHORIZATION: &str = "/cosmos.staking.v1beta1.StakeAuthorization";
+
+// cosmos.vesting.v1beta1
+pub const MSG_CREATE_VESTING_ACCOUNT: &str = "/cosmos.vesting.v1beta1.MsgCreateVestingAccount";
+
+// cosmwasm.wasm.v1
+pub const MSG_CLEAR_ADMIN: &str = "/cosmwasm.wasm.v1.MsgClearAdmin";
+pub const MSG_UPDATE_ADMIN: &str = "/cosmwasm.wasm.v1.MsgUpdateAdmin";
+
+// ibc.applications.transfer.v1
+pub const MSG_TRANSFER: &str = "/ibc.applications.transfer.v1.MsgTransfer";
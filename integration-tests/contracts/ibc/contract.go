@@ -12,4 +12,6 @@ var (
 	IBCTransferWASM []byte
 	//go:embed ibc-call/artifacts/ibc_call.wasm
 	IBCCallWASM []byte
+	//go:embed ibc-asset-channel/artifacts/ibc_asset_channel.wasm
+	IBCAssetChannelWASM []byte
 )
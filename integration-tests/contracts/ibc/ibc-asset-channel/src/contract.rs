@@ -0,0 +1,193 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, IbcMsg, IbcTimeout, MessageInfo, Reply, Response,
+    StdResult, SubMsgResult, Uint128,
+};
+use cw2::set_contract_version;
+
+use crate::ack::make_ack_fail;
+use crate::error::ContractError;
+use crate::ibc::UNESCROW_REPLY_ID;
+use crate::msg::{AmountResponse, AssetPacket, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{CHANNELS, ESCROWS, PENDING_UNESCROW, VOUCHERS};
+
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::new().add_attribute("method", "instantiate"))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Transfer { channel, receiver } => transfer(deps, env, info, channel, receiver),
+        ExecuteMsg::TransferVoucher {
+            channel,
+            denom,
+            amount,
+            receiver,
+        } => transfer_voucher(deps, env, info, channel, denom, amount, receiver),
+    }
+}
+
+fn transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel: String,
+    receiver: String,
+) -> Result<Response, ContractError> {
+    ensure_channel(deps.as_ref(), &channel)?;
+
+    if info.funds.len() != 1 {
+        return Err(ContractError::InvalidFunds {});
+    }
+    let coin = info.funds[0].clone();
+    if coin.amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    // the tokens stay on the contract balance until they are sent back over the same channel
+    ESCROWS.update(
+        deps.storage,
+        (channel.clone(), coin.denom.clone()),
+        |escrow| -> StdResult<_> { Ok(escrow.unwrap_or_default() + coin.amount) },
+    )?;
+
+    let packet = AssetPacket {
+        denom: coin.denom,
+        amount: coin.amount,
+        sender: info.sender.to_string(),
+        receiver,
+    };
+
+    Ok(Response::new()
+        .add_attribute("method", "transfer")
+        .add_attribute("channel", channel.clone())
+        .add_attribute("denom", packet.denom.clone())
+        .add_attribute("amount", packet.amount)
+        .add_message(send_packet(&env, channel, &packet)?))
+}
+
+fn transfer_voucher(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    channel: String,
+    denom: String,
+    amount: Uint128,
+    receiver: String,
+) -> Result<Response, ContractError> {
+    ensure_channel(deps.as_ref(), &channel)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    // the vouchers are burnt here and re-credited if the packet fails
+    let balance = VOUCHERS
+        .may_load(deps.storage, (denom.clone(), info.sender.clone()))?
+        .unwrap_or_default();
+    let balance = balance
+        .checked_sub(amount)
+        .map_err(|_| ContractError::InsufficientVoucher {
+            denom: denom.clone(),
+        })?;
+    VOUCHERS.save(deps.storage, (denom.clone(), info.sender.clone()), &balance)?;
+
+    let packet = AssetPacket {
+        denom,
+        amount,
+        sender: info.sender.to_string(),
+        receiver,
+    };
+
+    Ok(Response::new()
+        .add_attribute("method", "transfer_voucher")
+        .add_attribute("channel", channel.clone())
+        .add_attribute("denom", packet.denom.clone())
+        .add_attribute("amount", packet.amount)
+        .add_message(send_packet(&env, channel, &packet)?))
+}
+
+fn ensure_channel(deps: Deps, channel: &str) -> Result<(), ContractError> {
+    if !CHANNELS.has(deps.storage, channel.to_string()) {
+        return Err(ContractError::UnknownChannel {
+            channel: channel.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn send_packet(env: &Env, channel: String, packet: &AssetPacket) -> StdResult<IbcMsg> {
+    Ok(IbcMsg::SendPacket {
+        channel_id: channel,
+        data: to_binary(packet)?,
+        // default timeout of two minutes.
+        timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(120)),
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    if msg.id != UNESCROW_REPLY_ID {
+        return Err(ContractError::UnknownReplyId { id: msg.id });
+    }
+
+    match msg.result {
+        SubMsgResult::Ok(_) => Ok(Response::new()),
+        SubMsgResult::Err(err) => {
+            // the bank send was rejected (e.g. by the assetft whitelisting or freezing), so the
+            // escrow is restored and the error ack makes the counterparty refund the sender
+            let pending = PENDING_UNESCROW.load(deps.storage)?;
+            ESCROWS.update(
+                deps.storage,
+                (pending.channel, pending.denom),
+                |escrow| -> StdResult<_> { Ok(escrow.unwrap_or_default() + pending.amount) },
+            )?;
+            PENDING_UNESCROW.remove(deps.storage);
+
+            Ok(Response::new()
+                .add_attribute("method", "reply_unescrow")
+                .add_attribute("error", err.clone())
+                .set_data(make_ack_fail(err)))
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Escrow { channel, denom } => to_binary(&query_escrow(deps, channel, denom)?),
+        QueryMsg::Voucher { denom, address } => to_binary(&query_voucher(deps, denom, address)?),
+    }
+}
+
+fn query_escrow(deps: Deps, channel: String, denom: String) -> StdResult<AmountResponse> {
+    let amount = ESCROWS
+        .may_load(deps.storage, (channel, denom))?
+        .unwrap_or_default();
+    Ok(AmountResponse { amount })
+}
+
+fn query_voucher(deps: Deps, denom: String, address: String) -> StdResult<AmountResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let amount = VOUCHERS
+        .may_load(deps.storage, (denom, address))?
+        .unwrap_or_default();
+    Ok(AmountResponse { amount })
+}
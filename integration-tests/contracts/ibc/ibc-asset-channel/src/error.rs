@@ -0,0 +1,38 @@
+use cosmwasm_std::{OverflowError, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Never {}
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("only unordered channels are supported")]
+    OrderedChannel {},
+
+    #[error("invalid IBC channel version. Got ({actual}), expected ({expected})")]
+    InvalidVersion { actual: String, expected: String },
+
+    #[error("channel {channel} is not connected")]
+    UnknownChannel { channel: String },
+
+    #[error("exactly one coin must be sent")]
+    InvalidFunds {},
+
+    #[error("amount must be greater than zero")]
+    InvalidZeroAmount {},
+
+    #[error("insufficient escrow of {denom} on channel {channel}")]
+    InsufficientEscrow { channel: String, denom: String },
+
+    #[error("insufficient voucher balance of {denom}")]
+    InsufficientVoucher { denom: String },
+
+    #[error("unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+}
@@ -0,0 +1,435 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    coin, from_binary, BankMsg, DepsMut, Env, IbcBasicResponse, IbcChannel, IbcChannelCloseMsg,
+    IbcChannelConnectMsg, IbcChannelOpenMsg, IbcChannelOpenResponse, IbcOrder, IbcPacket,
+    IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg, IbcReceiveResponse, StdResult,
+    SubMsg,
+};
+
+use crate::{
+    ack::{make_ack_fail, make_ack_success, Ack},
+    error::Never,
+    msg::AssetPacket,
+    state::{PendingUnescrow, CHANNELS, ESCROWS, PENDING_UNESCROW, VOUCHERS},
+    ContractError,
+};
+
+pub const IBC_VERSION: &str = "asset-channel-1";
+
+pub const UNESCROW_REPLY_ID: u64 = 1;
+
+/// Returns the prefix of the vouchers minted for the tokens received over the channel, the same
+/// way ICS-20 prefixes the denom traces.
+pub fn voucher_prefix(port_id: &str, channel_id: &str) -> String {
+    format!("{port_id}/{channel_id}/")
+}
+
+/// Handles the `OpenInit` and `OpenTry` parts of the IBC handshake.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    _deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    validate_order_and_version(msg.channel(), msg.counterparty_version())?;
+    Ok(None)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    validate_order_and_version(msg.channel(), msg.counterparty_version())?;
+
+    let channel = msg.channel().endpoint.channel_id.clone();
+    let counterparty = &msg.channel().counterparty_endpoint;
+    CHANNELS.save(
+        deps.storage,
+        channel.clone(),
+        &(
+            counterparty.port_id.clone(),
+            counterparty.channel_id.clone(),
+        ),
+    )?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_channel_connect")
+        .add_attribute("channel_id", channel))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel().endpoint.channel_id.clone();
+    // The escrow is kept, so the tokens locked for the channel stay accounted for, but no new
+    // transfers are accepted.
+    CHANNELS.remove(deps.storage, channel.clone());
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_channel_close")
+        .add_attribute("channel", channel))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, Never> {
+    // Regardless of if our processing of this packet works we need to
+    // commit an ACK to the chain. As such, we wrap all handling logic
+    // in a seprate function and on error write out an error ack.
+    match do_ibc_packet_receive(deps, env, msg) {
+        Ok(response) => Ok(response),
+        Err(error) => Ok(IbcReceiveResponse::new()
+            .add_attribute("method", "ibc_packet_receive")
+            .add_attribute("error", error.to_string())
+            .set_ack(make_ack_fail(error.to_string()))),
+    }
+}
+
+pub fn do_ibc_packet_receive(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let packet = msg.packet;
+    let data: AssetPacket = from_binary(&packet.data)?;
+    let receiver = deps.api.addr_validate(&data.receiver)?;
+
+    // The tokens coming back carry the prefix the counterparty has added, so they are released
+    // from the escrow, otherwise the vouchers are minted.
+    let source_prefix = voucher_prefix(&packet.src.port_id, &packet.src.channel_id);
+    if let Some(denom) = data.denom.strip_prefix(&source_prefix) {
+        let channel = packet.dest.channel_id;
+        let escrow = ESCROWS
+            .may_load(deps.storage, (channel.clone(), denom.to_string()))?
+            .unwrap_or_default();
+        let escrow =
+            escrow
+                .checked_sub(data.amount)
+                .map_err(|_| ContractError::InsufficientEscrow {
+                    channel: channel.clone(),
+                    denom: denom.to_string(),
+                })?;
+        ESCROWS.save(deps.storage, (channel.clone(), denom.to_string()), &escrow)?;
+        PENDING_UNESCROW.save(
+            deps.storage,
+            &PendingUnescrow {
+                channel,
+                denom: denom.to_string(),
+                amount: data.amount,
+            },
+        )?;
+
+        // The send is dispatched as a submessage, so the rejection of it by the asset
+        // module turns into an error ack instead of failing the relayer transaction.
+        return Ok(IbcReceiveResponse::new()
+            .add_attribute("method", "ibc_packet_receive")
+            .add_attribute("unescrow", data.amount.to_string() + denom)
+            .add_submessage(SubMsg::reply_on_error(
+                BankMsg::Send {
+                    to_address: receiver.to_string(),
+                    amount: vec![coin(data.amount.u128(), denom)],
+                },
+                UNESCROW_REPLY_ID,
+            ))
+            .set_ack(make_ack_success()));
+    }
+
+    let voucher = voucher_prefix(&packet.dest.port_id, &packet.dest.channel_id) + &data.denom;
+    VOUCHERS.update(
+        deps.storage,
+        (voucher.clone(), receiver),
+        |balance| -> StdResult<_> { Ok(balance.unwrap_or_default() + data.amount) },
+    )?;
+
+    Ok(IbcReceiveResponse::new()
+        .add_attribute("method", "ibc_packet_receive")
+        .add_attribute("voucher", data.amount.to_string() + &voucher)
+        .set_ack(make_ack_success()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let ack: Ack = from_binary(&msg.acknowledgement.data)?;
+    match ack {
+        Ack::Result(_) => Ok(IbcBasicResponse::new().add_attribute("method", "ibc_packet_ack")),
+        Ack::Error(error) => Ok(refund(deps, msg.original_packet)?
+            .add_attribute("method", "ibc_packet_ack")
+            .add_attribute("error", error)),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    Ok(refund(deps, msg.packet)?.add_attribute("method", "ibc_packet_timeout"))
+}
+
+/// Returns the tokens of the failed packet to the sender.
+fn refund(deps: DepsMut, packet: IbcPacket) -> Result<IbcBasicResponse, ContractError> {
+    let data: AssetPacket = from_binary(&packet.data)?;
+    let sender = deps.api.addr_validate(&data.sender)?;
+
+    // Timed out packets are sent by us, so the vouchers carry the source prefix.
+    let prefix = voucher_prefix(&packet.src.port_id, &packet.src.channel_id);
+    if data.denom.starts_with(&prefix) {
+        VOUCHERS.update(
+            deps.storage,
+            (data.denom.clone(), sender),
+            |balance| -> StdResult<_> { Ok(balance.unwrap_or_default() + data.amount) },
+        )?;
+        return Ok(IbcBasicResponse::new().add_attribute("refund", data.denom));
+    }
+
+    let channel = packet.src.channel_id;
+    let escrow = ESCROWS
+        .may_load(deps.storage, (channel.clone(), data.denom.clone()))?
+        .unwrap_or_default();
+    let escrow = escrow.checked_sub(data.amount)?;
+    ESCROWS.save(deps.storage, (channel, data.denom.clone()), &escrow)?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("refund", data.denom.clone())
+        .add_message(BankMsg::Send {
+            to_address: sender.to_string(),
+            amount: vec![coin(data.amount.u128(), data.denom)],
+        }))
+}
+
+pub fn validate_order_and_version(
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    // We expect an unordered channel here. Ordered channels have the
+    // property that if a message is lost the entire channel will stop
+    // working until you start it again.
+    if channel.order != IbcOrder::Unordered {
+        return Err(ContractError::OrderedChannel {});
+    }
+
+    if channel.version != IBC_VERSION {
+        return Err(ContractError::InvalidVersion {
+            actual: channel.version.to_string(),
+            expected: IBC_VERSION.to_string(),
+        });
+    }
+
+    // Make sure that we're talking with a counterparty who speaks the
+    // same "protocol" as us.
+    if let Some(counterparty_version) = counterparty_version {
+        if counterparty_version != IBC_VERSION {
+            return Err(ContractError::InvalidVersion {
+                actual: counterparty_version.to_string(),
+                expected: IBC_VERSION.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_ibc_channel_close_init, mock_ibc_channel_connect_ack,
+        mock_ibc_channel_open_init, mock_ibc_packet_ack, mock_ibc_packet_recv,
+        mock_ibc_packet_timeout, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{Addr, CosmosMsg, IbcAcknowledgement, OwnedDeps, ReplyOn, Uint128};
+
+    use super::*;
+
+    const CHANNEL: &str = "channel-0";
+    const SENDER: &str = "sender";
+    const RECEIVER: &str = "receiver";
+
+    type TestDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn connected() -> TestDeps {
+        let mut deps = mock_dependencies();
+        let msg = mock_ibc_channel_connect_ack(CHANNEL, IbcOrder::Unordered, IBC_VERSION);
+        ibc_channel_connect(deps.as_mut(), mock_env(), msg).unwrap();
+        deps
+    }
+
+    fn packet(denom: &str, amount: u128) -> AssetPacket {
+        AssetPacket {
+            denom: denom.to_string(),
+            amount: Uint128::new(amount),
+            sender: SENDER.to_string(),
+            receiver: RECEIVER.to_string(),
+        }
+    }
+
+    fn receive(deps: &mut TestDeps, packet: &AssetPacket) -> IbcReceiveResponse {
+        let msg = mock_ibc_packet_recv(CHANNEL, packet).unwrap();
+        ibc_packet_receive(deps.as_mut(), mock_env(), msg).unwrap()
+    }
+
+    fn ack_of(res: &IbcReceiveResponse) -> Ack {
+        from_binary(&res.acknowledgement).unwrap()
+    }
+
+    fn escrow(deps: &TestDeps, denom: &str) -> u128 {
+        ESCROWS
+            .may_load(&deps.storage, (CHANNEL.to_string(), denom.to_string()))
+            .unwrap()
+            .unwrap_or_default()
+            .u128()
+    }
+
+    fn set_escrow(deps: &mut TestDeps, denom: &str, amount: u128) {
+        ESCROWS
+            .save(
+                &mut deps.storage,
+                (CHANNEL.to_string(), denom.to_string()),
+                &Uint128::new(amount),
+            )
+            .unwrap();
+    }
+
+    fn voucher(deps: &TestDeps, denom: &str, holder: &str) -> u128 {
+        VOUCHERS
+            .may_load(&deps.storage, (denom.to_string(), Addr::unchecked(holder)))
+            .unwrap()
+            .unwrap_or_default()
+            .u128()
+    }
+
+    #[test]
+    fn channel_lifecycle() {
+        let mut deps = mock_dependencies();
+        let msg = mock_ibc_channel_open_init(CHANNEL, IbcOrder::Ordered, IBC_VERSION);
+        assert!(matches!(
+            ibc_channel_open(deps.as_mut(), mock_env(), msg),
+            Err(ContractError::OrderedChannel {})
+        ));
+        let msg = mock_ibc_channel_open_init(CHANNEL, IbcOrder::Unordered, "ics20-1");
+        assert!(matches!(
+            ibc_channel_open(deps.as_mut(), mock_env(), msg),
+            Err(ContractError::InvalidVersion { .. })
+        ));
+        let msg = mock_ibc_channel_open_init(CHANNEL, IbcOrder::Unordered, IBC_VERSION);
+        ibc_channel_open(deps.as_mut(), mock_env(), msg).unwrap();
+
+        let mut deps = connected();
+        assert_eq!(
+            CHANNELS.load(&deps.storage, CHANNEL.to_string()).unwrap(),
+            ("their_port".to_string(), "channel-7".to_string())
+        );
+        let msg = mock_ibc_channel_close_init(CHANNEL, IbcOrder::Unordered, IBC_VERSION);
+        ibc_channel_close(deps.as_mut(), mock_env(), msg).unwrap();
+        assert!(!CHANNELS.has(&deps.storage, CHANNEL.to_string()));
+    }
+
+    #[test]
+    fn foreign_tokens_are_received_as_vouchers() {
+        let mut deps = connected();
+        let res = receive(&mut deps, &packet("uatom", 30));
+        assert_eq!(ack_of(&res), Ack::Result(b"1".into()));
+        assert!(res.messages.is_empty());
+        receive(&mut deps, &packet("uatom", 12));
+
+        let denom = voucher_prefix("our-port", CHANNEL) + "uatom";
+        assert_eq!(voucher(&deps, &denom, RECEIVER), 42);
+    }
+
+    #[test]
+    fn returning_tokens_are_released_from_the_escrow() {
+        let mut deps = connected();
+        set_escrow(&mut deps, "ucore", 100);
+
+        // the counterparty sends back the tokens under its voucher prefix
+        let denom = voucher_prefix("their-port", "channel-1234") + "ucore";
+        let res = receive(&mut deps, &packet(&denom, 40));
+        assert_eq!(ack_of(&res), Ack::Result(b"1".into()));
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].id, UNESCROW_REPLY_ID);
+        assert_eq!(res.messages[0].reply_on, ReplyOn::Error);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: RECEIVER.to_string(),
+                amount: vec![coin(40, "ucore")],
+            })
+        );
+        assert_eq!(escrow(&deps, "ucore"), 60);
+        assert_eq!(
+            PENDING_UNESCROW.load(&deps.storage).unwrap(),
+            PendingUnescrow {
+                channel: CHANNEL.to_string(),
+                denom: "ucore".to_string(),
+                amount: Uint128::new(40),
+            }
+        );
+    }
+
+    #[test]
+    fn failed_packets_are_acked_with_an_error() {
+        let mut deps = connected();
+        set_escrow(&mut deps, "ucore", 10);
+
+        // more than the escrow holds
+        let denom = voucher_prefix("their-port", "channel-1234") + "ucore";
+        let res = receive(&mut deps, &packet(&denom, 11));
+        assert!(matches!(ack_of(&res), Ack::Error(err) if err.contains("insufficient escrow")));
+        assert!(res.messages.is_empty());
+        assert_eq!(escrow(&deps, "ucore"), 10);
+
+        // data which isn't an asset packet
+        let msg = mock_ibc_packet_recv(CHANNEL, &"not a packet").unwrap();
+        let res = ibc_packet_receive(deps.as_mut(), mock_env(), msg).unwrap();
+        assert!(matches!(ack_of(&res), Ack::Error(_)));
+    }
+
+    #[test]
+    fn error_acks_refund_the_escrow() {
+        let mut deps = connected();
+        set_escrow(&mut deps, "ucore", 50);
+
+        let ack = IbcAcknowledgement::new(make_ack_fail("rejected".to_string()));
+        let msg = mock_ibc_packet_ack(CHANNEL, &packet("ucore", 50), ack).unwrap();
+        let res = ibc_packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(BankMsg::Send {
+                to_address: SENDER.to_string(),
+                amount: vec![coin(50, "ucore")],
+            })
+        );
+        assert_eq!(escrow(&deps, "ucore"), 0);
+
+        // a successful ack leaves the escrow in place
+        set_escrow(&mut deps, "ucore", 50);
+        let ack = IbcAcknowledgement::new(make_ack_success());
+        let msg = mock_ibc_packet_ack(CHANNEL, &packet("ucore", 50), ack).unwrap();
+        let res = ibc_packet_ack(deps.as_mut(), mock_env(), msg).unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(escrow(&deps, "ucore"), 50);
+    }
+
+    #[test]
+    fn timeouts_give_the_vouchers_back() {
+        let mut deps = connected();
+        // vouchers sent out carry the prefix of the sending end
+        let denom = voucher_prefix("their-port", CHANNEL) + "uatom";
+        let msg = mock_ibc_packet_timeout(CHANNEL, &packet(&denom, 25)).unwrap();
+        let res = ibc_packet_timeout(deps.as_mut(), mock_env(), msg).unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(voucher(&deps, &denom, SENDER), 25);
+    }
+}
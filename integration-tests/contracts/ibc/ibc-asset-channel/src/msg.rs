@@ -0,0 +1,48 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // Transfer escrows the attached local tokens and sends them to the receiver on the other side
+    // of the channel.
+    Transfer {
+        channel: String,
+        receiver: String,
+    },
+    // TransferVoucher burns the sender's vouchers and sends them back to the receiver on the other
+    // side of the channel.
+    TransferVoucher {
+        channel: String,
+        denom: String,
+        amount: Uint128,
+        receiver: String,
+    },
+}
+
+/// The packet sent over the channel, modeled after the ICS-20 FungibleTokenPacketData.
+#[cw_serde]
+pub struct AssetPacket {
+    pub denom: String,
+    pub amount: Uint128,
+    pub sender: String,
+    pub receiver: String,
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    // Escrow returns the amount of local tokens locked for the LOCAL channel `channel`.
+    #[returns(crate::msg::AmountResponse)]
+    Escrow { channel: String, denom: String },
+    // Voucher returns the voucher balance of the `address`.
+    #[returns(crate::msg::AmountResponse)]
+    Voucher { denom: String, address: String },
+}
+
+#[cw_serde]
+pub struct AmountResponse {
+    pub amount: Uint128,
+}
@@ -0,0 +1,19 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+
+/// (channel_id) -> counterparty (port_id, channel_id). Removed on channel closure.
+pub const CHANNELS: Map<String, (String, String)> = Map::new("channels");
+/// (channel_id, denom) -> amount of local tokens locked in the contract for the channel.
+pub const ESCROWS: Map<(String, String), Uint128> = Map::new("escrows");
+/// (voucher_denom, holder) -> amount of remote tokens received by the holder.
+pub const VOUCHERS: Map<(String, Addr), Uint128> = Map::new("vouchers");
+/// Escrow released by the packet currently being received, restored if the unescrow fails.
+pub const PENDING_UNESCROW: Item<PendingUnescrow> = Item::new("pending_unescrow");
+
+#[cw_serde]
+pub struct PendingUnescrow {
+    pub channel: String,
+    pub denom: String,
+    pub amount: Uint128,
+}
@@ -0,0 +1,238 @@
+use std::time::Duration;
+
+use common::any_msg::AnyMsg;
+use common::protos::IbcTransfer;
+use cosmwasm_std::{Coin, CosmosMsg, Env, IbcMsg, IbcTimeout, IbcTimeoutBlock, Timestamp};
+
+use crate::error::ContractError;
+
+pub(crate) const TRANSFER_PORT: &str = "transfer";
+const MAX_MEMO_LEN: usize = 32 * 1024;
+
+/// Builds an ICS-20 transfer making sure exactly one timeout, a remote block height or a
+/// timestamp in nanoseconds, is set and the memo fits in 32 KiB.
+pub struct IbcTransferBuilder {
+    channel_id: String,
+    to_address: String,
+    amount: Coin,
+    timeout_height: Option<IbcTimeoutBlock>,
+    timeout_timestamp: Option<Timestamp>,
+    memo: Option<String>,
+}
+
+impl IbcTransferBuilder {
+    pub fn new(channel_id: String, to_address: String, amount: Coin) -> Self {
+        Self {
+            channel_id,
+            to_address,
+            amount,
+            timeout_height: None,
+            timeout_timestamp: None,
+            memo: None,
+        }
+    }
+
+    /// Times the packet out `duration` after the current block time.
+    pub fn timeout_in(mut self, env: &Env, duration: Duration) -> Result<Self, ContractError> {
+        if duration.is_zero() {
+            return Err(ContractError::ZeroTimeoutDuration {});
+        }
+
+        let nanos = u64::try_from(duration.as_nanos())
+            .ok()
+            .and_then(|nanos| env.block.time.nanos().checked_add(nanos))
+            .ok_or(ContractError::TimeoutOverflow {})?;
+        self.timeout_timestamp = Some(Timestamp::from_nanos(nanos));
+        Ok(self)
+    }
+
+    /// Times the packet out at an absolute timestamp of the remote chain.
+    pub fn timeout_at(mut self, timestamp: Timestamp) -> Self {
+        self.timeout_timestamp = Some(timestamp);
+        self
+    }
+
+    /// Times the packet out once the remote chain reaches `height` in `revision`.
+    pub fn timeout_height(mut self, revision: u64, height: u64) -> Self {
+        self.timeout_height = Some(IbcTimeoutBlock { revision, height });
+        self
+    }
+
+    /// Attaches a memo to the packet. IbcMsg::Transfer has no memo, so the transfer is sent as a
+    /// MsgTransfer stargate message instead.
+    pub fn memo(mut self, memo: String) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+
+    pub fn build(self, env: &Env) -> Result<CosmosMsg, ContractError> {
+        let timeout = match (self.timeout_height, self.timeout_timestamp) {
+            (Some(block), None) => IbcTimeout::with_block(block),
+            (None, Some(timestamp)) => IbcTimeout::with_timestamp(timestamp),
+            _ => return Err(ContractError::InvalidTimeout {}),
+        };
+
+        if let Some(memo) = self.memo {
+            if memo.len() > MAX_MEMO_LEN {
+                return Err(ContractError::MemoTooLong { max: MAX_MEMO_LEN });
+            }
+
+            let mut msg = IbcTransfer::MsgTransfer::new();
+            msg.source_port = TRANSFER_PORT.to_string();
+            msg.source_channel = self.channel_id;
+            msg.token = Some(IbcTransfer::Coin {
+                denom: self.amount.denom,
+                amount: self.amount.amount.to_string(),
+                ..Default::default()
+            })
+            .into();
+            msg.sender = env.contract.address.to_string();
+            msg.receiver = self.to_address;
+            if let Some(block) = timeout.block() {
+                msg.timeout_height = Some(IbcTransfer::Height {
+                    revision_number: block.revision,
+                    revision_height: block.height,
+                    ..Default::default()
+                })
+                .into();
+            }
+            msg.timeout_timestamp = timeout.timestamp().map_or(0, |timestamp| timestamp.nanos());
+            msg.memo = memo;
+            return Ok(msg.to_stargate()?);
+        }
+
+        Ok(IbcMsg::Transfer {
+            channel_id: self.channel_id,
+            to_address: self.to_address,
+            amount: self.amount,
+            timeout,
+        }
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::mock_env;
+    use protobuf::Message;
+
+    use super::*;
+
+    fn builder() -> IbcTransferBuilder {
+        IbcTransferBuilder::new(
+            "channel-0".to_string(),
+            "receiver".to_string(),
+            Coin::new(100, "ucore"),
+        )
+    }
+
+    fn env_at(seconds: u64) -> Env {
+        let mut env = mock_env();
+        env.block.time = Timestamp::from_seconds(seconds);
+        env
+    }
+
+    fn timeout(msg: CosmosMsg) -> IbcTimeout {
+        match msg {
+            CosmosMsg::Ibc(IbcMsg::Transfer { timeout, .. }) => timeout,
+            msg => panic!("unexpected message {msg:?}"),
+        }
+    }
+
+    fn msg_transfer(msg: CosmosMsg) -> IbcTransfer::MsgTransfer {
+        match msg {
+            CosmosMsg::Stargate { type_url, value } => {
+                assert_eq!(type_url, IbcTransfer::MsgTransfer::TYPE_URL);
+                IbcTransfer::MsgTransfer::parse_from_bytes(value.as_slice()).unwrap()
+            }
+            msg => panic!("unexpected message {msg:?}"),
+        }
+    }
+
+    #[test]
+    fn timeout_in_is_converted_to_nanoseconds() {
+        let env = env_at(1_000);
+        let msg = builder()
+            .timeout_in(&env, Duration::from_millis(1_500))
+            .unwrap()
+            .build(&env)
+            .unwrap();
+
+        assert_eq!(
+            timeout(msg).timestamp(),
+            Some(Timestamp::from_nanos(1_001_500_000_000))
+        );
+    }
+
+    #[test]
+    fn timeout_height_is_kept() {
+        let msg = builder().timeout_height(2, 300).build(&mock_env()).unwrap();
+        assert_eq!(
+            timeout(msg).block(),
+            Some(IbcTimeoutBlock {
+                revision: 2,
+                height: 300
+            })
+        );
+    }
+
+    #[test]
+    fn exactly_one_timeout_is_required() {
+        let env = mock_env();
+        let both = builder()
+            .timeout_height(1, 100)
+            .timeout_in(&env, Duration::from_secs(60))
+            .unwrap()
+            .build(&env);
+        assert!(matches!(both, Err(ContractError::InvalidTimeout {})));
+
+        let neither = builder().build(&env);
+        assert!(matches!(neither, Err(ContractError::InvalidTimeout {})));
+    }
+
+    #[test]
+    fn zero_duration_is_refused() {
+        let res = builder().timeout_in(&mock_env(), Duration::ZERO);
+        assert!(matches!(res, Err(ContractError::ZeroTimeoutDuration {})));
+    }
+
+    #[test]
+    fn overflowing_timeout_is_refused() {
+        let res = builder().timeout_in(&mock_env(), Duration::from_secs(u64::MAX));
+        assert!(matches!(res, Err(ContractError::TimeoutOverflow {})));
+    }
+
+    #[test]
+    fn memo_is_sent_as_msg_transfer() {
+        let env = env_at(1_000);
+        let msg = builder()
+            .timeout_in(&env, Duration::from_secs(1))
+            .unwrap()
+            .memo("{}".to_string())
+            .build(&env)
+            .unwrap();
+
+        let transfer = msg_transfer(msg);
+        assert_eq!(transfer.memo, "{}");
+        assert_eq!(transfer.timeout_timestamp, 1_001_000_000_000);
+        assert!(transfer.timeout_height.is_none());
+        assert_eq!(transfer.sender, env.contract.address.as_str());
+    }
+
+    #[test]
+    fn memo_length_is_limited() {
+        let env = mock_env();
+        let build = |len| {
+            builder()
+                .timeout_height(1, 100)
+                .memo("a".repeat(len))
+                .build(&env)
+        };
+
+        assert!(build(MAX_MEMO_LEN).is_ok());
+        assert!(matches!(
+            build(MAX_MEMO_LEN + 1),
+            Err(ContractError::MemoTooLong { max: MAX_MEMO_LEN })
+        ));
+    }
+}
@@ -1,9 +1,13 @@
+use std::time::Duration;
+
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Coin, CosmosMsg, DepsMut, Env, IbcMsg, IbcTimeout, MessageInfo, Response};
+use cosmwasm_std::{Coin, DepsMut, Empty, Env, IbcTimeout, MessageInfo, Response};
 use cw2::set_contract_version;
 
+use crate::builder::IbcTransferBuilder;
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg};
+use crate::memo::{Forward, Memo};
+use crate::msg::{ExecuteMsg, Hop, InstantiateMsg};
 
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -23,7 +27,7 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     _deps: DepsMut,
-    _env: Env,
+    env: Env,
     _info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
@@ -33,25 +37,79 @@ pub fn execute(
             to_address,
             amount,
             timeout,
-        } => transfer(channel_id, to_address, amount, timeout),
+            timeout_in_seconds,
+            memo,
+        } => transfer(
+            env,
+            channel_id,
+            to_address,
+            amount,
+            timeout,
+            timeout_in_seconds,
+            memo,
+        ),
+        ExecuteMsg::MultiHopTransfer {
+            hops,
+            amount,
+            timeout_in_seconds,
+        } => multi_hop_transfer(env, hops, amount, timeout_in_seconds),
     }
 }
 
 pub fn transfer(
+    env: Env,
     channel_id: String,
     to_address: String,
     amount: Coin,
-    timeout: IbcTimeout,
+    timeout: Option<IbcTimeout>,
+    timeout_in_seconds: Option<u64>,
+    memo: Option<String>,
 ) -> Result<Response, ContractError> {
-    let ibc_transfer_msg: CosmosMsg = IbcMsg::Transfer {
-        channel_id,
-        to_address,
-        amount,
-        timeout,
+    let mut builder = IbcTransferBuilder::new(channel_id, to_address, amount);
+    if let Some(timeout) = timeout {
+        if let Some(block) = timeout.block() {
+            builder = builder.timeout_height(block.revision, block.height);
+        }
+        if let Some(timestamp) = timeout.timestamp() {
+            builder = builder.timeout_at(timestamp);
+        }
+    }
+    if let Some(seconds) = timeout_in_seconds {
+        builder = builder.timeout_in(&env, Duration::from_secs(seconds))?;
     }
-    .into();
+
+    if let Some(memo) = memo {
+        builder = builder.memo(memo);
+    }
+
+    let ibc_transfer_msg = builder.build(&env)?;
     let res = Response::new()
         .add_attribute("method", "transfer")
         .add_message(ibc_transfer_msg);
     Ok(res)
 }
+
+pub fn multi_hop_transfer(
+    env: Env,
+    hops: Vec<Hop>,
+    amount: Coin,
+    timeout_in_seconds: u64,
+) -> Result<Response, ContractError> {
+    let mut hops = hops.into_iter();
+    let first = hops.next().ok_or(ContractError::EmptyRoute {})?;
+    let forwards = hops
+        .map(|hop| Forward::<Empty>::new(hop.to_address, hop.channel_id))
+        .collect();
+
+    let mut builder = IbcTransferBuilder::new(first.channel_id, first.to_address, amount)
+        .timeout_in(&env, Duration::from_secs(timeout_in_seconds))?;
+    if let Some(memo) = Memo::route(forwards) {
+        builder = builder.memo(memo.to_json()?);
+    }
+
+    let ibc_transfer_msg = builder.build(&env)?;
+    let res = Response::new()
+        .add_attribute("method", "multi_hop_transfer")
+        .add_message(ibc_transfer_msg);
+    Ok(res)
+}
@@ -11,4 +11,22 @@ pub enum ContractError {
 
     #[error("Custom Error val: {val:?}")]
     CustomError { val: String },
+
+    #[error("Exactly one of timeout height or timeout timestamp must be set")]
+    InvalidTimeout {},
+
+    #[error("Timeout duration must be greater than zero")]
+    ZeroTimeoutDuration {},
+
+    #[error("Timeout timestamp overflows")]
+    TimeoutOverflow {},
+
+    #[error("Memo is longer than {max} bytes")]
+    MemoTooLong { max: usize },
+
+    #[error("Multi-hop transfer must have at least one hop")]
+    EmptyRoute {},
+
+    #[error("{0}")]
+    Protobuf(#[from] protobuf::Error),
 }
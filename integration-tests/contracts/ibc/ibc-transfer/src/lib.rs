@@ -1,5 +1,7 @@
+pub mod builder;
 pub mod contract;
 mod error;
+pub mod memo;
 pub mod msg;
 
 pub use crate::error::ContractError;
@@ -0,0 +1,178 @@
+// Typed ICS-20 memos of the packet-forward middleware and the wasm hooks, see:
+// https://github.com/cosmos/ibc-apps/tree/main/middleware/packet-forward-middleware
+// https://github.com/osmosis-labs/osmosis/tree/main/x/ibc-hooks
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{to_json_vec, Empty, StdError, StdResult};
+
+use crate::builder::TRANSFER_PORT;
+
+/// The JSON memo of an ICS-20 transfer. `M` is the execute message of the wasm hook.
+#[cw_serde]
+pub struct Memo<M = Empty> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forward: Option<Forward<M>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wasm: Option<WasmHook<M>>,
+}
+
+impl<M> Memo<M> {
+    /// Forwards the received tokens to the next chain.
+    pub fn forward(forward: Forward<M>) -> Self {
+        Self {
+            forward: Some(forward),
+            wasm: None,
+        }
+    }
+
+    /// Executes the contract with the received tokens attached.
+    pub fn wasm(contract: impl Into<String>, msg: M) -> Self {
+        Self {
+            forward: None,
+            wasm: Some(WasmHook {
+                contract: contract.into(),
+                msg,
+            }),
+        }
+    }
+
+    /// Chains the forwards, so the tokens are passed through the hops in order. Returns None for
+    /// an empty route.
+    pub fn route(hops: Vec<Forward<M>>) -> Option<Self> {
+        hops.into_iter().rev().fold(None, |next, mut forward| {
+            forward.next = next.map(Box::new);
+            Some(Self::forward(forward))
+        })
+    }
+}
+
+impl<M: Serialize> Memo<M> {
+    pub fn to_json(&self) -> StdResult<String> {
+        String::from_utf8(to_json_vec(self)?).map_err(StdError::from)
+    }
+}
+
+/// The packet-forward middleware payload.
+#[cw_serde]
+pub struct Forward<M = Empty> {
+    /// address on the next chain to receive the tokens
+    pub receiver: String,
+    pub port: String,
+    /// channel of the intermediate chain to forward the tokens over
+    pub channel: String,
+    /// duration of the forwarded packet timeout, e.g. "10m"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u8>,
+    /// memo of the forwarded packet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<Memo<M>>>,
+}
+
+impl<M> Forward<M> {
+    pub fn new(receiver: impl Into<String>, channel: impl Into<String>) -> Self {
+        Self {
+            receiver: receiver.into(),
+            port: TRANSFER_PORT.to_string(),
+            channel: channel.into(),
+            timeout: None,
+            retries: None,
+            next: None,
+        }
+    }
+
+    pub fn timeout(mut self, timeout: impl Into<String>) -> Self {
+        self.timeout = Some(timeout.into());
+        self
+    }
+
+    pub fn retries(mut self, retries: u8) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    pub fn next(mut self, next: Memo<M>) -> Self {
+        self.next = Some(Box::new(next));
+        self
+    }
+}
+
+/// The wasm hook payload, executing `msg` on the `contract` of the receiving chain.
+#[cw_serde]
+pub struct WasmHook<M = Empty> {
+    pub contract: String,
+    pub msg: M,
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, Uint128};
+
+    use super::*;
+
+    // execute message of a swap contract on the receiving chain
+    #[cw_serde]
+    enum SwapMsg {
+        Swap {
+            output_denom: String,
+            min_output: Uint128,
+        },
+    }
+
+    // the memo shapes of the middleware docs, compact and with the fields in declaration order
+    const FORWARD: &str = r#"{"forward":{"receiver":"osmo1receiver","port":"transfer","channel":"channel-141","timeout":"10m","retries":2}}"#;
+    const MULTI_HOP: &str = r#"{"forward":{"receiver":"pfm","port":"transfer","channel":"channel-0","next":{"forward":{"receiver":"cosmos1receiver","port":"transfer","channel":"channel-1"}}}}"#;
+    const WASM_HOOK: &str = r#"{"wasm":{"contract":"osmo1swap","msg":{"swap":{"output_denom":"uosmo","min_output":"100"}}}}"#;
+    const FORWARD_TO_HOOK: &str = r#"{"forward":{"receiver":"osmo1swap","port":"transfer","channel":"channel-141","next":{"wasm":{"contract":"osmo1swap","msg":{"swap":{"output_denom":"uosmo","min_output":"100"}}}}}}"#;
+
+    fn swap() -> SwapMsg {
+        SwapMsg::Swap {
+            output_denom: "uosmo".to_string(),
+            min_output: Uint128::new(100),
+        }
+    }
+
+    fn assert_round_trip<M>(memo: Memo<M>, json: &str)
+    where
+        M: Serialize + cosmwasm_schema::serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+    {
+        assert_eq!(memo.to_json().unwrap(), json);
+        assert_eq!(from_json::<Memo<M>>(json).unwrap(), memo);
+    }
+
+    #[test]
+    fn forward_round_trip() {
+        let memo: Memo = Memo::forward(
+            Forward::new("osmo1receiver", "channel-141")
+                .timeout("10m")
+                .retries(2),
+        );
+        assert_round_trip(memo, FORWARD);
+    }
+
+    #[test]
+    fn multi_hop_round_trip() {
+        let memo: Memo = Memo::route(vec![
+            Forward::new("pfm", "channel-0"),
+            Forward::new("cosmos1receiver", "channel-1"),
+        ])
+        .unwrap();
+        assert_round_trip(memo, MULTI_HOP);
+        assert_eq!(Memo::<Empty>::route(vec![]), None);
+    }
+
+    #[test]
+    fn wasm_hook_round_trip() {
+        assert_round_trip(Memo::wasm("osmo1swap", swap()), WASM_HOOK);
+    }
+
+    #[test]
+    fn forward_to_a_wasm_hook_round_trip() {
+        let memo = Memo::forward(
+            Forward::new("osmo1swap", "channel-141").next(Memo::wasm("osmo1swap", swap())),
+        );
+        assert_round_trip(memo, FORWARD_TO_HOOK);
+    }
+}
@@ -15,6 +15,26 @@ pub enum ExecuteMsg {
         /// https://github.com/cosmos/cosmos-sdk/blob/v0.40.0/proto/ibc/applications/transfer/v1/transfer.proto#L11-L20
         amount: Coin,
         /// when packet times out, measured on remote chain
-        timeout: IbcTimeout,
+        timeout: Option<IbcTimeout>,
+        /// relative alternative to `timeout`, added to the current block time
+        timeout_in_seconds: Option<u64>,
+        /// memo forwarded in the packet, sends the transfer as a MsgTransfer stargate message
+        memo: Option<String>,
     },
+    MultiHopTransfer {
+        /// hops the tokens take, the first one is sent from this chain and the rest are
+        /// forwarded by the packet-forward middleware of the intermediate chains
+        hops: Vec<Hop>,
+        amount: Coin,
+        /// timeout of the first hop, added to the current block time
+        timeout_in_seconds: u64,
+    },
+}
+
+#[cw_serde]
+pub struct Hop {
+    /// channel to send the tokens over
+    pub channel_id: String,
+    /// address on the chain at the other end of the channel to receive the tokens
+    pub to_address: String,
 }
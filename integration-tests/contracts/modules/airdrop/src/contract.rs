@@ -0,0 +1,309 @@
+use coreum_wasm_sdk::assetft::{self, Query, WhitelistedBalanceResponse};
+use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries, CoreumResult};
+use cosmwasm_std::{
+    coin, entry_point, to_binary, Addr, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Response,
+    StdResult, Uint128,
+};
+use cw2::set_contract_version;
+
+use crate::error::ContractError;
+use crate::merkle;
+use crate::msg::{Claim, ConfigResponse, ExecuteMsg, InstantiateMsg, IsClaimedResponse, QueryMsg};
+use crate::state::{CLAIMED, DENOM, MERKLE_ROOT, TOTAL_CLAIMED};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// features 0 and 3 of x/asset/ft
+const FEATURE_MINTING: u32 = 0;
+const FEATURE_WHITELISTING: u32 = 3;
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> CoreumResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let merkle_root = merkle::decode_hash(&msg.merkle_root)?;
+    // nothing is issued upfront, the supply is the sum of the claims
+    let issue_msg = CoreumMsg::AssetFT(assetft::Msg::Issue {
+        symbol: msg.symbol,
+        subunit: msg.subunit.clone(),
+        precision: msg.precision,
+        initial_amount: Uint128::zero(),
+        description: msg.description,
+        features: Some(vec![FEATURE_MINTING, FEATURE_WHITELISTING]),
+        burn_rate: None,
+        send_commission_rate: None,
+    });
+    let denom = format!("{}-{}", msg.subunit, env.contract.address).to_lowercase();
+
+    DENOM.save(deps.storage, &denom)?;
+    MERKLE_ROOT.save(deps.storage, &merkle_root)?;
+    TOTAL_CLAIMED.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("denom", denom)
+        .add_attribute("merkle_root", msg.merkle_root)
+        .add_message(issue_msg))
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<CoreumQueries>,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> CoreumResult<ContractError> {
+    match msg {
+        ExecuteMsg::Claim { amount, proof } => claim(
+            deps,
+            vec![Claim {
+                address: info.sender.to_string(),
+                amount,
+                proof,
+            }],
+        ),
+        ExecuteMsg::ClaimBatch { claims } => claim(deps, claims),
+    }
+}
+
+// ********** Transactions **********
+
+fn claim(deps: DepsMut<CoreumQueries>, claims: Vec<Claim>) -> CoreumResult<ContractError> {
+    if claims.is_empty() {
+        return Err(ContractError::EmptyClaims {});
+    }
+    let denom = DENOM.load(deps.storage)?;
+    let merkle_root = MERKLE_ROOT.load(deps.storage)?;
+
+    let mut total = Uint128::zero();
+    let mut whitelist_msgs = Vec::with_capacity(claims.len());
+    let mut send_msgs = Vec::with_capacity(claims.len());
+    for claim in claims {
+        let account = deps.api.addr_validate(&claim.address)?;
+        verify_claim(deps.as_ref(), &merkle_root, &account, &claim)?;
+        // saved right away so an account repeated in the batch fails the check above
+        CLAIMED.save(deps.storage, &account, &claim.amount)?;
+        total += claim.amount;
+
+        // the whitelisted limit covers the whole balance, so the claim is added to the current one
+        let whitelisted: WhitelistedBalanceResponse = deps.querier.query(
+            &CoreumQueries::AssetFT(Query::WhitelistedBalance {
+                account: account.to_string(),
+                denom: denom.clone(),
+            })
+            .into(),
+        )?;
+        let limit = whitelisted.balance.amount + claim.amount;
+        ensure_receivable(deps.as_ref(), &account, &denom, claim.amount, limit)?;
+        whitelist_msgs.push(CoreumMsg::AssetFT(assetft::Msg::SetWhitelistedLimit {
+            account: account.to_string(),
+            coin: coin(limit.u128(), denom.clone()),
+        }));
+        send_msgs.push(BankMsg::Send {
+            to_address: account.to_string(),
+            amount: vec![coin(claim.amount.u128(), denom.clone())],
+        });
+    }
+    TOTAL_CLAIMED.update(deps.storage, |claimed| -> StdResult<_> {
+        Ok(claimed + total)
+    })?;
+
+    let mint_msg = CoreumMsg::AssetFT(assetft::Msg::Mint {
+        coin: coin(total.u128(), denom),
+    });
+
+    Ok(Response::new()
+        .add_attribute("method", "claim")
+        .add_attribute("claims", send_msgs.len().to_string())
+        .add_attribute("amount", total)
+        .add_messages(whitelist_msgs)
+        .add_message(mint_msg)
+        .add_messages(send_msgs))
+}
+
+fn verify_claim(
+    deps: Deps<CoreumQueries>,
+    merkle_root: &[u8; 32],
+    account: &Addr,
+    claim: &Claim,
+) -> Result<(), ContractError> {
+    if claim.amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    if CLAIMED.has(deps.storage, account) {
+        return Err(ContractError::AlreadyClaimed {
+            account: account.to_string(),
+        });
+    }
+    let leaf = merkle::leaf(account.as_str(), &claim.amount.to_string());
+    if !merkle::verify(merkle_root, leaf, &claim.proof)? {
+        return Err(ContractError::InvalidProof {
+            account: account.to_string(),
+        });
+    }
+    Ok(())
+}
+
+// The limit is raised within the same response, so the send is checked against the raised limit
+// rather than the current one. It only fails for accounts already holding more than their limit,
+// which would otherwise revert the whole batch with the chain's error.
+fn ensure_receivable(
+    deps: Deps<CoreumQueries>,
+    account: &Addr,
+    denom: &str,
+    amount: Uint128,
+    limit: Uint128,
+) -> Result<(), ContractError> {
+    let after = deps.querier.query_balance(account, denom)?.amount + amount;
+    if after > limit {
+        return Err(ContractError::WhitelistLimitExceeded {
+            account: account.to_string(),
+            excess: after - limit,
+        });
+    }
+    Ok(())
+}
+
+// ********** Queries **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<CoreumQueries>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&ConfigResponse {
+            denom: DENOM.load(deps.storage)?,
+            merkle_root: hex::encode(MERKLE_ROOT.load(deps.storage)?),
+            total_claimed: TOTAL_CLAIMED.load(deps.storage)?,
+        }),
+        QueryMsg::IsClaimed { address } => {
+            let address = deps.api.addr_validate(&address)?;
+            to_binary(&IsClaimedResponse {
+                is_claimed: CLAIMED.has(deps.storage, &address),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coreum_wasm_sdk::assetft::WhitelistedBalanceResponse;
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+    use cosmwasm_std::{ContractResult, CosmosMsg, OwnedDeps, SystemResult};
+    use sha2::{Digest, Sha256};
+
+    use super::*;
+
+    const DENOM: &str = "drop-cosmos2contract";
+
+    type TestDeps = OwnedDeps<MockStorage, MockApi, MockQuerier<CoreumQueries>, CoreumQueries>;
+
+    // a tree of the claims of alice and bob, each proven by the leaf of the other
+    fn setup(whitelisted: u128) -> TestDeps {
+        let querier = MockQuerier::new(&[]).with_custom_handler(move |query| match query {
+            CoreumQueries::AssetFT(Query::WhitelistedBalance { denom, .. }) => {
+                SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&WhitelistedBalanceResponse {
+                        balance: coin(whitelisted, denom),
+                    })
+                    .unwrap(),
+                ))
+            }
+            _ => panic!("unexpected query"),
+        });
+        let mut deps = OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier,
+            custom_query_type: Default::default(),
+        };
+
+        let (alice, bob) = (merkle::leaf("alice", "100"), merkle::leaf("bob", "50"));
+        let (first, second) = if alice <= bob {
+            (alice, bob)
+        } else {
+            (bob, alice)
+        };
+        let root: [u8; 32] = Sha256::new()
+            .chain_update(first)
+            .chain_update(second)
+            .finalize()
+            .into();
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("creator", &[]),
+            InstantiateMsg {
+                symbol: "DROP".to_string(),
+                subunit: "drop".to_string(),
+                precision: 6,
+                description: None,
+                merkle_root: hex::encode(root),
+            },
+        )
+        .unwrap();
+        deps
+    }
+
+    fn claim_batch(deps: &mut TestDeps) -> CoreumResult<ContractError> {
+        let claims = vec![
+            Claim {
+                address: "alice".to_string(),
+                amount: Uint128::new(100),
+                proof: vec![hex::encode(merkle::leaf("bob", "50"))],
+            },
+            Claim {
+                address: "bob".to_string(),
+                amount: Uint128::new(50),
+                proof: vec![hex::encode(merkle::leaf("alice", "100"))],
+            },
+        ];
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("relayer", &[]),
+            ExecuteMsg::ClaimBatch { claims },
+        )
+    }
+
+    #[test]
+    fn claims_raise_the_limit_by_the_claimed_amount() {
+        let mut deps = setup(20);
+        deps.querier.update_balance("bob", vec![coin(20, DENOM)]);
+        let res = claim_batch(&mut deps).unwrap();
+
+        let limits: Vec<_> = res
+            .messages
+            .iter()
+            .filter_map(|sub_msg| match &sub_msg.msg {
+                CosmosMsg::Custom(CoreumMsg::AssetFT(assetft::Msg::SetWhitelistedLimit {
+                    account,
+                    coin,
+                })) => Some((account.as_str(), coin.amount.u128())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(limits, vec![("alice", 120), ("bob", 70)]);
+    }
+
+    #[test]
+    fn balance_above_the_limit_fails_the_batch() {
+        let mut deps = setup(20);
+        deps.querier.update_balance("bob", vec![coin(30, DENOM)]);
+        let err = claim_batch(&mut deps).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::WhitelistLimitExceeded { account, excess }
+                if account == "bob" && excess.u128() == 10
+        ));
+    }
+}
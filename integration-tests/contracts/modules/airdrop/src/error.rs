@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+use cosmwasm_std::{StdError, Uint128};
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Invalid hex: {0}")]
+    Hex(#[from] hex::FromHexError),
+
+    #[error("Invalid zero amount")]
+    InvalidZeroAmount {},
+
+    #[error("Invalid hash length, expected 32 bytes")]
+    InvalidHashLength {},
+
+    #[error("Account {account} has already claimed")]
+    AlreadyClaimed { account: String },
+
+    #[error("Merkle proof of {account} doesn't match the root")]
+    InvalidProof { account: String },
+
+    #[error("No claims")]
+    EmptyClaims {},
+
+    #[error("Whitelisted limit of {account} would be exceeded by {excess}")]
+    WhitelistLimitExceeded { account: String, excess: Uint128 },
+}
@@ -0,0 +1,42 @@
+use sha2::{Digest, Sha256};
+
+use crate::error::ContractError;
+
+// The tree follows cw20-merkle-airdrop so the same tooling builds it: a leaf is
+// sha256(address + amount) with the amount in decimal, and every inner node hashes the two
+// children in ascending byte order, so a proof carries sibling hashes only, without positions.
+
+/// Hashes a claim into its leaf.
+pub fn leaf(address: &str, amount: &str) -> [u8; 32] {
+    Sha256::digest(format!("{address}{amount}").as_bytes()).into()
+}
+
+/// Decodes a hex encoded sha256 hash.
+pub fn decode_hash(hash: &str) -> Result<[u8; 32], ContractError> {
+    let mut buf = [0u8; 32];
+    hex::decode_to_slice(hash, &mut buf).map_err(|err| match err {
+        hex::FromHexError::InvalidStringLength => ContractError::InvalidHashLength {},
+        err => err.into(),
+    })?;
+    Ok(buf)
+}
+
+/// Folds the proof into the leaf and compares the result with the root.
+pub fn verify(root: &[u8; 32], leaf: [u8; 32], proof: &[String]) -> Result<bool, ContractError> {
+    let hash = proof.iter().try_fold(leaf, |hash, sibling| {
+        let sibling = decode_hash(sibling)?;
+        let (first, second) = if hash <= sibling {
+            (hash, sibling)
+        } else {
+            (sibling, hash)
+        };
+        Ok::<_, ContractError>(
+            Sha256::new()
+                .chain_update(first)
+                .chain_update(second)
+                .finalize()
+                .into(),
+        )
+    })?;
+    Ok(&hash == root)
+}
@@ -0,0 +1,48 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub symbol: String,
+    pub subunit: String,
+    pub precision: u32,
+    pub description: Option<String>,
+    // hex encoded root of the claims tree
+    pub merkle_root: String,
+}
+
+#[cw_serde]
+pub struct Claim {
+    pub address: String,
+    pub amount: Uint128,
+    // hex encoded sibling hashes from the leaf up to the root
+    pub proof: Vec<String>,
+}
+
+// Claims are minted by the contract and sent to the claimant, whose whitelisted limit is raised by
+// the claimed amount first, since the token is issued with the whitelisting feature.
+#[cw_serde]
+pub enum ExecuteMsg {
+    // claims the amount for the sender
+    Claim { amount: Uint128, proof: Vec<String> },
+    // claims on behalf of many accounts in one transaction, with a single mint of the total
+    ClaimBatch { claims: Vec<Claim> },
+}
+
+#[cw_serde]
+pub enum QueryMsg {
+    Config {},
+    IsClaimed { address: String },
+}
+
+#[cw_serde]
+pub struct ConfigResponse {
+    pub denom: String,
+    pub merkle_root: String,
+    pub total_claimed: Uint128,
+}
+
+#[cw_serde]
+pub struct IsClaimedResponse {
+    pub is_claimed: bool,
+}
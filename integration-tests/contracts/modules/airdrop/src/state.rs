@@ -0,0 +1,9 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+
+// token issued by the contract, minted on every claim
+pub const DENOM: Item<String> = Item::new("denom");
+// sha256 root of the tree of (address, amount) leaves, see merkle.rs
+pub const MERKLE_ROOT: Item<[u8; 32]> = Item::new("merkle_root");
+pub const TOTAL_CLAIMED: Item<Uint128> = Item::new("total_claimed");
+pub const CLAIMED: Map<&Addr, Uint128> = Map::new("claimed");
@@ -0,0 +1,108 @@
+// Runs against the proto backend the crate is built with, compare the default legacy backend with
+// `cargo bench --no-default-features --features prost`.
+#![allow(clippy::field_reassign_with_default)]
+
+use authz::any_msg::AnyMsg;
+use authz::proto::CosmosAuthz::MsgExec;
+use authz::proto::CosmosBankSend::{Coin, Input, MsgMultiSend, MsgSend, Output};
+use authz::proto::ProtoMessage;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const GRANTER: &str = "devcore1qg5ega6dykkxc307y25pecuufrjkxkaggkkxh7nad0vhyhtuhw3sqaa3c5";
+const RECIPIENT: &str = "devcore1p5eq9fsmsykcdyt0k0nssd8yxyryr5r04ewkqz";
+
+fn send_msg(coins: usize) -> MsgSend {
+    let mut send = MsgSend::default();
+    send.from_address = GRANTER.to_string();
+    send.to_address = RECIPIENT.to_string();
+    send.amount = (0..coins)
+        .map(|i| {
+            let mut coin = Coin::default();
+            coin.denom = format!("subunit{}-{}", i, GRANTER);
+            coin.amount = "1000000000".to_string();
+            coin
+        })
+        .collect();
+    send
+}
+
+fn exec_msg(msgs: usize) -> MsgExec {
+    let mut exec = MsgExec::default();
+    exec.grantee = RECIPIENT.to_string();
+    exec.msgs = (0..msgs).map(|_| send_msg(1).to_any().unwrap()).collect();
+    exec
+}
+
+fn multi_send_msg(outputs: usize) -> MsgMultiSend {
+    let coin = |amount: usize| {
+        let mut coin = Coin::default();
+        coin.denom = "ucore".to_string();
+        coin.amount = amount.to_string();
+        coin
+    };
+    let mut input = Input::default();
+    input.address = GRANTER.to_string();
+    input.coins = vec![coin(outputs * 1000)];
+    let mut multi_send = MsgMultiSend::default();
+    multi_send.inputs = vec![input];
+    multi_send.outputs = (0..outputs)
+        .map(|_| {
+            let mut output = Output::default();
+            output.address = RECIPIENT.to_string();
+            output.coins = vec![coin(1000)];
+            output
+        })
+        .collect();
+    multi_send
+}
+
+fn bench_msg_send(c: &mut Criterion) {
+    let mut group = c.benchmark_group("MsgSend");
+    for coins in [1, 10, 100] {
+        let msg = send_msg(coins);
+        let bytes = msg.encode_bytes().unwrap();
+        group.bench_with_input(BenchmarkId::new("encode", coins), &msg, |b, msg| {
+            b.iter(|| black_box(msg).encode_bytes().unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("decode", coins), &bytes, |b, bytes| {
+            b.iter(|| MsgSend::decode_bytes(black_box(bytes)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_msg_exec(c: &mut Criterion) {
+    let mut group = c.benchmark_group("MsgExec");
+    for msgs in [1, 10] {
+        let msg = exec_msg(msgs);
+        let bytes = msg.encode_bytes().unwrap();
+        group.bench_with_input(BenchmarkId::new("encode", msgs), &msg, |b, msg| {
+            b.iter(|| black_box(msg).encode_bytes().unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("decode", msgs), &bytes, |b, bytes| {
+            b.iter(|| MsgExec::decode_bytes(black_box(bytes)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn bench_msg_multi_send(c: &mut Criterion) {
+    let mut group = c.benchmark_group("MsgMultiSend");
+    let msg = multi_send_msg(100);
+    let bytes = msg.encode_bytes().unwrap();
+    group.bench_with_input(BenchmarkId::new("encode", 100), &msg, |b, msg| {
+        b.iter(|| black_box(msg).encode_bytes().unwrap())
+    });
+    group.bench_with_input(BenchmarkId::new("decode", 100), &bytes, |b, bytes| {
+        b.iter(|| MsgMultiSend::decode_bytes(black_box(bytes)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_msg_send,
+    bench_msg_exec,
+    bench_msg_multi_send
+);
+criterion_main!(benches);
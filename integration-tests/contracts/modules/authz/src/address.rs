@@ -0,0 +1,37 @@
+use bech32::{ToBase32, Variant};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+use crate::error::ContractError;
+
+const COMPRESSED_PUBKEY_LEN: usize = 33;
+
+/// Derives the bech32 account address of a compressed secp256k1 public key the same way the
+/// cosmos sdk does, ripemd160(sha256(pubkey)) under the given human readable prefix.
+pub fn secp256k1_address(pubkey: &[u8], prefix: &str) -> Result<String, ContractError> {
+    if pubkey.len() != COMPRESSED_PUBKEY_LEN {
+        return Err(ContractError::InvalidPubkey {
+            reason: format!(
+                "expected {} bytes of compressed key, got {}",
+                COMPRESSED_PUBKEY_LEN,
+                pubkey.len()
+            ),
+        });
+    }
+
+    let hash = Ripemd160::digest(Sha256::digest(pubkey));
+    bech32::encode(prefix, hash.to_base32(), Variant::Bech32).map_err(|err| {
+        ContractError::InvalidPubkey {
+            reason: err.to_string(),
+        }
+    })
+}
+
+/// Returns the human readable prefix of a bech32 address.
+pub fn bech32_prefix(address: &str) -> Result<String, ContractError> {
+    bech32::decode(address)
+        .map(|(prefix, _, _)| prefix)
+        .map_err(|err| ContractError::InvalidPubkey {
+            reason: format!("invalid address {}: {}", address, err),
+        })
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::{Binary, CosmosMsg};
+
+use crate::proto::{any, Any, ProtoError, ProtoMessage};
+
+/// Protobuf message with a cosmos type URL, so it can be packed into an Any or dispatched as a
+/// stargate message.
+pub trait AnyMsg: ProtoMessage {
+    const TYPE_URL: &'static str;
+
+    fn type_url(&self) -> &'static str {
+        Self::TYPE_URL
+    }
+
+    fn to_any(&self) -> Result<Any, ProtoError> {
+        Ok(any(Self::TYPE_URL.to_string(), self.encode_bytes()?))
+    }
+
+    fn to_stargate<T>(&self) -> Result<CosmosMsg<T>, ProtoError> {
+        Ok(CosmosMsg::Stargate {
+            type_url: Self::TYPE_URL.to_string(),
+            value: Binary::from(self.encode_bytes()?),
+        })
+    }
+}
@@ -0,0 +1,7 @@
+// Prints the fixtures of the Go tests, see fixtures.rs.
+//
+// cargo run --features fixtures --bin gen-fixtures > fixtures.json
+
+fn main() {
+    println!("{}", authz::fixtures::generate());
+}
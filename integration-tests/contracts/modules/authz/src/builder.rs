@@ -0,0 +1,187 @@
+// proto messages are built from Default field by field, which is the same for both proto backends
+#![allow(clippy::field_reassign_with_default)]
+
+use cosmwasm_std::{CosmosMsg, Env, Storage};
+
+use crate::any_msg::AnyMsg;
+use crate::error::ContractError;
+use crate::msg::PolicyMode;
+use crate::proto::Any;
+use crate::proto::CosmosAuthz::MsgExec;
+use crate::state::TYPE_URL_POLICY;
+
+/// Collects the messages the contract executes through authz as grantee into a single MsgExec,
+/// which runs them in order and fails as a whole. Every message passes the type url policy when
+/// the exec is built.
+pub struct ExecBuilder {
+    grantee: String,
+    msgs: Vec<Any>,
+}
+
+impl ExecBuilder {
+    pub fn new(env: &Env) -> Self {
+        Self {
+            grantee: env.contract.address.to_string(),
+            msgs: vec![],
+        }
+    }
+
+    pub fn push(mut self, msg: Any) -> Self {
+        self.msgs.push(msg);
+        self
+    }
+
+    /// Packs the message and adds it to the exec.
+    pub fn push_msg(self, msg: &impl AnyMsg) -> Result<Self, ContractError> {
+        Ok(self.push(msg.to_any()?))
+    }
+
+    pub fn extend(mut self, msgs: impl IntoIterator<Item = Any>) -> Self {
+        self.msgs.extend(msgs);
+        self
+    }
+
+    /// Builds the MsgExec, the chain rejects one without messages.
+    pub fn build(self, storage: &dyn Storage) -> Result<MsgExec, ContractError> {
+        if self.msgs.is_empty() {
+            return Err(ContractError::EmptyExec {});
+        }
+        for msg in &self.msgs {
+            ensure_type_url_allowed(storage, &msg.type_url)?;
+        }
+
+        let mut exec = MsgExec::default();
+        exec.grantee = self.grantee;
+        exec.msgs = self.msgs;
+        Ok(exec)
+    }
+
+    pub fn into_msg(self, storage: &dyn Storage) -> Result<CosmosMsg, ContractError> {
+        Ok(self.build(storage)?.to_stargate()?)
+    }
+}
+
+// ensure_type_url_allowed checks the type url against the policy, no policy allows everything.
+fn ensure_type_url_allowed(storage: &dyn Storage, type_url: &str) -> Result<(), ContractError> {
+    let Some(policy) = TYPE_URL_POLICY.may_load(storage)? else {
+        return Ok(());
+    };
+
+    let listed = policy
+        .type_urls
+        .iter()
+        .any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => type_url.starts_with(prefix),
+            None => type_url == pattern,
+        });
+    let allowed = match policy.mode {
+        PolicyMode::Allow => listed,
+        PolicyMode::Deny => !listed,
+    };
+    if !allowed {
+        return Err(ContractError::TypeUrlBlocked {
+            type_url: type_url.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+    use crate::msg::TypeUrlPolicy;
+
+    const MSG_SEND: &str = "/cosmos.bank.v1beta1.MsgSend";
+    const MSG_MULTI_SEND: &str = "/cosmos.bank.v1beta1.MsgMultiSend";
+    const MSG_GRANT: &str = "/cosmos.authz.v1beta1.MsgGrant";
+
+    fn with_policy(mode: PolicyMode, type_urls: &[&str]) -> MockStorage {
+        let mut storage = MockStorage::new();
+        TYPE_URL_POLICY
+            .save(
+                &mut storage,
+                &TypeUrlPolicy {
+                    mode,
+                    type_urls: type_urls
+                        .iter()
+                        .map(|type_url| type_url.to_string())
+                        .collect(),
+                },
+            )
+            .unwrap();
+        storage
+    }
+
+    fn is_allowed(storage: &dyn Storage, type_url: &str) -> bool {
+        match ensure_type_url_allowed(storage, type_url) {
+            Ok(()) => true,
+            Err(ContractError::TypeUrlBlocked { type_url: blocked }) => {
+                assert_eq!(blocked, type_url);
+                false
+            }
+            Err(err) => panic!("unexpected error: {err}"),
+        }
+    }
+
+    #[test]
+    fn everything_is_allowed_without_a_policy() {
+        let storage = MockStorage::new();
+        assert!(is_allowed(&storage, MSG_SEND));
+        assert!(is_allowed(&storage, MSG_GRANT));
+    }
+
+    #[test]
+    fn allow_mode_only_passes_the_listed_type_urls() {
+        let storage = with_policy(PolicyMode::Allow, &[MSG_SEND]);
+        assert!(is_allowed(&storage, MSG_SEND));
+        assert!(!is_allowed(&storage, MSG_MULTI_SEND));
+        assert!(!is_allowed(&storage, MSG_GRANT));
+
+        // an empty allow list passes nothing
+        let storage = with_policy(PolicyMode::Allow, &[]);
+        assert!(!is_allowed(&storage, MSG_SEND));
+    }
+
+    #[test]
+    fn wildcard_matches_the_prefix() {
+        let storage = with_policy(PolicyMode::Allow, &["/cosmos.bank.*"]);
+        assert!(is_allowed(&storage, MSG_SEND));
+        assert!(is_allowed(&storage, MSG_MULTI_SEND));
+        assert!(!is_allowed(&storage, MSG_GRANT));
+
+        // the prefix is matched as is, without a separator it covers longer package names too
+        let storage = with_policy(PolicyMode::Deny, &["/cosmos.bank*"]);
+        assert!(!is_allowed(&storage, MSG_SEND));
+        assert!(!is_allowed(&storage, "/cosmos.banking.v1.MsgSend"));
+        assert!(is_allowed(&storage, MSG_GRANT));
+
+        // a lone wildcard matches everything, a pattern without one only the exact type url
+        let storage = with_policy(PolicyMode::Deny, &["*"]);
+        assert!(!is_allowed(&storage, MSG_GRANT));
+        let storage = with_policy(PolicyMode::Deny, &["/cosmos.bank.v1beta1.MsgSen"]);
+        assert!(is_allowed(&storage, MSG_SEND));
+    }
+
+    #[test]
+    fn every_message_of_the_exec_is_checked() {
+        let storage = with_policy(PolicyMode::Allow, &[MSG_SEND]);
+        let any = |type_url: &str| {
+            let mut any = Any::default();
+            any.type_url = type_url.to_string();
+            any
+        };
+
+        let err = ExecBuilder::new(&cosmwasm_std::testing::mock_env())
+            .push(any(MSG_SEND))
+            .push(any(MSG_GRANT))
+            .build(&storage)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::TypeUrlBlocked { type_url } if type_url == MSG_GRANT
+        ));
+    }
+}
@@ -0,0 +1,543 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Write};
+
+use serde::ser::{self, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::ContractError;
+
+/// Renders a value as JSON with sorted object keys and no whitespace, so the same payload always
+/// produces the same bytes no matter how it was built. Floats are rejected since their textual
+/// form is not stable between encoders.
+pub fn canonical_json(value: &impl Serialize) -> Result<String, ContractError> {
+    let value = value
+        .serialize(ValueSerializer)
+        .map_err(|err| ContractError::InvalidCanonicalJson { reason: err.0 })?;
+
+    let mut out = String::new();
+    write_value(&mut out, &value);
+    Ok(out)
+}
+
+/// Returns the hex encoded sha256 of the canonical JSON of a value.
+pub fn payload_hash(value: &impl Serialize) -> Result<String, ContractError> {
+    let json = canonical_json(value)?;
+    Ok(hex::encode(Sha256::digest(json.as_bytes())))
+}
+
+enum Value {
+    Null,
+    Bool(bool),
+    Unsigned(u128),
+    Signed(i128),
+    String(String),
+    Array(Vec<Value>),
+    Object(BTreeMap<String, Value>),
+}
+
+fn write_value(out: &mut String, value: &Value) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(v) => out.push_str(if *v { "true" } else { "false" }),
+        Value::Unsigned(v) => out.push_str(&v.to_string()),
+        Value::Signed(v) => out.push_str(&v.to_string()),
+        Value::String(v) => write_string(out, v),
+        Value::Array(values) => {
+            out.push('[');
+            for (i, v) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(out, v);
+            }
+            out.push(']');
+        }
+        Value::Object(entries) => {
+            out.push('{');
+            for (i, (k, v)) in entries.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(out, k);
+                out.push(':');
+                write_value(out, v);
+            }
+            out.push('}');
+        }
+    }
+}
+
+// write_string escapes only what JSON requires, everything else is emitted as UTF-8.
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[derive(Debug)]
+struct Error(String);
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+fn float_error() -> Error {
+    Error("floating point numbers are not allowed".to_string())
+}
+
+struct ValueSerializer;
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = VariantSerializer<SeqSerializer>;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = VariantSerializer<MapSerializer>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        Ok(Value::Signed(v.into()))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        Ok(Value::Signed(v.into()))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        Ok(Value::Signed(v.into()))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Ok(Value::Signed(v.into()))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Value, Error> {
+        Ok(Value::Signed(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        Ok(Value::Unsigned(v.into()))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        Ok(Value::Unsigned(v.into()))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        Ok(Value::Unsigned(v.into()))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        Ok(Value::Unsigned(v.into()))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Value, Error> {
+        Ok(Value::Unsigned(v))
+    }
+
+    fn serialize_f32(self, _v: f32) -> Result<Value, Error> {
+        Err(float_error())
+    }
+
+    fn serialize_f64(self, _v: f64) -> Result<Value, Error> {
+        Err(float_error())
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        Ok(Value::Array(
+            v.iter().map(|b| Value::Unsigned((*b).into())).collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        Ok(Value::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        let mut entries = BTreeMap::new();
+        entries.insert(variant.to_string(), value.serialize(self)?);
+        Ok(Value::Object(entries))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, Error> {
+        Ok(SeqSerializer(Vec::with_capacity(len.unwrap_or_default())))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer<SeqSerializer>, Error> {
+        Ok(VariantSerializer {
+            variant,
+            inner: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSerializer, Error> {
+        Ok(MapSerializer {
+            entries: BTreeMap::new(),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> Result<MapSerializer, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<VariantSerializer<MapSerializer>, Error> {
+        Ok(VariantSerializer {
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+struct SeqSerializer(Vec<Value>);
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.0.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Array(self.0))
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        ser::SerializeSeq::end(self)
+    }
+}
+
+struct MapSerializer {
+    entries: BTreeMap<String, Value>,
+    next_key: Option<String>,
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        // JSON object keys are always strings, integer keys are rendered in decimal.
+        let key = match key.serialize(ValueSerializer)? {
+            Value::String(s) => s,
+            Value::Unsigned(v) => v.to_string(),
+            Value::Signed(v) => v.to_string(),
+            _ => return Err(Error("map keys must be strings or integers".to_string())),
+        };
+        self.next_key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .next_key
+            .take()
+            .ok_or_else(|| Error("map value serialized before its key".to_string()))?;
+        self.entries.insert(key, value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Object(self.entries))
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.entries
+            .insert(key.to_string(), value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        Ok(Value::Object(self.entries))
+    }
+}
+
+// VariantSerializer wraps the content of a tuple or struct variant into `{"variant": content}`.
+struct VariantSerializer<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl<S> VariantSerializer<S> {
+    fn wrap(variant: &'static str, value: Value) -> Value {
+        let mut entries = BTreeMap::new();
+        entries.insert(variant.to_string(), value);
+        Value::Object(entries)
+    }
+}
+
+impl ser::SerializeTupleVariant for VariantSerializer<SeqSerializer> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        ser::SerializeSeq::serialize_element(&mut self.inner, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        let value = ser::SerializeSeq::end(self.inner)?;
+        Ok(Self::wrap(self.variant, value))
+    }
+}
+
+impl ser::SerializeStructVariant for VariantSerializer<MapSerializer> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        ser::SerializeStruct::serialize_field(&mut self.inner, key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        let value = ser::SerializeStruct::end(self.inner)?;
+        Ok(Self::wrap(self.variant, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Send {
+        to_address: String,
+        amount: u64,
+        memo: Option<String>,
+    }
+
+    // the same fields as Send, declared in another order
+    #[derive(Serialize)]
+    struct Reordered {
+        memo: Option<String>,
+        amount: u64,
+        to_address: String,
+    }
+
+    #[test]
+    fn field_order_does_not_change_the_hash() {
+        let send = Send {
+            to_address: "alice".to_string(),
+            amount: 10,
+            memo: None,
+        };
+        let reordered = Reordered {
+            memo: None,
+            amount: 10,
+            to_address: "alice".to_string(),
+        };
+
+        assert_eq!(
+            canonical_json(&send).unwrap(),
+            r#"{"amount":10,"memo":null,"to_address":"alice"}"#
+        );
+        assert_eq!(
+            payload_hash(&send).unwrap(),
+            payload_hash(&reordered).unwrap()
+        );
+    }
+
+    #[test]
+    fn map_insertion_order_does_not_change_the_hash() {
+        let keys = ["b", "a", "d", "c"];
+        let forward: HashMap<_, _> = keys.iter().map(|key| (*key, 1u8)).collect();
+        let mut backward = HashMap::new();
+        for key in keys.iter().rev() {
+            backward.insert(*key, 1u8);
+        }
+
+        assert_eq!(
+            canonical_json(&forward).unwrap(),
+            r#"{"a":1,"b":1,"c":1,"d":1}"#
+        );
+        assert_eq!(
+            payload_hash(&forward).unwrap(),
+            payload_hash(&backward).unwrap()
+        );
+    }
+
+    #[test]
+    fn nested_maps_and_options() {
+        let mut inner = HashMap::new();
+        inner.insert(2u32, Some(vec![Some(-1i32), None]));
+        inner.insert(10u32, None);
+        let mut outer = HashMap::new();
+        outer.insert("z", Some(inner));
+        outer.insert("a", None);
+
+        assert_eq!(
+            canonical_json(&outer).unwrap(),
+            r#"{"a":null,"z":{"10":null,"2":[-1,null]}}"#
+        );
+    }
+
+    #[test]
+    fn floats_are_rejected() {
+        #[derive(Serialize)]
+        struct Priced {
+            price: Option<f64>,
+        }
+
+        assert!(canonical_json(&Priced { price: None }).is_ok());
+        assert!(matches!(
+            canonical_json(&Priced { price: Some(1.5) }),
+            Err(ContractError::InvalidCanonicalJson { reason })
+                if reason == "floating point numbers are not allowed"
+        ));
+        assert!(payload_hash(&vec![0.5f32]).is_err());
+    }
+
+    #[test]
+    fn strings_are_escaped() {
+        assert_eq!(
+            canonical_json(&"a\"b\\c\n\u{01}é").unwrap(),
+            r#""a\"b\\c\n\u0001é""#
+        );
+    }
+
+    #[test]
+    fn non_string_map_keys_are_rejected() {
+        let mut map = HashMap::new();
+        map.insert(true, 1u8);
+        assert!(canonical_json(&map).is_err());
+    }
+}
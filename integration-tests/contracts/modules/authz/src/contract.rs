@@ -1,31 +1,126 @@
+// proto messages are built from Default field by field and nested messages are set through into(),
+// which compiles for both proto backends but is a no-op conversion for prost
+#![allow(clippy::field_reassign_with_default, clippy::useless_conversion)]
+
+use std::collections::BTreeMap;
+
+use common::denom::{classify_denom, DenomKind};
+use common::guard::ReentrancyGuard;
+#[cfg(feature = "debug")]
+use common::raw_range::raw_range;
+use common::split::split_amount;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{Addr, Binary, CosmosMsg, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, Event,
+    MessageInfo, Order, QueryRequest, Reply, Response, StdError, StdResult, Storage, SubMsg,
+    Timestamp, Uint128,
+};
 use cw2::set_contract_version;
-use protobuf::Message;
+use cw_ownable::{assert_owner, initialize_owner};
+use cw_storage_plus::{Bound, PrefixBound};
+use sha2::{Digest, Sha256};
 
+use crate::address::{bech32_prefix, secp256k1_address};
+use crate::any_msg::AnyMsg;
+use crate::builder::ExecBuilder;
+use crate::canonical::{canonical_json, payload_hash};
+#[cfg(feature = "debug")]
+use crate::debug::{debug_log, record_dispatch};
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg};
-use crate::state::GRANTER;
-// Get Protos
-include!("protos/mod.rs");
-use CosmosAuthz::MsgExec;
-use CosmosBankSend::Coin;
-use CosmosBankSend::MsgSend;
+use crate::gas_hints::exec_send_gas;
+use crate::msg::{
+    Authorization, ChainInfoResponse, ExecuteMsg, GrantInfo, GrantsResponse, InstantiateMsg,
+    InstantiateMsgV2, PolicyMode, QueryMsg, QueueEntry, QueueResponse, RateLimit,
+    RecipientsResponse, RemainingSpendLimitResponse, ScheduledEntry, ScheduledResponse,
+    ScheduledSend, SendSpec, SessionResponse, SignedPayload, SimulateResponse, SplitRecipient,
+    StakeAuthorizationType, StakeValidators, TotalSpentResponse, TypeUrlPolicy,
+};
+use crate::proto::CosmosAuthz::{Grant, MsgGrant, MsgRevoke};
+use crate::proto::CosmosAuthzQuery::{
+    GenericAuthorization, QueryGranteeGrantsRequest, QueryGranteeGrantsResponse,
+    QueryGrantsRequest, QueryGrantsResponse, SendAuthorization, StakeAuthorization,
+};
+use crate::proto::CosmosBankParams::{MsgSetSendEnabled, SendEnabled};
+use crate::proto::CosmosBankSend::{Input, MsgMultiSend, MsgSend, Output};
+use crate::proto::CosmosGov::MsgSubmitProposal;
+use crate::proto::CosmosWasm::{MsgClearAdmin, MsgUpdateAdmin};
+use crate::proto::{
+    any, block_time, timestamp, Any, CosmosAuthzQuery, CosmosBankSend, CosmosGov, ProtoError,
+    ProtoMessage, StakeAuthorizationFields, ValidatorPolicy, AUTHORIZATION_TYPE_DELEGATE,
+    AUTHORIZATION_TYPE_REDELEGATE, AUTHORIZATION_TYPE_UNDELEGATE,
+};
+use crate::registry::decode_registered;
+use crate::state::{
+    ExecOutcomeHash, FeeConfig, PendingAssert, RateWindow, Spent, EXPECTED_CHAIN_ID, FEE_CONFIG,
+    GRANTER, IDEMPOTENCY_HEIGHTS, IDEMPOTENCY_KEYS, IDEMPOTENCY_TTL, INSTANTIATE_HEIGHT,
+    PENDING_ASSERT, PENDING_SPEND, QUEUE, QUEUE_NEXT_ID, RATE_LIMIT, RATE_WINDOWS, RECIPIENTS,
+    SCHEDULED, SCHEDULED_NEXT_ID, SIGNER, SIGNER_NONCE, SPENT, TYPE_URL_POLICY, UPLOAD_CHUNKS,
+};
 
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const SEND_AND_ASSERT_REPLY_ID: u64 = 1;
+const SPEND_REPLY_ID: u64 = 2;
+
+// held between a handler dispatching a send and its reply
+const GUARD: ReentrancyGuard = ReentrancyGuard::new("reentrancy_guard");
+
+// expired idempotency keys dropped per use of a key, so the cost of a call stays bounded
+const MAX_PRUNED_KEYS: usize = 10;
+
+const MAX_FEE_BPS: u16 = 1000;
+const BPS_DENOMINATOR: u16 = 10_000;
+
+const MAX_UPLOAD_SIZE: usize = 256 * 1024;
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 30;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
+    let msg = InstantiateMsgV2::try_from(msg)?;
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    initialize_owner(deps.storage, deps.api, Some(info.sender.as_ref()))?;
 
     GRANTER.save(deps.storage, &deps.api.addr_validate(msg.granter.as_ref())?)?;
+    IDEMPOTENCY_TTL.save(deps.storage, &msg.idempotency_ttl_blocks)?;
+    match (msg.fee_bps, msg.fee_collector) {
+        (Some(fee_bps), Some(collector)) => {
+            if fee_bps > MAX_FEE_BPS {
+                return Err(ContractError::FeeTooHigh {
+                    fee_bps,
+                    max: MAX_FEE_BPS,
+                });
+            }
+            FEE_CONFIG.save(
+                deps.storage,
+                &FeeConfig {
+                    fee_bps,
+                    collector: deps.api.addr_validate(&collector)?,
+                },
+            )?;
+        }
+        (None, None) => {}
+        _ => {
+            return Err(ContractError::InvalidFeeConfig {
+                reason: "fee_bps and fee_collector must be set together".to_string(),
+            })
+        }
+    }
+    if let Some(signer) = msg.signer {
+        SIGNER.save(deps.storage, &deps.api.addr_validate(&signer)?)?;
+    }
+    if let Some(chain_id) = msg.expected_chain_id {
+        EXPECTED_CHAIN_ID.save(deps.storage, &chain_id)?;
+    }
+    INSTANTIATE_HEIGHT.save(deps.storage, &env.block.height)?;
 
     Ok(Response::new()
         .add_attribute("contract", CONTRACT_NAME)
@@ -37,12 +132,208 @@ pub fn instantiate(
 pub fn execute(
     deps: DepsMut,
     env: Env,
-    _info: MessageInfo,
+    info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    match msg {
-        ExecuteMsg::Transfer{ address, amount, denom } => execute_transfer(deps, env, address, amount, denom),
+    GUARD.ensure_idle(deps.storage)?;
+    if let Some(expected) = EXPECTED_CHAIN_ID.may_load(deps.storage)? {
+        if expected != env.block.chain_id {
+            return Err(ContractError::WrongChain {
+                expected,
+                actual: env.block.chain_id,
+            });
+        }
+    }
+
+    let payload_hash = payload_hash(&msg)?;
+
+    let res = match msg {
+        ExecuteMsg::Transfer {
+            address,
+            amount,
+            denom,
+            idempotency_key,
+        } => {
+            if is_replay(deps.storage, &env, idempotency_key, &payload_hash)? {
+                return Ok(replayed(payload_hash));
+            }
+            execute_transfer(deps, env, address, amount, denom)
+        }
+        ExecuteMsg::SendAndAssert {
+            to_address,
+            coins,
+            expected_recipient_increase,
+            idempotency_key,
+        } => {
+            if is_replay(deps.storage, &env, idempotency_key, &payload_hash)? {
+                return Ok(replayed(payload_hash));
+            }
+            execute_send_and_assert(deps, env, to_address, coins, expected_recipient_increase)
+        }
+        ExecuteMsg::UpdateAdmin {
+            contract,
+            new_admin,
+        } => execute_update_admin(deps, env, info, contract, new_admin),
+        ExecuteMsg::ClearAdmin { contract } => execute_clear_admin(deps, env, info, contract),
+        ExecuteMsg::Grant {
+            grantee,
+            authorization,
+            expiration,
+            expires_in_seconds,
+        } => execute_grant(
+            deps,
+            env,
+            info,
+            grantee,
+            authorization,
+            expiration,
+            expires_in_seconds,
+        ),
+        ExecuteMsg::Revoke {
+            grantee,
+            msg_type_url,
+        } => execute_revoke(deps, env, info, grantee, msg_type_url),
+        ExecuteMsg::AddRecipient { address } => execute_add_recipient(deps, info, address),
+        ExecuteMsg::RemoveRecipient { address } => execute_remove_recipient(deps, info, address),
+        ExecuteMsg::ProposeSendEnabled {
+            authority,
+            denom,
+            enabled,
+            deposit,
+            title,
+            summary,
+        } => execute_propose_send_enabled(
+            deps, env, info, authority, denom, enabled, deposit, title, summary,
+        ),
+        ExecuteMsg::SplitSend {
+            denom,
+            amount,
+            recipients,
+            idempotency_key,
+        } => {
+            if is_replay(deps.storage, &env, idempotency_key, &payload_hash)? {
+                return Ok(replayed(payload_hash));
+            }
+            execute_split_send(deps, env, denom, amount, recipients)
+        }
+        ExecuteMsg::MultiSend {
+            outputs,
+            idempotency_key,
+        } => {
+            if is_replay(deps.storage, &env, idempotency_key, &payload_hash)? {
+                return Ok(replayed(payload_hash));
+            }
+            execute_multi_send(deps, env, outputs)
+        }
+        ExecuteMsg::ExecSigned {
+            payload,
+            signature,
+            pubkey,
+        } => execute_exec_signed(deps, env, payload, signature, pubkey),
+        ExecuteMsg::SetTypeUrlPolicy { mode, type_urls } => {
+            execute_set_type_url_policy(deps, info, mode, type_urls)
+        }
+        ExecuteMsg::SetRateLimit { limit } => execute_set_rate_limit(deps, info, limit),
+        ExecuteMsg::UploadChunk {
+            session_id,
+            index,
+            data,
+        } => execute_upload_chunk(deps, info, session_id, index, data),
+        ExecuteMsg::ExecUploaded {
+            session_id,
+            type_url,
+        } => execute_exec_uploaded(deps, env, info, session_id, type_url),
+        ExecuteMsg::ScheduleSend {
+            to_address,
+            coins,
+            execute_after_height,
+        } => execute_schedule_send(deps, info, to_address, coins, execute_after_height),
+        ExecuteMsg::ExecuteScheduled { id } => execute_execute_scheduled(deps, env, id),
+        ExecuteMsg::CancelScheduled { id } => execute_cancel_scheduled(deps, info, id),
+        ExecuteMsg::Enqueue { send } => execute_enqueue(deps, send),
+        ExecuteMsg::Flush {
+            max,
+            idempotency_key,
+        } => {
+            if is_replay(deps.storage, &env, idempotency_key, &payload_hash)? {
+                return Ok(replayed(payload_hash));
+            }
+            execute_flush(deps, env, max)
+        }
+    }?;
+
+    Ok(res.add_attribute("payload_hash", payload_hash))
+}
+
+// is_replay reserves the idempotency key for the payload. It reports a replay if the key is
+// already reserved for the same payload and fails if it is reserved for a different one. Expired
+// reservations are dropped when the key is used again, and every use prunes a bounded number of
+// the oldest expired ones.
+fn is_replay(
+    storage: &mut dyn Storage,
+    env: &Env,
+    key: Option<String>,
+    payload_hash: &str,
+) -> Result<bool, ContractError> {
+    let Some(key) = key else {
+        return Ok(false);
+    };
+
+    let height = env.block.height;
+    let ttl = IDEMPOTENCY_TTL.load(storage)?;
+    prune_idempotency_keys(storage, height, ttl)?;
+    if let Some(outcome) = IDEMPOTENCY_KEYS.may_load(storage, &key)? {
+        if outcome.height.saturating_add(ttl) > height {
+            if outcome.hash == payload_hash {
+                return Ok(true);
+            }
+            return Err(ContractError::DuplicateKey { key });
+        }
+        IDEMPOTENCY_HEIGHTS.remove(storage, (outcome.height, &key));
+    }
+
+    IDEMPOTENCY_KEYS.save(
+        storage,
+        &key,
+        &ExecOutcomeHash {
+            hash: payload_hash.to_string(),
+            height,
+        },
+    )?;
+    IDEMPOTENCY_HEIGHTS.save(storage, (height, &key), &Empty {})?;
+
+    Ok(false)
+}
+
+// prune_idempotency_keys drops up to MAX_PRUNED_KEYS of the reservations that expired by the
+// height, oldest first.
+fn prune_idempotency_keys(storage: &mut dyn Storage, height: u64, ttl: u64) -> StdResult<()> {
+    let Some(last_expired) = height.checked_sub(ttl) else {
+        return Ok(());
+    };
+
+    let expired = IDEMPOTENCY_HEIGHTS
+        .prefix_range(
+            storage,
+            None,
+            Some(PrefixBound::inclusive(last_expired)),
+            Order::Ascending,
+        )
+        .take(MAX_PRUNED_KEYS)
+        .map(|entry| entry.map(|(key, _)| key))
+        .collect::<StdResult<Vec<_>>>()?;
+    for (reserved_at, key) in expired {
+        IDEMPOTENCY_HEIGHTS.remove(storage, (reserved_at, &key));
+        IDEMPOTENCY_KEYS.remove(storage, &key);
     }
+
+    Ok(())
+}
+
+fn replayed(payload_hash: String) -> Response {
+    Response::new()
+        .add_attribute("method", "replayed")
+        .add_attribute("payload_hash", payload_hash)
 }
 
 pub fn execute_transfer(
@@ -53,28 +344,3050 @@ pub fn execute_transfer(
     denom: String,
 ) -> Result<Response, ContractError> {
     deps.api.addr_validate(address.as_ref())?;
+    ensure_recipient_allowed(deps.storage, &address, 0)?;
     let granter = GRANTER.load(deps.storage)?;
 
-    let mut send = MsgSend::new();
-    send.from_address = granter.into_string();
-    send.to_address = address.to_string();
-    send.amount = vec![];
-    let mut coin = Coin::new();
-    coin.amount = amount.to_string();
-    coin.denom = denom;
-    send.amount.push(coin);
-
-    let mut exec = MsgExec::new();
-    exec.grantee = env.contract.address.to_string();
-    exec.msgs = vec![send.to_any().unwrap()];
-    let exec_bytes: Vec<u8> = exec.write_to_bytes().unwrap();
-
-    let msg = CosmosMsg::Stargate {
-        type_url: "/cosmos.authz.v1beta1.MsgExec".to_string(),
-        value: Binary::from(exec_bytes),
-    };
+    let coins = vec![Coin::new(amount.into(), denom)];
+    let (msg, fee) = exec_send_msg(deps.storage, &env, &granter, address.as_str(), &coins)?;
+    GUARD.enter(deps.storage, SPEND_REPLY_ID)?;
+    PENDING_SPEND.save(deps.storage, &coins)?;
 
     Ok(Response::new()
         .add_attribute("method", "execute_authz_transfer")
-        .add_message(msg))
+        .add_events(fee_event(&fee))
+        .add_submessage(SubMsg::reply_on_success(msg, SPEND_REPLY_ID)))
+}
+
+pub fn execute_send_and_assert(
+    deps: DepsMut,
+    env: Env,
+    to_address: String,
+    coins: Vec<Coin>,
+    expected_recipient_increase: Vec<Coin>,
+) -> Result<Response, ContractError> {
+    let recipient = deps.api.addr_validate(&to_address)?;
+    ensure_recipient_allowed(deps.storage, &recipient, 0)?;
+    if coins.is_empty() {
+        return Err(ContractError::EmptyCoins {});
+    }
+    if expected_recipient_increase.is_empty() {
+        return Err(ContractError::EmptyExpectedIncrease {});
+    }
+    let granter = GRANTER.load(deps.storage)?;
+
+    // snapshot the recipient balance in the same transaction as the send, so nothing else can
+    // change it between the two reads
+    let balances_before = query_balances(deps.as_ref(), &recipient, &expected_recipient_increase)?;
+    GUARD.enter(deps.storage, SEND_AND_ASSERT_REPLY_ID)?;
+    PENDING_ASSERT.save(
+        deps.storage,
+        &PendingAssert {
+            recipient: recipient.clone(),
+            balances_before,
+            expected_increase: expected_recipient_increase,
+        },
+    )?;
+
+    let (msg, fee) = exec_send_msg(deps.storage, &env, &granter, recipient.as_str(), &coins)?;
+    PENDING_SPEND.save(deps.storage, &coins)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_send_and_assert")
+        .add_attribute("recipient", recipient)
+        .add_events(fee_event(&fee))
+        .add_submessage(SubMsg::reply_on_success(msg, SEND_AND_ASSERT_REPLY_ID)))
+}
+
+pub fn execute_update_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+    new_admin: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let contract = deps.api.addr_validate(&contract)?;
+    let new_admin = deps.api.addr_validate(&new_admin)?;
+    let granter = GRANTER.load(deps.storage)?;
+    // the granter is the current admin executing through authz, handing the admin over to itself
+    // would be a no-op
+    if new_admin == granter {
+        return Err(ContractError::PointlessAdminRotation {});
+    }
+
+    let mut update_admin = MsgUpdateAdmin::default();
+    update_admin.sender = granter.to_string();
+    update_admin.new_admin = new_admin.to_string();
+    update_admin.contract = contract.to_string();
+    let update_admin_any = update_admin.to_any()?;
+    record_dispatch(deps.storage, &env, &update_admin_any, &update_admin)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_update_admin")
+        .add_attribute("contract", contract)
+        .add_attribute("new_admin", new_admin)
+        .add_message(
+            ExecBuilder::new(&env)
+                .push(update_admin_any)
+                .into_msg(deps.storage)?,
+        ))
+}
+
+pub fn execute_clear_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let contract = deps.api.addr_validate(&contract)?;
+    let granter = GRANTER.load(deps.storage)?;
+
+    let mut clear_admin = MsgClearAdmin::default();
+    clear_admin.sender = granter.to_string();
+    clear_admin.contract = contract.to_string();
+    let clear_admin_any = clear_admin.to_any()?;
+    record_dispatch(deps.storage, &env, &clear_admin_any, &clear_admin)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_clear_admin")
+        .add_attribute("contract", contract)
+        .add_message(
+            ExecBuilder::new(&env)
+                .push(clear_admin_any)
+                .into_msg(deps.storage)?,
+        ))
+}
+
+pub fn execute_grant(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    grantee: String,
+    authorization: Authorization,
+    expiration: Option<Timestamp>,
+    expires_in_seconds: Option<u64>,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let expiration = match (expiration, expires_in_seconds) {
+        (Some(_), Some(_)) => return Err(ContractError::AmbiguousExpiration {}),
+        (None, Some(seconds)) => Some(env.block.time.plus_seconds(seconds)),
+        (expiration, None) => expiration,
+    };
+    // the chain rejects grants expiring in the past, failing here names the block time
+    if let Some(expiration) = expiration {
+        if expiration <= env.block.time {
+            return Err(ContractError::ExpirationInPast {
+                expiration,
+                block_time: env.block.time,
+            });
+        }
+    }
+    let grantee = deps.api.addr_validate(&grantee)?;
+    let granter = GRANTER.load(deps.storage)?;
+
+    let authorization = encode_authorization(deps.as_ref(), authorization)?;
+    let authorization_type_url = authorization.type_url.clone();
+    let mut grant = Grant::default();
+    grant.authorization = Some(authorization).into();
+    grant.expiration = expiration.map(timestamp).into();
+
+    let mut msg_grant = MsgGrant::default();
+    msg_grant.granter = granter.to_string();
+    msg_grant.grantee = grantee.to_string();
+    msg_grant.grant = Some(grant).into();
+    let msg_grant_any = msg_grant.to_any()?;
+    record_dispatch(deps.storage, &env, &msg_grant_any, &msg_grant)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_grant")
+        .add_attribute("grantee", grantee)
+        .add_attribute("authorization_type_url", authorization_type_url)
+        .add_attribute(
+            "expiration",
+            expiration.map_or_else(|| "none".to_string(), |expiration| expiration.to_string()),
+        )
+        .add_message(
+            ExecBuilder::new(&env)
+                .push(msg_grant_any)
+                .into_msg(deps.storage)?,
+        ))
+}
+
+pub fn execute_revoke(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    grantee: String,
+    msg_type_url: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let grantee = deps.api.addr_validate(&grantee)?;
+    let granter = GRANTER.load(deps.storage)?;
+
+    let mut revoke = MsgRevoke::default();
+    revoke.granter = granter.to_string();
+    revoke.grantee = grantee.to_string();
+    revoke.msg_type_url = msg_type_url.clone();
+    let revoke_any = revoke.to_any()?;
+    record_dispatch(deps.storage, &env, &revoke_any, &revoke)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_revoke")
+        .add_attribute("grantee", grantee)
+        .add_attribute("msg_type_url", msg_type_url)
+        .add_message(
+            ExecBuilder::new(&env)
+                .push(revoke_any)
+                .into_msg(deps.storage)?,
+        ))
+}
+
+pub fn execute_add_recipient(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let address = deps.api.addr_validate(&address)?;
+
+    // adding an address twice is a no-op
+    RECIPIENTS.save(deps.storage, &address, &Empty {})?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_add_recipient")
+        .add_attribute("address", address))
+}
+
+pub fn execute_remove_recipient(
+    deps: DepsMut,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let address = deps.api.addr_validate(&address)?;
+
+    RECIPIENTS.remove(deps.storage, &address);
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_remove_recipient")
+        .add_attribute("address", address))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_propose_send_enabled(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    authority: String,
+    denom: String,
+    enabled: bool,
+    deposit: Vec<Coin>,
+    title: String,
+    summary: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let authority = deps.api.addr_validate(&authority)?;
+
+    let mut send_enabled = SendEnabled::default();
+    send_enabled.denom = denom.clone();
+    send_enabled.enabled = enabled;
+
+    let mut set_send_enabled = MsgSetSendEnabled::default();
+    set_send_enabled.authority = authority.to_string();
+    set_send_enabled.send_enabled = vec![send_enabled];
+
+    // the proposal carries the authority gated message packed as a nested Any, gov executes it
+    // with its own module account as the signer once the proposal passes
+    let mut proposal = MsgSubmitProposal::default();
+    let set_send_enabled_any = set_send_enabled.to_any()?;
+    record_dispatch(deps.storage, &env, &set_send_enabled_any, &set_send_enabled)?;
+    proposal.messages = vec![set_send_enabled_any];
+    proposal.initial_deposit = deposit
+        .iter()
+        .map(|c| {
+            let mut coin = CosmosGov::Coin::default();
+            coin.amount = c.amount.to_string();
+            coin.denom = c.denom.clone();
+            coin
+        })
+        .collect();
+    proposal.proposer = env.contract.address.to_string();
+    proposal.title = title;
+    proposal.summary = summary;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_propose_send_enabled")
+        .add_attribute("denom", denom)
+        .add_attribute("enabled", enabled.to_string())
+        .add_message(proposal.to_stargate()?))
+}
+
+pub fn execute_split_send(
+    deps: DepsMut,
+    env: Env,
+    denom: String,
+    amount: Uint128,
+    recipients: Vec<SplitRecipient>,
+) -> Result<Response, ContractError> {
+    let shares = split_amount(deps.api, amount, &recipients)?;
+    for (index, recipient) in recipients.iter().enumerate() {
+        let address = deps.api.addr_validate(&recipient.address)?;
+        ensure_recipient_allowed(deps.storage, &address, index)?;
+    }
+    let granter = GRANTER.load(deps.storage)?;
+    let fee_config = FEE_CONFIG.may_load(deps.storage)?;
+
+    let mut sends = Vec::with_capacity(shares.len() + 1);
+    let mut coins = Vec::with_capacity(shares.len());
+    let mut fees = Vec::new();
+    for (address, share) in shares {
+        let coin = Coin::new(share.u128(), denom.clone());
+        let (main, fee) = deduct_fee(fee_config.as_ref(), std::slice::from_ref(&coin))?;
+        sends.push(send_any(
+            deps.storage,
+            &env,
+            &granter,
+            address.as_str(),
+            &main,
+        )?);
+        fees.extend(fee);
+        coins.push(coin);
+    }
+    let fee = sum_coins(&fees)?;
+    sends.extend(fee_send_any(
+        deps.storage,
+        &env,
+        &granter,
+        fee_config,
+        &fee,
+    )?);
+    let msg = ExecBuilder::new(&env)
+        .extend(sends)
+        .into_msg(deps.storage)?;
+    GUARD.enter(deps.storage, SPEND_REPLY_ID)?;
+    PENDING_SPEND.save(deps.storage, &coins)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_split_send")
+        .add_events(fee_event(&fee))
+        .add_attribute("denom", denom)
+        .add_attribute("amount", amount)
+        .add_submessage(SubMsg::reply_on_success(msg, SPEND_REPLY_ID)))
+}
+
+pub fn execute_multi_send(
+    deps: DepsMut,
+    env: Env,
+    outputs: Vec<SendSpec>,
+) -> Result<Response, ContractError> {
+    if outputs.is_empty() {
+        return Err(ContractError::EmptyRecipients {});
+    }
+    let granter = GRANTER.load(deps.storage)?;
+    let fee_config = FEE_CONFIG.may_load(deps.storage)?;
+
+    // the fee of every output goes to the collector in one extra output, so the single input still
+    // matches the sum of the outputs as the bank module requires
+    let mut proto_outputs = Vec::with_capacity(outputs.len() + 1);
+    let mut fees = Vec::new();
+    for (index, send) in outputs.iter().enumerate() {
+        let recipient = validate_send(deps.as_ref(), send, index)?;
+        let (main, fee) = deduct_fee(fee_config.as_ref(), &send.coins)?;
+        fees.extend(fee);
+
+        let mut output = Output::default();
+        output.address = recipient.to_string();
+        output.coins = proto_coins(&main);
+        proto_outputs.push(output);
+    }
+    let fee = sum_coins(&fees)?;
+    if let Some(config) = fee_config.filter(|_| !fee.is_empty()) {
+        let mut output = Output::default();
+        output.address = config.collector.to_string();
+        output.coins = proto_coins(&fee);
+        proto_outputs.push(output);
+    }
+    let coins = sum_coins(outputs.iter().flat_map(|send| &send.coins))?;
+
+    let mut input = Input::default();
+    input.address = granter.to_string();
+    input.coins = proto_coins(&coins);
+    let mut multi_send = MsgMultiSend::default();
+    multi_send.inputs = vec![input];
+    multi_send.outputs = proto_outputs;
+    let any = multi_send.to_any()?;
+    record_dispatch(deps.storage, &env, &any, &multi_send)?;
+    let msg = ExecBuilder::new(&env).push(any).into_msg(deps.storage)?;
+    GUARD.enter(deps.storage, SPEND_REPLY_ID)?;
+    PENDING_SPEND.save(deps.storage, &coins)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_multi_send")
+        .add_events(fee_event(&fee))
+        .add_attribute("outputs", outputs.len().to_string())
+        .add_submessage(SubMsg::reply_on_success(msg, SPEND_REPLY_ID)))
+}
+
+pub fn execute_exec_signed(
+    deps: DepsMut,
+    env: Env,
+    payload: Binary,
+    signature: Binary,
+    pubkey: Binary,
+) -> Result<Response, ContractError> {
+    let signer = SIGNER
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoSigner {})?;
+
+    let hash = Sha256::digest(payload.as_slice());
+    if !deps
+        .api
+        .secp256k1_verify(&hash, signature.as_slice(), pubkey.as_slice())
+        .map_err(StdError::from)?
+    {
+        return Err(ContractError::InvalidSignature {});
+    }
+
+    let prefix = bech32_prefix(env.contract.address.as_str())?;
+    let address = secp256k1_address(pubkey.as_slice(), &prefix)?;
+    if address != signer.as_str() {
+        return Err(ContractError::UnauthorizedSigner { address });
+    }
+
+    // the signature covers the exact bytes, so a non canonical encoding of the same payload would
+    // be a different authorization
+    let signed: SignedPayload = from_json(payload.as_slice())?;
+    if canonical_json(&signed)?.as_bytes() != payload.as_slice() {
+        return Err(ContractError::InvalidCanonicalJson {
+            reason: "payload is not canonically encoded".to_string(),
+        });
+    }
+
+    if let Some(last) = SIGNER_NONCE.may_load(deps.storage)? {
+        if signed.nonce <= last {
+            return Err(ContractError::NonceReused {
+                nonce: signed.nonce,
+            });
+        }
+    }
+    SIGNER_NONCE.save(deps.storage, &signed.nonce)?;
+
+    let recipient = validate_send(deps.as_ref(), &signed.send, 0)?;
+    let granter = GRANTER.load(deps.storage)?;
+    let (msg, fee) = exec_send_msg(
+        deps.storage,
+        &env,
+        &granter,
+        recipient.as_str(),
+        &signed.send.coins,
+    )?;
+    GUARD.enter(deps.storage, SPEND_REPLY_ID)?;
+    PENDING_SPEND.save(deps.storage, &signed.send.coins)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_exec_signed")
+        .add_events(fee_event(&fee))
+        .add_attribute("signer", signer)
+        .add_attribute("nonce", signed.nonce.to_string())
+        .add_submessage(SubMsg::reply_on_success(msg, SPEND_REPLY_ID)))
+}
+
+// validate_send checks the recipient and coins of a send spec, the index is its position in the
+// batch it is part of.
+fn validate_send(deps: Deps, send: &SendSpec, index: usize) -> Result<Addr, ContractError> {
+    let recipient = deps.api.addr_validate(&send.to_address)?;
+    ensure_recipient_allowed(deps.storage, &recipient, index)?;
+    if send.coins.is_empty() {
+        return Err(ContractError::EmptyCoins {});
+    }
+    if let Some(coin) = send.coins.iter().find(|coin| coin.amount.is_zero()) {
+        return Err(ContractError::ZeroAmount {
+            denom: coin.denom.clone(),
+        });
+    }
+
+    Ok(recipient)
+}
+
+pub fn execute_set_type_url_policy(
+    deps: DepsMut,
+    info: MessageInfo,
+    mode: PolicyMode,
+    type_urls: Vec<String>,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    let res = Response::new()
+        .add_attribute("method", "execute_set_type_url_policy")
+        .add_attribute("type_urls", type_urls.len().to_string());
+    TYPE_URL_POLICY.save(deps.storage, &TypeUrlPolicy { mode, type_urls })?;
+
+    Ok(res)
+}
+
+pub fn execute_set_rate_limit(
+    deps: DepsMut,
+    info: MessageInfo,
+    limit: Option<RateLimit>,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    let res = Response::new().add_attribute("method", "execute_set_rate_limit");
+    let Some(limit) = limit else {
+        RATE_LIMIT.remove(deps.storage);
+        return Ok(res.add_attribute("limit", "none"));
+    };
+    if limit.window_blocks == 0 {
+        return Err(ContractError::InvalidRateLimit {
+            reason: "window_blocks must be positive".to_string(),
+        });
+    }
+    // windows of the previous limit would count towards the new one
+    RATE_WINDOWS.clear(deps.storage);
+    RATE_LIMIT.save(deps.storage, &limit)?;
+
+    Ok(res
+        .add_attribute("limit", limit.amount)
+        .add_attribute("window_blocks", limit.window_blocks.to_string()))
+}
+
+pub fn execute_upload_chunk(
+    deps: DepsMut,
+    info: MessageInfo,
+    session_id: String,
+    index: u32,
+    data: Binary,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    if data.len() > MAX_UPLOAD_SIZE {
+        return Err(ContractError::UploadTooLarge {
+            max: MAX_UPLOAD_SIZE,
+        });
+    }
+
+    // chunks may arrive in any order, uploading an index again replaces it
+    UPLOAD_CHUNKS.save(deps.storage, (&session_id, index), &data)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_upload_chunk")
+        .add_attribute("session_id", session_id)
+        .add_attribute("index", index.to_string()))
+}
+
+pub fn execute_exec_uploaded(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    session_id: String,
+    type_url: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    let chunks = UPLOAD_CHUNKS
+        .prefix(&session_id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    if chunks.is_empty() {
+        return Err(ContractError::EmptySession { session_id });
+    }
+
+    let mut value = Vec::new();
+    for (expected, (index, data)) in (0u32..).zip(chunks.iter()) {
+        if *index != expected {
+            return Err(ContractError::MissingChunk {
+                session_id,
+                index: expected,
+            });
+        }
+        if value.len() + data.len() > MAX_UPLOAD_SIZE {
+            return Err(ContractError::UploadTooLarge {
+                max: MAX_UPLOAD_SIZE,
+            });
+        }
+        value.extend_from_slice(data);
+    }
+
+    // the value has to decode as the message registered for the type url, so a malformed or
+    // mistyped upload fails here instead of inside the MsgExec
+    decode_registered(&type_url, &value)?;
+
+    for (index, _) in chunks {
+        UPLOAD_CHUNKS.remove(deps.storage, (&session_id, index));
+    }
+
+    let size = value.len();
+    let any = any(type_url.clone(), value);
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_exec_uploaded")
+        .add_attribute("session_id", session_id)
+        .add_attribute("type_url", type_url)
+        .add_attribute("size", size.to_string())
+        .add_message(ExecBuilder::new(&env).push(any).into_msg(deps.storage)?))
+}
+
+pub fn execute_schedule_send(
+    deps: DepsMut,
+    info: MessageInfo,
+    to_address: String,
+    coins: Vec<Coin>,
+    execute_after_height: u64,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let send = SendSpec { to_address, coins };
+    let recipient = validate_send(deps.as_ref(), &send, 0)?;
+
+    let id = SCHEDULED_NEXT_ID
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    SCHEDULED_NEXT_ID.save(deps.storage, &(id + 1))?;
+    SCHEDULED.save(
+        deps.storage,
+        id,
+        &ScheduledSend {
+            send: SendSpec {
+                to_address: recipient.to_string(),
+                coins: send.coins,
+            },
+            execute_after_height,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_schedule_send")
+        .add_attribute("id", id.to_string())
+        .add_attribute("execute_after_height", execute_after_height.to_string()))
+}
+
+pub fn execute_execute_scheduled(
+    deps: DepsMut,
+    env: Env,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let scheduled = SCHEDULED
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::ScheduledNotFound { id })?;
+    if env.block.height <= scheduled.execute_after_height {
+        return Err(ContractError::TooEarly {
+            remaining_blocks: scheduled.execute_after_height + 1 - env.block.height,
+        });
+    }
+
+    // removing the entry before dispatching makes a second execution fail with not found
+    SCHEDULED.remove(deps.storage, id);
+
+    let granter = GRANTER.load(deps.storage)?;
+    let send = scheduled.send;
+    let (msg, fee) = exec_send_msg(deps.storage, &env, &granter, &send.to_address, &send.coins)?;
+    GUARD.enter(deps.storage, SPEND_REPLY_ID)?;
+    PENDING_SPEND.save(deps.storage, &send.coins)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_execute_scheduled")
+        .add_events(fee_event(&fee))
+        .add_attribute("id", id.to_string())
+        .add_submessage(SubMsg::reply_on_success(msg, SPEND_REPLY_ID)))
+}
+
+pub fn execute_cancel_scheduled(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    if !SCHEDULED.has(deps.storage, id) {
+        return Err(ContractError::ScheduledNotFound { id });
+    }
+    SCHEDULED.remove(deps.storage, id);
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_cancel_scheduled")
+        .add_attribute("id", id.to_string()))
+}
+
+pub fn execute_enqueue(deps: DepsMut, send: SendSpec) -> Result<Response, ContractError> {
+    // everything flush relies on is checked here, so a staged send can't fail it later
+    let recipient = validate_send(deps.as_ref(), &send, 0)?;
+
+    let id = QUEUE_NEXT_ID.may_load(deps.storage)?.unwrap_or_default();
+    QUEUE_NEXT_ID.save(deps.storage, &(id + 1))?;
+    QUEUE.save(
+        deps.storage,
+        id,
+        &SendSpec {
+            to_address: recipient.to_string(),
+            coins: send.coins,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "execute_enqueue")
+        .add_attribute("id", id.to_string()))
+}
+
+pub fn execute_flush(deps: DepsMut, env: Env, max: Option<u32>) -> Result<Response, ContractError> {
+    let max = max.map_or(usize::MAX, |max| max as usize);
+    let staged = QUEUE
+        .range(deps.storage, None, None, Order::Ascending)
+        .take(max)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let res = Response::new()
+        .add_attribute("method", "execute_flush")
+        .add_attribute("flushed", staged.len().to_string());
+    if staged.is_empty() {
+        return Ok(res);
+    }
+
+    let granter = GRANTER.load(deps.storage)?;
+    let fee_config = FEE_CONFIG.may_load(deps.storage)?;
+    let mut sends = Vec::with_capacity(staged.len() + 1);
+    let mut coins = Vec::new();
+    let mut fees = Vec::new();
+    for (id, send) in staged {
+        QUEUE.remove(deps.storage, id);
+        let (main, fee) = deduct_fee(fee_config.as_ref(), &send.coins)?;
+        sends.push(send_any(
+            deps.storage,
+            &env,
+            &granter,
+            &send.to_address,
+            &main,
+        )?);
+        fees.extend(fee);
+        coins.extend(send.coins);
+    }
+    let fee = sum_coins(&fees)?;
+    sends.extend(fee_send_any(
+        deps.storage,
+        &env,
+        &granter,
+        fee_config,
+        &fee,
+    )?);
+    let msg = ExecBuilder::new(&env)
+        .extend(sends)
+        .into_msg(deps.storage)?;
+    GUARD.enter(deps.storage, SPEND_REPLY_ID)?;
+    PENDING_SPEND.save(deps.storage, &coins)?;
+
+    Ok(res
+        .add_events(fee_event(&fee))
+        .add_submessage(SubMsg::reply_on_success(msg, SPEND_REPLY_ID)))
+}
+
+// ensure_recipient_allowed checks the address against the allowlist, an empty allowlist allows
+// everybody. The index of the recipient in its batch is reported, single sends are at index 0.
+fn ensure_recipient_allowed(
+    storage: &dyn Storage,
+    address: &Addr,
+    index: usize,
+) -> Result<(), ContractError> {
+    if RECIPIENTS.has(storage, address) || RECIPIENTS.is_empty(storage) {
+        return Ok(());
+    }
+
+    Err(ContractError::RecipientNotAllowed {
+        address: address.to_string(),
+        index,
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        SEND_AND_ASSERT_REPLY_ID => reply_send_and_assert(deps, env),
+        SPEND_REPLY_ID => reply_spend(deps, env),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+fn reply_send_and_assert(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    GUARD.exit(deps.storage)?;
+    let pending = PENDING_ASSERT.load(deps.storage)?;
+    PENDING_ASSERT.remove(deps.storage);
+
+    let balances_after = query_balances(
+        deps.as_ref(),
+        &pending.recipient,
+        &pending.expected_increase,
+    )?;
+    for ((expected, before), after) in pending
+        .expected_increase
+        .iter()
+        .zip(pending.balances_before.iter())
+        .zip(balances_after.iter())
+    {
+        // burn rate and send commission are charged to the sender on top of the sent amount, the
+        // contract fee is taken out of it, so the caller states the increase net of the fee
+        let actual = after.checked_sub(*before).unwrap_or_default();
+        if actual != expected.amount {
+            return Err(ContractError::UnexpectedBalanceIncrease {
+                denom: expected.denom.clone(),
+                expected: expected.amount,
+                actual,
+            });
+        }
+    }
+
+    record_spend(deps.storage, env.block.height)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "reply_send_and_assert")
+        .add_attribute("recipient", pending.recipient))
+}
+
+fn reply_spend(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    GUARD.exit(deps.storage)?;
+    record_spend(deps.storage, env.block.height)?;
+
+    Ok(Response::new().add_attribute("method", "reply_spend"))
+}
+
+// record_spend adds the coins of the pending send to the totals, it is only called from success
+// replies so failed execs never count. A send over the rate limit fails the reply, which reverts
+// the send with it.
+fn record_spend(storage: &mut dyn Storage, height: u64) -> Result<(), ContractError> {
+    let coins = PENDING_SPEND.load(storage)?;
+    PENDING_SPEND.remove(storage);
+    let rate_limit = RATE_LIMIT.may_load(storage)?;
+
+    for coin in coins {
+        if let Some(limit) = &rate_limit {
+            if classify_denom(&coin.denom) == DenomKind::Native {
+                limit_rate(storage, limit, height, &coin)?;
+            }
+        }
+        SPENT.update(storage, &coin.denom, |spent| -> StdResult<_> {
+            let spent = spent.unwrap_or_default();
+            Ok(match spent.amount.checked_add(coin.amount) {
+                Ok(amount) => Spent { amount, ..spent },
+                Err(_) => Spent {
+                    amount: Uint128::MAX,
+                    saturated: true,
+                },
+            })
+        })?;
+    }
+
+    Ok(())
+}
+
+// limit_rate adds the coin to the window of its denom, starting a new window once the current one
+// ended
+fn limit_rate(
+    storage: &mut dyn Storage,
+    limit: &RateLimit,
+    height: u64,
+    coin: &Coin,
+) -> Result<(), ContractError> {
+    let window = RATE_WINDOWS
+        .may_load(storage, &coin.denom)?
+        .filter(|window| height < window.start + limit.window_blocks)
+        .unwrap_or(RateWindow {
+            start: height,
+            spent: Uint128::zero(),
+        });
+    let spent = window.spent.saturating_add(coin.amount);
+    if spent > limit.amount {
+        return Err(ContractError::RateLimitExceeded {
+            denom: coin.denom.clone(),
+            limit: limit.amount,
+            resets_at: window.start + limit.window_blocks,
+        });
+    }
+    RATE_WINDOWS.save(storage, &coin.denom, &RateWindow { spent, ..window })?;
+
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::ChainInfo {} => to_json_binary(&query_chain_info(deps, env)?),
+        QueryMsg::Session { id } => to_json_binary(&query_session(deps, id)?),
+        QueryMsg::Grants {} => to_json_binary(&query_grants(deps, env)?),
+        QueryMsg::RemainingSpendLimit { granter } => {
+            to_json_binary(&query_remaining_spend_limit(deps, env, granter)?)
+        }
+        QueryMsg::TypeUrlPolicy {} => to_json_binary(&TYPE_URL_POLICY.may_load(deps.storage)?),
+        QueryMsg::RateLimit {} => to_json_binary(&RATE_LIMIT.may_load(deps.storage)?),
+        QueryMsg::Simulate { send } => to_json_binary(&query_simulate(deps, env, send)?),
+        QueryMsg::Recipients { start_after, limit } => {
+            to_json_binary(&query_recipients(deps, start_after, limit)?)
+        }
+        QueryMsg::Queue { start_after, limit } => {
+            to_json_binary(&query_queue(deps, start_after, limit)?)
+        }
+        QueryMsg::Scheduled { start_after, limit } => {
+            to_json_binary(&query_scheduled(deps, start_after, limit)?)
+        }
+        QueryMsg::TotalSpent {
+            denom,
+            start_after,
+            limit,
+        } => to_json_binary(&query_total_spent(deps, denom, start_after, limit)?),
+        #[cfg(feature = "debug")]
+        QueryMsg::RawRange { start, end, limit } => {
+            to_json_binary(&raw_range(deps.storage, start, end, limit)?)
+        }
+        #[cfg(feature = "debug")]
+        QueryMsg::DebugLog { limit } => to_json_binary(&debug_log(deps.storage, limit)?),
+    }
+}
+
+fn query_chain_info(deps: Deps, env: Env) -> StdResult<ChainInfoResponse> {
+    Ok(ChainInfoResponse {
+        chain_id: env.block.chain_id,
+        height: env.block.height,
+        time: env.block.time,
+        expected_chain_id: EXPECTED_CHAIN_ID.may_load(deps.storage)?,
+        instantiate_height: INSTANTIATE_HEIGHT.load(deps.storage)?,
+    })
+}
+
+fn query_grants(deps: Deps, env: Env) -> StdResult<GrantsResponse> {
+    let mut request = QueryGranteeGrantsRequest::default();
+    request.grantee = env.contract.address.to_string();
+    let res: QueryGranteeGrantsResponse =
+        query_stargate(deps, "/cosmos.authz.v1beta1.Query/GranteeGrants", &request)?;
+
+    let grants = res
+        .grants
+        .into_iter()
+        .map(|grant| {
+            Ok(GrantInfo {
+                granter: grant.granter,
+                expiration: grant.expiration.as_ref().map(block_time),
+                authorization: decode_authorization(grant.authorization.unwrap_or_default())?,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    Ok(GrantsResponse { grants })
+}
+
+fn query_remaining_spend_limit(
+    deps: Deps,
+    env: Env,
+    granter: String,
+) -> StdResult<RemainingSpendLimitResponse> {
+    let mut request = QueryGrantsRequest::default();
+    request.granter = deps.api.addr_validate(&granter)?.to_string();
+    request.grantee = env.contract.address.to_string();
+    request.msg_type_url = MsgSend::TYPE_URL.to_string();
+    let res: QueryGrantsResponse =
+        query_stargate(deps, "/cosmos.authz.v1beta1.Query/Grants", &request)?;
+
+    let authorization = res
+        .grants
+        .into_iter()
+        .next()
+        .filter(|grant| grant.authorization.is_some())
+        .map(|grant| grant.authorization.unwrap_or_default())
+        .ok_or_else(|| StdError::not_found("send grant"))?;
+
+    Ok(match decode_authorization(authorization)? {
+        Authorization::Send { spend_limit, .. } => {
+            RemainingSpendLimitResponse::SpendLimit { coins: spend_limit }
+        }
+        Authorization::Generic { .. } => RemainingSpendLimitResponse::NotSendAuthorization {
+            type_url: GenericAuthorization::TYPE_URL.to_string(),
+        },
+        Authorization::Stake { .. } => RemainingSpendLimitResponse::NotSendAuthorization {
+            type_url: StakeAuthorization::TYPE_URL.to_string(),
+        },
+        Authorization::Unknown { type_url, .. } => {
+            RemainingSpendLimitResponse::NotSendAuthorization { type_url }
+        }
+    })
+}
+
+// decode_authorization unpacks the authorization types the contract knows, anything else is
+// returned raw.
+fn decode_authorization(any: Any) -> StdResult<Authorization> {
+    let parse_err = |err: ProtoError| StdError::parse_err(&any.type_url, err.to_string());
+
+    match any.type_url.as_str() {
+        SendAuthorization::TYPE_URL => {
+            let send = SendAuthorization::decode_bytes(&any.value).map_err(parse_err)?;
+            let spend_limit = send
+                .spend_limit
+                .into_iter()
+                .map(|coin| {
+                    Ok(Coin {
+                        amount: coin.amount.parse::<Uint128>()?,
+                        denom: coin.denom,
+                    })
+                })
+                .collect::<StdResult<_>>()?;
+            Ok(Authorization::Send {
+                spend_limit,
+                allow_list: send.allow_list,
+            })
+        }
+        GenericAuthorization::TYPE_URL => {
+            let generic = GenericAuthorization::decode_bytes(&any.value).map_err(parse_err)?;
+            Ok(Authorization::Generic { msg: generic.msg })
+        }
+        StakeAuthorization::TYPE_URL => {
+            let stake = StakeAuthorization::decode_bytes(&any.value).map_err(parse_err)?;
+            let fields = StakeAuthorizationFields::from_proto(stake);
+            let max_tokens = fields
+                .max_tokens
+                .map(|coin| -> StdResult<_> {
+                    Ok(Coin {
+                        amount: coin.amount.parse::<Uint128>()?,
+                        denom: coin.denom,
+                    })
+                })
+                .transpose()?;
+            let authorization_type = match fields.authorization_type {
+                AUTHORIZATION_TYPE_DELEGATE => StakeAuthorizationType::Delegate,
+                AUTHORIZATION_TYPE_UNDELEGATE => StakeAuthorizationType::Undelegate,
+                AUTHORIZATION_TYPE_REDELEGATE => StakeAuthorizationType::Redelegate,
+                other => {
+                    return Err(StdError::parse_err(
+                        &any.type_url,
+                        format!("unknown authorization type {other}"),
+                    ))
+                }
+            };
+            Ok(Authorization::Stake {
+                max_tokens,
+                validators: fields.validators.map(|policy| match policy {
+                    ValidatorPolicy::AllowList(validators) => {
+                        StakeValidators::AllowList(validators)
+                    }
+                    ValidatorPolicy::DenyList(validators) => StakeValidators::DenyList(validators),
+                }),
+                authorization_type,
+            })
+        }
+        _ => Ok(Authorization::Unknown {
+            value: Binary::from(any.value.as_slice()),
+            type_url: any.type_url,
+        }),
+    }
+}
+
+// encode_authorization packs an authorization into the Any carried by a grant.
+fn encode_authorization(deps: Deps, authorization: Authorization) -> Result<Any, ContractError> {
+    let any = match authorization {
+        Authorization::Send {
+            spend_limit,
+            allow_list,
+        } => {
+            let mut send = SendAuthorization::default();
+            send.spend_limit = spend_limit
+                .into_iter()
+                .map(|coin| {
+                    let mut proto_coin = CosmosAuthzQuery::Coin::default();
+                    proto_coin.denom = coin.denom;
+                    proto_coin.amount = coin.amount.to_string();
+                    proto_coin
+                })
+                .collect();
+            send.allow_list = allow_list
+                .iter()
+                .map(|address| Ok(deps.api.addr_validate(address)?.to_string()))
+                .collect::<StdResult<_>>()?;
+            send.to_any()?
+        }
+        Authorization::Generic { msg } => {
+            let mut generic = GenericAuthorization::default();
+            generic.msg = msg;
+            generic.to_any()?
+        }
+        Authorization::Stake {
+            max_tokens,
+            validators,
+            authorization_type,
+        } => StakeAuthorizationFields {
+            max_tokens: max_tokens.map(|coin| {
+                let mut proto_coin = CosmosAuthzQuery::Coin::default();
+                proto_coin.denom = coin.denom;
+                proto_coin.amount = coin.amount.to_string();
+                proto_coin
+            }),
+            // validator operator addresses are checked by the staking module
+            validators: validators.map(|validators| match validators {
+                StakeValidators::AllowList(validators) => ValidatorPolicy::AllowList(validators),
+                StakeValidators::DenyList(validators) => ValidatorPolicy::DenyList(validators),
+            }),
+            authorization_type: match authorization_type {
+                StakeAuthorizationType::Delegate => AUTHORIZATION_TYPE_DELEGATE,
+                StakeAuthorizationType::Undelegate => AUTHORIZATION_TYPE_UNDELEGATE,
+                StakeAuthorizationType::Redelegate => AUTHORIZATION_TYPE_REDELEGATE,
+            },
+        }
+        .into_proto()
+        .to_any()?,
+        Authorization::Unknown { type_url, value } => any(type_url, value.to_vec()),
+    };
+    Ok(any)
+}
+
+// query_stargate runs a protobuf encoded query, the path has to be accepted by the chain's
+// stargate querier.
+fn query_stargate<R: ProtoMessage>(
+    deps: Deps,
+    path: &str,
+    request: &impl ProtoMessage,
+) -> StdResult<R> {
+    let data = request
+        .encode_bytes()
+        .map_err(|err| StdError::serialize_err(path, err.to_string()))?;
+    let res: Binary = deps.querier.query(&QueryRequest::Stargate {
+        path: path.to_string(),
+        data: data.into(),
+    })?;
+
+    R::decode_bytes(res.as_slice()).map_err(|err| StdError::parse_err(path, err.to_string()))
+}
+
+fn query_session(deps: Deps, id: String) -> StdResult<SessionResponse> {
+    let mut chunks = Vec::new();
+    let mut total_size = 0;
+    for chunk in UPLOAD_CHUNKS
+        .prefix(&id)
+        .range(deps.storage, None, None, Order::Ascending)
+    {
+        let (index, data) = chunk?;
+        chunks.push(index);
+        total_size += data.len() as u64;
+    }
+
+    Ok(SessionResponse { chunks, total_size })
+}
+
+fn query_simulate(deps: Deps, env: Env, send: SendSpec) -> StdResult<SimulateResponse> {
+    let granter = GRANTER.load(deps.storage)?;
+    let any = send_msg(&granter, &send.to_address, &send.coins)
+        .to_any()
+        .map_err(|err| StdError::generic_err(err.to_string()))?;
+    let encoded_size = ExecBuilder::new(&env)
+        .push(any)
+        .build(deps.storage)
+        .map_err(|err| StdError::generic_err(err.to_string()))?
+        .encoded_size();
+
+    Ok(exec_send_gas(encoded_size, send.coins.len()))
+}
+
+fn query_recipients(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<RecipientsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start_after = start_after
+        .map(|address| deps.api.addr_validate(&address))
+        .transpose()?;
+
+    let recipients = RECIPIENTS
+        .keys(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+
+    Ok(RecipientsResponse { recipients })
+}
+
+fn query_queue(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<QueueResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let entries = QUEUE
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| entry.map(|(id, send)| QueueEntry { id, send }))
+        .collect::<StdResult<_>>()?;
+
+    Ok(QueueResponse { entries })
+}
+
+fn query_scheduled(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<ScheduledResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let entries = SCHEDULED
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|entry| entry.map(|(id, scheduled)| ScheduledEntry { id, scheduled }))
+        .collect::<StdResult<_>>()?;
+
+    Ok(ScheduledResponse { entries })
+}
+
+fn query_total_spent(
+    deps: Deps,
+    denom: Option<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TotalSpentResponse> {
+    let entries: Vec<(String, Spent)> = match denom {
+        Some(denom) => {
+            let spent = SPENT.may_load(deps.storage, &denom)?.unwrap_or_default();
+            vec![(denom, spent)]
+        }
+        None => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            SPENT
+                .range(
+                    deps.storage,
+                    start_after.as_deref().map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit)
+                .collect::<StdResult<_>>()?
+        }
+    };
+
+    Ok(TotalSpentResponse {
+        saturated: entries.iter().any(|(_, spent)| spent.saturated),
+        spent: entries
+            .into_iter()
+            .map(|(denom, spent)| Coin::new(spent.amount.u128(), denom))
+            .collect(),
+    })
+}
+
+fn query_balances(
+    deps: Deps,
+    address: &Addr,
+    coins: &[Coin],
+) -> Result<Vec<Uint128>, ContractError> {
+    coins
+        .iter()
+        .map(|coin| {
+            Ok(deps
+                .querier
+                .query_balance(address, coin.denom.clone())?
+                .amount)
+        })
+        .collect()
+}
+
+// exec_send_msg wraps a bank send from the granter into an authz MsgExec executed by the contract,
+// returning the fee skimmed from the coins.
+fn exec_send_msg(
+    storage: &mut dyn Storage,
+    env: &Env,
+    granter: &Addr,
+    to_address: &str,
+    coins: &[Coin],
+) -> Result<(CosmosMsg, Vec<Coin>), ContractError> {
+    let (anys, fee) = send_anys(storage, env, granter, to_address, coins)?;
+    Ok((ExecBuilder::new(env).extend(anys).into_msg(storage)?, fee))
+}
+
+// send_anys builds the bank sends of a transfer from the granter. With a fee configured the coins
+// are split into the send to the recipient and a send of the fee to the collector, the fee send is
+// left out when every fee rounds down to zero.
+fn send_anys(
+    storage: &mut dyn Storage,
+    env: &Env,
+    granter: &Addr,
+    to_address: &str,
+    coins: &[Coin],
+) -> Result<(Vec<Any>, Vec<Coin>), ContractError> {
+    let fee_config = FEE_CONFIG.may_load(storage)?;
+    let (main, fee) = deduct_fee(fee_config.as_ref(), coins)?;
+
+    let mut anys = Vec::with_capacity(2);
+    anys.push(send_any(storage, env, granter, to_address, &main)?);
+    anys.extend(fee_send_any(storage, env, granter, fee_config, &fee)?);
+
+    Ok((anys, fee))
+}
+
+// deduct_fee splits the coins into what is left after the fee and the fee itself, fees rounding
+// down to zero are left out.
+fn deduct_fee(
+    fee_config: Option<&FeeConfig>,
+    coins: &[Coin],
+) -> Result<(Vec<Coin>, Vec<Coin>), ContractError> {
+    let Some(config) = fee_config else {
+        return Ok((coins.to_vec(), Vec::new()));
+    };
+
+    let mut main = Vec::with_capacity(coins.len());
+    let mut fee = Vec::new();
+    for coin in coins {
+        let amount = coin
+            .amount
+            .checked_multiply_ratio(config.fee_bps, BPS_DENOMINATOR)
+            .map_err(|err| StdError::generic_err(err.to_string()))?;
+        main.push(Coin::new(
+            coin.amount
+                .checked_sub(amount)
+                .map_err(StdError::from)?
+                .u128(),
+            coin.denom.clone(),
+        ));
+        if !amount.is_zero() {
+            fee.push(Coin::new(amount.u128(), coin.denom.clone()));
+        }
+    }
+
+    Ok((main, fee))
+}
+
+fn send_any(
+    storage: &mut dyn Storage,
+    env: &Env,
+    granter: &Addr,
+    to_address: &str,
+    coins: &[Coin],
+) -> Result<Any, ContractError> {
+    let send = send_msg(granter, to_address, coins);
+    let any = send.to_any()?;
+    record_dispatch(storage, env, &any, &send)?;
+    Ok(any)
+}
+
+// fee_send_any builds the send of the fee to the collector, there is none without a fee.
+fn fee_send_any(
+    storage: &mut dyn Storage,
+    env: &Env,
+    granter: &Addr,
+    fee_config: Option<FeeConfig>,
+    fee: &[Coin],
+) -> Result<Option<Any>, ContractError> {
+    match fee_config {
+        Some(config) if !fee.is_empty() => Ok(Some(send_any(
+            storage,
+            env,
+            granter,
+            config.collector.as_str(),
+            fee,
+        )?)),
+        _ => Ok(None),
+    }
+}
+
+// sum_coins adds up the coins per denom, ordered by denom.
+fn sum_coins<'a>(coins: impl IntoIterator<Item = &'a Coin>) -> Result<Vec<Coin>, ContractError> {
+    let mut total: BTreeMap<&str, Uint128> = BTreeMap::new();
+    for coin in coins {
+        let sum = total.entry(&coin.denom).or_default();
+        *sum = sum.checked_add(coin.amount).map_err(StdError::from)?;
+    }
+
+    Ok(total
+        .into_iter()
+        .map(|(denom, amount)| Coin::new(amount.u128(), denom))
+        .collect())
+}
+
+// fee_event reports the fee skimmed by a handler, nothing is reported without a fee.
+fn fee_event(fee: &[Coin]) -> Option<Event> {
+    if fee.is_empty() {
+        return None;
+    }
+    let amount = fee
+        .iter()
+        .map(|coin| coin.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    Some(Event::new("fee_skimmed").add_attribute("amount", amount))
+}
+
+fn send_msg(granter: &Addr, to_address: &str, coins: &[Coin]) -> MsgSend {
+    let mut send = MsgSend::default();
+    send.from_address = granter.to_string();
+    send.to_address = to_address.to_string();
+    send.amount = proto_coins(coins);
+    send
+}
+
+fn proto_coins(coins: &[Coin]) -> Vec<CosmosBankSend::Coin> {
+    coins
+        .iter()
+        .map(|c| {
+            let mut coin = CosmosBankSend::Coin::default();
+            coin.amount = c.amount.to_string();
+            coin.denom = c.denom.clone();
+            coin
+        })
+        .collect()
+}
+
+// record_dispatch only keeps a log on test builds
+#[cfg(not(feature = "debug"))]
+fn record_dispatch(_: &mut dyn Storage, _: &Env, _: &Any, _: &impl ProtoMessage) -> StdResult<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::exhaustive_cases;
+    use common::guard::GuardError;
+    use common::test_exhaustive::{run_cases, Outcome};
+    use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+    use cosmwasm_std::{SubMsgResponse, SubMsgResult};
+    use cw_ownable::OwnershipError;
+
+    use super::*;
+    use crate::msg::DEFAULT_IDEMPOTENCY_TTL_BLOCKS;
+    use crate::testing::*;
+
+    fn send_and_assert(amount: u128, expected: u128) -> ExecuteMsg {
+        ExecuteMsg::SendAndAssert {
+            to_address: RECIPIENT.to_string(),
+            coins: coins(amount),
+            expected_recipient_increase: coins(expected),
+            idempotency_key: None,
+        }
+    }
+
+    fn reply_ok(deps: &mut TestDeps, id: u64) -> Result<Response, ContractError> {
+        reply_ok_at(deps, id, mock_env().block.height)
+    }
+
+    fn reply_ok_at(deps: &mut TestDeps, id: u64, height: u64) -> Result<Response, ContractError> {
+        reply(
+            deps.as_mut(),
+            env_at(height),
+            Reply {
+                id,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![],
+                    data: None,
+                }),
+            },
+        )
+    }
+
+    // executes the message at the height and runs the success reply of a dispatched send, which
+    // releases the guard for the next execute
+    fn exec_settled_at(
+        deps: &mut TestDeps,
+        height: u64,
+        msg: ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        let res = exec_at(deps, height, msg)?;
+        if res
+            .messages
+            .iter()
+            .any(|sub_msg| sub_msg.id == SPEND_REPLY_ID)
+        {
+            reply_ok_at(deps, SPEND_REPLY_ID, height)?;
+        }
+        Ok(res)
+    }
+
+    fn exec_settled(deps: &mut TestDeps, msg: ExecuteMsg) -> Result<Response, ContractError> {
+        exec_settled_at(deps, mock_env().block.height, msg)
+    }
+
+    fn set_rate_limit(amount: u128, window_blocks: u64) -> ExecuteMsg {
+        ExecuteMsg::SetRateLimit {
+            limit: Some(RateLimit {
+                amount: amount.into(),
+                window_blocks,
+            }),
+        }
+    }
+
+    #[test]
+    fn rate_limit_applies_per_window() {
+        let mut deps = setup(instantiate_msg());
+        exec(&mut deps, set_rate_limit(150, 10)).unwrap();
+        assert_eq!(
+            from_json::<Option<RateLimit>>(
+                &query(deps.as_ref(), mock_env(), QueryMsg::RateLimit {}).unwrap()
+            )
+            .unwrap(),
+            Some(RateLimit {
+                amount: 150u128.into(),
+                window_blocks: 10,
+            })
+        );
+
+        exec_settled_at(&mut deps, 100, transfer(100, None)).unwrap();
+        let err = exec_settled_at(&mut deps, 109, transfer(51, None)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::RateLimitExceeded { denom, limit, resets_at: 110 }
+                if denom == DENOM && limit.u128() == 150
+        ));
+        exec_settled_at(&mut deps, 109, transfer(50, None)).unwrap();
+
+        // the next window starts with the first send after the previous one ended
+        exec_settled_at(&mut deps, 115, transfer(150, None)).unwrap();
+        let err = exec_settled_at(&mut deps, 124, transfer(1, None)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::RateLimitExceeded { resets_at: 125, .. }
+        ));
+
+        exec(&mut deps, ExecuteMsg::SetRateLimit { limit: None }).unwrap();
+        exec_settled_at(&mut deps, 124, transfer(1000, None)).unwrap();
+    }
+
+    #[test]
+    fn rate_limit_only_applies_to_native_denoms() {
+        let mut deps = setup(instantiate_msg());
+        exec(&mut deps, set_rate_limit(100, 10)).unwrap();
+        for denom in [
+            "abc-core1qyqszqgpqyqszqgpqyqszqgpqyqszqgppae928",
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+        ] {
+            let send = ExecuteMsg::Transfer {
+                address: Addr::unchecked(RECIPIENT),
+                amount: 1000,
+                denom: denom.to_string(),
+                idempotency_key: None,
+            };
+            exec_settled(&mut deps, send).unwrap();
+        }
+
+        // every native denom has its own window
+        exec_settled(&mut deps, transfer(100, None)).unwrap();
+        let send = ExecuteMsg::Transfer {
+            address: Addr::unchecked(RECIPIENT),
+            amount: 100,
+            denom: "udevcore".to_string(),
+            idempotency_key: None,
+        };
+        exec_settled(&mut deps, send).unwrap();
+        assert!(matches!(
+            exec_settled(&mut deps, transfer(1, None)).unwrap_err(),
+            ContractError::RateLimitExceeded { .. }
+        ));
+    }
+
+    #[test]
+    fn rate_limit_is_set_by_the_owner_with_a_window() {
+        let mut deps = setup(instantiate_msg());
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            set_rate_limit(100, 10),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Ownership(_)));
+
+        let err = exec(&mut deps, set_rate_limit(100, 0)).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidRateLimit { .. }));
+    }
+
+    #[test]
+    fn execute_between_dispatch_and_reply_is_rejected() {
+        let mut deps = setup(instantiate_msg());
+        let res = exec(&mut deps, send_and_assert(100, 100)).unwrap();
+        assert_eq!(res.messages[0].id, SEND_AND_ASSERT_REPLY_ID);
+
+        // the callee of the submessage re-enters the contract before the reply
+        let err = exec(&mut deps, transfer(1, None)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Guard(GuardError::OperationInProgress {
+                reply_id: SEND_AND_ASSERT_REPLY_ID
+            })
+        ));
+        let err = exec(&mut deps, send_and_assert(1, 1)).unwrap_err();
+        assert!(matches!(err, ContractError::Guard(_)));
+
+        deps.querier.update_balance(RECIPIENT, coins(100));
+        reply_ok(&mut deps, SEND_AND_ASSERT_REPLY_ID).unwrap();
+        exec(&mut deps, transfer(1, None)).unwrap();
+    }
+
+    #[test]
+    fn execute_between_a_spend_and_its_reply_is_rejected() {
+        let mut deps = setup(instantiate_msg());
+        let res = exec(&mut deps, transfer(100, None)).unwrap();
+        assert_eq!(res.messages[0].id, SPEND_REPLY_ID);
+
+        let err = exec(&mut deps, split_send(100, &[("alice", 1)])).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::Guard(GuardError::OperationInProgress {
+                reply_id: SPEND_REPLY_ID
+            })
+        ));
+        let err = exec(
+            &mut deps,
+            ExecuteMsg::Flush {
+                max: None,
+                idempotency_key: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Guard(_)));
+
+        // the pending spend is recorded once and the next spend goes through
+        reply_ok(&mut deps, SPEND_REPLY_ID).unwrap();
+        assert_eq!(total_spent(&deps, Some(DENOM)).spent, coins(100));
+        exec_settled(&mut deps, split_send(100, &[("alice", 1)])).unwrap();
+        assert_eq!(total_spent(&deps, Some(DENOM)).spent, coins(200));
+    }
+
+    #[test]
+    fn type_url_policy_applies_to_every_exec() {
+        let mut deps = setup(instantiate_msg());
+        exec(
+            &mut deps,
+            ExecuteMsg::SetTypeUrlPolicy {
+                mode: PolicyMode::Deny,
+                type_urls: vec!["/cosmos.bank.*".to_string()],
+            },
+        )
+        .unwrap();
+
+        let err = exec(&mut deps, transfer(100, None)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::TypeUrlBlocked { type_url } if type_url == MsgSend::TYPE_URL
+        ));
+        let err = exec(
+            &mut deps,
+            ExecuteMsg::SplitSend {
+                denom: DENOM.to_string(),
+                amount: 100u128.into(),
+                recipients: vec![SplitRecipient {
+                    address: RECIPIENT.to_string(),
+                    weight: 1,
+                }],
+                idempotency_key: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::TypeUrlBlocked { .. }));
+
+        exec(
+            &mut deps,
+            ExecuteMsg::Revoke {
+                grantee: RECIPIENT.to_string(),
+                msg_type_url: MsgSend::TYPE_URL.to_string(),
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn type_url_policy_update_applies_from_the_next_exec() {
+        let mut deps = setup(instantiate_msg());
+        let set_policy = |mode: PolicyMode, type_urls: &[&str]| ExecuteMsg::SetTypeUrlPolicy {
+            mode,
+            type_urls: type_urls
+                .iter()
+                .map(|type_url| type_url.to_string())
+                .collect(),
+        };
+        exec(
+            &mut deps,
+            set_policy(PolicyMode::Allow, &[MsgSend::TYPE_URL]),
+        )
+        .unwrap();
+        exec_settled(&mut deps, transfer(100, None)).unwrap();
+        let err = exec(&mut deps, clear_admin()).unwrap_err();
+        assert!(matches!(err, ContractError::TypeUrlBlocked { .. }));
+
+        // the owner switches to denying the bank sends partway through
+        exec(&mut deps, set_policy(PolicyMode::Deny, &["/cosmos.bank.*"])).unwrap();
+        let err = exec(&mut deps, transfer(100, None)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::TypeUrlBlocked { type_url } if type_url == MsgSend::TYPE_URL
+        ));
+        exec(&mut deps, clear_admin()).unwrap();
+        assert_eq!(
+            query(deps.as_ref(), mock_env(), QueryMsg::TypeUrlPolicy {}).unwrap(),
+            to_json_binary(&Some(TypeUrlPolicy {
+                mode: PolicyMode::Deny,
+                type_urls: vec!["/cosmos.bank.*".to_string()],
+            }))
+            .unwrap()
+        );
+
+        // and only the owner may change it
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("mallory", &[]),
+            set_policy(PolicyMode::Deny, &[]),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Ownership(_)));
+        exec(&mut deps, set_policy(PolicyMode::Deny, &[])).unwrap();
+        exec_settled(&mut deps, transfer(100, None)).unwrap();
+    }
+
+    fn upload_chunk(
+        deps: &mut TestDeps,
+        index: u32,
+        data: &[u8],
+    ) -> Result<Response, ContractError> {
+        exec(
+            deps,
+            ExecuteMsg::UploadChunk {
+                session_id: "session".to_string(),
+                index,
+                data: Binary::from(data),
+            },
+        )
+    }
+
+    fn exec_uploaded(deps: &mut TestDeps) -> Result<Response, ContractError> {
+        exec(
+            deps,
+            ExecuteMsg::ExecUploaded {
+                session_id: "session".to_string(),
+                type_url: MsgSend::TYPE_URL.to_string(),
+            },
+        )
+    }
+
+    fn encoded_send() -> Vec<u8> {
+        send_msg(
+            &Addr::unchecked(crate::testing::GRANTER),
+            RECIPIENT,
+            &coins(100),
+        )
+        .encode_bytes()
+        .unwrap()
+    }
+
+    #[test]
+    fn chunks_uploaded_out_of_order_are_reassembled_by_index() {
+        let mut deps = setup(instantiate_msg());
+        let value = encoded_send();
+        let chunks: Vec<&[u8]> = value.chunks(value.len() / 3 + 1).collect();
+        assert_eq!(chunks.len(), 3);
+        for index in [2, 0, 1] {
+            upload_chunk(&mut deps, index, chunks[index as usize]).unwrap();
+        }
+        assert_eq!(
+            query_session(deps.as_ref(), "session".to_string()).unwrap(),
+            SessionResponse {
+                chunks: vec![0, 1, 2],
+                total_size: value.len() as u64,
+            }
+        );
+
+        let res = exec_uploaded(&mut deps).unwrap();
+        let anys = exec_anys(&res);
+        assert_eq!(anys.len(), 1);
+        assert_eq!(anys[0].type_url, MsgSend::TYPE_URL);
+        assert_eq!(anys[0].value, value);
+        assert_eq!(bank_sends(&res), vec![(RECIPIENT.to_string(), coins(100))]);
+
+        // the session is consumed by the exec
+        assert!(query_session(deps.as_ref(), "session".to_string())
+            .unwrap()
+            .chunks
+            .is_empty());
+        let err = exec_uploaded(&mut deps).unwrap_err();
+        assert!(matches!(err, ContractError::EmptySession { .. }));
+    }
+
+    #[test]
+    fn exec_with_a_missing_chunk_is_rejected() {
+        let mut deps = setup(instantiate_msg());
+        let value = encoded_send();
+        let (first, rest) = value.split_at(value.len() / 2);
+        upload_chunk(&mut deps, 0, first).unwrap();
+        upload_chunk(&mut deps, 2, rest).unwrap();
+
+        let err = exec_uploaded(&mut deps).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::MissingChunk { session_id, index: 1 } if session_id == "session"
+        ));
+        // the uploaded chunks are kept, so the missing one can still be added
+        assert_eq!(
+            query_session(deps.as_ref(), "session".to_string())
+                .unwrap()
+                .chunks,
+            vec![0, 2]
+        );
+        // a chunk starting past index 0 is missing the first one
+        let mut deps = setup(instantiate_msg());
+        upload_chunk(&mut deps, 1, &value).unwrap();
+        let err = exec_uploaded(&mut deps).unwrap_err();
+        assert!(matches!(err, ContractError::MissingChunk { index: 0, .. }));
+    }
+
+    #[test]
+    fn uploads_are_capped_in_size() {
+        let mut deps = setup(instantiate_msg());
+        let err = upload_chunk(&mut deps, 0, &vec![0; MAX_UPLOAD_SIZE + 1]).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::UploadTooLarge {
+                max: MAX_UPLOAD_SIZE
+            }
+        ));
+
+        // chunks within the cap one by one may still exceed it together
+        upload_chunk(&mut deps, 0, &vec![0; MAX_UPLOAD_SIZE]).unwrap();
+        upload_chunk(&mut deps, 1, &[0]).unwrap();
+        let err = exec_uploaded(&mut deps).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::UploadTooLarge {
+                max: MAX_UPLOAD_SIZE
+            }
+        ));
+    }
+
+    fn simulate(deps: &TestDeps, coins: Vec<Coin>) -> SimulateResponse {
+        query_simulate(
+            deps.as_ref(),
+            mock_env(),
+            SendSpec {
+                to_address: RECIPIENT.to_string(),
+                coins,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn simulate_pins_the_gas_of_one_and_ten_coins() {
+        let deps = setup(instantiate_msg());
+        let res = simulate(&deps, coins(100));
+        assert_eq!((res.encoded_size, res.estimated_gas), (85, 117_000));
+
+        let ten = (0..10)
+            .map(|i| Coin::new(100, format!("denom{i}")))
+            .collect();
+        let res = simulate(&deps, ten);
+        assert_eq!((res.encoded_size, res.estimated_gas), (223, 567_000));
+    }
+
+    fn exec_on_chain(deps: &mut TestDeps, chain_id: &str) -> Result<Response, ContractError> {
+        let mut env = mock_env();
+        env.block.chain_id = chain_id.to_string();
+        execute(deps.as_mut(), env, mock_info(OWNER, &[]), transfer(1, None))
+    }
+
+    fn with_chain_id(chain_id: &str) -> InstantiateMsg {
+        InstantiateMsg {
+            expected_chain_id: Some(chain_id.to_string()),
+            ..instantiate_msg()
+        }
+    }
+
+    #[test]
+    fn execute_on_the_expected_chain_is_accepted() {
+        let mut deps = setup(with_chain_id("coreum-devnet-1"));
+        exec_on_chain(&mut deps, "coreum-devnet-1").unwrap();
+        assert_eq!(
+            query_chain_info(deps.as_ref(), mock_env())
+                .unwrap()
+                .expected_chain_id,
+            Some("coreum-devnet-1".to_string())
+        );
+    }
+
+    #[test]
+    fn execute_on_another_chain_is_rejected() {
+        let mut deps = setup(with_chain_id("coreum-devnet-1"));
+        let err = exec_on_chain(&mut deps, "coreum-mainnet-1").unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::WrongChain { expected, actual }
+                if expected == "coreum-devnet-1" && actual == "coreum-mainnet-1"
+        ));
+        // the chain id is compared exactly
+        let err = exec_on_chain(&mut deps, "coreum-devnet-11").unwrap_err();
+        assert!(matches!(err, ContractError::WrongChain { .. }));
+    }
+
+    #[test]
+    fn execute_without_an_expected_chain_is_accepted_anywhere() {
+        let mut deps = setup(instantiate_msg());
+        exec_on_chain(&mut deps, "coreum-mainnet-1").unwrap();
+        reply_ok(&mut deps, SPEND_REPLY_ID).unwrap();
+        exec_on_chain(&mut deps, "coreum-testnet-1").unwrap();
+        assert!(query_chain_info(deps.as_ref(), mock_env())
+            .unwrap()
+            .expected_chain_id
+            .is_none());
+    }
+
+    #[test]
+    fn debug_queries_are_only_compiled_with_the_feature() {
+        let raw_range = from_json::<QueryMsg>(br#"{"raw_range":{"limit":1}}"#);
+        let debug_log = from_json::<QueryMsg>(br#"{"debug_log":{}}"#);
+        if cfg!(feature = "debug") {
+            raw_range.unwrap();
+            debug_log.unwrap();
+        } else {
+            raw_range.unwrap_err();
+            debug_log.unwrap_err();
+        }
+    }
+
+    fn split_send(amount: u128, recipients: &[(&str, u32)]) -> ExecuteMsg {
+        ExecuteMsg::SplitSend {
+            denom: DENOM.to_string(),
+            amount: amount.into(),
+            recipients: recipients
+                .iter()
+                .map(|(address, weight)| SplitRecipient {
+                    address: address.to_string(),
+                    weight: *weight,
+                })
+                .collect(),
+            idempotency_key: None,
+        }
+    }
+
+    fn fee_config(fee_bps: u16) -> FeeConfig {
+        FeeConfig {
+            fee_bps,
+            collector: Addr::unchecked(COLLECTOR),
+        }
+    }
+
+    #[test]
+    fn v1_payload_instantiates_with_the_defaults() {
+        let msg: InstantiateMsg =
+            from_json(include_bytes!("../testdata/instantiate_msg_v1.json")).unwrap();
+        let deps = setup(msg);
+        assert_eq!(
+            IDEMPOTENCY_TTL.load(&deps.storage).unwrap(),
+            DEFAULT_IDEMPOTENCY_TTL_BLOCKS
+        );
+        assert!(FEE_CONFIG.may_load(&deps.storage).unwrap().is_none());
+        assert!(EXPECTED_CHAIN_ID.may_load(&deps.storage).unwrap().is_none());
+    }
+
+    #[test]
+    fn fee_rounds_down() {
+        let config = fee_config(25);
+        // 2.5 rounds down to 2
+        assert_eq!(
+            deduct_fee(Some(&config), &coins(1000)).unwrap(),
+            (coins(998), coins(2))
+        );
+        // 0.0975 rounds down to zero, which is left out of the fee
+        assert_eq!(
+            deduct_fee(Some(&config), &coins(39)).unwrap(),
+            (coins(39), vec![])
+        );
+        assert_eq!(
+            deduct_fee(
+                Some(&config),
+                &[Coin::new(39, DENOM), Coin::new(400, "uother")]
+            )
+            .unwrap(),
+            (
+                vec![Coin::new(39, DENOM), Coin::new(399, "uother")],
+                vec![Coin::new(1, "uother")]
+            )
+        );
+        assert_eq!(deduct_fee(None, &coins(39)).unwrap(), (coins(39), vec![]));
+    }
+
+    #[test]
+    fn fee_rounding_to_zero_sends_nothing_to_the_collector() {
+        let mut deps = setup(with_fee(25));
+        let res = exec(&mut deps, transfer(39, None)).unwrap();
+        assert_eq!(bank_sends(&res), vec![(RECIPIENT.to_string(), coins(39))]);
+        assert!(res.events.is_empty());
+    }
+
+    #[test]
+    fn fee_is_capped_at_instantiate() {
+        let mut deps = setup(with_fee(MAX_FEE_BPS));
+        let res = exec(&mut deps, transfer(1000, None)).unwrap();
+        assert_eq!(
+            bank_sends(&res),
+            vec![
+                (RECIPIENT.to_string(), coins(900)),
+                (COLLECTOR.to_string(), coins(100)),
+            ]
+        );
+
+        let err = instantiate(
+            mock_dependencies().as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            with_fee(MAX_FEE_BPS + 1),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::FeeTooHigh {
+                fee_bps: 1001,
+                max: 1000
+            }
+        ));
+    }
+
+    #[test]
+    fn split_send_pays_the_fee_in_one_send() {
+        let mut deps = setup(with_fee(100));
+        let res = exec(
+            &mut deps,
+            split_send(3000, &[("alice", 1), ("bob", 1), ("carol", 1)]),
+        )
+        .unwrap();
+
+        assert_eq!(
+            bank_sends(&res),
+            vec![
+                ("alice".to_string(), coins(990)),
+                ("bob".to_string(), coins(990)),
+                ("carol".to_string(), coins(990)),
+                (COLLECTOR.to_string(), coins(30)),
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_send_pays_the_fee_in_one_output() {
+        let mut deps = setup(with_fee(100));
+        let res = exec(
+            &mut deps,
+            ExecuteMsg::MultiSend {
+                outputs: vec![
+                    SendSpec {
+                        to_address: "alice".to_string(),
+                        coins: vec![Coin::new(1000, DENOM), Coin::new(500, "uother")],
+                    },
+                    SendSpec {
+                        to_address: "bob".to_string(),
+                        coins: coins(2000),
+                    },
+                ],
+                idempotency_key: None,
+            },
+        )
+        .unwrap();
+
+        let (input, outputs) = multi_send(&res);
+        assert_eq!(
+            input,
+            vec![Coin::new(3000, DENOM), Coin::new(500, "uother")]
+        );
+        assert_eq!(
+            outputs,
+            vec![
+                (
+                    "alice".to_string(),
+                    vec![Coin::new(990, DENOM), Coin::new(495, "uother")]
+                ),
+                ("bob".to_string(), coins(1980)),
+                (
+                    COLLECTOR.to_string(),
+                    vec![Coin::new(30, DENOM), Coin::new(5, "uother")]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_send_without_fee_has_no_collector_output() {
+        let mut deps = setup(instantiate_msg());
+        let res = exec(
+            &mut deps,
+            ExecuteMsg::MultiSend {
+                outputs: vec![SendSpec {
+                    to_address: "alice".to_string(),
+                    coins: coins(1000),
+                }],
+                idempotency_key: None,
+            },
+        )
+        .unwrap();
+
+        let (input, outputs) = multi_send(&res);
+        assert_eq!(input, coins(1000));
+        assert_eq!(outputs, vec![("alice".to_string(), coins(1000))]);
+    }
+
+    // runs a SendAndAssert and its reply with the recipient balance moved from before to after in
+    // between, like the bank send would
+    fn assert_send(
+        deps: &mut TestDeps,
+        amount: u128,
+        expected: u128,
+        before: u128,
+        after: u128,
+    ) -> Result<Response, ContractError> {
+        deps.querier.update_balance(RECIPIENT, coins(before));
+        exec(deps, send_and_assert(amount, expected)).unwrap();
+        deps.querier.update_balance(RECIPIENT, coins(after));
+        reply_ok(deps, SEND_AND_ASSERT_REPLY_ID)
+    }
+
+    #[test]
+    fn send_and_assert_checks_the_balance_delta() {
+        let mut deps = setup(instantiate_msg());
+        assert_send(&mut deps, 100, 100, 50, 150).unwrap();
+
+        let err = assert_send(&mut deps, 100, 100, 50, 149).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::UnexpectedBalanceIncrease { expected, actual, .. }
+                if expected.u128() == 100 && actual.u128() == 99
+        ));
+    }
+
+    #[test]
+    fn send_and_assert_expects_the_increase_net_of_the_fee() {
+        let mut deps = setup(with_fee(100));
+        assert_send(&mut deps, 1000, 990, 0, 990).unwrap();
+
+        let err = assert_send(&mut deps, 1000, 990, 0, 1000).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::UnexpectedBalanceIncrease { expected, actual, .. }
+                if expected.u128() == 990 && actual.u128() == 1000
+        ));
+    }
+
+    #[test]
+    fn send_and_assert_does_not_adjust_the_expected_increase_for_the_fee() {
+        let mut deps = setup(with_fee(100));
+        let res = exec(&mut deps, send_and_assert(1000, 1000)).unwrap();
+        assert_eq!(
+            bank_sends(&res),
+            vec![
+                (RECIPIENT.to_string(), coins(990)),
+                (COLLECTOR.to_string(), coins(10)),
+            ]
+        );
+
+        // the recipient gets the send less the fee, which falls short of the gross amount expected
+        deps.querier.update_balance(RECIPIENT, coins(990));
+        let err = reply_ok(&mut deps, SEND_AND_ASSERT_REPLY_ID).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::UnexpectedBalanceIncrease { expected, actual, .. }
+                if expected.u128() == 1000 && actual.u128() == 990
+        ));
+    }
+
+    #[test]
+    fn send_and_assert_requires_an_expected_increase() {
+        let mut deps = setup(instantiate_msg());
+        let err = exec(
+            &mut deps,
+            ExecuteMsg::SendAndAssert {
+                to_address: RECIPIENT.to_string(),
+                coins: coins(100),
+                expected_recipient_increase: vec![],
+                idempotency_key: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::EmptyExpectedIncrease {}));
+    }
+
+    fn is_replayed(res: &Response) -> bool {
+        res.messages.is_empty()
+            && res
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "method" && attr.value == "replayed")
+    }
+
+    #[test]
+    fn batch_sends_replay_with_the_same_key() {
+        let mut deps = setup(instantiate_msg());
+        let key = Some("batch".to_string());
+        let split = ExecuteMsg::SplitSend {
+            denom: DENOM.to_string(),
+            amount: 100u128.into(),
+            recipients: vec![SplitRecipient {
+                address: RECIPIENT.to_string(),
+                weight: 1,
+            }],
+            idempotency_key: key.clone(),
+        };
+        assert!(!is_replayed(
+            &exec_settled(&mut deps, split.clone()).unwrap()
+        ));
+        assert!(is_replayed(&exec(&mut deps, split).unwrap()));
+
+        let multi = ExecuteMsg::MultiSend {
+            outputs: vec![SendSpec {
+                to_address: RECIPIENT.to_string(),
+                coins: coins(100),
+            }],
+            idempotency_key: Some("multi".to_string()),
+        };
+        assert!(!is_replayed(
+            &exec_settled(&mut deps, multi.clone()).unwrap()
+        ));
+        assert!(is_replayed(&exec(&mut deps, multi).unwrap()));
+
+        exec(
+            &mut deps,
+            ExecuteMsg::Enqueue {
+                send: SendSpec {
+                    to_address: RECIPIENT.to_string(),
+                    coins: coins(100),
+                },
+            },
+        )
+        .unwrap();
+        let flush = ExecuteMsg::Flush {
+            max: None,
+            idempotency_key: Some("flush".to_string()),
+        };
+        assert!(!is_replayed(
+            &exec_settled(&mut deps, flush.clone()).unwrap()
+        ));
+        assert!(is_replayed(&exec(&mut deps, flush).unwrap()));
+    }
+
+    #[test]
+    fn key_reused_with_other_parameters_is_rejected() {
+        let mut deps = setup(instantiate_msg());
+        exec_settled(&mut deps, transfer(1, Some("key"))).unwrap();
+
+        let err = exec(
+            &mut deps,
+            ExecuteMsg::Flush {
+                max: Some(1),
+                idempotency_key: Some("key".to_string()),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateKey { key } if key == "key"));
+    }
+
+    #[test]
+    fn key_expires_after_the_ttl() {
+        let mut deps = setup(instantiate_msg());
+        exec_settled_at(&mut deps, 1000, transfer(1, Some("key"))).unwrap();
+
+        let err = exec_at(&mut deps, 1099, transfer(2, Some("key"))).unwrap_err();
+        assert!(matches!(err, ContractError::DuplicateKey { .. }));
+        let res = exec_settled_at(&mut deps, 1100, transfer(2, Some("key"))).unwrap();
+        assert!(!is_replayed(&res));
+        // reserved again for the new payload
+        let res = exec_at(&mut deps, 1101, transfer(2, Some("key"))).unwrap();
+        assert!(is_replayed(&res));
+    }
+
+    #[test]
+    fn expired_keys_are_pruned_in_bounded_batches() {
+        let mut deps = setup(instantiate_msg());
+        for i in 0..15 {
+            exec_settled_at(&mut deps, 1000, transfer(1, Some(&format!("key{i}")))).unwrap();
+        }
+        let reserved = |deps: &TestDeps| {
+            IDEMPOTENCY_KEYS
+                .keys(&deps.storage, None, None, Order::Ascending)
+                .count()
+        };
+
+        exec_settled_at(&mut deps, 1100, transfer(1, Some("next"))).unwrap();
+        assert_eq!(reserved(&deps), 15 - MAX_PRUNED_KEYS + 1);
+        exec_at(&mut deps, 1100, transfer(1, Some("last"))).unwrap();
+        assert_eq!(reserved(&deps), 2);
+        assert_eq!(
+            IDEMPOTENCY_HEIGHTS
+                .keys(&deps.storage, None, None, Order::Ascending)
+                .count(),
+            2
+        );
+    }
+
+    fn add_recipient(deps: &mut TestDeps, address: &str) {
+        exec(
+            deps,
+            ExecuteMsg::AddRecipient {
+                address: address.to_string(),
+            },
+        )
+        .unwrap();
+    }
+
+    fn recipients(deps: &TestDeps, start_after: Option<&str>, limit: Option<u32>) -> Vec<String> {
+        query_recipients(deps.as_ref(), start_after.map(str::to_string), limit)
+            .unwrap()
+            .recipients
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn allowlist_add_and_remove() {
+        let mut deps = setup(instantiate_msg());
+        add_recipient(&mut deps, "alice");
+        add_recipient(&mut deps, "alice");
+        assert_eq!(recipients(&deps, None, None), vec!["alice"]);
+
+        let err = exec(&mut deps, transfer(1, None)).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::RecipientNotAllowed { address, index: 0 } if address == RECIPIENT
+        ));
+        add_recipient(&mut deps, RECIPIENT);
+        exec_settled(&mut deps, transfer(1, None)).unwrap();
+
+        // removing every address lifts the restriction
+        for address in ["alice", RECIPIENT] {
+            exec(
+                &mut deps,
+                ExecuteMsg::RemoveRecipient {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        }
+        assert!(recipients(&deps, None, None).is_empty());
+        exec(&mut deps, transfer(1, None)).unwrap();
+    }
+
+    #[test]
+    fn recipients_are_paginated() {
+        let mut deps = setup(instantiate_msg());
+        let addresses: Vec<String> = (0..40).map(|i| format!("recipient{i:02}")).collect();
+        for address in &addresses {
+            add_recipient(&mut deps, address);
+        }
+
+        assert_eq!(
+            recipients(&deps, None, None),
+            addresses[..DEFAULT_LIMIT as usize]
+        );
+        assert_eq!(
+            recipients(&deps, None, Some(100)),
+            addresses[..MAX_LIMIT as usize]
+        );
+        assert_eq!(
+            recipients(&deps, Some("recipient09"), Some(3)),
+            addresses[10..13]
+        );
+        assert!(recipients(&deps, Some("recipient39"), None).is_empty());
+    }
+
+    #[test]
+    fn batch_violation_reports_the_index() {
+        let mut deps = setup(instantiate_msg());
+        add_recipient(&mut deps, "alice");
+        add_recipient(&mut deps, "bob");
+
+        let err = exec(
+            &mut deps,
+            split_send(100, &[("alice", 1), ("bob", 1), ("mallory", 1)]),
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::RecipientNotAllowed { address, index: 2 } if address == "mallory"
+        ));
+
+        let send = |to_address: &str| SendSpec {
+            to_address: to_address.to_string(),
+            coins: coins(100),
+        };
+        let err = exec(
+            &mut deps,
+            ExecuteMsg::MultiSend {
+                outputs: vec![send("alice"), send("mallory"), send("bob")],
+                idempotency_key: None,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::RecipientNotAllowed { address, index: 1 } if address == "mallory"
+        ));
+    }
+
+    fn total_spent(deps: &TestDeps, denom: Option<&str>) -> TotalSpentResponse {
+        query_total_spent(deps.as_ref(), denom.map(str::to_string), None, None).unwrap()
+    }
+
+    fn spend(deps: &mut TestDeps, amount: u64, denom: &str) {
+        exec(
+            deps,
+            ExecuteMsg::Transfer {
+                address: Addr::unchecked(RECIPIENT),
+                amount,
+                denom: denom.to_string(),
+                idempotency_key: None,
+            },
+        )
+        .unwrap();
+        reply_ok(deps, SPEND_REPLY_ID).unwrap();
+    }
+
+    #[test]
+    fn spends_accumulate_per_denom() {
+        let mut deps = setup(instantiate_msg());
+        spend(&mut deps, 100, DENOM);
+        spend(&mut deps, 50, "uother");
+        spend(&mut deps, 25, DENOM);
+
+        let res = exec(
+            &mut deps,
+            ExecuteMsg::MultiSend {
+                outputs: vec![SendSpec {
+                    to_address: RECIPIENT.to_string(),
+                    coins: vec![Coin::new(1, DENOM), Coin::new(2, "uother")],
+                }],
+                idempotency_key: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages[0].id, SPEND_REPLY_ID);
+        reply_ok(&mut deps, SPEND_REPLY_ID).unwrap();
+
+        assert_eq!(
+            total_spent(&deps, None),
+            TotalSpentResponse {
+                spent: vec![Coin::new(126, DENOM), Coin::new(52, "uother")],
+                saturated: false,
+            }
+        );
+        assert_eq!(
+            total_spent(&deps, Some("uother")).spent,
+            vec![Coin::new(52, "uother")]
+        );
+        assert_eq!(
+            total_spent(&deps, Some("unknown")).spent,
+            vec![Coin::new(0, "unknown")]
+        );
+    }
+
+    #[test]
+    fn sends_without_a_success_reply_are_not_counted() {
+        let mut deps = setup(instantiate_msg());
+        // the exec fails on chain, so the reply_on_success never comes and the transaction is
+        // reverted, the pending spend and the guard with it
+        exec(&mut deps, transfer(100, None)).unwrap();
+        assert!(total_spent(&deps, Some(DENOM)).spent[0].amount.is_zero());
+        PENDING_SPEND.remove(deps.as_mut().storage);
+        GUARD.exit(deps.as_mut().storage).unwrap();
+        spend(&mut deps, 7, DENOM);
+
+        assert_eq!(total_spent(&deps, Some(DENOM)).spent, coins(7));
+    }
+
+    #[test]
+    fn spends_saturate_at_the_maximum() {
+        let mut deps = setup(instantiate_msg());
+        SPENT
+            .save(
+                deps.as_mut().storage,
+                DENOM,
+                &Spent {
+                    amount: Uint128::MAX - Uint128::new(10),
+                    saturated: false,
+                },
+            )
+            .unwrap();
+        spend(&mut deps, 5, "uother");
+        spend(&mut deps, 10, DENOM);
+        assert!(!total_spent(&deps, None).saturated);
+
+        spend(&mut deps, 1, DENOM);
+        let res = total_spent(&deps, None);
+        assert!(res.saturated);
+        assert_eq!(res.spent[0], Coin::new(u128::MAX, DENOM));
+
+        // stays saturated, and the flag is reported for the denom alone too
+        spend(&mut deps, 1, DENOM);
+        assert!(total_spent(&deps, Some(DENOM)).saturated);
+        assert!(!total_spent(&deps, Some("uother")).saturated);
+    }
+
+    fn enqueue(deps: &mut TestDeps, to_address: &str, amount: u128) {
+        exec(
+            deps,
+            ExecuteMsg::Enqueue {
+                send: SendSpec {
+                    to_address: to_address.to_string(),
+                    coins: coins(amount),
+                },
+            },
+        )
+        .unwrap();
+    }
+
+    fn flush(deps: &mut TestDeps, max: Option<u32>) -> Response {
+        exec_settled(
+            deps,
+            ExecuteMsg::Flush {
+                max,
+                idempotency_key: None,
+            },
+        )
+        .unwrap()
+    }
+
+    fn flushed(res: &Response) -> &str {
+        &res.attributes
+            .iter()
+            .find(|attr| attr.key == "flushed")
+            .unwrap()
+            .value
+    }
+
+    #[test]
+    fn partial_flush_releases_the_oldest_first() {
+        let mut deps = setup(instantiate_msg());
+        for (i, address) in ["alice", "bob", "carol", "dave", "erin"].iter().enumerate() {
+            enqueue(&mut deps, address, 100 + i as u128);
+        }
+
+        let res = flush(&mut deps, Some(2));
+        assert_eq!(flushed(&res), "2");
+        assert_eq!(
+            bank_sends(&res),
+            vec![
+                ("alice".to_string(), coins(100)),
+                ("bob".to_string(), coins(101)),
+            ]
+        );
+        let queued: Vec<u64> = query_queue(deps.as_ref(), None, None)
+            .unwrap()
+            .entries
+            .into_iter()
+            .map(|entry| entry.id)
+            .collect();
+        assert_eq!(queued, vec![2, 3, 4]);
+
+        // entries staged after a partial flush queue up behind the remaining ones
+        enqueue(&mut deps, "frank", 105);
+        let res = flush(&mut deps, None);
+        assert_eq!(flushed(&res), "4");
+        let recipients: Vec<String> = bank_sends(&res)
+            .into_iter()
+            .map(|(address, _)| address)
+            .collect();
+        assert_eq!(recipients, vec!["carol", "dave", "erin", "frank"]);
+        assert!(query_queue(deps.as_ref(), None, None)
+            .unwrap()
+            .entries
+            .is_empty());
+    }
+
+    #[test]
+    fn flush_of_an_empty_queue_is_a_no_op() {
+        let mut deps = setup(instantiate_msg());
+        let res = flush(&mut deps, None);
+        assert_eq!(flushed(&res), "0");
+        assert!(res.messages.is_empty());
+
+        enqueue(&mut deps, "alice", 100);
+        flush(&mut deps, Some(5));
+        let res = flush(&mut deps, Some(5));
+        assert_eq!(flushed(&res), "0");
+        assert!(res.messages.is_empty());
+    }
+
+    #[test]
+    fn queue_is_paginated_by_id() {
+        let mut deps = setup(instantiate_msg());
+        for i in 0..35 {
+            enqueue(&mut deps, "alice", 100 + i);
+        }
+
+        let page = query_queue(deps.as_ref(), Some(4), Some(3))
+            .unwrap()
+            .entries;
+        assert_eq!(
+            page.iter().map(|entry| entry.id).collect::<Vec<_>>(),
+            vec![5, 6, 7]
+        );
+        assert_eq!(page[0].send.coins, coins(105));
+        assert_eq!(
+            query_queue(deps.as_ref(), None, Some(100))
+                .unwrap()
+                .entries
+                .len(),
+            MAX_LIMIT as usize
+        );
+    }
+
+    #[test]
+    fn invalid_sends_are_rejected_when_staged() {
+        let mut deps = setup(instantiate_msg());
+        let err = exec(
+            &mut deps,
+            ExecuteMsg::Enqueue {
+                send: SendSpec {
+                    to_address: "alice".to_string(),
+                    coins: coins(0),
+                },
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ZeroAmount { .. }));
+        assert!(query_queue(deps.as_ref(), None, None)
+            .unwrap()
+            .entries
+            .is_empty());
+    }
+
+    // payloads signed offline with the secp256k1 keys [1; 32] (the configured signer) and [2; 32]
+    const SIGNER_PUBKEY: &str = "AxuExVZ7EmRAmV0+1aq6BWXXHhg0YEgZ/5wX9enV3QeP";
+    const OTHER_PUBKEY: &str = "Ak1LbNE2EDLKm9KuudkAqk1F2erYCslCM3TEUaclTQdm";
+    const PAYLOAD_1: &str = r#"{"nonce":1,"send":{"coins":[{"amount":"100","denom":"ucore"}],"to_address":"recipient"}}"#;
+    const PAYLOAD_2: &str = r#"{"nonce":2,"send":{"coins":[{"amount":"200","denom":"ucore"}],"to_address":"recipient"}}"#;
+    const SIGNATURE_1: &str =
+        "pxU25w5BbfzsoIsz+ABjxrtDUqXBb54PcqIPo9jRC9A6sP8+lSVo/oUXzCUef2daE9GPLru4hXmlA74fnDwsWg==";
+    const SIGNATURE_2: &str =
+        "afibOPNFK6jTKwAqtVa1wACsUmnvBNoNIN7ywAoH9KB1ZWO39KoHCB20aGScnYXNLlkAxn+Ro9oEaJ7k8bqtAw==";
+    const OTHER_SIGNATURE_1: &str =
+        "SAA7qKkmmi3dZMstI49VLWhLUrKz6rZy3kcBV5XVxDZYaYs0MrXOE5SlayYH515hR6aAYrkRdPhDuYFXtoRhLA==";
+
+    fn signed_env() -> Env {
+        use bech32::{ToBase32, Variant};
+
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked(
+            bech32::encode("core", [0u8; 20].to_base32(), Variant::Bech32).unwrap(),
+        );
+        env
+    }
+
+    fn setup_signer() -> TestDeps {
+        let pubkey = Binary::from_base64(SIGNER_PUBKEY).unwrap();
+        setup(InstantiateMsg {
+            signer: Some(secp256k1_address(pubkey.as_slice(), "core").unwrap()),
+            ..instantiate_msg()
+        })
+    }
+
+    fn exec_signed(
+        deps: &mut TestDeps,
+        payload: &str,
+        signature: &str,
+        pubkey: &str,
+    ) -> Result<Response, ContractError> {
+        // anyone may relay a signed payload
+        execute(
+            deps.as_mut(),
+            signed_env(),
+            mock_info("relayer", &[]),
+            ExecuteMsg::ExecSigned {
+                payload: Binary::from(payload.as_bytes()),
+                signature: Binary::from_base64(signature).unwrap(),
+                pubkey: Binary::from_base64(pubkey).unwrap(),
+            },
+        )
+    }
+
+    #[test]
+    fn signed_payload_is_executed() {
+        let mut deps = setup_signer();
+        let res = exec_signed(&mut deps, PAYLOAD_1, SIGNATURE_1, SIGNER_PUBKEY).unwrap();
+        assert_eq!(bank_sends(&res), vec![(RECIPIENT.to_string(), coins(100))]);
+        assert_eq!(res.messages[0].id, SPEND_REPLY_ID);
+        assert_eq!(SIGNER_NONCE.load(&deps.storage).unwrap(), 1);
+    }
+
+    #[test]
+    fn signature_of_another_key_is_rejected() {
+        let mut deps = setup_signer();
+        let err = exec_signed(&mut deps, PAYLOAD_1, OTHER_SIGNATURE_1, OTHER_PUBKEY).unwrap_err();
+        assert!(matches!(err, ContractError::UnauthorizedSigner { .. }));
+        // the signer's key does not verify the other key's signature either
+        let err = exec_signed(&mut deps, PAYLOAD_1, OTHER_SIGNATURE_1, SIGNER_PUBKEY).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSignature {}));
+        assert!(SIGNER_NONCE.may_load(&deps.storage).unwrap().is_none());
+    }
+
+    #[test]
+    fn tampered_payload_is_rejected() {
+        let mut deps = setup_signer();
+        let tampered = PAYLOAD_1.replace("\"100\"", "\"100000\"");
+        let err = exec_signed(&mut deps, &tampered, SIGNATURE_1, SIGNER_PUBKEY).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSignature {}));
+
+        // payloads of other signatures are not interchangeable
+        let err = exec_signed(&mut deps, PAYLOAD_2, SIGNATURE_1, SIGNER_PUBKEY).unwrap_err();
+        assert!(matches!(err, ContractError::InvalidSignature {}));
+    }
+
+    #[test]
+    fn nonces_cannot_be_reused() {
+        let mut deps = setup_signer();
+        exec_signed(&mut deps, PAYLOAD_1, SIGNATURE_1, SIGNER_PUBKEY).unwrap();
+        reply_ok(&mut deps, SPEND_REPLY_ID).unwrap();
+        let err = exec_signed(&mut deps, PAYLOAD_1, SIGNATURE_1, SIGNER_PUBKEY).unwrap_err();
+        assert!(matches!(err, ContractError::NonceReused { nonce: 1 }));
+
+        exec_signed(&mut deps, PAYLOAD_2, SIGNATURE_2, SIGNER_PUBKEY).unwrap();
+        reply_ok(&mut deps, SPEND_REPLY_ID).unwrap();
+        let err = exec_signed(&mut deps, PAYLOAD_1, SIGNATURE_1, SIGNER_PUBKEY).unwrap_err();
+        assert!(matches!(err, ContractError::NonceReused { nonce: 1 }));
+    }
+
+    #[test]
+    fn signed_payloads_need_a_configured_signer() {
+        let mut deps = setup(instantiate_msg());
+        let err = exec_signed(&mut deps, PAYLOAD_1, SIGNATURE_1, SIGNER_PUBKEY).unwrap_err();
+        assert!(matches!(err, ContractError::NoSigner {}));
+    }
+
+    #[test]
+    fn exhaustive_dispatch() {
+        let send = || SendSpec {
+            to_address: RECIPIENT.to_string(),
+            coins: coins(100),
+        };
+        let execute_cases = exhaustive_cases!(ExecuteMsg {
+            Transfer {
+                address: Addr::unchecked(RECIPIENT),
+                amount: 100,
+                denom: DENOM.to_string(),
+                idempotency_key: None,
+            } => Outcome::Ok,
+            SendAndAssert {
+                to_address: RECIPIENT.to_string(),
+                coins: coins(100),
+                expected_recipient_increase: coins(100),
+                idempotency_key: None,
+            } => Outcome::Ok,
+            UpdateAdmin {
+                contract: "contract".to_string(),
+                new_admin: "admin".to_string(),
+            } => Outcome::Ok,
+            ClearAdmin {
+                contract: "contract".to_string(),
+            } => Outcome::Ok,
+            Grant {
+                grantee: "grantee".to_string(),
+                authorization: Authorization::Generic {
+                    msg: MsgSend::TYPE_URL.to_string(),
+                },
+                expiration: None,
+                expires_in_seconds: Some(60),
+            } => Outcome::Ok,
+            Revoke {
+                grantee: "grantee".to_string(),
+                msg_type_url: MsgSend::TYPE_URL.to_string(),
+            } => Outcome::Ok,
+            AddRecipient {
+                address: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            RemoveRecipient {
+                address: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            ProposeSendEnabled {
+                authority: "gov".to_string(),
+                denom: DENOM.to_string(),
+                enabled: false,
+                deposit: coins(10_000_000),
+                title: "Disable sends".to_string(),
+                summary: "Disable sends".to_string(),
+            } => Outcome::Ok,
+            SplitSend {
+                denom: DENOM.to_string(),
+                amount: 100u128.into(),
+                recipients: vec![SplitRecipient {
+                    address: RECIPIENT.to_string(),
+                    weight: 1,
+                }],
+                idempotency_key: None,
+            } => Outcome::Ok,
+            MultiSend {
+                outputs: vec![send()],
+                idempotency_key: None,
+            } => Outcome::Ok,
+            ExecSigned {
+                payload: Binary::from(PAYLOAD_1.as_bytes()),
+                signature: Binary::from_base64(SIGNATURE_1).unwrap(),
+                pubkey: Binary::from_base64(SIGNER_PUBKEY).unwrap(),
+            } => Outcome::Ok,
+            SetTypeUrlPolicy {
+                mode: PolicyMode::Allow,
+                type_urls: vec![MsgSend::TYPE_URL.to_string()],
+            } => Outcome::Ok,
+            SetRateLimit {
+                limit: Some(RateLimit {
+                    amount: 1000u128.into(),
+                    window_blocks: 10,
+                }),
+            } => Outcome::Ok,
+            UploadChunk {
+                session_id: "session".to_string(),
+                index: 0,
+                data: Binary::from(b"chunk"),
+            } => Outcome::Ok,
+            ExecUploaded {
+                session_id: "session".to_string(),
+                type_url: MsgSend::TYPE_URL.to_string(),
+            } => Outcome::Err(|err| matches!(err, ContractError::EmptySession { .. })),
+            ScheduleSend {
+                to_address: RECIPIENT.to_string(),
+                coins: coins(100),
+                execute_after_height: 1000,
+            } => Outcome::Ok,
+            ExecuteScheduled { id: 0 } => Outcome::Err(|err| {
+                matches!(err, ContractError::ScheduledNotFound { id: 0 })
+            }),
+            CancelScheduled { id: 0 } => Outcome::Err(|err| {
+                matches!(err, ContractError::ScheduledNotFound { id: 0 })
+            }),
+            Enqueue { send: send() } => Outcome::Ok,
+            Flush {
+                max: None,
+                idempotency_key: None,
+            } => Outcome::Ok,
+        });
+        run_cases(execute_cases, |msg| {
+            let mut deps = setup_signer();
+            execute(deps.as_mut(), signed_env(), mock_info(OWNER, &[]), msg)
+        });
+
+        // the mock querier answers no stargate queries, so the ones reading grants from the chain
+        // fail after dispatching them
+        let query_cases = exhaustive_cases!(QueryMsg {
+            ChainInfo {} => Outcome::Ok,
+            Session {
+                id: "session".to_string(),
+            } => Outcome::Ok,
+            Grants {} => Outcome::Err(is_unsupported_query),
+            RemainingSpendLimit {
+                granter: crate::testing::GRANTER.to_string(),
+            } => Outcome::Err(is_unsupported_query),
+            TypeUrlPolicy {} => Outcome::Ok,
+            RateLimit {} => Outcome::Ok,
+            Simulate { send: send() } => Outcome::Ok,
+            Recipients {
+                start_after: None,
+                limit: None,
+            } => Outcome::Ok,
+            Queue {
+                start_after: None,
+                limit: None,
+            } => Outcome::Ok,
+            Scheduled {
+                start_after: None,
+                limit: None,
+            } => Outcome::Ok,
+            TotalSpent {
+                denom: None,
+                start_after: None,
+                limit: None,
+            } => Outcome::Ok,
+            #[cfg(feature = "debug")]
+            RawRange {
+                start: None,
+                end: None,
+                limit: 10,
+            } => Outcome::Ok,
+            #[cfg(feature = "debug")]
+            DebugLog { limit: None } => Outcome::Ok,
+        });
+        run_cases(query_cases, |msg| {
+            let deps = setup_signer();
+            query(deps.as_ref(), signed_env(), msg)
+        });
+    }
+
+    fn is_unsupported_query(err: &StdError) -> bool {
+        matches!(err, StdError::GenericErr { msg } if msg.contains("Unsupported query type"))
+    }
+
+    fn schedule(deps: &mut TestDeps, amount: u128, execute_after_height: u64) -> u64 {
+        let res = exec(
+            deps,
+            ExecuteMsg::ScheduleSend {
+                to_address: RECIPIENT.to_string(),
+                coins: coins(amount),
+                execute_after_height,
+            },
+        )
+        .unwrap();
+        res.attributes
+            .iter()
+            .find(|attr| attr.key == "id")
+            .unwrap()
+            .value
+            .parse()
+            .unwrap()
+    }
+
+    fn scheduled_ids(deps: &TestDeps) -> Vec<u64> {
+        query_scheduled(deps.as_ref(), None, None)
+            .unwrap()
+            .entries
+            .into_iter()
+            .map(|entry| entry.id)
+            .collect()
+    }
+
+    #[test]
+    fn scheduled_send_runs_once_after_its_height() {
+        let mut deps = setup(instantiate_msg());
+        let id = schedule(&mut deps, 100, 1000);
+        assert_eq!(scheduled_ids(&deps), vec![id]);
+
+        let err = exec_at(&mut deps, 998, ExecuteMsg::ExecuteScheduled { id }).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::TooEarly {
+                remaining_blocks: 3
+            }
+        ));
+        // the height itself is still too early
+        let err = exec_at(&mut deps, 1000, ExecuteMsg::ExecuteScheduled { id }).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::TooEarly {
+                remaining_blocks: 1
+            }
+        ));
+
+        // anyone may trigger a due send
+        let res = execute(
+            deps.as_mut(),
+            env_at(1001),
+            mock_info("keeper", &[]),
+            ExecuteMsg::ExecuteScheduled { id },
+        )
+        .unwrap();
+        assert_eq!(bank_sends(&res), vec![(RECIPIENT.to_string(), coins(100))]);
+        assert_eq!(res.messages[0].id, SPEND_REPLY_ID);
+        reply_ok(&mut deps, SPEND_REPLY_ID).unwrap();
+        assert!(scheduled_ids(&deps).is_empty());
+
+        let err = exec_at(&mut deps, 1002, ExecuteMsg::ExecuteScheduled { id }).unwrap_err();
+        assert!(matches!(err, ContractError::ScheduledNotFound { id: found } if found == id));
+        let err = exec(&mut deps, ExecuteMsg::CancelScheduled { id }).unwrap_err();
+        assert!(matches!(err, ContractError::ScheduledNotFound { id: found } if found == id));
+    }
+
+    #[test]
+    fn cancelled_send_cannot_be_executed() {
+        let mut deps = setup(instantiate_msg());
+        let cancelled = schedule(&mut deps, 100, 1000);
+        let kept = schedule(&mut deps, 200, 1000);
+        assert_ne!(cancelled, kept);
+
+        exec(&mut deps, ExecuteMsg::CancelScheduled { id: cancelled }).unwrap();
+        assert_eq!(scheduled_ids(&deps), vec![kept]);
+
+        let err = exec_at(
+            &mut deps,
+            1001,
+            ExecuteMsg::ExecuteScheduled { id: cancelled },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::ScheduledNotFound { .. }));
+        let res = exec_at(&mut deps, 1001, ExecuteMsg::ExecuteScheduled { id: kept }).unwrap();
+        assert_eq!(bank_sends(&res), vec![(RECIPIENT.to_string(), coins(200))]);
+    }
+
+    #[test]
+    fn only_the_owner_schedules_and_cancels() {
+        let mut deps = setup(instantiate_msg());
+        let id = schedule(&mut deps, 100, 1000);
+
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            ExecuteMsg::CancelScheduled { id },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Ownership(_)));
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info("stranger", &[]),
+            ExecuteMsg::ScheduleSend {
+                to_address: RECIPIENT.to_string(),
+                coins: coins(100),
+                execute_after_height: 1000,
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Ownership(_)));
+        assert_eq!(scheduled_ids(&deps), vec![id]);
+    }
+
+    fn update_admin(new_admin: &str) -> ExecuteMsg {
+        ExecuteMsg::UpdateAdmin {
+            contract: "contract".to_string(),
+            new_admin: new_admin.to_string(),
+        }
+    }
+
+    fn clear_admin() -> ExecuteMsg {
+        ExecuteMsg::ClearAdmin {
+            contract: "contract".to_string(),
+        }
+    }
+
+    #[test]
+    fn owner_rotates_and_clears_the_admin() {
+        let mut deps = setup(instantiate_msg());
+        let res = exec(&mut deps, update_admin("admin")).unwrap();
+        let anys = exec_anys(&res);
+        assert_eq!(anys.len(), 1);
+        assert_eq!(anys[0].type_url, MsgUpdateAdmin::TYPE_URL);
+
+        let res = exec(&mut deps, clear_admin()).unwrap();
+        let anys = exec_anys(&res);
+        assert_eq!(anys.len(), 1);
+        assert_eq!(anys[0].type_url, MsgClearAdmin::TYPE_URL);
+    }
+
+    #[test]
+    fn only_the_owner_rotates_and_clears_the_admin() {
+        let mut deps = setup(instantiate_msg());
+        for msg in [update_admin("mallory"), clear_admin()] {
+            let err =
+                execute(deps.as_mut(), mock_env(), mock_info("mallory", &[]), msg).unwrap_err();
+            assert!(matches!(
+                err,
+                ContractError::Ownership(OwnershipError::NotOwner)
+            ));
+        }
+    }
+
+    #[test]
+    fn admin_changes_are_rejected_once_the_owner_is_cleared() {
+        let mut deps = setup(instantiate_msg());
+        cw_ownable::update_ownership(
+            deps.as_mut(),
+            &mock_env().block,
+            &Addr::unchecked(OWNER),
+            cw_ownable::Action::RenounceOwnership,
+        )
+        .unwrap();
+
+        for msg in [update_admin("admin"), clear_admin()] {
+            let err = exec(&mut deps, msg).unwrap_err();
+            assert!(matches!(
+                err,
+                ContractError::Ownership(OwnershipError::NoOwner)
+            ));
+        }
+    }
 }
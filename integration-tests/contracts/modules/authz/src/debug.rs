@@ -0,0 +1,113 @@
+use cosmwasm_std::{Env, StdResult, Storage};
+use cw_storage_plus::{Item, Map};
+
+use crate::msg::{DebugEntry, DebugLogResponse};
+use crate::proto::{Any, ProtoMessage};
+
+// number of dispatched messages kept, older ones are overwritten
+const DEBUG_LOG_SIZE: u64 = 10;
+
+// Ring buffer of the last dispatched messages, slot is the sequence number modulo the size
+const DEBUG_LOG: Map<u64, DebugEntry> = Map::new("debug_log");
+const DEBUG_LOG_SEQ: Item<u64> = Item::new("debug_log_seq");
+
+// record_dispatch stores the text format rendering of a message packed into a MsgExec, so failed
+// integration tests can read what was actually sent.
+pub fn record_dispatch(
+    storage: &mut dyn Storage,
+    env: &Env,
+    any: &Any,
+    msg: &impl ProtoMessage,
+) -> StdResult<()> {
+    let seq = DEBUG_LOG_SEQ.may_load(storage)?.unwrap_or_default();
+    DEBUG_LOG.save(
+        storage,
+        seq % DEBUG_LOG_SIZE,
+        &DebugEntry {
+            height: env.block.height,
+            type_url: any.type_url.clone(),
+            text: msg.to_text(),
+        },
+    )?;
+    DEBUG_LOG_SEQ.save(storage, &(seq + 1))
+}
+
+// debug_log returns the most recent entries first.
+pub fn debug_log(storage: &dyn Storage, limit: Option<u32>) -> StdResult<DebugLogResponse> {
+    let seq = DEBUG_LOG_SEQ.may_load(storage)?.unwrap_or_default();
+    let limit = limit
+        .map_or(DEBUG_LOG_SIZE, u64::from)
+        .min(DEBUG_LOG_SIZE)
+        .min(seq);
+
+    let entries = (1..=limit)
+        .map(|back| DEBUG_LOG.load(storage, (seq - back) % DEBUG_LOG_SIZE))
+        .collect::<StdResult<_>>()?;
+
+    Ok(DebugLogResponse { entries })
+}
+
+#[cfg(test)]
+mod tests {
+    // proto messages are built from Default field by field for both proto backends
+    #![allow(clippy::field_reassign_with_default)]
+
+    use cosmwasm_std::testing::{mock_env, MockStorage};
+    use cosmwasm_std::Order;
+
+    use super::*;
+    use crate::any_msg::AnyMsg;
+    use crate::proto::CosmosBankSend::MsgSend;
+
+    fn record_at(storage: &mut dyn Storage, height: u64) {
+        let mut env = mock_env();
+        env.block.height = height;
+        let mut send = MsgSend::default();
+        send.to_address = format!("recipient{height}");
+        record_dispatch(storage, &env, &send.to_any().unwrap(), &send).unwrap();
+    }
+
+    fn heights(storage: &dyn Storage, limit: Option<u32>) -> Vec<u64> {
+        debug_log(storage, limit)
+            .unwrap()
+            .entries
+            .into_iter()
+            .map(|entry| entry.height)
+            .collect()
+    }
+
+    #[test]
+    fn log_is_empty_before_the_first_dispatch() {
+        let storage = MockStorage::new();
+        assert!(heights(&storage, None).is_empty());
+    }
+
+    #[test]
+    fn log_keeps_the_last_ten_entries() {
+        let mut storage = MockStorage::new();
+        for height in 1..=10 {
+            record_at(&mut storage, height);
+        }
+        assert_eq!(heights(&storage, None), (1..=10).rev().collect::<Vec<_>>());
+
+        // the eleventh entry overwrites the oldest slot
+        record_at(&mut storage, 11);
+        assert_eq!(heights(&storage, None), (2..=11).rev().collect::<Vec<_>>());
+        for height in 12..=23 {
+            record_at(&mut storage, height);
+        }
+        assert_eq!(heights(&storage, None), (14..=23).rev().collect::<Vec<_>>());
+        assert_eq!(heights(&storage, Some(3)), vec![23, 22, 21]);
+        assert_eq!(heights(&storage, Some(100)).len(), 10);
+        assert_eq!(
+            DEBUG_LOG
+                .keys(&storage, None, None, Order::Ascending)
+                .count(),
+            10
+        );
+
+        let newest = debug_log(&storage, Some(1)).unwrap().entries.remove(0);
+        assert_eq!(newest.type_url, MsgSend::TYPE_URL);
+        assert!(newest.text.contains("recipient23"));
+    }
+}
@@ -1,8 +1,130 @@
-use cosmwasm_std::StdError;
+use common::guard::GuardError;
+use common::split::SplitError;
+use cosmwasm_std::{StdError, Timestamp, Uint128};
+use cw_ownable::OwnershipError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
+
+    #[error(transparent)]
+    Ownership(#[from] OwnershipError),
+
+    #[error("{0}")]
+    Protobuf(#[from] crate::proto::ProtoError),
+
+    #[error("Unsupported message version {version}, latest is {latest}")]
+    UnsupportedVersion { version: u8, latest: u8 },
+
+    #[error("Fee of {fee_bps} bps exceeds the maximum of {max} bps")]
+    FeeTooHigh { fee_bps: u16, max: u16 },
+
+    #[error("Invalid fee config: {reason}")]
+    InvalidFeeConfig { reason: String },
+
+    #[error("Invalid rate limit: {reason}")]
+    InvalidRateLimit { reason: String },
+
+    #[error("Rate limit of {limit}{denom} is exceeded until height {resets_at}")]
+    RateLimitExceeded {
+        denom: String,
+        limit: Uint128,
+        resets_at: u64,
+    },
+
+    #[error("Wrong chain: expected {expected}, got {actual}")]
+    WrongChain { expected: String, actual: String },
+
+    #[error("Invalid public key: {reason}")]
+    InvalidPubkey { reason: String },
+
+    #[error("Invalid signature")]
+    InvalidSignature {},
+
+    #[error("No signer configured")]
+    NoSigner {},
+
+    #[error("Payload signed by {address} which is not the authorized signer")]
+    UnauthorizedSigner { address: String },
+
+    #[error("Nonce {nonce} was already used")]
+    NonceReused { nonce: u64 },
+
+    #[error("Invalid canonical JSON: {reason}")]
+    InvalidCanonicalJson { reason: String },
+
+    #[error("No coins to send")]
+    EmptyCoins {},
+
+    #[error("No expected recipient increase to assert")]
+    EmptyExpectedIncrease {},
+
+    #[error("Zero amount of {denom}")]
+    ZeroAmount { denom: String },
+
+    #[error("New admin is the same as the current admin")]
+    PointlessAdminRotation {},
+
+    #[error("Recipient {address} at index {index} is not allowed")]
+    RecipientNotAllowed { address: String, index: usize },
+
+    #[error("No recipients")]
+    EmptyRecipients {},
+
+    #[error("Upload session {session_id} has no chunks")]
+    EmptySession { session_id: String },
+
+    #[error("Upload session {session_id} is missing chunk {index}")]
+    MissingChunk { session_id: String, index: u32 },
+
+    #[error("Upload exceeds the maximum size of {max} bytes")]
+    UploadTooLarge { max: usize },
+
+    #[error("Scheduled send {id} not found")]
+    ScheduledNotFound { id: u64 },
+
+    #[error("Scheduled send can be executed in {remaining_blocks} blocks")]
+    TooEarly { remaining_blocks: u64 },
+
+    #[error("Type url {type_url} is blocked by the policy")]
+    TypeUrlBlocked { type_url: String },
+
+    #[error("Type url {type_url} is not registered")]
+    UnregisteredTypeUrl { type_url: String },
+
+    #[error("Value of {type_url} has fields the message doesn't define")]
+    UnknownFields { type_url: String },
+
+    #[error("Idempotency key {key} was already used with different parameters")]
+    DuplicateKey { key: String },
+
+    #[error(transparent)]
+    Guard(#[from] GuardError),
+
+    #[error(transparent)]
+    Split(#[from] SplitError),
+
+    #[error("Only one of expiration or expires_in_seconds may be set")]
+    AmbiguousExpiration {},
+
+    #[error("Expiration {expiration} is not after the block time {block_time}")]
+    ExpirationInPast {
+        expiration: Timestamp,
+        block_time: Timestamp,
+    },
+
+    #[error("Exec without messages")]
+    EmptyExec {},
+
+    #[error("Unknown reply id: {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("Unexpected balance increase of {denom}: expected {expected}, got {actual}")]
+    UnexpectedBalanceIncrease {
+        denom: String,
+        expected: Uint128,
+        actual: Uint128,
+    },
 }
@@ -0,0 +1,175 @@
+// Messages the authz contract dispatches for a fixed set of executions, so the Go tests can
+// compare against them instead of maintaining their own encodings. Printed by the gen-fixtures
+// binary into fixtures.json, which a test keeps in sync with the generated output.
+
+use std::collections::BTreeMap;
+
+use common::denom::build_denom;
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{coin, Addr, Uint128};
+use serde_json::{json, Value};
+
+use crate::contract::{execute, instantiate};
+use crate::msg::{ExecuteMsg, InstantiateMsg, SplitRecipient};
+use crate::registry::ALL_TYPE_URLS;
+
+const OWNER: &str = "owner";
+const GRANTER: &str = "granter";
+const RECIPIENT: &str = "recipient";
+
+fn instantiate_msg() -> InstantiateMsg {
+    InstantiateMsg {
+        version: None,
+        granter: Addr::unchecked(GRANTER),
+        idempotency_ttl_blocks: None,
+        expected_chain_id: None,
+        fee_bps: None,
+        fee_collector: None,
+        signer: None,
+    }
+}
+
+// run instantiates a fresh contract and returns the messages of the execution.
+fn run(instantiate_msg: InstantiateMsg, msg: ExecuteMsg) -> Value {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(OWNER, &[]),
+        instantiate_msg,
+    )
+    .expect("instantiate");
+    let res = execute(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).expect("execute");
+
+    json!({ "messages": res.messages })
+}
+
+/// Renders the fixtures as pretty printed JSON, keys are sorted so the output is reproducible.
+pub fn generate() -> String {
+    let mut fixtures = BTreeMap::new();
+
+    fixtures.insert(
+        "transfer",
+        run(
+            instantiate_msg(),
+            ExecuteMsg::Transfer {
+                address: Addr::unchecked(RECIPIENT),
+                amount: 1000,
+                denom: "ucore".to_string(),
+                idempotency_key: None,
+            },
+        ),
+    );
+    fixtures.insert(
+        "transfer_with_fee",
+        run(
+            InstantiateMsg {
+                fee_bps: Some(250),
+                fee_collector: Some("collector".to_string()),
+                ..instantiate_msg()
+            },
+            ExecuteMsg::Transfer {
+                address: Addr::unchecked(RECIPIENT),
+                amount: 1000,
+                denom: "ucore".to_string(),
+                idempotency_key: None,
+            },
+        ),
+    );
+    fixtures.insert(
+        "split_send",
+        run(
+            instantiate_msg(),
+            ExecuteMsg::SplitSend {
+                denom: "ucore".to_string(),
+                amount: Uint128::new(1000),
+                recipients: vec![
+                    SplitRecipient {
+                        address: "alice".to_string(),
+                        weight: 1,
+                    },
+                    SplitRecipient {
+                        address: "bob".to_string(),
+                        weight: 2,
+                    },
+                ],
+                idempotency_key: None,
+            },
+        ),
+    );
+    fixtures.insert(
+        "update_admin",
+        run(
+            instantiate_msg(),
+            ExecuteMsg::UpdateAdmin {
+                contract: "contract".to_string(),
+                new_admin: "admin".to_string(),
+            },
+        ),
+    );
+    fixtures.insert(
+        "clear_admin",
+        run(
+            instantiate_msg(),
+            ExecuteMsg::ClearAdmin {
+                contract: "contract".to_string(),
+            },
+        ),
+    );
+    fixtures.insert(
+        "propose_send_enabled",
+        run(
+            instantiate_msg(),
+            ExecuteMsg::ProposeSendEnabled {
+                authority: "gov".to_string(),
+                denom: "ucore".to_string(),
+                enabled: false,
+                deposit: vec![coin(10_000_000, "ucore")],
+                title: "Disable ucore sends".to_string(),
+                summary: "Disable ucore sends".to_string(),
+            },
+        ),
+    );
+    // a message type registered without a fixture still changes the file
+    fixtures.insert("type_urls", json!(ALL_TYPE_URLS));
+    fixtures.insert("denoms", derived_denoms());
+
+    serde_json::to_string_pretty(&fixtures).expect("serialize fixtures")
+}
+
+// derived_denoms lists the denoms of smart tokens issued by fixed issuers, so the Go side can check
+// them against BuildDenom of x/asset/ft.
+fn derived_denoms() -> Value {
+    let issuers = [
+        "devcore172rc5sz2uclpsy3vvx3y79ah5dk450z5ruq2r5",
+        "devcore1k3mke3gyf9apyd8vxveutgp9h4j2e80e05yfuq",
+    ];
+    let denoms: Vec<Value> = issuers
+        .iter()
+        .flat_map(|issuer| {
+            ["abc", "uABC1"].map(|subunit| {
+                json!({
+                    "subunit": subunit,
+                    "issuer": issuer,
+                    "denom": build_denom(subunit, &Addr::unchecked(*issuer)),
+                })
+            })
+        })
+        .collect();
+    Value::Array(denoms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_in_copy_is_current() {
+        // the binary prints the fixtures with a trailing newline
+        assert!(
+            format!("{}\n", generate()) == include_str!("../fixtures.json"),
+            "fixtures.json is stale, regenerate it with \
+             `cargo run --features fixtures --bin gen-fixtures > fixtures.json`"
+        );
+    }
+}
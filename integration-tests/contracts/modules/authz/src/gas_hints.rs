@@ -0,0 +1,89 @@
+use crate::msg::{GasComponent, SimulateResponse};
+
+// Deterministic gas constants of x/deterministicgas/config.go together with the default
+// tx_size_cost_per_byte auth param, keep them in sync with the chain.
+pub const FIXED_GAS: u64 = 65_000;
+pub const FREE_BYTES: u64 = 2_048;
+pub const TX_SIZE_COST_PER_BYTE: u64 = 10;
+pub const AUTHZ_EXEC_OVERHEAD: u64 = 2_000;
+pub const BANK_SEND_PER_COIN_GAS: u64 = 50_000;
+
+/// Estimates the deterministic gas of a transaction carrying a single MsgExec with one MsgSend of
+/// the given number of coins. Only the encoded message counts towards the size, so the estimate
+/// is lower than the real one once signatures and fees push the transaction over the free bytes.
+pub fn exec_send_gas(encoded_size: u64, coins: usize) -> SimulateResponse {
+    let breakdown = vec![
+        GasComponent {
+            component: "fixed".to_string(),
+            gas: FIXED_GAS,
+        },
+        GasComponent {
+            component: "authz_exec".to_string(),
+            gas: AUTHZ_EXEC_OVERHEAD,
+        },
+        GasComponent {
+            component: "bank_send".to_string(),
+            gas: coins.max(1) as u64 * BANK_SEND_PER_COIN_GAS,
+        },
+        GasComponent {
+            component: "tx_size".to_string(),
+            gas: encoded_size.saturating_sub(FREE_BYTES) * TX_SIZE_COST_PER_BYTE,
+        },
+    ];
+
+    SimulateResponse {
+        encoded_size,
+        estimated_gas: breakdown.iter().map(|component| component.gas).sum(),
+        breakdown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breakdown(res: &SimulateResponse) -> Vec<(&str, u64)> {
+        res.breakdown
+            .iter()
+            .map(|component| (component.component.as_str(), component.gas))
+            .collect()
+    }
+
+    #[test]
+    fn one_coin() {
+        let res = exec_send_gas(180, 1);
+        assert_eq!(res.encoded_size, 180);
+        assert_eq!(res.estimated_gas, 117_000);
+        assert_eq!(
+            breakdown(&res),
+            vec![
+                ("fixed", 65_000),
+                ("authz_exec", 2_000),
+                ("bank_send", 50_000),
+                ("tx_size", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn ten_coins() {
+        let res = exec_send_gas(900, 10);
+        assert_eq!(res.estimated_gas, 567_000);
+        assert_eq!(
+            breakdown(&res),
+            vec![
+                ("fixed", 65_000),
+                ("authz_exec", 2_000),
+                ("bank_send", 500_000),
+                ("tx_size", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn bytes_past_the_free_ones_are_charged() {
+        assert_eq!(exec_send_gas(2_048, 1).estimated_gas, 117_000);
+        assert_eq!(exec_send_gas(2_049, 1).estimated_gas, 117_010);
+        assert_eq!(exec_send_gas(3_048, 10).estimated_gas, 577_000);
+    }
+}
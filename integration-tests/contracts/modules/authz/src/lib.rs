@@ -1,6 +1,19 @@
+pub mod address;
+pub mod any_msg;
+pub mod builder;
+pub mod canonical;
 pub mod contract;
+#[cfg(feature = "debug")]
+mod debug;
 mod error;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+pub mod gas_hints;
 pub mod msg;
+pub mod proto;
+pub mod registry;
 pub mod state;
+#[cfg(test)]
+mod testing;
 
 pub use crate::error::ContractError;
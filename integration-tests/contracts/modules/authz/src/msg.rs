@@ -1,16 +1,503 @@
+pub use common::raw_range::{RawEntry, RawRangeResponse};
+pub use common::split::SplitRecipient;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Binary, Coin, Timestamp, Uint128};
 
+use crate::error::ContractError;
+
+// latest InstantiateMsg layout, payloads without a version are treated as version 1 which only
+// carried the granter, every field added since is optional
+pub const INSTANTIATE_MSG_VERSION: u8 = 2;
+
+// idempotency key lifetime of payloads that omit it
+pub const DEFAULT_IDEMPOTENCY_TTL_BLOCKS: u64 = 100;
+
+// Wire format accepted by instantiate
 #[cw_serde]
 pub struct InstantiateMsg {
+    pub version: Option<u8>,
     pub granter: Addr,
+    // number of blocks an idempotency key is remembered for, defaults to 100
+    pub idempotency_ttl_blocks: Option<u64>,
+    // execute fails on any other chain when set
+    pub expected_chain_id: Option<String>,
+    // fee in basis points skimmed from every send and sent to the collector, at most 1000
+    pub fee_bps: Option<u16>,
+    pub fee_collector: Option<String>,
+    // address whose signatures ExecSigned accepts, ExecSigned is disabled if not set
+    pub signer: Option<String>,
+}
+
+// InstantiateMsg with the version specific defaults applied
+#[cw_serde]
+pub struct InstantiateMsgV2 {
+    pub granter: Addr,
+    pub idempotency_ttl_blocks: u64,
+    pub expected_chain_id: Option<String>,
+    pub fee_bps: Option<u16>,
+    pub fee_collector: Option<String>,
+    pub signer: Option<String>,
+}
+
+impl InstantiateMsgV2 {
+    /// Applies the defaults of version 1 payloads: the default idempotency TTL and nothing of the
+    /// fee, chain and signer settings added with version 2.
+    pub fn from_legacy(msg: InstantiateMsg) -> Self {
+        Self {
+            granter: msg.granter,
+            idempotency_ttl_blocks: msg
+                .idempotency_ttl_blocks
+                .unwrap_or(DEFAULT_IDEMPOTENCY_TTL_BLOCKS),
+            expected_chain_id: msg.expected_chain_id,
+            fee_bps: msg.fee_bps,
+            fee_collector: msg.fee_collector,
+            signer: msg.signer,
+        }
+    }
+}
+
+impl TryFrom<InstantiateMsg> for InstantiateMsgV2 {
+    type Error = ContractError;
+
+    fn try_from(msg: InstantiateMsg) -> Result<Self, ContractError> {
+        match msg.version.unwrap_or(1) {
+            1 | 2 => Ok(Self::from_legacy(msg)),
+            version => Err(ContractError::UnsupportedVersion {
+                version,
+                latest: INSTANTIATE_MSG_VERSION,
+            }),
+        }
+    }
+}
+
+// signed by the authorized signer and passed to ExecSigned as canonical JSON, the nonce must be
+// greater than the one of the previously executed payload
+#[cw_serde]
+pub struct SignedPayload {
+    pub send: SendSpec,
+    pub nonce: u64,
+}
+
+#[cw_serde]
+pub struct SendSpec {
+    pub to_address: String,
+    pub coins: Vec<Coin>,
+}
+
+#[cw_serde]
+pub enum PolicyMode {
+    // only the listed type urls may be executed
+    Allow,
+    // the listed type urls may not be executed
+    Deny,
+}
+
+// type urls ending with `*` match every type url with the preceding prefix
+#[cw_serde]
+pub struct TypeUrlPolicy {
+    pub mode: PolicyMode,
+    pub type_urls: Vec<String>,
+}
+
+// most the grant may send of every native denom within a window of blocks, the window starts with
+// the first send after the previous one ended
+#[cw_serde]
+pub struct RateLimit {
+    pub amount: Uint128,
+    pub window_blocks: u64,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
+    // a repeated call with the same idempotency key and parameters succeeds without sending again,
+    // the same key with other parameters is rejected. The other sends take an idempotency key the
+    // same way.
     Transfer {
         address: Addr,
         amount: u64,
         denom: String,
+        idempotency_key: Option<String>,
+    },
+    // sends the coins and fails the whole transaction if the recipient balance does not increase
+    // by exactly the expected amounts, with a fee configured they have to be net of it
+    SendAndAssert {
+        to_address: String,
+        coins: Vec<Coin>,
+        expected_recipient_increase: Vec<Coin>,
+        idempotency_key: Option<String>,
+    },
+    // rotates the admin of a contract the granter administers
+    UpdateAdmin {
+        contract: String,
+        new_admin: String,
+    },
+    ClearAdmin {
+        contract: String,
+    },
+    // grants the grantee an authorization of the granter, Unknown authorizations are passed on
+    // as given. The grant expires at expiration or expires_in_seconds after the current block
+    // time, at most one of them may be set
+    Grant {
+        grantee: String,
+        authorization: Authorization,
+        expiration: Option<Timestamp>,
+        expires_in_seconds: Option<u64>,
+    },
+    // revokes the grantee's authorization of the granter for msg_type_url
+    Revoke {
+        grantee: String,
+        msg_type_url: String,
+    },
+    // owner managed allowlist of recipients, an empty allowlist allows any recipient
+    AddRecipient {
+        address: String,
+    },
+    RemoveRecipient {
+        address: String,
+    },
+    // submits a gov proposal, deposited from the contract balance, toggling sending of the denom
+    // through the authority gated MsgSetSendEnabled
+    ProposeSendEnabled {
+        authority: String,
+        denom: String,
+        enabled: bool,
+        deposit: Vec<Coin>,
+        title: String,
+        summary: String,
+    },
+    // divides the amount across the recipients proportionally to the weights, sending everything
+    // in a single MsgExec with the fee of all shares in one send to the collector
+    SplitSend {
+        denom: String,
+        amount: Uint128,
+        recipients: Vec<SplitRecipient>,
+        idempotency_key: Option<String>,
+    },
+    // sends to every output in a single MsgMultiSend from the granter, the granter has to authorize
+    // MsgMultiSend since a SendAuthorization only covers MsgSend. The fee of all outputs goes to the
+    // collector in one extra output.
+    MultiSend {
+        outputs: Vec<SendSpec>,
+        idempotency_key: Option<String>,
+    },
+    // executes a send authorized off-chain by the signer, the signature covers the sha256 of the
+    // canonical JSON SignedPayload
+    ExecSigned {
+        payload: Binary,
+        signature: Binary,
+        pubkey: Binary,
+    },
+    // restricts the type urls of the messages the contract executes through authz
+    SetTypeUrlPolicy {
+        mode: PolicyMode,
+        type_urls: Vec<String>,
+    },
+    // limits the sends of native denoms, smart tokens and IBC vouchers are not limited. Removes
+    // the limit if not set
+    SetRateLimit {
+        limit: Option<RateLimit>,
     },
+    // stores one chunk of an encoded message too large for a single transaction
+    UploadChunk {
+        session_id: String,
+        index: u32,
+        data: Binary,
+    },
+    // reassembles the chunks of the session in index order and executes them as the value of an
+    // Any with the type url through authz
+    ExecUploaded {
+        session_id: String,
+        type_url: String,
+    },
+    // time locked send, anybody may execute it once the height is passed while the owner may
+    // cancel it until then
+    ScheduleSend {
+        to_address: String,
+        coins: Vec<Coin>,
+        execute_after_height: u64,
+    },
+    ExecuteScheduled {
+        id: u64,
+    },
+    CancelScheduled {
+        id: u64,
+    },
+    // stages a send to be released later by Flush
+    Enqueue {
+        send: SendSpec,
+    },
+    // releases up to max staged sends, oldest first, in a single MsgExec
+    Flush {
+        max: Option<u32>,
+        idempotency_key: Option<String>,
+    },
+}
+
+#[cw_serde]
+pub enum QueryMsg {
+    ChainInfo {},
+    Session {
+        id: String,
+    },
+    // grants the contract holds as grantee with their authorizations decoded
+    Grants {},
+    // spend limit left on the send authorization the granter gave the contract
+    RemainingSpendLimit {
+        granter: String,
+    },
+    // returns null while no policy is set
+    TypeUrlPolicy {},
+    // returns null while no limit is set
+    RateLimit {},
+    // encodes the send without executing it and reports its deterministic gas estimate
+    Simulate {
+        send: SendSpec,
+    },
+    Recipients {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    Queue {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    Scheduled {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    // amounts sent from the grant so far, a single denom or all denoms paginated
+    TotalSpent {
+        denom: Option<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    // dumps raw storage entries, only compiled into test builds
+    #[cfg(feature = "debug")]
+    RawRange {
+        start: Option<Binary>,
+        end: Option<Binary>,
+        limit: u32,
+    },
+    // text format rendering of the last dispatched messages, newest first, only compiled into test
+    // builds
+    #[cfg(feature = "debug")]
+    DebugLog {
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct ChainInfoResponse {
+    pub chain_id: String,
+    pub height: u64,
+    pub time: Timestamp,
+    pub expected_chain_id: Option<String>,
+    pub instantiate_height: u64,
+}
+
+#[cw_serde]
+pub struct GasComponent {
+    pub component: String,
+    pub gas: u64,
+}
+
+#[cw_serde]
+pub struct SimulateResponse {
+    pub encoded_size: u64,
+    pub estimated_gas: u64,
+    pub breakdown: Vec<GasComponent>,
+}
+
+#[cw_serde]
+pub struct SessionResponse {
+    // uploaded chunk indexes in ascending order
+    pub chunks: Vec<u32>,
+    pub total_size: u64,
+}
+
+#[cw_serde]
+pub enum Authorization {
+    Send {
+        spend_limit: Vec<Coin>,
+        // recipients the grantee may send to, empty allows any
+        #[serde(default)]
+        allow_list: Vec<String>,
+    },
+    Generic {
+        msg: String,
+    },
+    Stake {
+        // maximum amount the grantee may (un/re)delegate, None for no limit
+        max_tokens: Option<Coin>,
+        // validators the grantee may or may not use, None allows any
+        validators: Option<StakeValidators>,
+        authorization_type: StakeAuthorizationType,
+    },
+    // authorization types the contract does not decode
+    Unknown {
+        type_url: String,
+        value: Binary,
+    },
+}
+
+#[cw_serde]
+pub enum StakeValidators {
+    AllowList(Vec<String>),
+    DenyList(Vec<String>),
+}
+
+// the staking message a StakeAuthorization authorizes
+#[cw_serde]
+pub enum StakeAuthorizationType {
+    Delegate,
+    Undelegate,
+    Redelegate,
+}
+
+#[cw_serde]
+pub struct GrantInfo {
+    pub granter: String,
+    pub authorization: Authorization,
+    // None for grants without expiration
+    pub expiration: Option<Timestamp>,
+}
+
+#[cw_serde]
+pub struct GrantsResponse {
+    pub grants: Vec<GrantInfo>,
+}
+
+#[cw_serde]
+pub enum RemainingSpendLimitResponse {
+    SpendLimit { coins: Vec<Coin> },
+    // the granter's grant for MsgSend is of another type
+    NotSendAuthorization { type_url: String },
+}
+
+#[cw_serde]
+pub struct RecipientsResponse {
+    pub recipients: Vec<Addr>,
+}
+
+#[cw_serde]
+pub struct QueueEntry {
+    pub id: u64,
+    pub send: SendSpec,
+}
+
+#[cw_serde]
+pub struct QueueResponse {
+    pub entries: Vec<QueueEntry>,
+}
+
+#[cw_serde]
+pub struct ScheduledSend {
+    pub send: SendSpec,
+    pub execute_after_height: u64,
+}
+
+#[cw_serde]
+pub struct ScheduledEntry {
+    pub id: u64,
+    pub scheduled: ScheduledSend,
+}
+
+#[cw_serde]
+pub struct ScheduledResponse {
+    pub entries: Vec<ScheduledEntry>,
+}
+
+#[cw_serde]
+pub struct TotalSpentResponse {
+    pub spent: Vec<Coin>,
+    // set if any of the returned amounts overflowed and is capped at Uint128::MAX
+    pub saturated: bool,
+}
+
+#[cw_serde]
+pub struct DebugEntry {
+    pub height: u64,
+    pub type_url: String,
+    pub text: String,
+}
+
+#[cw_serde]
+pub struct DebugLogResponse {
+    pub entries: Vec<DebugEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, to_json_vec};
+
+    use super::*;
+
+    const V1: &str = include_str!("../testdata/instantiate_msg_v1.json");
+    const V2: &str = include_str!("../testdata/instantiate_msg_v2.json");
+
+    // parses the fixture and checks it survives a serialization round trip unchanged
+    fn round_trip(fixture: &str) -> InstantiateMsg {
+        let msg: InstantiateMsg = from_json(fixture.trim().as_bytes()).unwrap();
+        let encoded = to_json_vec(&msg).unwrap();
+        assert_eq!(from_json::<InstantiateMsg>(&encoded).unwrap(), msg);
+        msg
+    }
+
+    #[test]
+    fn v1_payload_gets_the_defaults() {
+        let msg = round_trip(V1);
+        assert_eq!(msg.version, None);
+        assert_eq!(
+            InstantiateMsgV2::try_from(msg).unwrap(),
+            InstantiateMsgV2 {
+                granter: Addr::unchecked("granter"),
+                idempotency_ttl_blocks: DEFAULT_IDEMPOTENCY_TTL_BLOCKS,
+                expected_chain_id: None,
+                fee_bps: None,
+                fee_collector: None,
+                signer: None,
+            }
+        );
+    }
+
+    #[test]
+    fn v2_payload_is_kept() {
+        let msg = round_trip(V2);
+        assert_eq!(
+            InstantiateMsgV2::try_from(msg).unwrap(),
+            InstantiateMsgV2 {
+                granter: Addr::unchecked("granter"),
+                idempotency_ttl_blocks: 50,
+                expected_chain_id: Some("coreum-devnet-1".to_string()),
+                fee_bps: Some(25),
+                fee_collector: Some("collector".to_string()),
+                signer: Some("signer".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn explicit_v1_is_the_legacy_layout() {
+        let mut msg = round_trip(V1);
+        msg.version = Some(1);
+        assert_eq!(
+            InstantiateMsgV2::try_from(msg.clone()).unwrap(),
+            InstantiateMsgV2::from_legacy(msg)
+        );
+    }
+
+    #[test]
+    fn unknown_versions_are_rejected() {
+        for version in [0, INSTANTIATE_MSG_VERSION + 1] {
+            let msg = InstantiateMsg {
+                version: Some(version),
+                ..round_trip(V1)
+            };
+            let err = InstantiateMsgV2::try_from(msg).unwrap_err();
+            assert!(matches!(
+                err,
+                ContractError::UnsupportedVersion { version: found, latest: 2 } if found == version
+            ));
+        }
+    }
 }
@@ -0,0 +1,333 @@
+// Proto layer of the contract. The default legacy feature uses the rust-protobuf messages the common
+// crate generates, the prost feature the prost types of cosmos-sdk-proto, which give a
+// smaller binary. Contract code only relies on what both have in common: messages are built from
+// Default by assigning fields, and encoded or decoded through ProtoMessage.
+
+#[cfg(all(feature = "legacy", feature = "prost"))]
+compile_error!("features legacy and prost are mutually exclusive");
+
+#[cfg(not(any(feature = "legacy", feature = "prost")))]
+compile_error!("one of the features legacy or prost must be enabled");
+
+/// Encoding and decoding of a protobuf message, implemented for the messages of the enabled proto
+/// backend.
+pub trait ProtoMessage: Sized {
+    fn encode_bytes(&self) -> Result<Vec<u8>, ProtoError>;
+
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, ProtoError>;
+
+    fn encoded_size(&self) -> u64;
+
+    /// Reports whether the message, decoded from the encoded bytes, carried fields it doesn't
+    /// define.
+    fn has_unknown_fields(&self, encoded: &[u8]) -> bool;
+
+    /// Human readable rendering of the message, as kept in the debug log.
+    fn to_text(&self) -> String;
+}
+
+/// Converts a cosmwasm time, e.g. env.block.time, to the proto Timestamp.
+#[allow(clippy::field_reassign_with_default)]
+pub fn timestamp(time: cosmwasm_std::Timestamp) -> Timestamp {
+    let mut timestamp = Timestamp::default();
+    timestamp.seconds = time.seconds() as i64;
+    timestamp.nanos = time.subsec_nanos() as i32;
+    timestamp
+}
+
+/// Converts a proto Timestamp back to the cosmwasm time, times before the epoch are clamped to it.
+pub fn block_time(timestamp: &Timestamp) -> cosmwasm_std::Timestamp {
+    cosmwasm_std::Timestamp::from_seconds(timestamp.seconds.max(0) as u64)
+        .plus_nanos(timestamp.nanos.max(0) as u64)
+}
+
+// values of the staking AuthorizationType enum, named differently by the backends
+pub const AUTHORIZATION_TYPE_DELEGATE: i32 = 1;
+pub const AUTHORIZATION_TYPE_UNDELEGATE: i32 = 2;
+pub const AUTHORIZATION_TYPE_REDELEGATE: i32 = 3;
+
+/// Validators a StakeAuthorization is restricted to.
+pub enum ValidatorPolicy {
+    AllowList(Vec<String>),
+    DenyList(Vec<String>),
+}
+
+/// Fields of a StakeAuthorization. The backends represent its validators oneof and its
+/// authorization type enum differently, so it is built and read through this struct.
+pub struct StakeAuthorizationFields {
+    pub max_tokens: Option<CosmosAuthzQuery::Coin>,
+    pub validators: Option<ValidatorPolicy>,
+    pub authorization_type: i32,
+}
+
+#[cfg(feature = "legacy")]
+pub use common::protos::{
+    CosmosAuthz, CosmosAuthzQuery, CosmosBankParams, CosmosBankSend, CosmosGov, CosmosWasm,
+};
+
+#[cfg(feature = "legacy")]
+pub use protobuf::well_known_types::{any::Any, timestamp::Timestamp};
+
+#[cfg(feature = "legacy")]
+pub type ProtoError = protobuf::Error;
+
+#[cfg(feature = "legacy")]
+impl<T: protobuf::MessageFull + std::fmt::Display> ProtoMessage for T {
+    fn encode_bytes(&self) -> Result<Vec<u8>, ProtoError> {
+        self.write_to_bytes()
+    }
+
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, ProtoError> {
+        Self::parse_from_bytes(bytes)
+    }
+
+    fn encoded_size(&self) -> u64 {
+        self.compute_size()
+    }
+
+    fn has_unknown_fields(&self, _encoded: &[u8]) -> bool {
+        carries_unknown_fields(self)
+    }
+
+    fn to_text(&self) -> String {
+        self.to_string()
+    }
+}
+
+// carries_unknown_fields walks the message and the messages nested in it for the unknown fields
+// rust-protobuf keeps while decoding
+#[cfg(feature = "legacy")]
+fn carries_unknown_fields(msg: &dyn protobuf::MessageDyn) -> bool {
+    use protobuf::reflect::{ReflectFieldRef, ReflectValueRef};
+
+    let nested = |value: ReflectValueRef| match value {
+        ReflectValueRef::Message(msg) => carries_unknown_fields(&*msg),
+        _ => false,
+    };
+    msg.unknown_fields_dyn().iter().next().is_some()
+        || msg
+            .descriptor_dyn()
+            .fields()
+            .any(|field| match field.get_reflect(msg) {
+                ReflectFieldRef::Optional(value) => value.value().is_some_and(nested),
+                ReflectFieldRef::Repeated(values) => values.into_iter().any(nested),
+                ReflectFieldRef::Map(entries) => {
+                    (&entries).into_iter().any(|(_, value)| nested(value))
+                }
+            })
+}
+
+#[cfg(all(test, feature = "legacy"))]
+pub fn full_name<T: protobuf::MessageFull>() -> String {
+    T::descriptor().full_name().to_string()
+}
+
+#[cfg(feature = "legacy")]
+pub fn any(type_url: String, value: Vec<u8>) -> Any {
+    Any {
+        type_url,
+        value,
+        special_fields: Default::default(),
+    }
+}
+
+#[cfg(feature = "legacy")]
+impl StakeAuthorizationFields {
+    pub fn into_proto(self) -> CosmosAuthzQuery::StakeAuthorization {
+        use CosmosAuthzQuery::stake_authorization::{Policy, Validators};
+
+        let validators = |address| Validators {
+            address,
+            ..Default::default()
+        };
+        CosmosAuthzQuery::StakeAuthorization {
+            max_tokens: self.max_tokens.into(),
+            policy: self.validators.map(|policy| match policy {
+                ValidatorPolicy::AllowList(address) => Policy::AllowList(validators(address)),
+                ValidatorPolicy::DenyList(address) => Policy::DenyList(validators(address)),
+            }),
+            authorization_type: protobuf::EnumOrUnknown::from_i32(self.authorization_type),
+            ..Default::default()
+        }
+    }
+
+    pub fn from_proto(stake: CosmosAuthzQuery::StakeAuthorization) -> Self {
+        use CosmosAuthzQuery::stake_authorization::Policy;
+
+        Self {
+            max_tokens: stake.max_tokens.into_option(),
+            // the generated oneof is non_exhaustive outside of the common crate
+            validators: stake.policy.and_then(|policy| match policy {
+                Policy::AllowList(validators) => {
+                    Some(ValidatorPolicy::AllowList(validators.address))
+                }
+                Policy::DenyList(validators) => Some(ValidatorPolicy::DenyList(validators.address)),
+                _ => None,
+            }),
+            authorization_type: stake.authorization_type.value(),
+        }
+    }
+}
+
+#[cfg(feature = "prost")]
+pub use cosmos_sdk_proto::{Any, Timestamp};
+
+#[cfg(feature = "prost")]
+pub type ProtoError = cosmos_sdk_proto::prost::DecodeError;
+
+#[cfg(feature = "prost")]
+impl<T: cosmos_sdk_proto::prost::Message + Default> ProtoMessage for T {
+    fn encode_bytes(&self) -> Result<Vec<u8>, ProtoError> {
+        Ok(self.encode_to_vec())
+    }
+
+    fn decode_bytes(bytes: &[u8]) -> Result<Self, ProtoError> {
+        Self::decode(bytes)
+    }
+
+    fn encoded_size(&self) -> u64 {
+        self.encoded_len() as u64
+    }
+
+    // prost drops unknown fields while decoding, a message that doesn't encode back to the same
+    // bytes had fields it doesn't define or wasn't canonically encoded
+    fn has_unknown_fields(&self, encoded: &[u8]) -> bool {
+        self.encode_to_vec() != encoded
+    }
+
+    fn to_text(&self) -> String {
+        format!("{self:?}")
+    }
+}
+
+#[cfg(all(test, feature = "prost"))]
+pub fn full_name<T: cosmos_sdk_proto::prost::Name>() -> String {
+    T::full_name()
+}
+
+#[cfg(feature = "prost")]
+pub fn any(type_url: String, value: Vec<u8>) -> Any {
+    Any { type_url, value }
+}
+
+#[cfg(feature = "prost")]
+impl StakeAuthorizationFields {
+    pub fn into_proto(self) -> CosmosAuthzQuery::StakeAuthorization {
+        use CosmosAuthzQuery::stake_authorization::{Policy, Validators};
+
+        CosmosAuthzQuery::StakeAuthorization {
+            max_tokens: self.max_tokens,
+            validators: self.validators.map(|policy| match policy {
+                ValidatorPolicy::AllowList(address) => Policy::AllowList(Validators { address }),
+                ValidatorPolicy::DenyList(address) => Policy::DenyList(Validators { address }),
+            }),
+            authorization_type: self.authorization_type,
+        }
+    }
+
+    pub fn from_proto(stake: CosmosAuthzQuery::StakeAuthorization) -> Self {
+        use CosmosAuthzQuery::stake_authorization::Policy;
+
+        Self {
+            max_tokens: stake.max_tokens,
+            validators: stake.validators.map(|policy| match policy {
+                Policy::AllowList(validators) => ValidatorPolicy::AllowList(validators.address),
+                Policy::DenyList(validators) => ValidatorPolicy::DenyList(validators.address),
+            }),
+            authorization_type: stake.authorization_type,
+        }
+    }
+}
+
+// the prost messages grouped like the generated legacy modules, so both backends share the paths
+
+#[cfg(feature = "prost")]
+#[allow(non_snake_case)]
+pub mod CosmosAuthz {
+    pub use cosmos_sdk_proto::cosmos::authz::v1beta1::{Grant, MsgExec, MsgGrant, MsgRevoke};
+}
+
+#[cfg(feature = "prost")]
+#[allow(non_snake_case)]
+pub mod CosmosAuthzQuery {
+    pub use cosmos_sdk_proto::cosmos::authz::v1beta1::{
+        GenericAuthorization, Grant, GrantAuthorization, QueryGranteeGrantsRequest,
+        QueryGranteeGrantsResponse, QueryGrantsRequest, QueryGrantsResponse,
+    };
+    pub use cosmos_sdk_proto::cosmos::bank::v1beta1::SendAuthorization;
+    pub use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    pub use cosmos_sdk_proto::cosmos::staking::v1beta1::{
+        stake_authorization, AuthorizationType, StakeAuthorization,
+    };
+}
+
+#[cfg(feature = "prost")]
+#[allow(non_snake_case)]
+pub mod CosmosBankParams {
+    pub use cosmos_sdk_proto::cosmos::bank::v1beta1::{
+        MsgSetSendEnabled, MsgUpdateParams, Params, SendEnabled,
+    };
+}
+
+#[cfg(feature = "prost")]
+#[allow(non_snake_case)]
+pub mod CosmosBankSend {
+    pub use cosmos_sdk_proto::cosmos::bank::v1beta1::{Input, MsgMultiSend, MsgSend, Output};
+    pub use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+}
+
+#[cfg(feature = "prost")]
+#[allow(non_snake_case)]
+pub mod CosmosGov {
+    pub use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
+    pub use cosmos_sdk_proto::cosmos::gov::v1::MsgSubmitProposal;
+}
+
+#[cfg(feature = "prost")]
+#[allow(non_snake_case)]
+pub mod CosmosWasm {
+    pub use cosmos_sdk_proto::cosmwasm::wasm::v1::{MsgClearAdmin, MsgUpdateAdmin};
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::CosmosBankSend::{Coin, MsgSend};
+    use super::*;
+
+    // generous enough for unoptimized builds on a loaded machine, a gross regression of the
+    // encoding still fails it, see benches/proto_bench.rs for the actual numbers
+    const ENCODE_BOUND: Duration = Duration::from_secs(2);
+    const ENCODE_ROUNDS: u32 = 1000;
+
+    #[test]
+    #[allow(clippy::field_reassign_with_default)]
+    fn encode_of_a_hundred_coins_is_bounded() {
+        let mut send = MsgSend::default();
+        send.from_address = "granter".to_string();
+        send.to_address = "recipient".to_string();
+        send.amount = (0..100)
+            .map(|i| {
+                let mut coin = Coin::default();
+                coin.denom = format!("subunit{i}-granter");
+                coin.amount = "1000000000".to_string();
+                coin
+            })
+            .collect();
+
+        let start = Instant::now();
+        let mut encoded = vec![];
+        for _ in 0..ENCODE_ROUNDS {
+            encoded = send.encode_bytes().unwrap();
+        }
+        let elapsed = start.elapsed();
+
+        assert_eq!(encoded.len() as u64, send.encoded_size());
+        assert_eq!(MsgSend::decode_bytes(&encoded).unwrap(), send);
+        assert!(
+            elapsed < ENCODE_BOUND,
+            "{ENCODE_ROUNDS} encodes took {elapsed:?}, bound is {ENCODE_BOUND:?}"
+        );
+    }
+}
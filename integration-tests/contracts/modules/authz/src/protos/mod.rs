@@ -1,2 +0,0 @@
-pub mod CosmosAuthz;
-pub mod CosmosBankSend;
@@ -0,0 +1,232 @@
+use common::typeurl;
+
+use crate::any_msg::AnyMsg;
+use crate::error::ContractError;
+use crate::proto::{
+    CosmosAuthz, CosmosAuthzQuery, CosmosBankParams, CosmosBankSend, CosmosGov, CosmosWasm,
+    ProtoMessage,
+};
+
+// register_type_urls implements AnyMsg for every listed proto message, collects the type URLs in
+// ALL_TYPE_URLS and generates the decode_registered dispatch with an arm per type URL. The list is
+// checked while compiling, so a copy-pasted URL or one missing the leading slash breaks the build
+// instead of the dispatch.
+macro_rules! register_type_urls {
+    ($($msg:ty => $type_url:path),* $(,)?) => {
+        $(
+            impl AnyMsg for $msg {
+                const TYPE_URL: &'static str = $type_url;
+            }
+        )*
+
+        /// Type URLs of all the proto messages the contract packs into or unpacks from an Any.
+        pub const ALL_TYPE_URLS: &[&str] = &[$($type_url),*];
+
+        const _: () = check_type_urls(ALL_TYPE_URLS);
+
+        /// Decodes the value of an Any as the message registered for its type URL, values with
+        /// fields the message doesn't define are rejected.
+        pub fn decode_registered(type_url: &str, value: &[u8]) -> Result<(), ContractError> {
+            let unknown_fields = match type_url {
+                $($type_url => <$msg>::decode_bytes(value)?.has_unknown_fields(value),)*
+                _ => {
+                    return Err(ContractError::UnregisteredTypeUrl {
+                        type_url: type_url.to_string(),
+                    })
+                }
+            };
+            if unknown_fields {
+                return Err(ContractError::UnknownFields {
+                    type_url: type_url.to_string(),
+                });
+            }
+            Ok(())
+        }
+
+        // full proto names of the registered messages as reported by the proto backend, paired
+        // with the type URLs they are registered under
+        #[cfg(test)]
+        fn registered_names() -> Vec<(&'static str, String)> {
+            vec![$(($type_url, crate::proto::full_name::<$msg>())),*]
+        }
+    };
+}
+
+register_type_urls! {
+    CosmosAuthz::MsgExec => typeurl::MSG_EXEC,
+    CosmosAuthz::MsgGrant => typeurl::MSG_GRANT,
+    CosmosAuthz::MsgRevoke => typeurl::MSG_REVOKE,
+    CosmosAuthzQuery::GenericAuthorization => typeurl::GENERIC_AUTHORIZATION,
+    CosmosAuthzQuery::SendAuthorization => typeurl::SEND_AUTHORIZATION,
+    CosmosAuthzQuery::StakeAuthorization => typeurl::STAKE_AUTHORIZATION,
+    CosmosBankParams::MsgSetSendEnabled => typeurl::MSG_SET_SEND_ENABLED,
+    CosmosBankParams::MsgUpdateParams => typeurl::MSG_UPDATE_PARAMS,
+    CosmosBankSend::MsgMultiSend => typeurl::MSG_MULTI_SEND,
+    CosmosBankSend::MsgSend => typeurl::MSG_SEND,
+    CosmosGov::MsgSubmitProposal => typeurl::MSG_SUBMIT_PROPOSAL,
+    CosmosWasm::MsgClearAdmin => typeurl::MSG_CLEAR_ADMIN,
+    CosmosWasm::MsgUpdateAdmin => typeurl::MSG_UPDATE_ADMIN,
+}
+
+/// Returns the type URL of a registered message by its name, e.g. MsgSend.
+pub fn lookup(name: &str) -> Option<&'static str> {
+    ALL_TYPE_URLS
+        .iter()
+        .copied()
+        .find(|type_url| type_url.rsplit('.').next() == Some(name))
+}
+
+const fn check_type_urls(type_urls: &[&str]) {
+    let mut i = 0;
+    while i < type_urls.len() {
+        let url = type_urls[i].as_bytes();
+        assert!(
+            !url.is_empty() && url[0] == b'/',
+            "type url must start with '/'"
+        );
+
+        let mut j = i + 1;
+        while j < type_urls.len() {
+            assert!(
+                !bytes_eq(url, type_urls[j].as_bytes()),
+                "duplicate type url"
+            );
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    // proto messages are built from Default field by field for both proto backends
+    #![allow(clippy::field_reassign_with_default)]
+
+    use super::*;
+
+    #[test]
+    fn type_urls_match_proto_descriptors() {
+        let names = registered_names();
+        assert_eq!(names.len(), ALL_TYPE_URLS.len());
+        for (type_url, name) in names {
+            #[cfg(feature = "prost")]
+            assert_eq!(type_url, format!("/{name}"));
+            // the trimmed protos of the common crate declare no package, only the message name
+            // can be checked against them
+            #[cfg(feature = "legacy")]
+            assert_eq!(type_url.rsplit('.').next(), Some(name.as_str()));
+        }
+    }
+
+    // Msg and Authorization messages of the proto files the contract never packs into an Any, the
+    // gov votes are only used by the gov contract and GrantAuthorization is a query result
+    #[cfg(feature = "legacy")]
+    const PASS_THROUGH: &[&str] = &[
+        "GrantAuthorization",
+        "MsgDeposit",
+        "MsgVote",
+        "MsgVoteWeighted",
+    ];
+
+    #[cfg(feature = "legacy")]
+    #[test]
+    fn every_packable_message_is_registered() {
+        let files = [
+            CosmosAuthz::file_descriptor(),
+            CosmosAuthzQuery::file_descriptor(),
+            CosmosBankParams::file_descriptor(),
+            CosmosBankSend::file_descriptor(),
+            CosmosGov::file_descriptor(),
+            CosmosWasm::file_descriptor(),
+        ];
+        let names: Vec<String> = registered_names()
+            .into_iter()
+            .map(|(_, name)| name)
+            .collect();
+        for message in files.iter().flat_map(|file| file.messages()) {
+            let name = message.name();
+            if !name.starts_with("Msg") && !name.ends_with("Authorization") {
+                continue;
+            }
+            assert!(
+                names.iter().any(|registered| registered == name) || PASS_THROUGH.contains(&name),
+                "{name} is neither registered nor listed as pass-through"
+            );
+        }
+    }
+
+    #[test]
+    fn every_type_url_has_a_dispatch_arm() {
+        // an empty value decodes to the default of every message
+        for type_url in ALL_TYPE_URLS {
+            decode_registered(type_url, &[]).unwrap();
+        }
+    }
+
+    #[test]
+    fn unknown_type_url_is_rejected() {
+        assert!(matches!(
+            decode_registered("/cosmos.bank.v1beta1.MsgBurn", &[]),
+            Err(ContractError::UnregisteredTypeUrl { .. })
+        ));
+    }
+
+    #[test]
+    fn malformed_value_is_rejected() {
+        // a length delimited field whose length runs past the end of the value
+        assert!(matches!(
+            decode_registered(typeurl::MSG_SEND, &[0x0a, 0x05, b'a']),
+            Err(ContractError::Protobuf(_))
+        ));
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        let mut send = CosmosBankSend::MsgSend::default();
+        send.from_address = "granter".to_string();
+        send.to_address = "recipient".to_string();
+        let value = send.encode_bytes().unwrap();
+        decode_registered(typeurl::MSG_SEND, &value).unwrap();
+
+        // field 15 isn't defined by MsgSend
+        let mut extended = value.clone();
+        extended.extend_from_slice(&[0x78, 0x01]);
+        assert!(matches!(
+            decode_registered(typeurl::MSG_SEND, &extended),
+            Err(ContractError::UnknownFields { .. })
+        ));
+
+        // nor by the Coin nested in the amount
+        let mut coin = vec![0x0a, 0x05];
+        coin.extend_from_slice(b"ucore");
+        coin.extend_from_slice(&[0x12, 0x01, b'1', 0x78, 0x01]);
+        let mut nested = value;
+        nested.push(0x1a);
+        nested.push(coin.len() as u8);
+        nested.extend_from_slice(&coin);
+        assert!(matches!(
+            decode_registered(typeurl::MSG_SEND, &nested),
+            Err(ContractError::UnknownFields { .. })
+        ));
+    }
+
+    #[test]
+    fn lookup_by_name() {
+        assert_eq!(lookup("MsgSend"), Some(typeurl::MSG_SEND));
+        assert_eq!(lookup("Send"), None);
+    }
+}
@@ -1,6 +1,94 @@
-use cosmwasm_std::Addr;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary, Coin, Empty, Uint128};
 
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
+
+use crate::msg::{RateLimit, ScheduledSend, SendSpec, TypeUrlPolicy};
 
 // We keep the granter address here
 pub const GRANTER: Item<Addr> = Item::new("granter");
+
+// Chain the contract is allowed to execute on, any chain if not set
+pub const EXPECTED_CHAIN_ID: Item<String> = Item::new("expected_chain_id");
+
+// Fee skimmed from the coins of every send, no fee if not set
+#[cw_serde]
+pub struct FeeConfig {
+    pub fee_bps: u16,
+    pub collector: Addr,
+}
+
+pub const FEE_CONFIG: Item<FeeConfig> = Item::new("fee_config");
+
+// Address allowed to authorize ExecSigned payloads and the nonce of the last executed one
+pub const SIGNER: Item<Addr> = Item::new("signer");
+pub const SIGNER_NONCE: Item<u64> = Item::new("signer_nonce");
+
+pub const INSTANTIATE_HEIGHT: Item<u64> = Item::new("instantiate_height");
+
+// Balance snapshot taken by SendAndAssert before the send, consumed by the reply
+#[cw_serde]
+pub struct PendingAssert {
+    pub recipient: Addr,
+    pub balances_before: Vec<Uint128>,
+    pub expected_increase: Vec<Coin>,
+}
+
+pub const PENDING_ASSERT: Item<PendingAssert> = Item::new("pending_assert");
+
+// Recipients the contract may send to, no restriction while empty
+pub const RECIPIENTS: Map<&Addr, Empty> = Map::new("recipients");
+
+// Cumulative amount sent from the grant per denom, the flag is set once the amount stopped growing
+// at Uint128::MAX
+#[cw_serde]
+#[derive(Default)]
+pub struct Spent {
+    pub amount: Uint128,
+    pub saturated: bool,
+}
+
+pub const SPENT: Map<&str, Spent> = Map::new("spent");
+
+pub const RATE_LIMIT: Item<RateLimit> = Item::new("rate_limit");
+
+// Amount of a native denom sent in the rate limit window that started at the height
+#[cw_serde]
+pub struct RateWindow {
+    pub start: u64,
+    pub spent: Uint128,
+}
+
+pub const RATE_WINDOWS: Map<&str, RateWindow> = Map::new("rate_windows");
+
+// Coins sent by the in-flight exec, accounted by the reply once the send succeeded
+pub const PENDING_SPEND: Item<Vec<Coin>> = Item::new("pending_spend");
+
+// Sends staged by Enqueue, validated on the way in and keyed by an increasing id so Flush drains
+// them in FIFO order
+pub const QUEUE: Map<u64, SendSpec> = Map::new("queue");
+pub const QUEUE_NEXT_ID: Item<u64> = Item::new("queue_next_id");
+
+// Blocks an idempotency key stays reserved for
+pub const IDEMPOTENCY_TTL: Item<u64> = Item::new("idempotency_ttl");
+
+// Payload hash of the execution that reserved an idempotency key, expired entries are overwritten
+// by the next use of the key or pruned in reservation height order
+#[cw_serde]
+pub struct ExecOutcomeHash {
+    pub hash: String,
+    pub height: u64,
+}
+
+pub const IDEMPOTENCY_KEYS: Map<&str, ExecOutcomeHash> = Map::new("idempotency_keys");
+pub const IDEMPOTENCY_HEIGHTS: Map<(u64, &str), Empty> = Map::new("idempotency_heights");
+
+// Type urls raw payloads may be executed with, everything is allowed if not set
+pub const TYPE_URL_POLICY: Item<TypeUrlPolicy> = Item::new("type_url_policy");
+
+// Chunks of large payloads uploaded by UploadChunk, keyed by session and chunk index
+pub const UPLOAD_CHUNKS: Map<(&str, u32), Binary> = Map::new("upload_chunks");
+
+// Time locked sends by id, removed once executed or cancelled
+pub const SCHEDULED: Map<u64, ScheduledSend> = Map::new("scheduled");
+pub const SCHEDULED_NEXT_ID: Item<u64> = Item::new("scheduled_next_id");
@@ -0,0 +1,133 @@
+// Helpers shared by the unit tests of the contract.
+
+use cosmwasm_std::testing::{
+    mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+};
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Env, OwnedDeps, Response, Uint128};
+
+use crate::any_msg::AnyMsg;
+use crate::contract::{execute, instantiate};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg};
+use crate::proto::CosmosAuthz::MsgExec;
+use crate::proto::CosmosBankSend::{self, MsgMultiSend, MsgSend};
+use crate::proto::{Any, ProtoMessage};
+
+pub const OWNER: &str = "owner";
+pub const GRANTER: &str = "granter";
+pub const RECIPIENT: &str = "recipient";
+pub const COLLECTOR: &str = "collector";
+pub const DENOM: &str = "ucore";
+
+pub type TestDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+pub fn instantiate_msg() -> InstantiateMsg {
+    InstantiateMsg {
+        version: None,
+        granter: Addr::unchecked(GRANTER),
+        idempotency_ttl_blocks: None,
+        expected_chain_id: None,
+        fee_bps: None,
+        fee_collector: None,
+        signer: None,
+    }
+}
+
+pub fn with_fee(fee_bps: u16) -> InstantiateMsg {
+    InstantiateMsg {
+        fee_bps: Some(fee_bps),
+        fee_collector: Some(COLLECTOR.to_string()),
+        ..instantiate_msg()
+    }
+}
+
+pub fn setup(msg: InstantiateMsg) -> TestDeps {
+    let mut deps = mock_dependencies();
+    instantiate(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).unwrap();
+    deps
+}
+
+/// Executes the message as the owner at the mock block.
+pub fn exec(deps: &mut TestDeps, msg: ExecuteMsg) -> Result<Response, ContractError> {
+    execute(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg)
+}
+
+/// Executes the message as the owner at the given height.
+pub fn exec_at(
+    deps: &mut TestDeps,
+    height: u64,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    execute(deps.as_mut(), env_at(height), mock_info(OWNER, &[]), msg)
+}
+
+pub fn env_at(height: u64) -> Env {
+    let mut env = mock_env();
+    env.block.height = height;
+    env
+}
+
+pub fn transfer(amount: u64, idempotency_key: Option<&str>) -> ExecuteMsg {
+    ExecuteMsg::Transfer {
+        address: Addr::unchecked(RECIPIENT),
+        amount,
+        denom: DENOM.to_string(),
+        idempotency_key: idempotency_key.map(str::to_string),
+    }
+}
+
+pub fn coins(amount: u128) -> Vec<Coin> {
+    vec![Coin::new(amount, DENOM)]
+}
+
+/// Returns the messages executed through authz by the response.
+pub fn exec_anys(res: &Response) -> Vec<Any> {
+    res.messages
+        .iter()
+        .filter_map(|sub_msg| match &sub_msg.msg {
+            CosmosMsg::Stargate { type_url, value } if type_url == MsgExec::TYPE_URL => {
+                Some(MsgExec::decode_bytes(value.as_slice()).unwrap().msgs)
+            }
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
+
+/// Returns the recipient and coins of every MsgSend executed through authz by the response.
+pub fn bank_sends(res: &Response) -> Vec<(String, Vec<Coin>)> {
+    exec_anys(res)
+        .into_iter()
+        .filter(|any| any.type_url == MsgSend::TYPE_URL)
+        .map(|any| {
+            let send = MsgSend::decode_bytes(&any.value).unwrap();
+            (send.to_address, from_proto_coins(&send.amount))
+        })
+        .collect()
+}
+
+/// Returns the input coins and the outputs of the MsgMultiSend executed through authz by the
+/// response.
+pub fn multi_send(res: &Response) -> (Vec<Coin>, Vec<(String, Vec<Coin>)>) {
+    let any = exec_anys(res)
+        .into_iter()
+        .find(|any| any.type_url == MsgMultiSend::TYPE_URL)
+        .expect("no MsgMultiSend");
+    let multi_send = MsgMultiSend::decode_bytes(&any.value).unwrap();
+    let outputs = multi_send
+        .outputs
+        .iter()
+        .map(|output| (output.address.clone(), from_proto_coins(&output.coins)))
+        .collect();
+    (from_proto_coins(&multi_send.inputs[0].coins), outputs)
+}
+
+fn from_proto_coins(coins: &[CosmosBankSend::Coin]) -> Vec<Coin> {
+    coins
+        .iter()
+        .map(|coin| Coin {
+            denom: coin.denom.clone(),
+            amount: coin.amount.parse::<Uint128>().unwrap(),
+        })
+        .collect()
+}
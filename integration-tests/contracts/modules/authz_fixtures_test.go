@@ -0,0 +1,174 @@
+package modules
+
+import (
+	_ "embed"
+	"encoding/json"
+	"testing"
+
+	sdkmath "cosmossdk.io/math"
+	wasmtypes "github.com/CosmWasm/wasmd/x/wasm/types"
+	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+	authztypes "github.com/cosmos/cosmos-sdk/x/authz"
+	banktypes "github.com/cosmos/cosmos-sdk/x/bank/types"
+	govtypesv1 "github.com/cosmos/cosmos-sdk/x/gov/types/v1"
+	"github.com/stretchr/testify/require"
+
+	"github.com/CoreumFoundation/coreum/v3/app"
+	"github.com/CoreumFoundation/coreum/v3/pkg/config"
+	"github.com/CoreumFoundation/coreum/v3/pkg/config/constant"
+	assetfttypes "github.com/CoreumFoundation/coreum/v3/x/asset/ft/types"
+)
+
+// authzFixtures are generated by the authz contract with
+// `cargo run --features fixtures --bin gen-fixtures > fixtures.json`.
+//
+//go:embed authz/fixtures.json
+var authzFixtures []byte
+
+// the contract address of the mocked environment the fixtures are generated in.
+const fixturesContract = "cosmos2contract"
+
+type authzFixtureMessage struct {
+	Msg struct {
+		Stargate struct {
+			TypeURL string `json:"type_url"`
+			Value   []byte `json:"value"`
+		} `json:"stargate"`
+	} `json:"msg"`
+}
+
+type authzFixtureDenom struct {
+	Subunit string `json:"subunit"`
+	Issuer  string `json:"issuer"`
+	Denom   string `json:"denom"`
+}
+
+func TestMain(m *testing.M) {
+	n, err := config.NetworkConfigByChainID(constant.ChainIDDev)
+	if err != nil {
+		panic(err)
+	}
+	n.SetSDKConfig()
+	m.Run()
+}
+
+// TestAuthzFixturesExec decodes the MsgExec dispatched by the authz contract and compares the
+// wrapped messages with the ones built here.
+func TestAuthzFixturesExec(t *testing.T) {
+	send := func(to string, amount int64) sdk.Msg {
+		return &banktypes.MsgSend{
+			FromAddress: "granter",
+			ToAddress:   to,
+			Amount:      sdk.NewCoins(sdk.NewInt64Coin("ucore", amount)),
+		}
+	}
+
+	testCases := map[string][]sdk.Msg{
+		"transfer":          {send("recipient", 1000)},
+		"transfer_with_fee": {send("recipient", 975), send("collector", 25)},
+		"split_send":        {send("alice", 334), send("bob", 666)},
+		"update_admin": {&wasmtypes.MsgUpdateAdmin{
+			Sender:   "granter",
+			NewAdmin: "admin",
+			Contract: "contract",
+		}},
+		"clear_admin": {&wasmtypes.MsgClearAdmin{
+			Sender:   "granter",
+			Contract: "contract",
+		}},
+	}
+
+	fixtures := readAuthzFixtures(t)
+	for name, expected := range testCases {
+		name := name
+		expected := expected
+		t.Run(name, func(t *testing.T) {
+			msg := decodeAuthzFixture(t, fixtures, name)
+			exec, ok := msg.(*authztypes.MsgExec)
+			require.True(t, ok, "unexpected message %T", msg)
+			require.Equal(t, fixturesContract, exec.Grantee)
+
+			msgs, err := exec.GetMessages()
+			require.NoError(t, err)
+			require.Equal(t, expected, msgs)
+		})
+	}
+}
+
+// TestAuthzFixturesProposal decodes the proposal disabling the sends of ucore.
+func TestAuthzFixturesProposal(t *testing.T) {
+	msg := decodeAuthzFixture(t, readAuthzFixtures(t), "propose_send_enabled")
+	proposal, ok := msg.(*govtypesv1.MsgSubmitProposal)
+	require.True(t, ok, "unexpected message %T", msg)
+
+	require.Equal(t, fixturesContract, proposal.Proposer)
+	require.Equal(t, sdk.NewCoins(sdk.NewCoin("ucore", sdkmath.NewInt(10_000_000))), proposal.InitialDeposit)
+	require.Equal(t, "Disable ucore sends", proposal.Title)
+	require.Equal(t, "Disable ucore sends", proposal.Summary)
+
+	msgs, err := proposal.GetMsgs()
+	require.NoError(t, err)
+	require.Equal(t, []sdk.Msg{&banktypes.MsgSetSendEnabled{
+		Authority: "gov",
+		SendEnabled: []*banktypes.SendEnabled{
+			{Denom: "ucore", Enabled: false},
+		},
+	}}, msgs)
+}
+
+// TestAuthzFixturesTypeURLs checks every type URL the contract encodes is known to the chain.
+func TestAuthzFixturesTypeURLs(t *testing.T) {
+	var typeURLs []string
+	require.NoError(t, json.Unmarshal(readAuthzFixtures(t)["type_urls"], &typeURLs))
+	require.NotEmpty(t, typeURLs)
+
+	registry := config.NewEncodingConfig(app.ModuleBasics).InterfaceRegistry
+	for _, typeURL := range typeURLs {
+		_, err := registry.Resolve(typeURL)
+		require.NoError(t, err, typeURL)
+	}
+}
+
+// TestAuthzFixturesDenoms compares the denoms derived by the contract with the ones of x/asset/ft.
+func TestAuthzFixturesDenoms(t *testing.T) {
+	var denoms []authzFixtureDenom
+	require.NoError(t, json.Unmarshal(readAuthzFixtures(t)["denoms"], &denoms))
+	require.NotEmpty(t, denoms)
+
+	for _, denom := range denoms {
+		issuer := sdk.MustAccAddressFromBech32(denom.Issuer)
+		require.Equal(t, assetfttypes.BuildDenom(denom.Subunit, issuer), denom.Denom)
+	}
+}
+
+func readAuthzFixtures(t *testing.T) map[string]json.RawMessage {
+	t.Helper()
+
+	var fixtures map[string]json.RawMessage
+	require.NoError(t, json.Unmarshal(authzFixtures, &fixtures))
+	return fixtures
+}
+
+// decodeAuthzFixture decodes the only stargate message of the fixture.
+func decodeAuthzFixture(t *testing.T, fixtures map[string]json.RawMessage, name string) sdk.Msg {
+	t.Helper()
+
+	raw, ok := fixtures[name]
+	require.True(t, ok, "fixture is missing")
+
+	var fixture struct {
+		Messages []authzFixtureMessage `json:"messages"`
+	}
+	require.NoError(t, json.Unmarshal(raw, &fixture))
+	require.Len(t, fixture.Messages, 1)
+
+	stargate := fixture.Messages[0].Msg.Stargate
+	var msg sdk.Msg
+	registry := config.NewEncodingConfig(app.ModuleBasics).InterfaceRegistry
+	require.NoError(t, registry.UnpackAny(&codectypes.Any{
+		TypeUrl: stargate.TypeURL,
+		Value:   stargate.Value,
+	}, &msg))
+	return msg
+}
@@ -18,4 +18,28 @@ var (
 	NftWASM []byte
 	//go:embed authz/artifacts/authz.wasm
 	AuthzWASM []byte
+	//go:embed reflect/artifacts/reflect.wasm
+	ReflectWASM []byte
+	//go:embed feegrant/artifacts/feegrant.wasm
+	FeegrantWASM []byte
+	//go:embed staking/artifacts/staking.wasm
+	StakingWASM []byte
+	//go:embed gov/artifacts/gov.wasm
+	GovWASM []byte
+	//go:embed vesting/artifacts/vesting.wasm
+	VestingWASM []byte
+	//go:embed cw20-adapter/artifacts/cw20_adapter.wasm
+	CW20AdapterWASM []byte
+	//go:embed cw721-adapter/artifacts/cw721_adapter.wasm
+	CW721AdapterWASM []byte
+	//go:embed escrow/artifacts/escrow.wasm
+	EscrowWASM []byte
+	//go:embed airdrop/artifacts/airdrop.wasm
+	AirdropWASM []byte
+	//go:embed vesting-payout/artifacts/vesting_payout.wasm
+	VestingPayoutWASM []byte
+	//go:embed migration-v1/artifacts/migration_v1.wasm
+	MigrationV1WASM []byte
+	//go:embed migration-v2/artifacts/migration_v2.wasm
+	MigrationV2WASM []byte
 )
@@ -0,0 +1,712 @@
+use coreum_wasm_sdk::assetft::{self, Query, TokenResponse};
+use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries, CoreumResult};
+use cosmwasm_std::{
+    coin, entry_point, to_binary, Addr, BankMsg, Binary, BlockInfo, Deps, DepsMut, Env,
+    MessageInfo, Response, StdResult, Storage, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw20::{
+    AllowanceResponse, BalanceResponse, Cw20ReceiveMsg, Expiration, MinterResponse,
+    TokenInfoResponse,
+};
+
+use crate::error::ContractError;
+use crate::msg::{DenomResponse, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{ALLOWANCES, BALANCES, DENOM, TOTAL_SUPPLY};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<CoreumQueries>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> CoreumResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // fails for denoms which are not assetft tokens
+    query_token(deps.as_ref(), msg.denom.clone())?;
+
+    DENOM.save(deps.storage, &msg.denom)?;
+    TOTAL_SUPPLY.save(deps.storage, &Uint128::zero())?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("denom", msg.denom))
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> CoreumResult<ContractError> {
+    match msg {
+        ExecuteMsg::Transfer { recipient, amount } => transfer(deps, info, recipient, amount),
+        ExecuteMsg::Burn { amount } => burn(deps, info, amount),
+        ExecuteMsg::Send {
+            contract,
+            amount,
+            msg,
+        } => send(deps, info, contract, amount, msg),
+        ExecuteMsg::IncreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => increase_allowance(deps, env, info, spender, amount, expires),
+        ExecuteMsg::DecreaseAllowance {
+            spender,
+            amount,
+            expires,
+        } => decrease_allowance(deps, env, info, spender, amount, expires),
+        ExecuteMsg::TransferFrom {
+            owner,
+            recipient,
+            amount,
+        } => transfer_from(deps, env, info, owner, recipient, amount),
+        ExecuteMsg::SendFrom {
+            owner,
+            contract,
+            amount,
+            msg,
+        } => send_from(deps, env, info, owner, contract, amount, msg),
+        ExecuteMsg::BurnFrom { owner, amount } => burn_from(deps, env, info, owner, amount),
+        ExecuteMsg::Deposit {} => deposit(deps, info),
+        ExecuteMsg::Withdraw { amount, recipient } => withdraw(deps, info, amount, recipient),
+    }
+}
+
+// ********** Transactions **********
+
+fn transfer(
+    deps: DepsMut<CoreumQueries>,
+    info: MessageInfo,
+    recipient: String,
+    amount: Uint128,
+) -> CoreumResult<ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    move_balance(deps.storage, &info.sender, &recipient, amount)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "transfer")
+        .add_attribute("from", info.sender)
+        .add_attribute("to", recipient)
+        .add_attribute("amount", amount))
+}
+
+fn burn(
+    deps: DepsMut<CoreumQueries>,
+    info: MessageInfo,
+    amount: Uint128,
+) -> CoreumResult<ContractError> {
+    let msg = burn_balance(deps.storage, &info.sender, amount)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "burn")
+        .add_attribute("from", info.sender)
+        .add_attribute("amount", amount)
+        .add_message(msg))
+}
+
+fn send(
+    deps: DepsMut<CoreumQueries>,
+    info: MessageInfo,
+    contract: String,
+    amount: Uint128,
+    msg: Binary,
+) -> CoreumResult<ContractError> {
+    let contract = deps.api.addr_validate(&contract)?;
+    move_balance(deps.storage, &info.sender, &contract, amount)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "send")
+        .add_attribute("from", info.sender.clone())
+        .add_attribute("to", contract.clone())
+        .add_attribute("amount", amount)
+        .add_message(receive_msg(info.sender, contract, amount, msg)?))
+}
+
+fn increase_allowance(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    amount: Uint128,
+    expires: Option<Expiration>,
+) -> CoreumResult<ContractError> {
+    let spender = deps.api.addr_validate(&spender)?;
+    if spender == info.sender {
+        return Err(ContractError::CannotSetOwnAccount {});
+    }
+
+    ALLOWANCES.update(
+        deps.storage,
+        (&info.sender, &spender),
+        |allowance| -> Result<_, ContractError> {
+            let mut allowance = allowance.unwrap_or_default();
+            if let Some(expires) = expires {
+                if expires.is_expired(&env.block) {
+                    return Err(ContractError::InvalidExpiration {});
+                }
+                allowance.expires = expires;
+            }
+            allowance.allowance = allowance.allowance.checked_add(amount)?;
+            Ok(allowance)
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "increase_allowance")
+        .add_attribute("owner", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("amount", amount))
+}
+
+fn decrease_allowance(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    amount: Uint128,
+    expires: Option<Expiration>,
+) -> CoreumResult<ContractError> {
+    let spender = deps.api.addr_validate(&spender)?;
+    if spender == info.sender {
+        return Err(ContractError::CannotSetOwnAccount {});
+    }
+
+    let key = (&info.sender, &spender);
+    let mut allowance = ALLOWANCES
+        .may_load(deps.storage, key)?
+        .ok_or(ContractError::NoAllowance {})?;
+    // like cw20-base, decreasing below zero removes the allowance
+    if amount < allowance.allowance {
+        allowance.allowance -= amount;
+        if let Some(expires) = expires {
+            if expires.is_expired(&env.block) {
+                return Err(ContractError::InvalidExpiration {});
+            }
+            allowance.expires = expires;
+        }
+        ALLOWANCES.save(deps.storage, key, &allowance)?;
+    } else {
+        ALLOWANCES.remove(deps.storage, key);
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "decrease_allowance")
+        .add_attribute("owner", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("amount", amount))
+}
+
+fn transfer_from(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    recipient: String,
+    amount: Uint128,
+) -> CoreumResult<ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+    spend_allowance(deps.storage, &env.block, &owner, &info.sender, amount)?;
+    move_balance(deps.storage, &owner, &recipient, amount)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "transfer_from")
+        .add_attribute("from", owner)
+        .add_attribute("to", recipient)
+        .add_attribute("by", info.sender)
+        .add_attribute("amount", amount))
+}
+
+fn send_from(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    contract: String,
+    amount: Uint128,
+    msg: Binary,
+) -> CoreumResult<ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let contract = deps.api.addr_validate(&contract)?;
+    spend_allowance(deps.storage, &env.block, &owner, &info.sender, amount)?;
+    move_balance(deps.storage, &owner, &contract, amount)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "send_from")
+        .add_attribute("from", owner.clone())
+        .add_attribute("to", contract.clone())
+        .add_attribute("by", info.sender)
+        .add_attribute("amount", amount)
+        .add_message(receive_msg(owner, contract, amount, msg)?))
+}
+
+fn burn_from(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    owner: String,
+    amount: Uint128,
+) -> CoreumResult<ContractError> {
+    let owner = deps.api.addr_validate(&owner)?;
+    spend_allowance(deps.storage, &env.block, &owner, &info.sender, amount)?;
+    let msg = burn_balance(deps.storage, &owner, amount)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "burn_from")
+        .add_attribute("from", owner)
+        .add_attribute("by", info.sender)
+        .add_attribute("amount", amount)
+        .add_message(msg))
+}
+
+fn deposit(deps: DepsMut<CoreumQueries>, info: MessageInfo) -> CoreumResult<ContractError> {
+    let denom = DENOM.load(deps.storage)?;
+    // burn rate and send commission are charged to the sender on top of the funds, so the
+    // contract receives the attached amount in full
+    let amount = match info.funds.as_slice() {
+        [funds] if funds.denom == denom => funds.amount,
+        _ => return Err(ContractError::InvalidDeposit { denom }),
+    };
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+
+    BALANCES.update(
+        deps.storage,
+        &info.sender,
+        |balance| -> Result<_, ContractError> {
+            Ok(balance.unwrap_or_default().checked_add(amount)?)
+        },
+    )?;
+    TOTAL_SUPPLY.update(deps.storage, |supply| -> Result<_, ContractError> {
+        Ok(supply.checked_add(amount)?)
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "deposit")
+        .add_attribute("to", info.sender)
+        .add_attribute("amount", amount))
+}
+
+fn withdraw(
+    deps: DepsMut<CoreumQueries>,
+    info: MessageInfo,
+    amount: Uint128,
+    recipient: Option<String>,
+) -> CoreumResult<ContractError> {
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => info.sender.clone(),
+    };
+    let denom = DENOM.load(deps.storage)?;
+    debit(deps.storage, &info.sender, amount)?;
+    TOTAL_SUPPLY.update(deps.storage, |supply| -> Result<_, ContractError> {
+        Ok(supply.checked_sub(amount)?)
+    })?;
+
+    // sends from smart contracts are exempt from the burn rate and send commission, so the
+    // recipient gets the amount in full
+    let msg = BankMsg::Send {
+        to_address: recipient.to_string(),
+        amount: vec![coin(amount.u128(), denom)],
+    };
+
+    Ok(Response::new()
+        .add_attribute("method", "withdraw")
+        .add_attribute("from", info.sender)
+        .add_attribute("to", recipient)
+        .add_attribute("amount", amount)
+        .add_message(msg))
+}
+
+// ********** Helpers **********
+
+fn debit(storage: &mut dyn Storage, account: &Addr, amount: Uint128) -> Result<(), ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    BALANCES.update(storage, account, |balance| -> Result<_, ContractError> {
+        Ok(balance.unwrap_or_default().checked_sub(amount)?)
+    })?;
+    Ok(())
+}
+
+fn move_balance(
+    storage: &mut dyn Storage,
+    from: &Addr,
+    to: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    debit(storage, from, amount)?;
+    BALANCES.update(storage, to, |balance| -> Result<_, ContractError> {
+        Ok(balance.unwrap_or_default().checked_add(amount)?)
+    })?;
+    Ok(())
+}
+
+fn burn_balance(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    amount: Uint128,
+) -> Result<CoreumMsg, ContractError> {
+    let denom = DENOM.load(storage)?;
+    debit(storage, account, amount)?;
+    TOTAL_SUPPLY.update(storage, |supply| -> Result<_, ContractError> {
+        Ok(supply.checked_sub(amount)?)
+    })?;
+
+    Ok(CoreumMsg::AssetFT(assetft::Msg::Burn {
+        coin: coin(amount.u128(), denom),
+    }))
+}
+
+fn spend_allowance(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    owner: &Addr,
+    spender: &Addr,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    ALLOWANCES.update(
+        storage,
+        (owner, spender),
+        |allowance| -> Result<_, ContractError> {
+            let mut allowance = allowance.ok_or(ContractError::NoAllowance {})?;
+            if allowance.expires.is_expired(block) {
+                return Err(ContractError::Expired {});
+            }
+            allowance.allowance = allowance.allowance.checked_sub(amount)?;
+            Ok(allowance)
+        },
+    )?;
+    Ok(())
+}
+
+fn receive_msg(sender: Addr, contract: Addr, amount: Uint128, msg: Binary) -> StdResult<WasmMsg> {
+    let msg = Cw20ReceiveMsg {
+        sender: sender.to_string(),
+        amount,
+        msg,
+    }
+    .into_binary()?;
+    Ok(WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg,
+        funds: vec![],
+    })
+}
+
+// ********** Queries **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<CoreumQueries>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Balance { address } => to_binary(&query_balance(deps, address)?),
+        QueryMsg::TokenInfo {} => to_binary(&query_token_info(deps)?),
+        QueryMsg::Minter {} => to_binary::<Option<MinterResponse>>(&None),
+        QueryMsg::Allowance { owner, spender } => {
+            to_binary(&query_allowance(deps, env, owner, spender)?)
+        }
+        QueryMsg::Denom {} => to_binary(&DenomResponse {
+            denom: DENOM.load(deps.storage)?,
+        }),
+    }
+}
+
+fn query_balance(deps: Deps<CoreumQueries>, address: String) -> StdResult<BalanceResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let balance = BALANCES
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    Ok(BalanceResponse { balance })
+}
+
+fn query_token_info(deps: Deps<CoreumQueries>) -> StdResult<TokenInfoResponse> {
+    let token = query_token(deps, DENOM.load(deps.storage)?)?.token;
+    Ok(TokenInfoResponse {
+        // assetft tokens carry no name apart from the symbol
+        name: token.symbol.clone(),
+        symbol: token.symbol,
+        // the chain caps the precision of a token far below u8::MAX
+        decimals: token.precision as u8,
+        total_supply: TOTAL_SUPPLY.load(deps.storage)?,
+    })
+}
+
+fn query_allowance(
+    deps: Deps<CoreumQueries>,
+    env: Env,
+    owner: String,
+    spender: String,
+) -> StdResult<AllowanceResponse> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let spender = deps.api.addr_validate(&spender)?;
+    let allowance = ALLOWANCES
+        .may_load(deps.storage, (&owner, &spender))?
+        .filter(|allowance| !allowance.expires.is_expired(&env.block))
+        .unwrap_or_default();
+    Ok(allowance)
+}
+
+fn query_token(deps: Deps<CoreumQueries>, denom: String) -> StdResult<TokenResponse> {
+    let request = CoreumQueries::AssetFT(Query::Token { denom }).into();
+    deps.querier.query(&request)
+}
+
+#[cfg(test)]
+mod tests {
+    use coreum_wasm_sdk::assetft::Token;
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+    use cosmwasm_std::{from_binary, CosmosMsg, OwnedDeps, SystemResult};
+
+    use super::*;
+
+    const WRAPPED: &str = "uabc-issuer";
+    const OWNER: &str = "owner";
+    const SPENDER: &str = "spender";
+    const RECIPIENT: &str = "recipient";
+    const RECEIVER: &str = "receiver";
+
+    type TestDeps = OwnedDeps<MockStorage, MockApi, MockQuerier<CoreumQueries>, CoreumQueries>;
+
+    // a chain with the wrapped token, the owner has deposited 100 of it
+    fn setup() -> TestDeps {
+        let querier = MockQuerier::new(&[]).with_custom_handler(|query| {
+            let CoreumQueries::AssetFT(Query::Token { denom }) = query else {
+                panic!("unexpected query {query:?}");
+            };
+            let token = Token {
+                denom: denom.clone(),
+                issuer: "issuer".to_string(),
+                symbol: "ABC".to_string(),
+                subunit: "uabc".to_string(),
+                precision: 6,
+                description: None,
+                features: Some(vec![]),
+                burn_rate: "0".to_string(),
+                send_commission_rate: "0".to_string(),
+                version: 1,
+            };
+            SystemResult::Ok(to_binary(&TokenResponse { token }).into())
+        });
+        let mut deps = OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier,
+            custom_query_type: Default::default(),
+        };
+        let msg = InstantiateMsg {
+            denom: WRAPPED.to_string(),
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).unwrap();
+        exec(
+            &mut deps,
+            OWNER,
+            &[coin(100, WRAPPED)],
+            ExecuteMsg::Deposit {},
+        )
+        .unwrap();
+        deps
+    }
+
+    fn exec(
+        deps: &mut TestDeps,
+        sender: &str,
+        funds: &[cosmwasm_std::Coin],
+        msg: ExecuteMsg,
+    ) -> CoreumResult<ContractError> {
+        execute(deps.as_mut(), mock_env(), mock_info(sender, funds), msg)
+    }
+
+    fn balance(deps: &TestDeps, address: &str) -> u128 {
+        let msg = QueryMsg::Balance {
+            address: address.to_string(),
+        };
+        let res: BalanceResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        res.balance.u128()
+    }
+
+    fn allow(deps: &mut TestDeps, amount: u128) {
+        let msg = ExecuteMsg::IncreaseAllowance {
+            spender: SPENDER.to_string(),
+            amount: Uint128::new(amount),
+            expires: None,
+        };
+        exec(deps, OWNER, &[], msg).unwrap();
+    }
+
+    fn receive(sender: &str, amount: u128, msg: &Binary) -> CosmosMsg<CoreumMsg> {
+        let msg = Cw20ReceiveMsg {
+            sender: sender.to_string(),
+            amount: Uint128::new(amount),
+            msg: msg.clone(),
+        };
+        CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: RECEIVER.to_string(),
+            msg: msg.into_binary().unwrap(),
+            funds: vec![],
+        })
+    }
+
+    #[test]
+    fn transfer_moves_the_balance_without_messages() {
+        let mut deps = setup();
+        let msg = ExecuteMsg::Transfer {
+            recipient: RECIPIENT.to_string(),
+            amount: Uint128::new(40),
+        };
+        let res = exec(&mut deps, OWNER, &[], msg).unwrap();
+        assert!(res.messages.is_empty());
+        assert_eq!(balance(&deps, OWNER), 60);
+        assert_eq!(balance(&deps, RECIPIENT), 40);
+    }
+
+    #[test]
+    fn send_executes_the_receive_hook() {
+        let mut deps = setup();
+        let payload = Binary::from(br#"{"stake":{}}"#);
+        let msg = ExecuteMsg::Send {
+            contract: RECEIVER.to_string(),
+            amount: Uint128::new(30),
+            msg: payload.clone(),
+        };
+        let res = exec(&mut deps, OWNER, &[], msg).unwrap();
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].msg, receive(OWNER, 30, &payload));
+        assert_eq!(balance(&deps, RECEIVER), 30);
+    }
+
+    #[test]
+    fn burn_burns_the_native_tokens() {
+        let mut deps = setup();
+        let res = exec(
+            &mut deps,
+            OWNER,
+            &[],
+            ExecuteMsg::Burn {
+                amount: Uint128::new(25),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Custom(CoreumMsg::AssetFT(assetft::Msg::Burn {
+                coin: coin(25, WRAPPED),
+            }))
+        );
+        assert_eq!(balance(&deps, OWNER), 75);
+        assert_eq!(TOTAL_SUPPLY.load(&deps.storage).unwrap().u128(), 75);
+    }
+
+    #[test]
+    fn withdraw_sends_the_native_coins() {
+        let mut deps = setup();
+        for (recipient, paid_to) in [(None, OWNER), (Some(RECIPIENT.to_string()), RECIPIENT)] {
+            let msg = ExecuteMsg::Withdraw {
+                amount: Uint128::new(10),
+                recipient,
+            };
+            let res = exec(&mut deps, OWNER, &[], msg).unwrap();
+            assert_eq!(
+                res.messages[0].msg,
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: paid_to.to_string(),
+                    amount: vec![coin(10, WRAPPED)],
+                })
+            );
+        }
+        assert_eq!(balance(&deps, OWNER), 80);
+        assert_eq!(TOTAL_SUPPLY.load(&deps.storage).unwrap().u128(), 80);
+    }
+
+    #[test]
+    fn deposit_takes_exactly_one_coin_of_the_denom() {
+        let mut deps = setup();
+        for funds in [
+            vec![],
+            vec![coin(10, "ucore")],
+            vec![coin(10, WRAPPED), coin(10, "ucore")],
+        ] {
+            let err = exec(&mut deps, OWNER, &funds, ExecuteMsg::Deposit {}).unwrap_err();
+            assert!(matches!(err, ContractError::InvalidDeposit { .. }));
+        }
+        let err = exec(
+            &mut deps,
+            OWNER,
+            &[coin(0, WRAPPED)],
+            ExecuteMsg::Deposit {},
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::InvalidZeroAmount {}));
+        assert_eq!(balance(&deps, OWNER), 100);
+    }
+
+    #[test]
+    fn from_messages_act_for_the_owner() {
+        let mut deps = setup();
+        allow(&mut deps, 50);
+        let payload = Binary::from(b"{}");
+
+        let msg = ExecuteMsg::SendFrom {
+            owner: OWNER.to_string(),
+            contract: RECEIVER.to_string(),
+            amount: Uint128::new(20),
+            msg: payload.clone(),
+        };
+        let res = exec(&mut deps, SPENDER, &[], msg).unwrap();
+        // the hook is told the tokens came from the owner, not the spender
+        assert_eq!(res.messages[0].msg, receive(OWNER, 20, &payload));
+
+        let msg = ExecuteMsg::BurnFrom {
+            owner: OWNER.to_string(),
+            amount: Uint128::new(30),
+        };
+        let res = exec(&mut deps, SPENDER, &[], msg).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Custom(CoreumMsg::AssetFT(assetft::Msg::Burn {
+                coin: coin(30, WRAPPED),
+            }))
+        );
+
+        // the allowance is used up
+        let msg = ExecuteMsg::TransferFrom {
+            owner: OWNER.to_string(),
+            recipient: RECIPIENT.to_string(),
+            amount: Uint128::new(1),
+        };
+        let err = exec(&mut deps, SPENDER, &[], msg).unwrap_err();
+        assert!(matches!(err, ContractError::Overflow(_)));
+        assert_eq!(balance(&deps, OWNER), 50);
+    }
+
+    #[test]
+    fn token_info_comes_from_the_token() {
+        let deps = setup();
+        let res: TokenInfoResponse =
+            from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::TokenInfo {}).unwrap())
+                .unwrap();
+        assert_eq!(
+            res,
+            TokenInfoResponse {
+                name: "ABC".to_string(),
+                symbol: "ABC".to_string(),
+                decimals: 6,
+                total_supply: Uint128::new(100),
+            }
+        );
+    }
+}
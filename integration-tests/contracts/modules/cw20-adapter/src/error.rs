@@ -0,0 +1,29 @@
+use cosmwasm_std::{OverflowError, StdError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+
+    #[error("Invalid zero amount")]
+    InvalidZeroAmount {},
+
+    #[error("Deposit must carry exactly one coin of {denom}")]
+    InvalidDeposit { denom: String },
+
+    #[error("Cannot set allowance to own account")]
+    CannotSetOwnAccount {},
+
+    #[error("No allowance for this account")]
+    NoAllowance {},
+
+    #[error("Allowance is expired")]
+    Expired {},
+
+    #[error("Invalid expiration value")]
+    InvalidExpiration {},
+}
@@ -0,0 +1,76 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, Uint128};
+use cw20::Expiration;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    // assetft denom wrapped by the contract, it must exist on the chain
+    pub denom: String,
+}
+
+// The cw20 messages apart from minting and marketing, with their cw20 wire format, plus Deposit
+// and Withdraw moving the native tokens in and out of the contract.
+#[cw_serde]
+pub enum ExecuteMsg {
+    Transfer {
+        recipient: String,
+        amount: Uint128,
+    },
+    // burns the native tokens behind the amount, which needs the burning feature of the token
+    Burn {
+        amount: Uint128,
+    },
+    Send {
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+    },
+    IncreaseAllowance {
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    },
+    DecreaseAllowance {
+        spender: String,
+        amount: Uint128,
+        expires: Option<Expiration>,
+    },
+    TransferFrom {
+        owner: String,
+        recipient: String,
+        amount: Uint128,
+    },
+    SendFrom {
+        owner: String,
+        contract: String,
+        amount: Uint128,
+        msg: Binary,
+    },
+    BurnFrom {
+        owner: String,
+        amount: Uint128,
+    },
+    // credits the sender with the coins of the denom attached to the message
+    Deposit {},
+    // pays the amount of the sender's balance out as coins of the denom
+    Withdraw {
+        amount: Uint128,
+        recipient: Option<String>,
+    },
+}
+
+// The cw20 queries of the messages above, answered with the cw20 response types.
+#[cw_serde]
+pub enum QueryMsg {
+    Balance { address: String },
+    TokenInfo {},
+    // always empty, the tokens are minted by the issuer of the denom only
+    Minter {},
+    Allowance { owner: String, spender: String },
+    Denom {},
+}
+
+#[cw_serde]
+pub struct DenomResponse {
+    pub denom: String,
+}
@@ -0,0 +1,11 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw20::AllowanceResponse;
+use cw_storage_plus::{Item, Map};
+
+// wrapped denom, an assetft token held by the contract on behalf of the cw20 balances
+pub const DENOM: Item<String> = Item::new("denom");
+// sum of the cw20 balances, always equal to the amount of the denom the contract holds
+pub const TOTAL_SUPPLY: Item<Uint128> = Item::new("total_supply");
+pub const BALANCES: Map<&Addr, Uint128> = Map::new("balances");
+// allowances keyed by (owner, spender)
+pub const ALLOWANCES: Map<(&Addr, &Addr), AllowanceResponse> = Map::new("allowances");
@@ -0,0 +1,658 @@
+use coreum_wasm_sdk::assetnft;
+use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries, CoreumResult};
+use coreum_wasm_sdk::nft::{self, NFTResponse};
+use cosmwasm_std::{
+    coin, entry_point, to_binary, Addr, BankMsg, Binary, BlockInfo, Coin, Deps, DepsMut, Empty,
+    Env, MessageInfo, Order, Response, StdResult, Storage, Uint128, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw721::{
+    Approval, ContractInfoResponse, Cw721ReceiveMsg, Expiration, NftInfoResponse,
+    NumTokensResponse, OwnerOfResponse, TokensResponse,
+};
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{
+    ClassIdResponse, ExecuteMsg, InstantiateMsg, ListingResponse, MinterResponse, QueryMsg,
+    RoyaltiesInfoResponse,
+};
+use crate::royalty::{query_royalty_rate, royalty_amount};
+use crate::state::{
+    TokenInfo, CLASS_ID, LISTINGS, MINTER, NAME, OPERATORS, OWNER_TOKENS, SYMBOL, TOKENS,
+    TOKEN_COUNT,
+};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// page sizes of the Tokens and AllTokens queries, the same as cw721-base
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> CoreumResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let minter = match msg.minter {
+        Some(minter) => deps.api.addr_validate(&minter)?,
+        None => info.sender,
+    };
+    let class_id = format!("{}-{}", msg.symbol, env.contract.address).to_lowercase();
+
+    // the contract issues the class, which lets it burn the nfts it holds without the burning
+    // feature
+    let issue_msg = CoreumMsg::AssetNFT(assetnft::Msg::IssueClass {
+        name: msg.name.clone(),
+        symbol: msg.symbol.clone(),
+        description: msg.description,
+        uri: msg.uri,
+        uri_hash: msg.uri_hash,
+        data: None,
+        features: None,
+        royalty_rate: msg.royalty_rate,
+    });
+
+    CLASS_ID.save(deps.storage, &class_id)?;
+    NAME.save(deps.storage, &msg.name)?;
+    SYMBOL.save(deps.storage, &msg.symbol)?;
+    MINTER.save(deps.storage, &minter)?;
+    TOKEN_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("minter", minter)
+        .add_attribute("class_id", class_id)
+        .add_message(issue_msg))
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> CoreumResult<ContractError> {
+    match msg {
+        ExecuteMsg::TransferNft {
+            recipient,
+            token_id,
+        } => transfer_nft(deps, env, info, recipient, token_id),
+        ExecuteMsg::SendNft {
+            contract,
+            token_id,
+            msg,
+        } => send_nft(deps, env, info, contract, token_id, msg),
+        ExecuteMsg::Approve {
+            spender,
+            token_id,
+            expires,
+        } => approve(deps, env, info, spender, token_id, expires),
+        ExecuteMsg::Revoke { spender, token_id } => revoke(deps, env, info, spender, token_id),
+        ExecuteMsg::ApproveAll { operator, expires } => {
+            approve_all(deps, env, info, operator, expires)
+        }
+        ExecuteMsg::RevokeAll { operator } => revoke_all(deps, info, operator),
+        ExecuteMsg::Mint {
+            token_id,
+            owner,
+            token_uri,
+            token_uri_hash,
+            data,
+        } => mint(deps, info, token_id, owner, token_uri, token_uri_hash, data),
+        ExecuteMsg::Burn { token_id } => burn(deps, env, info, token_id),
+        ExecuteMsg::Withdraw {
+            token_id,
+            recipient,
+        } => withdraw(deps, env, info, token_id, recipient),
+        ExecuteMsg::List { token_id, price } => list(deps, env, info, token_id, price),
+        ExecuteMsg::Unlist { token_id } => unlist(deps, env, info, token_id),
+        ExecuteMsg::Buy { token_id } => buy(deps, info, token_id),
+    }
+}
+
+// ********** Transactions **********
+
+fn transfer_nft(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    token_id: String,
+) -> CoreumResult<ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    let token = load_token(deps.storage, &token_id)?;
+    check_can_send(deps.storage, &env.block, &info.sender, &token)?;
+    move_token(deps.storage, &token_id, &token.owner, &recipient)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "transfer_nft")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("token_id", token_id))
+}
+
+fn send_nft(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    contract: String,
+    token_id: String,
+    msg: Binary,
+) -> CoreumResult<ContractError> {
+    let contract = deps.api.addr_validate(&contract)?;
+    let token = load_token(deps.storage, &token_id)?;
+    check_can_send(deps.storage, &env.block, &info.sender, &token)?;
+    move_token(deps.storage, &token_id, &token.owner, &contract)?;
+
+    let msg = Cw721ReceiveMsg {
+        sender: info.sender.to_string(),
+        token_id: token_id.clone(),
+        msg,
+    }
+    .into_binary()?;
+
+    Ok(Response::new()
+        .add_attribute("method", "send_nft")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", contract.clone())
+        .add_attribute("token_id", token_id)
+        .add_message(WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg,
+            funds: vec![],
+        }))
+}
+
+fn approve(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    token_id: String,
+    expires: Option<Expiration>,
+) -> CoreumResult<ContractError> {
+    let spender = deps.api.addr_validate(&spender)?;
+    let mut token = load_token(deps.storage, &token_id)?;
+    check_can_approve(deps.storage, &env.block, &info.sender, &token)?;
+
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+    token
+        .approvals
+        .retain(|approval| approval.spender != spender);
+    token.approvals.push(Approval {
+        spender: spender.to_string(),
+        expires,
+    });
+    TOKENS.save(deps.storage, &token_id, &token)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "approve")
+        .add_attribute("sender", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("token_id", token_id))
+}
+
+fn revoke(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    spender: String,
+    token_id: String,
+) -> CoreumResult<ContractError> {
+    let spender = deps.api.addr_validate(&spender)?;
+    let mut token = load_token(deps.storage, &token_id)?;
+    check_can_approve(deps.storage, &env.block, &info.sender, &token)?;
+
+    let approvals = token.approvals.len();
+    token
+        .approvals
+        .retain(|approval| approval.spender != spender);
+    if token.approvals.len() == approvals {
+        return Err(ContractError::ApprovalNotFound {
+            spender: spender.to_string(),
+        });
+    }
+    TOKENS.save(deps.storage, &token_id, &token)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "revoke")
+        .add_attribute("sender", info.sender)
+        .add_attribute("spender", spender)
+        .add_attribute("token_id", token_id))
+}
+
+fn approve_all(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    operator: String,
+    expires: Option<Expiration>,
+) -> CoreumResult<ContractError> {
+    let operator = deps.api.addr_validate(&operator)?;
+    let expires = expires.unwrap_or_default();
+    if expires.is_expired(&env.block) {
+        return Err(ContractError::InvalidExpiration {});
+    }
+    OPERATORS.save(deps.storage, (&info.sender, &operator), &expires)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "approve_all")
+        .add_attribute("sender", info.sender)
+        .add_attribute("operator", operator))
+}
+
+fn revoke_all(
+    deps: DepsMut<CoreumQueries>,
+    info: MessageInfo,
+    operator: String,
+) -> CoreumResult<ContractError> {
+    let operator = deps.api.addr_validate(&operator)?;
+    OPERATORS.remove(deps.storage, (&info.sender, &operator));
+
+    Ok(Response::new()
+        .add_attribute("method", "revoke_all")
+        .add_attribute("sender", info.sender)
+        .add_attribute("operator", operator))
+}
+
+fn mint(
+    deps: DepsMut<CoreumQueries>,
+    info: MessageInfo,
+    token_id: String,
+    owner: String,
+    token_uri: Option<String>,
+    token_uri_hash: Option<String>,
+    data: Option<Binary>,
+) -> CoreumResult<ContractError> {
+    if info.sender != MINTER.load(deps.storage)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    let owner = deps.api.addr_validate(&owner)?;
+    // withdrawn tokens are left to the chain, which rejects an id minted twice
+    if TOKENS.has(deps.storage, &token_id) {
+        return Err(ContractError::TokenExists { token_id });
+    }
+    let class_id = CLASS_ID.load(deps.storage)?;
+
+    TOKENS.save(
+        deps.storage,
+        &token_id,
+        &TokenInfo {
+            owner: owner.clone(),
+            approvals: vec![],
+        },
+    )?;
+    OWNER_TOKENS.save(deps.storage, (&owner, &token_id), &Empty {})?;
+    TOKEN_COUNT.update(deps.storage, |count| -> StdResult<_> { Ok(count + 1) })?;
+
+    let msg = CoreumMsg::AssetNFT(assetnft::Msg::Mint {
+        class_id,
+        id: token_id.clone(),
+        uri: token_uri,
+        uri_hash: token_uri_hash,
+        data,
+    });
+
+    Ok(Response::new()
+        .add_attribute("method", "mint")
+        .add_attribute("minter", info.sender)
+        .add_attribute("owner", owner)
+        .add_attribute("token_id", token_id)
+        .add_message(msg))
+}
+
+fn burn(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> CoreumResult<ContractError> {
+    let token = load_token(deps.storage, &token_id)?;
+    check_can_send(deps.storage, &env.block, &info.sender, &token)?;
+    remove_token(deps.storage, &token_id, &token.owner)?;
+
+    let msg = CoreumMsg::AssetNFT(assetnft::Msg::Burn {
+        class_id: CLASS_ID.load(deps.storage)?,
+        id: token_id.clone(),
+    });
+
+    Ok(Response::new()
+        .add_attribute("method", "burn")
+        .add_attribute("sender", info.sender)
+        .add_attribute("token_id", token_id)
+        .add_message(msg))
+}
+
+fn withdraw(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    recipient: Option<String>,
+) -> CoreumResult<ContractError> {
+    let token = load_token(deps.storage, &token_id)?;
+    check_can_send(deps.storage, &env.block, &info.sender, &token)?;
+    let recipient = match recipient {
+        Some(recipient) => deps.api.addr_validate(&recipient)?,
+        None => token.owner.clone(),
+    };
+    remove_token(deps.storage, &token_id, &token.owner)?;
+
+    let msg = CoreumMsg::NFT(nft::Msg::Send {
+        class_id: CLASS_ID.load(deps.storage)?,
+        id: token_id.clone(),
+        receiver: recipient.to_string(),
+    });
+
+    Ok(Response::new()
+        .add_attribute("method", "withdraw")
+        .add_attribute("sender", info.sender)
+        .add_attribute("recipient", recipient)
+        .add_attribute("token_id", token_id)
+        .add_message(msg))
+}
+
+fn list(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+    price: Coin,
+) -> CoreumResult<ContractError> {
+    let token = load_token(deps.storage, &token_id)?;
+    check_can_approve(deps.storage, &env.block, &info.sender, &token)?;
+    if price.amount.is_zero() {
+        return Err(ContractError::InvalidZeroPrice {});
+    }
+    LISTINGS.save(deps.storage, &token_id, &price)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "list")
+        .add_attribute("seller", token.owner)
+        .add_attribute("token_id", token_id)
+        .add_attribute("price", price.to_string()))
+}
+
+fn unlist(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    token_id: String,
+) -> CoreumResult<ContractError> {
+    let token = load_token(deps.storage, &token_id)?;
+    check_can_approve(deps.storage, &env.block, &info.sender, &token)?;
+    load_listing(deps.storage, &token_id)?;
+    LISTINGS.remove(deps.storage, &token_id);
+
+    Ok(Response::new()
+        .add_attribute("method", "unlist")
+        .add_attribute("token_id", token_id))
+}
+
+// the royalty is read from the class on every sale, so it follows the rate the chain holds
+fn buy(
+    deps: DepsMut<CoreumQueries>,
+    info: MessageInfo,
+    token_id: String,
+) -> CoreumResult<ContractError> {
+    let token = load_token(deps.storage, &token_id)?;
+    let price = load_listing(deps.storage, &token_id)?;
+    if info.funds != [price.clone()] {
+        return Err(ContractError::InvalidFunds {
+            price: price.to_string(),
+        });
+    }
+    let royalty = royalty_amount(price.amount, query_royalty_rate(deps.as_ref())?);
+    let proceeds = price.amount - royalty;
+    let minter = MINTER.load(deps.storage)?;
+    move_token(deps.storage, &token_id, &token.owner, &info.sender)?;
+
+    let mut response = Response::new()
+        .add_attribute("method", "buy")
+        .add_attribute("seller", token.owner.clone())
+        .add_attribute("buyer", info.sender)
+        .add_attribute("token_id", token_id)
+        .add_attribute("price", price.to_string())
+        .add_attribute("royalty", royalty);
+    for (recipient, amount) in [(minter, royalty), (token.owner, proceeds)] {
+        if !amount.is_zero() {
+            response = response.add_message(BankMsg::Send {
+                to_address: recipient.to_string(),
+                amount: vec![coin(amount.u128(), price.denom.clone())],
+            });
+        }
+    }
+    Ok(response)
+}
+
+// ********** Helpers **********
+
+fn load_token(storage: &dyn Storage, token_id: &str) -> Result<TokenInfo, ContractError> {
+    TOKENS
+        .may_load(storage, token_id)?
+        .ok_or_else(|| ContractError::TokenNotFound {
+            token_id: token_id.to_string(),
+        })
+}
+
+fn load_listing(storage: &dyn Storage, token_id: &str) -> Result<Coin, ContractError> {
+    LISTINGS
+        .may_load(storage, token_id)?
+        .ok_or_else(|| ContractError::NotListed {
+            token_id: token_id.to_string(),
+        })
+}
+
+fn is_operator(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    owner: &Addr,
+    operator: &Addr,
+) -> StdResult<bool> {
+    Ok(OPERATORS
+        .may_load(storage, (owner, operator))?
+        .is_some_and(|expires| !expires.is_expired(block)))
+}
+
+// check_can_approve allows the owner and its operators, like cw721-base
+fn check_can_approve(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    sender: &Addr,
+    token: &TokenInfo,
+) -> Result<(), ContractError> {
+    if *sender == token.owner || is_operator(storage, block, &token.owner, sender)? {
+        return Ok(());
+    }
+    Err(ContractError::Unauthorized {})
+}
+
+// check_can_send additionally allows the spenders approved for the token
+fn check_can_send(
+    storage: &dyn Storage,
+    block: &BlockInfo,
+    sender: &Addr,
+    token: &TokenInfo,
+) -> Result<(), ContractError> {
+    let approved = token
+        .approvals
+        .iter()
+        .any(|approval| approval.spender == *sender && !approval.expires.is_expired(block));
+    if approved {
+        return Ok(());
+    }
+    check_can_approve(storage, block, sender, token)
+}
+
+fn move_token(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    from: &Addr,
+    to: &Addr,
+) -> Result<(), ContractError> {
+    TOKENS.save(
+        storage,
+        token_id,
+        &TokenInfo {
+            owner: to.clone(),
+            approvals: vec![],
+        },
+    )?;
+    OWNER_TOKENS.remove(storage, (from, token_id));
+    OWNER_TOKENS.save(storage, (to, token_id), &Empty {})?;
+    LISTINGS.remove(storage, token_id);
+    Ok(())
+}
+
+fn remove_token(
+    storage: &mut dyn Storage,
+    token_id: &str,
+    owner: &Addr,
+) -> Result<(), ContractError> {
+    TOKENS.remove(storage, token_id);
+    OWNER_TOKENS.remove(storage, (owner, token_id));
+    LISTINGS.remove(storage, token_id);
+    TOKEN_COUNT.update(storage, |count| -> StdResult<_> { Ok(count - 1) })?;
+    Ok(())
+}
+
+// ********** Queries **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<CoreumQueries>, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::OwnerOf {
+            token_id,
+            include_expired,
+        } => to_binary(&query_owner_of(
+            deps,
+            env,
+            token_id,
+            include_expired.unwrap_or(false),
+        )?),
+        QueryMsg::NumTokens {} => to_binary(&NumTokensResponse {
+            count: TOKEN_COUNT.load(deps.storage)?,
+        }),
+        QueryMsg::ContractInfo {} => to_binary(&ContractInfoResponse {
+            name: NAME.load(deps.storage)?,
+            symbol: SYMBOL.load(deps.storage)?,
+        }),
+        QueryMsg::NftInfo { token_id } => to_binary(&query_nft_info(deps, token_id)?),
+        QueryMsg::Tokens {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&query_tokens(deps, owner, start_after, limit)?),
+        QueryMsg::AllTokens { start_after, limit } => {
+            to_binary(&query_all_tokens(deps, start_after, limit)?)
+        }
+        QueryMsg::Minter {} => to_binary(&MinterResponse {
+            minter: Some(MINTER.load(deps.storage)?.to_string()),
+        }),
+        QueryMsg::ClassId {} => to_binary(&ClassIdResponse {
+            class_id: CLASS_ID.load(deps.storage)?,
+        }),
+        QueryMsg::RoyaltyInfo {
+            token_id,
+            sale_price,
+        } => to_binary(&query_royalty_info(deps, token_id, sale_price)?),
+        QueryMsg::Listing { token_id } => to_binary(&ListingResponse {
+            seller: TOKENS.load(deps.storage, &token_id)?.owner,
+            price: LISTINGS.load(deps.storage, &token_id)?,
+        }),
+    }
+}
+
+fn query_owner_of(
+    deps: Deps<CoreumQueries>,
+    env: Env,
+    token_id: String,
+    include_expired: bool,
+) -> StdResult<OwnerOfResponse> {
+    let token = TOKENS.load(deps.storage, &token_id)?;
+    let approvals = token
+        .approvals
+        .into_iter()
+        .filter(|approval| include_expired || !approval.expires.is_expired(&env.block))
+        .collect();
+    Ok(OwnerOfResponse {
+        owner: token.owner.to_string(),
+        approvals,
+    })
+}
+
+fn query_nft_info(
+    deps: Deps<CoreumQueries>,
+    token_id: String,
+) -> StdResult<NftInfoResponse<Option<Binary>>> {
+    // withdrawn tokens are not part of the adapter anymore
+    TOKENS.load(deps.storage, &token_id)?;
+    let request = CoreumQueries::NFT(nft::Query::NFT {
+        class_id: CLASS_ID.load(deps.storage)?,
+        id: token_id,
+    })
+    .into();
+    let res: NFTResponse = deps.querier.query(&request)?;
+    Ok(NftInfoResponse {
+        token_uri: res.nft.uri,
+        extension: res.nft.data,
+    })
+}
+
+fn query_tokens(
+    deps: Deps<CoreumQueries>,
+    owner: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    let tokens = OWNER_TOKENS
+        .prefix(&owner)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+    Ok(TokensResponse { tokens })
+}
+
+fn query_all_tokens(
+    deps: Deps<CoreumQueries>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<TokensResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    let tokens = TOKENS
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+    Ok(TokensResponse { tokens })
+}
+
+fn query_royalty_info(
+    deps: Deps<CoreumQueries>,
+    token_id: String,
+    sale_price: Uint128,
+) -> StdResult<RoyaltiesInfoResponse> {
+    // every token of the class shares its royalty
+    TOKENS.load(deps.storage, &token_id)?;
+    Ok(RoyaltiesInfoResponse {
+        address: MINTER.load(deps.storage)?.to_string(),
+        royalty_amount: royalty_amount(sale_price, query_royalty_rate(deps)?),
+    })
+}
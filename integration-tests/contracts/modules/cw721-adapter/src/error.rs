@@ -0,0 +1,32 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Token {token_id} already exists")]
+    TokenExists { token_id: String },
+
+    #[error("Token {token_id} not found")]
+    TokenNotFound { token_id: String },
+
+    #[error("Approval not found for {spender}")]
+    ApprovalNotFound { spender: String },
+
+    #[error("Invalid expiration value")]
+    InvalidExpiration {},
+
+    #[error("Token {token_id} is not for sale")]
+    NotListed { token_id: String },
+
+    #[error("Invalid zero price")]
+    InvalidZeroPrice {},
+
+    #[error("Funds must be exactly the price of {price}")]
+    InvalidFunds { price: String },
+}
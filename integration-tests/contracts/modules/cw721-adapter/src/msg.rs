@@ -0,0 +1,133 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary, Coin, Uint128};
+use cw721::Expiration;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub name: String,
+    pub symbol: String,
+    pub description: Option<String>,
+    pub uri: Option<String>,
+    pub uri_hash: Option<String>,
+    pub royalty_rate: Option<String>,
+    // account allowed to mint, the instantiating account by default
+    pub minter: Option<String>,
+}
+
+// The cw721 messages with their cw721 wire format, plus Withdraw moving a token out of the
+// contract to its owner as a native nft, and a fixed price sale paying the class royalty to the
+// minter.
+#[cw_serde]
+pub enum ExecuteMsg {
+    TransferNft {
+        recipient: String,
+        token_id: String,
+    },
+    SendNft {
+        contract: String,
+        token_id: String,
+        msg: Binary,
+    },
+    Approve {
+        spender: String,
+        token_id: String,
+        expires: Option<Expiration>,
+    },
+    Revoke {
+        spender: String,
+        token_id: String,
+    },
+    ApproveAll {
+        operator: String,
+        expires: Option<Expiration>,
+    },
+    RevokeAll {
+        operator: String,
+    },
+    // mints the nft of the class into the contract and credits the token to the owner
+    Mint {
+        token_id: String,
+        owner: String,
+        token_uri: Option<String>,
+        token_uri_hash: Option<String>,
+        data: Option<Binary>,
+    },
+    Burn {
+        token_id: String,
+    },
+    // sends the native nft to the recipient, the owner by default, after which the contract no
+    // longer tracks the token
+    Withdraw {
+        token_id: String,
+        recipient: Option<String>,
+    },
+    // puts the token up for sale, by the owner or its operators
+    List {
+        token_id: String,
+        price: Coin,
+    },
+    Unlist {
+        token_id: String,
+    },
+    // buys a listed token with funds of exactly its price, the royalty goes to the minter and the
+    // rest to the seller
+    Buy {
+        token_id: String,
+    },
+}
+
+// The cw721 queries answered with the cw721 response types. NftInfo reads the nft from the chain,
+// the extension is its data.
+#[cw_serde]
+pub enum QueryMsg {
+    OwnerOf {
+        token_id: String,
+        include_expired: Option<bool>,
+    },
+    NumTokens {},
+    ContractInfo {},
+    NftInfo {
+        token_id: String,
+    },
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    Minter {},
+    ClassId {},
+    // the cw2981 royalty query, for a sale at the given price
+    RoyaltyInfo {
+        token_id: String,
+        sale_price: Uint128,
+    },
+    Listing {
+        token_id: String,
+    },
+}
+
+#[cw_serde]
+pub struct MinterResponse {
+    pub minter: Option<String>,
+}
+
+#[cw_serde]
+pub struct ClassIdResponse {
+    pub class_id: String,
+}
+
+#[cw_serde]
+pub struct RoyaltiesInfoResponse {
+    pub address: String,
+    pub royalty_amount: Uint128,
+}
+
+#[cw_serde]
+pub struct ListingResponse {
+    pub seller: Addr,
+    pub price: Coin,
+}
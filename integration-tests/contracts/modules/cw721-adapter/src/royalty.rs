@@ -0,0 +1,60 @@
+use std::str::FromStr;
+
+use coreum_wasm_sdk::assetnft::{self, ClassResponse};
+use coreum_wasm_sdk::core::CoreumQueries;
+use cosmwasm_std::{Decimal, Deps, StdResult, Uint128};
+
+use crate::state::CLASS_ID;
+
+/// Reads the royalty rate of the class from the chain, zero when the class was issued without one.
+pub fn query_royalty_rate(deps: Deps<CoreumQueries>) -> StdResult<Decimal> {
+    let request = CoreumQueries::AssetNFT(assetnft::Query::Class {
+        id: CLASS_ID.load(deps.storage)?,
+    })
+    .into();
+    let res: ClassResponse = deps.querier.query(&request)?;
+    // the chain sends the sdk.Dec with its 18 decimal places, which Decimal parses as is
+    res.class
+        .royalty_rate
+        .filter(|rate| !rate.is_empty())
+        .map_or(Ok(Decimal::zero()), |rate| Decimal::from_str(&rate))
+}
+
+/// Part of the sale price owed as royalty, rounded down so the seller keeps the remainder.
+pub fn royalty_amount(sale_price: Uint128, royalty_rate: Decimal) -> Uint128 {
+    sale_price * royalty_rate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn royalty_amounts() {
+        // (sale price, royalty rate, royalty)
+        let cases = [
+            // zero rate
+            (1_000, "0", 0),
+            (0, "0", 0),
+            (u128::MAX, "0.000000000000000000", 0),
+            // full rate
+            (1_000, "1", 1_000),
+            (1, "1.000000000000000000", 1),
+            (u128::MAX, "1", u128::MAX),
+            // rounding down
+            (999, "0.1", 99),
+            (1, "0.5", 0),
+            (3, "0.333333333333333333", 0),
+            (100, "0.015", 1),
+            (1_000_000, "0.000000999999999999", 0),
+            (1_000_000_000_000_000_000, "0.000000000000000001", 1),
+        ];
+        for (sale_price, rate, royalty) in cases {
+            assert_eq!(
+                royalty_amount(Uint128::new(sale_price), Decimal::from_str(rate).unwrap()),
+                Uint128::new(royalty),
+                "{sale_price} at {rate}"
+            );
+        }
+    }
+}
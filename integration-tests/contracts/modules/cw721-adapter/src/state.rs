@@ -0,0 +1,25 @@
+use cosmwasm_std::{Addr, Coin, Empty};
+use cw721::Approval;
+use cw_storage_plus::{Item, Map};
+
+#[cosmwasm_schema::cw_serde]
+pub struct TokenInfo {
+    pub owner: Addr,
+    // spenders allowed to move the token, cleared whenever it changes hands
+    pub approvals: Vec<Approval>,
+}
+
+// assetnft class issued by the contract, every token is an nft of it held by the contract
+pub const CLASS_ID: Item<String> = Item::new("class_id");
+pub const NAME: Item<String> = Item::new("name");
+pub const SYMBOL: Item<String> = Item::new("symbol");
+pub const MINTER: Item<Addr> = Item::new("minter");
+// tokens tracked by the contract, the class supply also counts the withdrawn ones
+pub const TOKEN_COUNT: Item<u64> = Item::new("token_count");
+pub const TOKENS: Map<&str, TokenInfo> = Map::new("tokens");
+// token ids by owner, the index of the Tokens query
+pub const OWNER_TOKENS: Map<(&Addr, &str), Empty> = Map::new("owner_tokens");
+// operators keyed by (owner, operator), allowed to move all tokens of the owner
+pub const OPERATORS: Map<(&Addr, &Addr), cw721::Expiration> = Map::new("operators");
+// asking prices of the tokens put up for sale by their owners, dropped whenever the token moves
+pub const LISTINGS: Map<&str, Coin> = Map::new("listings");
@@ -0,0 +1,532 @@
+use common::any_msg::AnyMsg;
+use common::balances::balances_matching;
+use common::contract_info::query_contract_info;
+use common::protos::{CosmosAuthz, CosmosBankSend};
+use coreum_wasm_sdk::assetft::{self, FrozenBalanceResponse, Query};
+use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries, CoreumResult};
+use cosmwasm_std::{
+    coin, entry_point, to_json_binary, BankMsg, Binary, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdResult, Uint128,
+};
+use cw2::set_contract_version;
+use cw_ownable::initialize_owner;
+use cw_storage_plus::Bound;
+use CosmosAuthz::MsgExec;
+use CosmosBankSend::MsgSend;
+
+use crate::error::ContractError;
+use crate::msg::{
+    ContractInfoResponse, DenomResponse, EscrowResponse, EscrowsResponse, ExecuteMsg,
+    InstantiateMsg, QueryMsg,
+};
+use crate::state::{Escrow, DENOM, ESCROWS, NEXT_ID};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// page sizes of the Escrows query
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
+
+// feature 2 of x/asset/ft, the contract only needs to freeze
+const FEATURE_FREEZING: u32 = 2;
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> CoreumResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    initialize_owner(deps.storage, deps.api, Some(info.sender.as_ref()))?;
+
+    // the issue fee is paid from the funds sent with the instantiation
+    let issue_msg = CoreumMsg::AssetFT(assetft::Msg::Issue {
+        symbol: msg.symbol,
+        subunit: msg.subunit.clone(),
+        precision: msg.precision,
+        initial_amount: msg.initial_amount,
+        description: msg.description,
+        features: Some(vec![FEATURE_FREEZING]),
+        burn_rate: None,
+        send_commission_rate: None,
+    });
+    let denom = format!("{}-{}", msg.subunit, env.contract.address).to_lowercase();
+
+    DENOM.save(deps.storage, &denom)?;
+    NEXT_ID.save(deps.storage, &1)?;
+
+    let mut response = Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", info.sender.clone())
+        .add_attribute("denom", denom.clone())
+        .add_message(issue_msg);
+    if !msg.initial_amount.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![coin(msg.initial_amount.u128(), denom)],
+        });
+    }
+    Ok(response)
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> CoreumResult<ContractError> {
+    match msg {
+        ExecuteMsg::CreateEscrow {
+            beneficiary,
+            arbiter,
+            amount,
+        } => create_escrow(deps, info, beneficiary, arbiter, amount),
+        ExecuteMsg::Release { id } => release(deps, env, info, id),
+        ExecuteMsg::Refund { id } => refund(deps, info, id),
+    }
+}
+
+// ********** Transactions **********
+
+fn create_escrow(
+    deps: DepsMut<CoreumQueries>,
+    info: MessageInfo,
+    beneficiary: String,
+    arbiter: String,
+    amount: Uint128,
+) -> CoreumResult<ContractError> {
+    let beneficiary = deps.api.addr_validate(&beneficiary)?;
+    let arbiter = deps.api.addr_validate(&arbiter)?;
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    let denom = DENOM.load(deps.storage)?;
+
+    // the chain freezes beyond the balance, so an escrow not covered by the unfrozen part of it
+    // must be rejected here
+    let balance = deps.querier.query_balance(&info.sender, &denom)?.amount;
+    let frozen: FrozenBalanceResponse = deps.querier.query(
+        &CoreumQueries::AssetFT(Query::FrozenBalance {
+            account: info.sender.to_string(),
+            denom: denom.clone(),
+        })
+        .into(),
+    )?;
+    let available = balance.saturating_sub(frozen.balance.amount);
+    if available < amount {
+        return Err(ContractError::InsufficientUnfrozenBalance {
+            account: info.sender.to_string(),
+            available,
+        });
+    }
+
+    let id = NEXT_ID.load(deps.storage)?;
+    NEXT_ID.save(deps.storage, &(id + 1))?;
+    ESCROWS.save(
+        deps.storage,
+        id,
+        &Escrow {
+            holder: info.sender.clone(),
+            beneficiary: beneficiary.clone(),
+            arbiter: arbiter.clone(),
+            amount,
+        },
+    )?;
+
+    let msg = CoreumMsg::AssetFT(assetft::Msg::Freeze {
+        account: info.sender.to_string(),
+        coin: coin(amount.u128(), denom),
+    });
+
+    Ok(Response::new()
+        .add_attribute("method", "create_escrow")
+        .add_attribute("id", id.to_string())
+        .add_attribute("holder", info.sender)
+        .add_attribute("beneficiary", beneficiary)
+        .add_attribute("arbiter", arbiter)
+        .add_attribute("amount", amount)
+        .add_message(msg))
+}
+
+// release runs the unfreeze before the send, messages are executed in order, so the send spends
+// the amount that was frozen.
+fn release(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> CoreumResult<ContractError> {
+    let escrow = load_escrow(deps.as_ref(), id)?;
+    if info.sender != escrow.arbiter {
+        return Err(ContractError::Unauthorized {});
+    }
+    let denom = DENOM.load(deps.storage)?;
+    ESCROWS.remove(deps.storage, id);
+
+    let mut send = MsgSend::new();
+    send.from_address = escrow.holder.to_string();
+    send.to_address = escrow.beneficiary.to_string();
+    send.amount = vec![CosmosBankSend::Coin {
+        denom: denom.clone(),
+        amount: escrow.amount.to_string(),
+        ..Default::default()
+    }];
+    let mut exec = MsgExec::new();
+    exec.grantee = env.contract.address.to_string();
+    exec.msgs = vec![send.to_any()?];
+
+    Ok(Response::new()
+        .add_attribute("method", "release")
+        .add_attribute("id", id.to_string())
+        .add_attribute("beneficiary", escrow.beneficiary.clone())
+        .add_attribute("amount", escrow.amount)
+        .add_message(unfreeze_msg(&escrow, denom))
+        .add_message(exec.to_stargate()?))
+}
+
+fn refund(deps: DepsMut<CoreumQueries>, info: MessageInfo, id: u64) -> CoreumResult<ContractError> {
+    let escrow = load_escrow(deps.as_ref(), id)?;
+    if info.sender != escrow.arbiter && info.sender != escrow.beneficiary {
+        return Err(ContractError::Unauthorized {});
+    }
+    let denom = DENOM.load(deps.storage)?;
+    ESCROWS.remove(deps.storage, id);
+
+    Ok(Response::new()
+        .add_attribute("method", "refund")
+        .add_attribute("id", id.to_string())
+        .add_attribute("holder", escrow.holder.clone())
+        .add_attribute("amount", escrow.amount)
+        .add_message(unfreeze_msg(&escrow, denom)))
+}
+
+fn load_escrow(deps: Deps<CoreumQueries>, id: u64) -> Result<Escrow, ContractError> {
+    ESCROWS
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::EscrowNotFound { id })
+}
+
+fn unfreeze_msg(escrow: &Escrow, denom: String) -> CoreumMsg {
+    CoreumMsg::AssetFT(assetft::Msg::Unfreeze {
+        account: escrow.holder.to_string(),
+        coin: coin(escrow.amount.u128(), denom),
+    })
+}
+
+// ********** Queries **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<CoreumQueries>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Escrow { id } => {
+            to_json_binary(&escrow_response(id, ESCROWS.load(deps.storage, id)?))
+        }
+        QueryMsg::Escrows { start_after, limit } => {
+            to_json_binary(&query_escrows(deps, start_after, limit)?)
+        }
+        QueryMsg::Denom {} => to_json_binary(&DenomResponse {
+            denom: DENOM.load(deps.storage)?,
+        }),
+        QueryMsg::ContractInfo { address } => to_json_binary(&query_contract(deps, address)?),
+        QueryMsg::BalancesMatching {
+            address,
+            denom_prefix,
+            denom_suffix,
+        } => to_json_binary(&balances_matching(
+            &deps.querier,
+            &address,
+            &denom_prefix,
+            denom_suffix.as_deref(),
+        )?),
+    }
+}
+
+fn query_contract(deps: Deps<CoreumQueries>, address: String) -> StdResult<ContractInfoResponse> {
+    Ok(match query_contract_info(&deps.querier, &address)? {
+        Some(contract_info) => ContractInfoResponse::Found { contract_info },
+        None => ContractInfoResponse::NotFound {},
+    })
+}
+
+fn query_escrows(
+    deps: Deps<CoreumQueries>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<EscrowsResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let escrows = ESCROWS
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|item| item.map(|(id, escrow)| escrow_response(id, escrow)))
+        .collect::<StdResult<_>>()?;
+    Ok(EscrowsResponse { escrows })
+}
+
+fn escrow_response(id: u64, escrow: Escrow) -> EscrowResponse {
+    EscrowResponse {
+        id,
+        holder: escrow.holder,
+        beneficiary: escrow.beneficiary,
+        arbiter: escrow.arbiter,
+        amount: escrow.amount,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::balances::{BalancesMatching, ALL_BALANCES_PATH};
+    use common::contract_info::{ContractInfo, CONTRACT_INFO_PATH};
+    use common::protos::CosmosBankQuery::{
+        self, QueryAllBalancesRequest, QueryAllBalancesResponse,
+    };
+    use common::protos::CosmosWasmQuery::{QueryContractInfoRequest, QueryContractInfoResponse};
+    use common::stargate_mock::StargateMockQuerier;
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+    use cosmwasm_std::{from_json, Addr, ContractResult, CosmosMsg, OwnedDeps};
+    use ft::mock::{CoreumMockQuerier, CoreumQuerier};
+    use protobuf::Message;
+
+    use super::*;
+
+    const ISSUER: &str = "core1zgqszqgpqyqszqgpqyqszqgpqyqszqgp0f2xnw";
+    const HOLDER: &str = "holder";
+    const ARBITER_CONTRACT: &str = "arbiter";
+    const OWNER: &str = "owner";
+    const BENEFICIARY: &str = "beneficiary";
+    const ARBITER: &str = "arbiter";
+    const OTHER_ARBITER: &str = "other_arbiter";
+
+    type TestDeps =
+        OwnedDeps<MockStorage, MockApi, StargateMockQuerier<CoreumQueries>, CoreumQueries>;
+
+    // a chain with the arbiter contract and the holder owning two tokens of the issuer and ucore
+    fn stargate_deps() -> TestDeps {
+        let querier = StargateMockQuerier::new(MockQuerier::new(&[]))
+            .with_handler(CONTRACT_INFO_PATH, |data| {
+                let request = QueryContractInfoRequest::parse_from_bytes(data).unwrap();
+                if request.address != ARBITER_CONTRACT {
+                    return ContractResult::Err("not found".to_string());
+                }
+                let mut res = QueryContractInfoResponse::new();
+                res.address = request.address;
+                let info = res.contract_info.mut_or_insert_default();
+                info.code_id = 3;
+                info.creator = ISSUER.to_string();
+                info.label = "arbiter".to_string();
+                ContractResult::Ok(res.write_to_bytes().unwrap().into())
+            })
+            .with_handler(ALL_BALANCES_PATH, |data| {
+                let request = QueryAllBalancesRequest::parse_from_bytes(data).unwrap();
+                assert_eq!(request.address, HOLDER);
+                let mut res = QueryAllBalancesResponse::new();
+                for (denom, amount) in [
+                    (format!("uabc-{ISSUER}"), "10"),
+                    (format!("uxyz-{ISSUER}"), "20"),
+                    ("ucore".to_string(), "30"),
+                ] {
+                    let mut coin = CosmosBankQuery::Coin::new();
+                    coin.denom = denom;
+                    coin.amount = amount.to_string();
+                    res.balances.push(coin);
+                }
+                ContractResult::Ok(res.write_to_bytes().unwrap().into())
+            });
+        OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier,
+            custom_query_type: Default::default(),
+        }
+    }
+
+    fn query_contract_info(deps: &TestDeps, address: &str) -> ContractInfoResponse {
+        let msg = QueryMsg::ContractInfo {
+            address: address.to_string(),
+        };
+        from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn contract_info_of_a_contract() {
+        let deps = stargate_deps();
+        assert_eq!(
+            query_contract_info(&deps, ARBITER_CONTRACT),
+            ContractInfoResponse::Found {
+                contract_info: ContractInfo {
+                    code_id: 3,
+                    creator: ISSUER.to_string(),
+                    admin: None,
+                    label: "arbiter".to_string(),
+                },
+            }
+        );
+        assert_eq!(
+            query_contract_info(&deps, HOLDER),
+            ContractInfoResponse::NotFound {}
+        );
+    }
+
+    #[test]
+    fn balances_matching_the_issuer() {
+        let deps = stargate_deps();
+        let msg = QueryMsg::BalancesMatching {
+            address: HOLDER.to_string(),
+            denom_prefix: "u".to_string(),
+            denom_suffix: Some(format!("-{ISSUER}")),
+        };
+        let res: BalancesMatching =
+            from_json(query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap();
+        assert_eq!(
+            res,
+            BalancesMatching {
+                balances: vec![
+                    coin(10, format!("uabc-{ISSUER}")),
+                    coin(20, format!("uxyz-{ISSUER}")),
+                ],
+                cap_reached: false,
+            }
+        );
+    }
+
+    type FrozenDeps = OwnedDeps<MockStorage, MockApi, CoreumQuerier, CoreumQueries>;
+
+    fn denom() -> String {
+        format!("uesc-{}", mock_env().contract.address)
+    }
+
+    // the holder owns 100 of the token with `frozen` of it frozen by an earlier escrow
+    fn frozen_deps(frozen: u128) -> FrozenDeps {
+        let registry = CoreumMockQuerier::new().with_frozen(HOLDER, coin(frozen, denom()));
+        let mut deps = OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier: CoreumQuerier::new(registry),
+            custom_query_type: Default::default(),
+        };
+        deps.querier
+            .update_balance(HOLDER, vec![coin(100, denom())]);
+
+        let msg = InstantiateMsg {
+            symbol: "ESC".to_string(),
+            subunit: "uesc".to_string(),
+            precision: 6,
+            initial_amount: Uint128::zero(),
+            description: None,
+        };
+        instantiate(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).unwrap();
+        deps
+    }
+
+    fn hold(deps: &mut FrozenDeps, arbiter: &str, amount: u128) -> CoreumResult<ContractError> {
+        let msg = ExecuteMsg::CreateEscrow {
+            beneficiary: BENEFICIARY.to_string(),
+            arbiter: arbiter.to_string(),
+            amount: Uint128::new(amount),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(HOLDER, &[]), msg)
+    }
+
+    fn release_as(deps: &mut FrozenDeps, sender: &str, id: u64) -> CoreumResult<ContractError> {
+        let msg = ExecuteMsg::Release { id };
+        execute(deps.as_mut(), mock_env(), mock_info(sender, &[]), msg)
+    }
+
+    #[test]
+    fn hold_freezes_the_amount_on_the_holder() {
+        let mut deps = frozen_deps(30);
+        let res = hold(&mut deps, ARBITER, 70).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Custom(CoreumMsg::AssetFT(assetft::Msg::Freeze {
+                account: HOLDER.to_string(),
+                coin: coin(70, denom()),
+            }))
+        );
+        assert_eq!(
+            ESCROWS.load(&deps.storage, 1).unwrap(),
+            Escrow {
+                holder: Addr::unchecked(HOLDER),
+                beneficiary: Addr::unchecked(BENEFICIARY),
+                arbiter: Addr::unchecked(ARBITER),
+                amount: Uint128::new(70),
+            }
+        );
+
+        // 30 of the 100 are frozen already
+        let err = hold(&mut deps, ARBITER, 71).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientUnfrozenBalance { available, .. } if available.u128() == 70
+        ));
+    }
+
+    #[test]
+    fn release_unfreezes_and_pays_the_beneficiary() {
+        let mut deps = frozen_deps(0);
+        hold(&mut deps, ARBITER, 40).unwrap();
+        let res = release_as(&mut deps, ARBITER, 1).unwrap();
+
+        assert_eq!(res.messages.len(), 2);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Custom(CoreumMsg::AssetFT(assetft::Msg::Unfreeze {
+                account: HOLDER.to_string(),
+                coin: coin(40, denom()),
+            }))
+        );
+        let CosmosMsg::Stargate { type_url, value } = &res.messages[1].msg else {
+            panic!("release must send through authz");
+        };
+        assert_eq!(type_url, "/cosmos.authz.v1beta1.MsgExec");
+        let exec = MsgExec::parse_from_bytes(value).unwrap();
+        assert_eq!(exec.grantee, mock_env().contract.address.as_str());
+        let send = MsgSend::parse_from_bytes(&exec.msgs[0].value).unwrap();
+        assert_eq!(send.from_address, HOLDER);
+        assert_eq!(send.to_address, BENEFICIARY);
+        assert_eq!(send.amount[0].denom, denom());
+        assert_eq!(send.amount[0].amount, "40");
+        assert!(!ESCROWS.has(&deps.storage, 1));
+    }
+
+    #[test]
+    fn double_release_finds_no_escrow() {
+        let mut deps = frozen_deps(0);
+        hold(&mut deps, ARBITER, 40).unwrap();
+        release_as(&mut deps, ARBITER, 1).unwrap();
+
+        let err = release_as(&mut deps, ARBITER, 1).unwrap_err();
+        assert!(matches!(err, ContractError::EscrowNotFound { id: 1 }));
+    }
+
+    #[test]
+    fn releasing_someone_elses_escrow_is_unauthorized() {
+        let mut deps = frozen_deps(0);
+        hold(&mut deps, ARBITER, 40).unwrap();
+        hold(&mut deps, OTHER_ARBITER, 20).unwrap();
+
+        // the arbiter of the first escrow has no say over the second
+        let err = release_as(&mut deps, ARBITER, 2).unwrap_err();
+        assert!(matches!(err, ContractError::Unauthorized {}));
+        for sender in [HOLDER, BENEFICIARY] {
+            let err = release_as(&mut deps, sender, 1).unwrap_err();
+            assert!(matches!(err, ContractError::Unauthorized {}));
+        }
+
+        assert!(ESCROWS.has(&deps.storage, 1));
+        assert!(ESCROWS.has(&deps.storage, 2));
+        release_as(&mut deps, OTHER_ARBITER, 2).unwrap();
+    }
+}
@@ -0,0 +1,27 @@
+use cosmwasm_std::{StdError, Uint128};
+use cw_ownable::OwnershipError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Ownership(#[from] OwnershipError),
+
+    #[error("{0}")]
+    Protobuf(#[from] protobuf::Error),
+
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Invalid zero amount")]
+    InvalidZeroAmount {},
+
+    #[error("Escrow {id} not found")]
+    EscrowNotFound { id: u64 },
+
+    #[error("Account {account} has {available} unfrozen, short of the escrowed amount")]
+    InsufficientUnfrozenBalance { account: String, available: Uint128 },
+}
@@ -0,0 +1,83 @@
+use common::contract_info::ContractInfo;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub symbol: String,
+    pub subunit: String,
+    pub precision: u32,
+    // issued to the contract and passed on to the instantiating account
+    pub initial_amount: Uint128,
+    pub description: Option<String>,
+}
+
+// Escrows keep the amount on the holder's account, frozen, instead of taking it into custody.
+// Release pays the beneficiary through an authz grant of MsgSend the holder gives the contract,
+// which must be in place when the arbiter releases.
+#[cw_serde]
+pub enum ExecuteMsg {
+    // freezes the amount on the sender's account, the sender is the holder
+    CreateEscrow {
+        beneficiary: String,
+        arbiter: String,
+        amount: Uint128,
+    },
+    // unfreezes the amount and sends it from the holder to the beneficiary, arbiter only
+    Release {
+        id: u64,
+    },
+    // unfreezes the amount leaving it with the holder, arbiter or beneficiary only
+    Refund {
+        id: u64,
+    },
+}
+
+#[cw_serde]
+pub enum QueryMsg {
+    Escrow {
+        id: u64,
+    },
+    Escrows {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    Denom {},
+    // info of the contract at the address as the wasm module has it
+    ContractInfo {
+        address: String,
+    },
+    // balances of the address whose denom starts with the prefix and ends with the suffix, the
+    // tokens of an issuer share the `-{issuer}` suffix. An empty prefix matches every denom.
+    BalancesMatching {
+        address: String,
+        denom_prefix: String,
+        denom_suffix: Option<String>,
+    },
+}
+
+#[cw_serde]
+pub struct EscrowResponse {
+    pub id: u64,
+    pub holder: Addr,
+    pub beneficiary: Addr,
+    pub arbiter: Addr,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct EscrowsResponse {
+    pub escrows: Vec<EscrowResponse>,
+}
+
+#[cw_serde]
+pub struct DenomResponse {
+    pub denom: String,
+}
+
+// an address without a contract is answered as NotFound instead of failing the query
+#[cw_serde]
+pub enum ContractInfoResponse {
+    Found { contract_info: ContractInfo },
+    NotFound {},
+}
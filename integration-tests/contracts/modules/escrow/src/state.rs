@@ -0,0 +1,17 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+
+#[cw_serde]
+pub struct Escrow {
+    // account the amount stays frozen on until the escrow is settled
+    pub holder: Addr,
+    pub beneficiary: Addr,
+    pub arbiter: Addr,
+    pub amount: Uint128,
+}
+
+// token issued by the contract, which makes it the only account allowed to freeze it
+pub const DENOM: Item<String> = Item::new("denom");
+pub const NEXT_ID: Item<u64> = Item::new("next_id");
+pub const ESCROWS: Map<u64, Escrow> = Map::new("escrows");
@@ -0,0 +1,169 @@
+use common::any_msg::AnyMsg;
+use common::protos::CosmosFeegrant;
+use cosmwasm_std::{entry_point, Coin, DepsMut, Env, MessageInfo, Response, Timestamp};
+use cw2::set_contract_version;
+use cw_ownable::{assert_owner, initialize_owner};
+use protobuf::well_known_types::any::Any;
+use protobuf::well_known_types::duration::Duration;
+use protobuf::MessageField;
+use CosmosFeegrant::{
+    AllowedMsgAllowance, BasicAllowance, MsgGrantAllowance, MsgRevokeAllowance, PeriodicAllowance,
+};
+
+use crate::error::ContractError;
+use crate::msg::{Allowance, ExecuteMsg, InstantiateMsg};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    initialize_owner(deps.storage, deps.api, Some(info.sender.as_ref()))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", info.sender))
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::GrantAllowance { grantee, allowance } => {
+            grant_allowance(deps, env, info, grantee, allowance)
+        }
+        ExecuteMsg::RevokeAllowance { grantee } => revoke_allowance(deps, env, info, grantee),
+    }
+}
+
+// ********** Transactions **********
+
+fn grant_allowance(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    grantee: String,
+    allowance: Allowance,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let grantee = deps.api.addr_validate(&grantee)?;
+
+    let allowance = encode_allowance(&env, allowance)?;
+    let allowance_type_url = allowance.type_url.clone();
+    let mut msg = MsgGrantAllowance::new();
+    msg.granter = env.contract.address.to_string();
+    msg.grantee = grantee.to_string();
+    msg.allowance = MessageField::some(allowance);
+
+    Ok(Response::new()
+        .add_attribute("method", "grant_allowance")
+        .add_attribute("grantee", grantee)
+        .add_attribute("allowance_type_url", allowance_type_url)
+        .add_message(msg.to_stargate()?))
+}
+
+fn revoke_allowance(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    grantee: String,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let grantee = deps.api.addr_validate(&grantee)?;
+
+    let mut msg = MsgRevokeAllowance::new();
+    msg.granter = env.contract.address.to_string();
+    msg.grantee = grantee.to_string();
+
+    Ok(Response::new()
+        .add_attribute("method", "revoke_allowance")
+        .add_attribute("grantee", grantee)
+        .add_message(msg.to_stargate()?))
+}
+
+// ********** Encoding **********
+
+fn encode_allowance(env: &Env, allowance: Allowance) -> Result<Any, ContractError> {
+    match allowance {
+        Allowance::Basic {
+            spend_limit,
+            expiration,
+        } => Ok(basic_allowance(spend_limit, expiration).to_any()?),
+        Allowance::Periodic {
+            spend_limit,
+            expiration,
+            period_seconds,
+            period_spend_limit,
+        } => {
+            if period_seconds == 0 {
+                return Err(ContractError::ZeroPeriod {});
+            }
+
+            // the first period starts with the grant, as the CLI does it
+            let mut periodic = PeriodicAllowance::new();
+            periodic.basic = MessageField::some(basic_allowance(spend_limit, expiration));
+            periodic.period = MessageField::some(Duration {
+                seconds: period_seconds as i64,
+                ..Default::default()
+            });
+            periodic.period_spend_limit = proto_coins(&period_spend_limit);
+            periodic.period_can_spend = proto_coins(&period_spend_limit);
+            periodic.period_reset =
+                MessageField::some(proto_timestamp(env.block.time.plus_seconds(period_seconds)));
+            Ok(periodic.to_any()?)
+        }
+        Allowance::AllowedMsg {
+            allowance,
+            allowed_messages,
+        } => {
+            if allowed_messages.is_empty() {
+                return Err(ContractError::NoAllowedMessages {});
+            }
+
+            let mut allowed = AllowedMsgAllowance::new();
+            allowed.allowance = MessageField::some(encode_allowance(env, *allowance)?);
+            allowed.allowed_messages = allowed_messages;
+            Ok(allowed.to_any()?)
+        }
+    }
+}
+
+fn basic_allowance(spend_limit: Vec<Coin>, expiration: Option<Timestamp>) -> BasicAllowance {
+    let mut basic = BasicAllowance::new();
+    basic.spend_limit = proto_coins(&spend_limit);
+    basic.expiration = expiration.map(proto_timestamp).into();
+    basic
+}
+
+fn proto_coins(coins: &[Coin]) -> Vec<CosmosFeegrant::Coin> {
+    coins
+        .iter()
+        .map(|coin| CosmosFeegrant::Coin {
+            denom: coin.denom.clone(),
+            amount: coin.amount.to_string(),
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn proto_timestamp(time: Timestamp) -> protobuf::well_known_types::timestamp::Timestamp {
+    let mut timestamp = protobuf::well_known_types::timestamp::Timestamp::new();
+    timestamp.seconds = time.seconds() as i64;
+    timestamp.nanos = time.subsec_nanos() as i32;
+    timestamp
+}
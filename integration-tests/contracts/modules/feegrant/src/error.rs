@@ -0,0 +1,21 @@
+use cosmwasm_std::StdError;
+use cw_ownable::OwnershipError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Ownership(#[from] OwnershipError),
+
+    #[error("{0}")]
+    Protobuf(#[from] protobuf::Error),
+
+    #[error("Allowed messages must not be empty")]
+    NoAllowedMessages {},
+
+    #[error("Period must be greater than zero")]
+    ZeroPeriod {},
+}
@@ -0,0 +1,36 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Timestamp};
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // grants the allowance with the contract as the granter
+    GrantAllowance {
+        grantee: String,
+        allowance: Allowance,
+    },
+    RevokeAllowance {
+        grantee: String,
+    },
+}
+
+// allowances of x/feegrant, an empty spend limit means no limit
+#[cw_serde]
+pub enum Allowance {
+    Basic {
+        spend_limit: Vec<Coin>,
+        expiration: Option<Timestamp>,
+    },
+    Periodic {
+        spend_limit: Vec<Coin>,
+        expiration: Option<Timestamp>,
+        period_seconds: u64,
+        period_spend_limit: Vec<Coin>,
+    },
+    AllowedMsg {
+        allowance: Box<Allowance>,
+        allowed_messages: Vec<String>,
+    },
+}
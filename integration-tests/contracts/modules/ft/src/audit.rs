@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::str::FromStr;
+
+use coreum_wasm_sdk::core::CoreumMsg;
+use cosmwasm_std::{from_json, to_json_vec, Decimal};
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+
+use crate::error::ContractError;
+
+// Fields the custom message handler decodes as JSON numbers, precision is an uint32 and features
+// are enums. Every other integer, amounts included, must travel as a string so it can't be
+// rounded by a decoder going through float64.
+const INTEGER_FIELDS: &[&str] = &["precision", "features"];
+
+// Fields decoded into sdk.Dec, which fails on null as well as on anything but a decimal string.
+const DECIMAL_FIELDS: &[&str] = &["burn_rate", "send_commission_rate", "royalty_rate"];
+
+/// Checks the JSON a custom message is sent to the chain with against what the custom message
+/// handler decodes and returns it rendered with sorted keys and no whitespace. The rendering is
+/// what the golden file of the handler tests is made of, so a coreum-wasm-sdk upgrade that
+/// renames, reorders or retypes a field shows up as a diff instead of a failing transaction.
+pub fn audit_custom_msg(msg: &CoreumMsg) -> Result<String, ContractError> {
+    let value: Value = from_json(&to_json_vec(msg)?)?;
+    check_value("$", None, &value)?;
+
+    let mut out = String::new();
+    write_value(&mut out, &value);
+    Ok(out)
+}
+
+enum Value {
+    Null,
+    Bool(bool),
+    Unsigned(u64),
+    Signed(i64),
+    String(String),
+    Array(Vec<Value>),
+    Object(BTreeMap<String, Value>),
+}
+
+// check_value walks the value keeping the name of the innermost field, array items are checked
+// against the field holding the array.
+fn check_value(path: &str, field: Option<&str>, value: &Value) -> Result<(), ContractError> {
+    if let Some(field) = field.filter(|field| DECIMAL_FIELDS.contains(field)) {
+        return match value {
+            Value::String(v) if Decimal::from_str(v).is_ok() => Ok(()),
+            Value::String(v) => Err(drift(path, format!("{v:?} is not a decimal"))),
+            _ => Err(drift(path, format!("{field} must be a decimal string"))),
+        };
+    }
+
+    match value {
+        Value::Unsigned(_) | Value::Signed(_) => match field {
+            Some(field) if INTEGER_FIELDS.contains(&field) => Ok(()),
+            _ => Err(drift(
+                path,
+                "integers must be encoded as strings".to_string(),
+            )),
+        },
+        Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, v)| check_value(&format!("{path}[{i}]"), field, v)),
+        Value::Object(entries) => entries
+            .iter()
+            .try_for_each(|(k, v)| check_value(&format!("{path}.{k}"), Some(k), v)),
+        Value::Null | Value::Bool(_) | Value::String(_) => Ok(()),
+    }
+}
+
+fn drift(path: &str, reason: String) -> ContractError {
+    ContractError::EncodingDrift {
+        path: path.to_string(),
+        reason,
+    }
+}
+
+fn write_value(out: &mut String, value: &Value) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(v) => out.push_str(if *v { "true" } else { "false" }),
+        Value::Unsigned(v) => out.push_str(&v.to_string()),
+        Value::Signed(v) => out.push_str(&v.to_string()),
+        Value::String(v) => write_string(out, v),
+        Value::Array(values) => {
+            out.push('[');
+            for (i, v) in values.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(out, v);
+            }
+            out.push(']');
+        }
+        Value::Object(entries) => {
+            out.push('{');
+            for (i, (k, v)) in entries.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(out, k);
+                out.push(':');
+                write_value(out, v);
+            }
+            out.push('}');
+        }
+    }
+}
+
+// write_string escapes only what JSON requires, everything else is emitted as UTF-8.
+fn write_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value without floating point numbers")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Value, E> {
+        Ok(Value::Unsigned(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::Signed(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, _v: f64) -> Result<Value, E> {
+        Err(E::custom("floating point numbers are not allowed"))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Value, E> {
+        Ok(Value::String(v))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        Deserialize::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut values = Vec::new();
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(Value::Array(values))
+    }
+
+    // Duplicate keys are rejected, the Go decoder keeps the last one while others keep the first.
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut entries = BTreeMap::new();
+        while let Some((key, value)) = map.next_entry::<String, Value>()? {
+            if entries.contains_key(&key) {
+                return Err(de::Error::custom(format!("duplicate key {key}")));
+            }
+            entries.insert(key, value);
+        }
+        Ok(Value::Object(entries))
+    }
+}
@@ -0,0 +1,61 @@
+use coreum_wasm_sdk::core::CoreumQueries;
+use coreum_wasm_sdk::pagination::PageRequest;
+use cosmwasm_std::{
+    from_json, to_json_vec, ContractResult, Deps, StdError, StdResult, SystemResult,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::msg::AccountBalancesResponse;
+
+// The Balances query of the chain's wasm handler answers the bank balance of every denom held by
+// an account together with its spendable, frozen, whitelisted and locked amounts, in one call
+// instead of a Balance query per denom. CoreumQueries of the bindings doesn't know it, so the
+// request is serialized here the way QueryRequest::Custom would be.
+
+/// Raw request of the Balances query, {"custom":{"AssetFT":{"Balances":{...}}}} on the wire.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BalancesRequest {
+    Custom(BalancesQuery),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum BalancesQuery {
+    AssetFT(AssetFTBalancesQuery),
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub enum AssetFTBalancesQuery {
+    Balances {
+        account: String,
+        pagination: Option<PageRequest>,
+    },
+}
+
+impl BalancesRequest {
+    pub fn new(account: impl Into<String>, pagination: Option<PageRequest>) -> Self {
+        BalancesRequest::Custom(BalancesQuery::AssetFT(AssetFTBalancesQuery::Balances {
+            account: account.into(),
+            pagination,
+        }))
+    }
+}
+
+/// Queries one page of the balances of the account, see BalancesRequest.
+pub fn query_account_balances(
+    deps: Deps<CoreumQueries>,
+    account: impl Into<String>,
+    pagination: Option<PageRequest>,
+) -> StdResult<AccountBalancesResponse> {
+    let request = to_json_vec(&BalancesRequest::new(account, pagination))?;
+    // mirrors QuerierWrapper::query, which only takes requests of the CoreumQueries type
+    match deps.querier.raw_query(&request) {
+        SystemResult::Err(system_err) => Err(StdError::generic_err(format!(
+            "Querier system error: {system_err}"
+        ))),
+        SystemResult::Ok(ContractResult::Err(contract_err)) => Err(StdError::generic_err(format!(
+            "Querier contract error: {contract_err}"
+        ))),
+        SystemResult::Ok(ContractResult::Ok(value)) => from_json(&value),
+    }
+}
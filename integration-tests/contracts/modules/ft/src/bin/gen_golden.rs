@@ -0,0 +1,171 @@
+// Prints every Coreum custom message in the encoding the contracts send it with, audited and with
+// sorted keys. The Go tests of the custom message handler decode the file, so an SDK upgrade that
+// changes the encoding fails here or shows up as a diff of the golden file.
+//
+// cargo run --features audit --bin gen-golden > ../../../../x/wasm/handler/testdata/custom_msgs.json
+
+use std::collections::BTreeMap;
+
+use coreum_wasm_sdk::core::CoreumMsg;
+use coreum_wasm_sdk::{assetft, assetnft, nft};
+use cosmwasm_std::{coin, Binary, Uint128};
+use ft::audit::audit_custom_msg;
+
+// The denom and class are issued by devcore172rc5sz2uclpsy3vvx3y79ah5dk450z5ruq2r5, the account
+// must be another address since the issuer's balance can't be frozen.
+const ACCOUNT: &str = "devcore1k3mke3gyf9apyd8vxveutgp9h4j2e80e05yfuq";
+const DENOM: &str = "abc-devcore172rc5sz2uclpsy3vvx3y79ah5dk450z5ruq2r5";
+const CLASS_ID: &str = "punk-devcore172rc5sz2uclpsy3vvx3y79ah5dk450z5ruq2r5";
+const NFT_ID: &str = "punk1";
+
+fn main() {
+    let msgs = [
+        (
+            "assetft_issue",
+            CoreumMsg::AssetFT(assetft::Msg::Issue {
+                symbol: "ABC".to_string(),
+                subunit: "abc".to_string(),
+                precision: 6,
+                initial_amount: Uint128::new(1_000_000_000_000_000_000_000),
+                description: Some("ABC coin".to_string()),
+                features: Some(vec![0, 1, 2, 3]),
+                burn_rate: Some("0.1".to_string()),
+                send_commission_rate: Some("0.05".to_string()),
+            }),
+        ),
+        (
+            "assetft_mint",
+            CoreumMsg::AssetFT(assetft::Msg::Mint {
+                coin: coin(1000, DENOM),
+            }),
+        ),
+        (
+            "assetft_burn",
+            CoreumMsg::AssetFT(assetft::Msg::Burn {
+                coin: coin(1000, DENOM),
+            }),
+        ),
+        (
+            "assetft_freeze",
+            CoreumMsg::AssetFT(assetft::Msg::Freeze {
+                account: ACCOUNT.to_string(),
+                coin: coin(1000, DENOM),
+            }),
+        ),
+        (
+            "assetft_unfreeze",
+            CoreumMsg::AssetFT(assetft::Msg::Unfreeze {
+                account: ACCOUNT.to_string(),
+                coin: coin(1000, DENOM),
+            }),
+        ),
+        (
+            "assetft_globally_freeze",
+            CoreumMsg::AssetFT(assetft::Msg::GloballyFreeze {
+                denom: DENOM.to_string(),
+            }),
+        ),
+        (
+            "assetft_globally_unfreeze",
+            CoreumMsg::AssetFT(assetft::Msg::GloballyUnfreeze {
+                denom: DENOM.to_string(),
+            }),
+        ),
+        (
+            "assetft_set_whitelisted_limit",
+            CoreumMsg::AssetFT(assetft::Msg::SetWhitelistedLimit {
+                account: ACCOUNT.to_string(),
+                coin: coin(1000, DENOM),
+            }),
+        ),
+        (
+            "assetft_upgrade_token_v1",
+            CoreumMsg::AssetFT(assetft::Msg::UpgradeTokenV1 {
+                denom: DENOM.to_string(),
+                ibc_enabled: true,
+            }),
+        ),
+        (
+            "assetnft_issue_class",
+            CoreumMsg::AssetNFT(assetnft::Msg::IssueClass {
+                name: "Punks".to_string(),
+                symbol: "punk".to_string(),
+                description: Some("Punk class".to_string()),
+                uri: Some("https://punks.example/class".to_string()),
+                uri_hash: Some("class-hash".to_string()),
+                data: Some(Binary::from(b"class data".as_slice())),
+                features: Some(vec![0, 1, 2, 3]),
+                royalty_rate: Some("0.1".to_string()),
+            }),
+        ),
+        (
+            "assetnft_mint",
+            CoreumMsg::AssetNFT(assetnft::Msg::Mint {
+                class_id: CLASS_ID.to_string(),
+                id: NFT_ID.to_string(),
+                uri: Some("https://punks.example/1".to_string()),
+                uri_hash: Some("nft-hash".to_string()),
+                data: Some(Binary::from(b"nft data".as_slice())),
+            }),
+        ),
+        (
+            "assetnft_burn",
+            CoreumMsg::AssetNFT(assetnft::Msg::Burn {
+                class_id: CLASS_ID.to_string(),
+                id: NFT_ID.to_string(),
+            }),
+        ),
+        (
+            "assetnft_freeze",
+            CoreumMsg::AssetNFT(assetnft::Msg::Freeze {
+                class_id: CLASS_ID.to_string(),
+                id: NFT_ID.to_string(),
+            }),
+        ),
+        (
+            "assetnft_unfreeze",
+            CoreumMsg::AssetNFT(assetnft::Msg::Unfreeze {
+                class_id: CLASS_ID.to_string(),
+                id: NFT_ID.to_string(),
+            }),
+        ),
+        (
+            "assetnft_add_to_whitelist",
+            CoreumMsg::AssetNFT(assetnft::Msg::AddToWhitelist {
+                class_id: CLASS_ID.to_string(),
+                id: NFT_ID.to_string(),
+                account: ACCOUNT.to_string(),
+            }),
+        ),
+        (
+            "assetnft_remove_from_whitelist",
+            CoreumMsg::AssetNFT(assetnft::Msg::RemoveFromWhitelist {
+                class_id: CLASS_ID.to_string(),
+                id: NFT_ID.to_string(),
+                account: ACCOUNT.to_string(),
+            }),
+        ),
+        (
+            "nft_send",
+            CoreumMsg::NFT(nft::Msg::Send {
+                class_id: CLASS_ID.to_string(),
+                id: NFT_ID.to_string(),
+                receiver: ACCOUNT.to_string(),
+            }),
+        ),
+    ];
+
+    let golden: BTreeMap<_, _> = msgs
+        .iter()
+        .map(|(name, msg)| {
+            let json = audit_custom_msg(msg).unwrap_or_else(|err| panic!("{name}: {err}"));
+            (*name, json)
+        })
+        .collect();
+
+    let entries: Vec<_> = golden
+        .iter()
+        .map(|(name, json)| format!("  \"{name}\": {json}"))
+        .collect();
+    println!("{{\n{}\n}}", entries.join(",\n"));
+}
@@ -0,0 +1,102 @@
+use thiserror::Error;
+
+// codespaces of the errors assetft messages fail with
+const CODESPACE_ASSETFT: &str = "assetft";
+const CODESPACE_SDK: &str = "sdk";
+
+/// Cause of a failed assetft message, recovered from the error the chain reports for it.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CoreumError {
+    #[error("Unauthorized")]
+    Unauthorized {},
+
+    #[error("Token not found")]
+    TokenNotFound {},
+
+    #[error("Feature disabled")]
+    FeatureDisabled {},
+
+    #[error("Token is globally frozen")]
+    GloballyFrozen {},
+
+    #[error("Not enough frozen balance")]
+    NotEnoughFrozen {},
+
+    #[error("Insufficient funds")]
+    InsufficientFunds {},
+
+    #[error("Whitelisted limit exceeded")]
+    WhitelistExceeded {},
+
+    #[error("Invalid denom")]
+    InvalidDenom {},
+
+    #[error("Invalid input")]
+    InvalidInput {},
+
+    #[error("{error}")]
+    Other { error: String },
+}
+
+impl CoreumError {
+    /// Maps an error of the chain to its cause. Transactions report the full error, where the
+    /// registered error comes last ("denom is globally frozen: token is globally frozen"), while
+    /// wasmd redacts the errors of submessages passed to reply to "codespace: assetft, code: 6".
+    /// The redacted form can't tell NotEnoughFrozen from InsufficientFunds.
+    pub fn from_chain_error(error: &str) -> Self {
+        Self::from_message(error)
+            .or_else(|| Self::from_redacted(error))
+            .unwrap_or_else(|| CoreumError::Other {
+                error: error.to_string(),
+            })
+    }
+
+    fn from_message(error: &str) -> Option<Self> {
+        // the wrapped message of an unfreeze above the frozen balance is the only way to tell it
+        // apart from the other insufficient funds errors
+        if error.contains("is greater than the available frozen balance") {
+            return Some(CoreumError::NotEnoughFrozen {});
+        }
+
+        // errors wrapped by wasmd or the contract are appended after the cause, so the segments
+        // are searched from the innermost one
+        error.rsplit(": ").find_map(|segment| {
+            // some errors carry the location they were raised at in brackets
+            let cause = segment.split(" [").next().unwrap_or(segment).trim();
+            match cause {
+                "unauthorized" => Some(CoreumError::Unauthorized {}),
+                "token not found" => Some(CoreumError::TokenNotFound {}),
+                "feature disabled" => Some(CoreumError::FeatureDisabled {}),
+                "token is globally frozen" => Some(CoreumError::GloballyFrozen {}),
+                "insufficient funds" => Some(CoreumError::InsufficientFunds {}),
+                "whitelisted limit exceeded" => Some(CoreumError::WhitelistExceeded {}),
+                "invalid denom" => Some(CoreumError::InvalidDenom {}),
+                "invalid input" => Some(CoreumError::InvalidInput {}),
+                _ => None,
+            }
+        })
+    }
+
+    fn from_redacted(error: &str) -> Option<Self> {
+        let (_, redacted) = error.split_once("codespace: ")?;
+        let (codespace, code) = redacted.split_once(", code: ")?;
+        let code: u32 = code
+            .split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok()?;
+
+        // codes of x/asset/ft/types/errors.go and the cosmos-sdk types/errors
+        match (codespace, code) {
+            (CODESPACE_ASSETFT, 1) => Some(CoreumError::InvalidInput {}),
+            (CODESPACE_ASSETFT, 2) => Some(CoreumError::TokenNotFound {}),
+            (CODESPACE_ASSETFT, 4) => Some(CoreumError::FeatureDisabled {}),
+            (CODESPACE_ASSETFT, 5) => Some(CoreumError::InvalidDenom {}),
+            (CODESPACE_ASSETFT, 6) => Some(CoreumError::GloballyFrozen {}),
+            (CODESPACE_ASSETFT, 7) => Some(CoreumError::WhitelistExceeded {}),
+            (CODESPACE_SDK, 4) => Some(CoreumError::Unauthorized {}),
+            (CODESPACE_SDK, 5) => Some(CoreumError::InsufficientFunds {}),
+            _ => None,
+        }
+    }
+}
@@ -1,27 +1,63 @@
+use common::any_msg::AnyMsg;
+use common::protos::AssetFTTx;
+#[cfg(feature = "debug")]
+use common::raw_range::raw_range;
+use common::split::split_amount;
 use coreum_wasm_sdk::assetft::{
-    self, BalanceResponse, FrozenBalanceResponse, FrozenBalancesResponse, ParamsResponse, Query,
-    TokenResponse, TokensResponse, WhitelistedBalanceResponse, WhitelistedBalancesResponse,
+    self, BalanceResponse, FrozenBalanceResponse, FrozenBalancesResponse, Query, TokenResponse,
+    TokensResponse, WhitelistedBalanceResponse, WhitelistedBalancesResponse,
 };
-use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries, CoreumResult};
+use coreum_wasm_sdk::core::{CoreumQueries, CoreumResult};
 use coreum_wasm_sdk::pagination::PageRequest;
-use cosmwasm_std::{coin, entry_point, to_binary, Binary, Deps, QueryRequest, StdResult};
-use cosmwasm_std::{Coin, DepsMut, Env, MessageInfo, Response, SubMsg};
+use cosmwasm_std::{
+    coin, entry_point, to_json_binary, Binary, Deps, QueryRequest, Reply, StdResult,
+};
+use cosmwasm_std::{
+    Coin, Decimal, DepsMut, Env, MessageInfo, Response, SubMsg, SubMsgResult, Uint128,
+};
 use cw2::set_contract_version;
 use cw_ownable::{assert_owner, initialize_owner};
+use protobuf::MessageField;
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+use AssetFTTx::MsgSetFrozen;
 
+use crate::balances::query_account_balances;
+use crate::denom::{build_denom, parse_denom};
+use crate::encoding::asset_msg;
 use crate::error::ContractError;
-use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
-use crate::state::DENOM;
+use crate::events::{find_event, EventIssued, EVENT_ISSUED};
+use crate::helpers::{ensure_issue_fee, ensure_receivable, ensure_transferable};
+use crate::issue::IssueBuilder;
+use crate::msg::{
+    AccountBalancesResponse, AssetFTParamsResponse, ExecuteMsg, InstantiateMsg, InstantiateMsgV2,
+    IssueResult, QueryMsg, SimulateSendResponse, SplitRecipient,
+};
+use crate::rates::{
+    compute_burn_amount, compute_commission, net_receive_amount, total_spent_amount,
+};
+use crate::state::{IssueCommit, DENOM, ENCODING, ISSUE_COMMITS};
+use common::guard::ReentrancyGuard;
 
 // version info for migration info
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const ISSUE_REPLY_ID: u64 = 1;
+const REVEAL_ISSUE_REPLY_ID: u64 = 2;
+
+// held between dispatching an issue and its reply
+const GUARD: ReentrancyGuard = ReentrancyGuard::new("reentrancy_guard");
+
+// blocks that must pass between CommitIssue and RevealIssue, the commit expires after the maximum
+const MIN_REVEAL_DELAY: u64 = 1;
+const MAX_REVEAL_DELAY: u64 = 100;
+
 // ********** Instantiate **********
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
-    deps: DepsMut,
+    deps: DepsMut<CoreumQueries>,
     env: Env,
     info: MessageInfo,
     msg: InstantiateMsg,
@@ -29,59 +65,99 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     initialize_owner(deps.storage, deps.api, Some(info.sender.as_ref()))?;
 
-    let issue_msg = CoreumMsg::AssetFT(assetft::Msg::Issue {
-        symbol: msg.symbol,
-        subunit: msg.subunit.clone(),
-        precision: msg.precision,
-        initial_amount: msg.initial_amount,
-        description: msg.description,
-        features: msg.features,
-        burn_rate: msg.burn_rate,
-        send_commission_rate: msg.send_commission_rate,
-    });
+    let msg = InstantiateMsgV2::try_from(msg)?;
+    ENCODING.save(deps.storage, &msg.encoding)?;
+    let initial_amount = msg.initial_amount;
+    let mut issue = IssueBuilder::new(msg.symbol, msg.subunit.clone(), msg.precision)
+        .initial_amount(initial_amount)
+        .features(msg.features);
+    if let Some(description) = msg.description {
+        issue = issue.description(description);
+    }
+    if let Some(burn_rate) = msg.burn_rate {
+        issue = issue.burn_rate(Decimal::from_str(&burn_rate)?);
+    }
+    if let Some(send_commission_rate) = msg.send_commission_rate {
+        issue = issue.send_commission_rate(Decimal::from_str(&send_commission_rate)?);
+    }
+    let issue_msg = asset_msg(deps.storage, &env, issue.build()?)?;
+    // the funds sent with the instantiation are already the contract's balance
+    ensure_issue_fee(deps.as_ref(), &env)?;
 
-    let denom = format!("{}-{}", msg.subunit, env.contract.address).to_lowercase();
+    let denom = build_denom(&msg.subunit, &env.contract.address);
 
     DENOM.save(deps.storage, &denom)?;
+    GUARD.enter(deps.storage, ISSUE_REPLY_ID)?;
 
     Ok(Response::new()
         .add_attribute("owner", info.sender)
-        .add_attribute("denom", denom)
-        .add_message(issue_msg))
+        .add_attribute("denom", denom.clone())
+        .add_submessage(SubMsg::reply_on_success(issue_msg, ISSUE_REPLY_ID))
+        .set_data(to_json_binary(&IssueResult {
+            denom,
+            amount_minted: initial_amount,
+        })?))
 }
 
 // ********** Execute **********
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
-    _env: Env,
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> CoreumResult<ContractError> {
+    GUARD.ensure_idle(deps.storage)?;
+
     match msg {
-        ExecuteMsg::Mint { amount } => mint(deps, info, amount),
-        ExecuteMsg::Burn { amount } => burn(deps, info, amount),
-        ExecuteMsg::Freeze { account, amount } => freeze(deps, info, account, amount),
-        ExecuteMsg::Unfreeze { account, amount } => unfreeze(deps, info, account, amount),
-        ExecuteMsg::GloballyFreeze {} => globally_freeze(deps, info),
-        ExecuteMsg::GloballyUnfreeze {} => globally_unfreeze(deps, info),
+        ExecuteMsg::Mint { amount } => mint(deps, env, info, amount),
+        ExecuteMsg::Burn { amount, denom } => burn(deps, env, info, amount, denom),
+        ExecuteMsg::Freeze { account, amount } => freeze(deps, env, info, account, amount),
+        ExecuteMsg::Unfreeze { account, amount } => unfreeze(deps, env, info, account, amount),
+        ExecuteMsg::SetFrozen { account, amount } => set_frozen(deps, env, info, account, amount),
+        ExecuteMsg::GloballyFreeze {} => globally_freeze(deps, env, info),
+        ExecuteMsg::GloballyUnfreeze {} => globally_unfreeze(deps, env, info),
         ExecuteMsg::SetWhitelistedLimit { account, amount } => {
-            set_whitelisted_limit(deps, info, account, amount)
+            set_whitelisted_limit(deps, env, info, account, amount)
+        }
+        ExecuteMsg::MintAndSend { account, amount } => {
+            mint_and_send(deps, env, info, account, amount)
+        }
+        ExecuteMsg::SendTo { account, amount } => send_to(deps, env, info, account, amount),
+        ExecuteMsg::UpgradeTokenV1 { ibc_enabled } => {
+            upgrate_token_v1(deps, env, info, ibc_enabled)
+        }
+        ExecuteMsg::CommitIssue { hash } => commit_issue(deps, env, info, hash),
+        ExecuteMsg::RevealIssue {
+            symbol,
+            subunit,
+            precision,
+            salt,
+        } => reveal_issue(deps, env, info, symbol, subunit, precision, salt),
+        ExecuteMsg::SplitSend { amount, recipients } => {
+            split_send(deps, env, info, amount, recipients)
         }
-        ExecuteMsg::MintAndSend { account, amount } => mint_and_send(deps, info, account, amount),
-        ExecuteMsg::UpgradeTokenV1 { ibc_enabled } => upgrate_token_v1(deps, info, ibc_enabled),
     }
 }
 
 // ********** Transactions **********
 
-fn mint(deps: DepsMut, info: MessageInfo, amount: u128) -> CoreumResult<ContractError> {
+fn mint(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    amount: u128,
+) -> CoreumResult<ContractError> {
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
-    let msg = CoreumMsg::AssetFT(assetft::Msg::Mint {
-        coin: coin(amount, denom.clone()),
-    });
+    let msg = asset_msg(
+        deps.storage,
+        &env,
+        assetft::Msg::Mint {
+            coin: coin(amount, denom.clone()),
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "mint")
@@ -90,13 +166,30 @@ fn mint(deps: DepsMut, info: MessageInfo, amount: u128) -> CoreumResult<Contract
         .add_message(msg))
 }
 
-fn burn(deps: DepsMut, info: MessageInfo, amount: u128) -> CoreumResult<ContractError> {
+fn burn(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    amount: u128,
+    denom: Option<String>,
+) -> CoreumResult<ContractError> {
     assert_owner(deps.storage, &info.sender)?;
-    let denom = DENOM.load(deps.storage)?;
+    let denom = match denom {
+        Some(denom) => denom,
+        None => DENOM.load(deps.storage)?,
+    };
+    let (_, issuer) = parse_denom(&denom)?;
+    if issuer != env.contract.address {
+        return Err(ContractError::NotIssuedByContract { denom });
+    }
 
-    let msg = CoreumMsg::AssetFT(assetft::Msg::Burn {
-        coin: coin(amount, denom.clone()),
-    });
+    let msg = asset_msg(
+        deps.storage,
+        &env,
+        assetft::Msg::Burn {
+            coin: coin(amount, denom.clone()),
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "burn")
@@ -106,7 +199,8 @@ fn burn(deps: DepsMut, info: MessageInfo, amount: u128) -> CoreumResult<Contract
 }
 
 fn freeze(
-    deps: DepsMut,
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
     info: MessageInfo,
     account: String,
     amount: u128,
@@ -114,10 +208,14 @@ fn freeze(
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
 
-    let msg = CoreumMsg::AssetFT(assetft::Msg::Freeze {
-        account,
-        coin: coin(amount, denom.clone()),
-    });
+    let msg = asset_msg(
+        deps.storage,
+        &env,
+        assetft::Msg::Freeze {
+            account,
+            coin: coin(amount, denom.clone()),
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "freeze")
@@ -126,8 +224,37 @@ fn freeze(
         .add_message(msg))
 }
 
+// MsgSetFrozen is not part of the custom message bindings so it is sent as a stargate message
+// with the contract as the sender.
+fn set_frozen(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+    amount: u128,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let denom = DENOM.load(deps.storage)?;
+
+    let mut frozen_coin = AssetFTTx::Coin::new();
+    frozen_coin.denom = denom.clone();
+    frozen_coin.amount = amount.to_string();
+    let mut set_frozen = MsgSetFrozen::new();
+    set_frozen.sender = env.contract.address.to_string();
+    set_frozen.account = account.clone();
+    set_frozen.coin = MessageField::some(frozen_coin);
+
+    Ok(Response::new()
+        .add_attribute("method", "set_frozen")
+        .add_attribute("denom", denom)
+        .add_attribute("account", account)
+        .add_attribute("amount", amount.to_string())
+        .add_message(set_frozen.to_stargate()?))
+}
+
 fn unfreeze(
-    deps: DepsMut,
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
     info: MessageInfo,
     account: String,
     amount: u128,
@@ -135,10 +262,14 @@ fn unfreeze(
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
 
-    let msg = CoreumMsg::AssetFT(assetft::Msg::Unfreeze {
-        account,
-        coin: coin(amount, denom.clone()),
-    });
+    let msg = asset_msg(
+        deps.storage,
+        &env,
+        assetft::Msg::Unfreeze {
+            account,
+            coin: coin(amount, denom.clone()),
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "unfreeze")
@@ -147,13 +278,21 @@ fn unfreeze(
         .add_message(msg))
 }
 
-fn globally_freeze(deps: DepsMut, info: MessageInfo) -> CoreumResult<ContractError> {
+fn globally_freeze(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+) -> CoreumResult<ContractError> {
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
 
-    let msg = CoreumMsg::AssetFT(assetft::Msg::GloballyFreeze {
-        denom: denom.clone(),
-    });
+    let msg = asset_msg(
+        deps.storage,
+        &env,
+        assetft::Msg::GloballyFreeze {
+            denom: denom.clone(),
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "globally_freeze")
@@ -161,13 +300,21 @@ fn globally_freeze(deps: DepsMut, info: MessageInfo) -> CoreumResult<ContractErr
         .add_message(msg))
 }
 
-fn globally_unfreeze(deps: DepsMut, info: MessageInfo) -> CoreumResult<ContractError> {
+fn globally_unfreeze(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+) -> CoreumResult<ContractError> {
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
 
-    let msg = CoreumMsg::AssetFT(assetft::Msg::GloballyUnfreeze {
-        denom: denom.clone(),
-    });
+    let msg = asset_msg(
+        deps.storage,
+        &env,
+        assetft::Msg::GloballyUnfreeze {
+            denom: denom.clone(),
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "globally_unfreeze")
@@ -176,7 +323,8 @@ fn globally_unfreeze(deps: DepsMut, info: MessageInfo) -> CoreumResult<ContractE
 }
 
 fn set_whitelisted_limit(
-    deps: DepsMut,
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
     info: MessageInfo,
     account: String,
     amount: u128,
@@ -184,10 +332,14 @@ fn set_whitelisted_limit(
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
 
-    let msg = CoreumMsg::AssetFT(assetft::Msg::SetWhitelistedLimit {
-        account,
-        coin: coin(amount, denom.clone()),
-    });
+    let msg = asset_msg(
+        deps.storage,
+        &env,
+        assetft::Msg::SetWhitelistedLimit {
+            account,
+            coin: coin(amount, denom.clone()),
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "set_whitelisted_limit")
@@ -197,7 +349,8 @@ fn set_whitelisted_limit(
 }
 
 fn mint_and_send(
-    deps: DepsMut,
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
     info: MessageInfo,
     account: String,
     amount: u128,
@@ -205,9 +358,16 @@ fn mint_and_send(
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
 
-    let mint_msg = SubMsg::new(CoreumMsg::AssetFT(assetft::Msg::Mint {
-        coin: coin(amount, denom.clone()),
-    }));
+    // the contract balance only exists once the mint executed, so only the recipient is checked
+    ensure_receivable(deps.as_ref(), &account, &denom, amount.into())?;
+
+    let mint_msg = SubMsg::new(asset_msg(
+        deps.storage,
+        &env,
+        assetft::Msg::Mint {
+            coin: coin(amount, denom.clone()),
+        },
+    )?);
 
     let send_msg = SubMsg::new(cosmwasm_std::BankMsg::Send {
         to_address: account,
@@ -219,23 +379,167 @@ fn mint_and_send(
 
     Ok(Response::new()
         .add_attribute("method", "mint_and_send")
+        .add_attribute("denom", denom.clone())
+        .add_attribute("amount", amount.to_string())
+        .add_submessages([mint_msg, send_msg])
+        .set_data(to_json_binary(&IssueResult {
+            denom,
+            amount_minted: amount.into(),
+        })?))
+}
+
+fn send_to(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+    amount: u128,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let denom = DENOM.load(deps.storage)?;
+
+    ensure_transferable(
+        deps.as_ref(),
+        env.contract.address.as_str(),
+        &account,
+        &denom,
+        amount.into(),
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "send_to")
+        .add_attribute("denom", denom.clone())
+        .add_attribute("account", account.clone())
+        .add_attribute("amount", amount.to_string())
+        .add_message(cosmwasm_std::BankMsg::Send {
+            to_address: account,
+            amount: vec![coin(amount, denom)],
+        }))
+}
+
+fn commit_issue(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    hash: Binary,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    // the same hash may only be committed again once the previous commit expired
+    if let Some(commit) = ISSUE_COMMITS.may_load(deps.storage, hash.as_slice())? {
+        let expires_at = commit.height + MAX_REVEAL_DELAY;
+        if env.block.height <= expires_at {
+            return Err(ContractError::CommitActive { expires_at });
+        }
+    }
+
+    ISSUE_COMMITS.save(
+        deps.storage,
+        hash.as_slice(),
+        &IssueCommit {
+            committer: info.sender.clone(),
+            height: env.block.height,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "commit_issue")
+        .add_attribute("committer", info.sender)
+        .add_attribute("hash", hash.to_base64()))
+}
+
+fn reveal_issue(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    symbol: String,
+    subunit: String,
+    precision: u32,
+    salt: Binary,
+) -> CoreumResult<ContractError> {
+    // the issue fee is paid from the contract balance, so only the owner may spend it
+    assert_owner(deps.storage, &info.sender)?;
+    let hash = Sha256::new()
+        .chain_update(symbol.as_bytes())
+        .chain_update(salt.as_slice())
+        .chain_update(info.sender.as_bytes())
+        .finalize();
+
+    let commit = ISSUE_COMMITS
+        .may_load(deps.storage, &hash)?
+        .filter(|commit| commit.committer == info.sender)
+        .ok_or(ContractError::CommitNotFound {})?;
+    let allowed_at = commit.height + MIN_REVEAL_DELAY;
+    if env.block.height < allowed_at {
+        return Err(ContractError::RevealTooEarly { allowed_at });
+    }
+    let expired_at = commit.height + MAX_REVEAL_DELAY;
+    if env.block.height > expired_at {
+        return Err(ContractError::CommitExpired { expired_at });
+    }
+    let denom = build_denom(&subunit, &env.contract.address);
+    let issue = IssueBuilder::new(symbol.clone(), subunit, precision).build()?;
+    let issue_msg = asset_msg(deps.storage, &env, issue)?;
+    ensure_issue_fee(deps.as_ref(), &env)?;
+    ISSUE_COMMITS.remove(deps.storage, &hash);
+    GUARD.enter(deps.storage, REVEAL_ISSUE_REPLY_ID)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "reveal_issue")
+        .add_attribute("symbol", symbol)
+        .add_attribute("denom", denom)
+        .add_submessage(SubMsg::reply_on_success(issue_msg, REVEAL_ISSUE_REPLY_ID)))
+}
+
+fn split_send(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    info: MessageInfo,
+    amount: u128,
+    recipients: Vec<SplitRecipient>,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let denom = DENOM.load(deps.storage)?;
+
+    let shares = split_amount(deps.api, amount.into(), &recipients)?;
+    let mut send_msgs = Vec::with_capacity(shares.len());
+    for (address, share) in shares {
+        ensure_transferable(
+            deps.as_ref(),
+            env.contract.address.as_str(),
+            address.as_str(),
+            &denom,
+            share,
+        )?;
+        send_msgs.push(SubMsg::new(cosmwasm_std::BankMsg::Send {
+            to_address: address.to_string(),
+            amount: vec![coin(share.u128(), denom.clone())],
+        }));
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "split_send")
         .add_attribute("denom", denom)
         .add_attribute("amount", amount.to_string())
-        .add_submessages([mint_msg, send_msg]))
+        .add_submessages(send_msgs))
 }
 
 fn upgrate_token_v1(
-    deps: DepsMut,
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
     info: MessageInfo,
     ibc_enabled: bool,
 ) -> CoreumResult<ContractError> {
     assert_owner(deps.storage, &info.sender)?;
     let denom = DENOM.load(deps.storage)?;
 
-    let upgrade_msg = CoreumMsg::AssetFT(assetft::Msg::UpgradeTokenV1 {
-        denom: denom.clone(),
-        ibc_enabled: ibc_enabled.clone(),
-    });
+    let upgrade_msg = asset_msg(
+        deps.storage,
+        &env,
+        assetft::Msg::UpgradeTokenV1 {
+            denom: denom.clone(),
+            ibc_enabled,
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("method", "upgrade_token_v1")
@@ -244,26 +548,77 @@ fn upgrate_token_v1(
         .add_message(upgrade_msg))
 }
 
+// ********** Reply **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> CoreumResult<ContractError> {
+    GUARD.exit(deps.storage)?;
+
+    match msg.id {
+        ISSUE_REPLY_ID => reply_issue(deps, msg.result),
+        REVEAL_ISSUE_REPLY_ID => reply_reveal_issue(msg.result),
+        id => Err(ContractError::UnknownReplyId { id }),
+    }
+}
+
+fn reply_issue(deps: DepsMut, result: SubMsgResult) -> CoreumResult<ContractError> {
+    // reply_on_success only calls back for successful submessages
+    let events = result.unwrap().events;
+    let issued = EventIssued::try_from(find_event(&events, EVENT_ISSUED)?)?;
+
+    // the denom reported by the chain is the source of truth
+    DENOM.save(deps.storage, &issued.denom)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "reply_issue")
+        .add_attribute("denom", issued.denom)
+        .add_attribute("issuer", issued.issuer))
+}
+
+// tokens issued by a reveal are not the one managed by the contract, so only report them
+fn reply_reveal_issue(result: SubMsgResult) -> CoreumResult<ContractError> {
+    let events = result.unwrap().events;
+    let issued = EventIssued::try_from(find_event(&events, EVENT_ISSUED)?)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "reply_reveal_issue")
+        .add_attribute("denom", issued.denom)
+        .add_attribute("symbol", issued.symbol))
+}
+
 // ********** Queries **********
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps<CoreumQueries>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::Params {} => to_binary(&query_params(deps)?),
-        QueryMsg::Token {} => to_binary(&query_token(deps)?),
-        QueryMsg::Tokens { issuer } => to_binary(&query_tokens(deps, issuer)?),
-        QueryMsg::FrozenBalance { account } => to_binary(&query_frozen_balance(deps, account)?),
+        QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
+        QueryMsg::Token {} => to_json_binary(&query_token(deps)?),
+        QueryMsg::Tokens { issuer } => to_json_binary(&query_tokens(deps, issuer)?),
+        QueryMsg::FrozenBalance { account } => {
+            to_json_binary(&query_frozen_balance(deps, account)?)
+        }
         QueryMsg::WhitelistedBalance { account } => {
-            to_binary(&query_whitelisted_balance(deps, account)?)
+            to_json_binary(&query_whitelisted_balance(deps, account)?)
+        }
+        QueryMsg::Balance { account } => to_json_binary(&query_balance(deps, account)?),
+        QueryMsg::FrozenBalances { account } => {
+            to_json_binary(&query_frozen_balances(deps, account)?)
         }
-        QueryMsg::Balance { account } => to_binary(&query_balance(deps, account)?),
-        QueryMsg::FrozenBalances { account } => to_binary(&query_frozen_balances(deps, account)?),
         QueryMsg::WhitelistedBalances { account } => {
-            to_binary(&query_whitelisted_balances(deps, account)?)
+            to_json_binary(&query_whitelisted_balances(deps, account)?)
+        }
+        QueryMsg::Balances { account } => to_json_binary(&query_balances(deps, account)?),
+        QueryMsg::SimulateSend { amount } => to_json_binary(&query_simulate_send(deps, amount)?),
+        QueryMsg::NetReceiveAmount { spendable } => {
+            to_json_binary(&query_net_receive_amount(deps, spendable)?)
+        }
+        #[cfg(feature = "debug")]
+        QueryMsg::RawRange { start, end, limit } => {
+            to_json_binary(&raw_range(deps.storage, start, end, limit)?)
         }
     }
 }
 
-fn query_params(deps: Deps<CoreumQueries>) -> StdResult<ParamsResponse> {
+fn query_params(deps: Deps<CoreumQueries>) -> StdResult<AssetFTParamsResponse> {
     let request = CoreumQueries::AssetFT(Query::Params {}).into();
     let res = deps.querier.query(&request)?;
     Ok(res)
@@ -403,3 +758,633 @@ fn query_whitelisted_balances(
     };
     Ok(res)
 }
+
+fn query_balances(
+    deps: Deps<CoreumQueries>,
+    account: String,
+) -> StdResult<AccountBalancesResponse> {
+    let mut pagination = None;
+    let mut balances = vec![];
+    let mut res: AccountBalancesResponse;
+    loop {
+        res = query_account_balances(deps, account.clone(), pagination)?;
+        balances.append(&mut res.balances);
+        if res.pagination.next_key.is_none() {
+            break;
+        } else {
+            pagination = Some(PageRequest {
+                key: res.pagination.next_key,
+                offset: None,
+                limit: None,
+                count_total: None,
+                reverse: None,
+            })
+        }
+    }
+    let res = AccountBalancesResponse {
+        pagination: res.pagination,
+        balances,
+    };
+    Ok(res)
+}
+
+fn query_simulate_send(
+    deps: Deps<CoreumQueries>,
+    amount: Uint128,
+) -> StdResult<SimulateSendResponse> {
+    let (burn_rate, send_commission_rate) = token_rates(deps)?;
+    Ok(SimulateSendResponse {
+        burn_amount: compute_burn_amount(amount, burn_rate),
+        commission_amount: compute_commission(amount, send_commission_rate),
+        total_spent: total_spent_amount(amount, burn_rate, send_commission_rate),
+    })
+}
+
+fn query_net_receive_amount(deps: Deps<CoreumQueries>, spendable: Uint128) -> StdResult<Uint128> {
+    let (burn_rate, send_commission_rate) = token_rates(deps)?;
+    Ok(net_receive_amount(
+        spendable,
+        burn_rate,
+        send_commission_rate,
+    ))
+}
+
+fn token_rates(deps: Deps<CoreumQueries>) -> StdResult<(Decimal, Decimal)> {
+    let token = query_token(deps)?.token;
+    Ok((
+        Decimal::from_str(&token.burn_rate)?,
+        Decimal::from_str(&token.send_commission_rate)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::exhaustive_cases;
+    use common::test_exhaustive::{run_cases, Outcome};
+    use coreum_wasm_sdk::assetft::Token;
+    use coreum_wasm_sdk::core::CoreumMsg;
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockStorage};
+    use cosmwasm_std::{
+        attr, from_json, Addr, CosmosMsg, Event, OwnedDeps, QuerierWrapper, SubMsgResponse,
+    };
+
+    use super::*;
+    use crate::feature::TokenFeature;
+    use crate::mock::{mock_dependencies, CoreumMockQuerier, CoreumQuerier};
+
+    const OWNER: &str = "owner";
+    const CONTRACT: &str = "core1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqdcyqx";
+    const SYMBOL: &str = "ABC";
+    const SALT: &[u8] = b"salt";
+
+    type TestDeps = OwnedDeps<MockStorage, MockApi, CoreumQuerier, CoreumQueries>;
+
+    fn setup() -> TestDeps {
+        let mut deps = mock_dependencies(CoreumMockQuerier::new());
+        initialize_owner(&mut deps.storage, &deps.api, Some(OWNER)).unwrap();
+        deps
+    }
+
+    fn exec_at(
+        deps: &mut TestDeps,
+        sender: &str,
+        height: u64,
+        msg: ExecuteMsg,
+    ) -> CoreumResult<ContractError> {
+        let mut env = mock_env();
+        env.block.height = height;
+        execute(deps.as_mut(), env, mock_info(sender, &[]), msg)
+    }
+
+    fn commit_hash(sender: &str) -> Binary {
+        Binary::from(
+            Sha256::new()
+                .chain_update(SYMBOL.as_bytes())
+                .chain_update(SALT)
+                .chain_update(sender.as_bytes())
+                .finalize()
+                .to_vec(),
+        )
+    }
+
+    fn reveal() -> ExecuteMsg {
+        ExecuteMsg::RevealIssue {
+            symbol: SYMBOL.to_string(),
+            subunit: "uabc".to_string(),
+            precision: 6,
+            salt: Binary::from(SALT),
+        }
+    }
+
+    #[test]
+    fn v1_payload_issues_with_the_defaults() {
+        let mut deps = mock_dependencies(CoreumMockQuerier::new());
+        deps.querier.update_balance(
+            mock_env().contract.address,
+            vec![coin(10_000_000, "udevcore")],
+        );
+        let msg: InstantiateMsg =
+            from_json(include_bytes!("../testdata/instantiate_msg_v1.json")).unwrap();
+        let res = instantiate(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg).unwrap();
+
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(res.messages[0].id, ISSUE_REPLY_ID);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Custom(CoreumMsg::AssetFT(assetft::Msg::Issue {
+                symbol: SYMBOL.to_string(),
+                subunit: "uabc".to_string(),
+                precision: 6,
+                initial_amount: Uint128::new(1000),
+                description: None,
+                features: Some(vec![]),
+                burn_rate: Some("0".to_string()),
+                send_commission_rate: Some("0".to_string()),
+            }))
+        );
+    }
+
+    #[test]
+    fn only_the_owner_commits_and_reveals() {
+        let mut deps = setup();
+        let err = exec_at(
+            &mut deps,
+            "stranger",
+            100,
+            ExecuteMsg::CommitIssue {
+                hash: commit_hash("stranger"),
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ContractError::Ownership(_)));
+
+        // a stranger can't spend the contract's issue fee on the owner's commit either
+        exec_at(
+            &mut deps,
+            OWNER,
+            100,
+            ExecuteMsg::CommitIssue {
+                hash: commit_hash(OWNER),
+            },
+        )
+        .unwrap();
+        deps.querier.update_balance(
+            mock_env().contract.address,
+            vec![coin(10_000_000, "udevcore")],
+        );
+        let err = exec_at(&mut deps, "stranger", 101, reveal()).unwrap_err();
+        assert!(matches!(err, ContractError::Ownership(_)));
+
+        let res = exec_at(&mut deps, OWNER, 101, reveal()).unwrap();
+        assert_eq!(res.messages[0].id, REVEAL_ISSUE_REPLY_ID);
+    }
+
+    #[test]
+    fn reveal_needs_the_issue_fee() {
+        let mut deps = setup();
+        exec_at(
+            &mut deps,
+            OWNER,
+            100,
+            ExecuteMsg::CommitIssue {
+                hash: commit_hash(OWNER),
+            },
+        )
+        .unwrap();
+        deps.querier.update_balance(
+            mock_env().contract.address,
+            vec![coin(9_999_999, "udevcore")],
+        );
+        let err = exec_at(&mut deps, OWNER, 101, reveal()).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InsufficientIssueFee { available, .. } if available.u128() == 9_999_999
+        ));
+    }
+
+    fn commit(deps: &mut TestDeps, height: u64) -> CoreumResult<ContractError> {
+        exec_at(
+            deps,
+            OWNER,
+            height,
+            ExecuteMsg::CommitIssue {
+                hash: commit_hash(OWNER),
+            },
+        )
+    }
+
+    // the contract holds the issue fee and has a commit of the owner at height 100
+    fn committed() -> TestDeps {
+        let mut deps = setup();
+        deps.querier.update_balance(
+            mock_env().contract.address,
+            vec![coin(10_000_000, "udevcore")],
+        );
+        commit(&mut deps, 100).unwrap();
+        deps
+    }
+
+    #[test]
+    fn reveal_with_a_wrong_salt_finds_no_commit() {
+        let mut deps = committed();
+        let wrong_salt = ExecuteMsg::RevealIssue {
+            symbol: SYMBOL.to_string(),
+            subunit: "uabc".to_string(),
+            precision: 6,
+            salt: Binary::from(b"pepper"),
+        };
+        assert!(matches!(
+            exec_at(&mut deps, OWNER, 101, wrong_salt).unwrap_err(),
+            ContractError::CommitNotFound {}
+        ));
+    }
+
+    #[test]
+    fn reveal_is_allowed_between_the_delays() {
+        let mut deps = committed();
+        assert!(matches!(
+            exec_at(&mut deps, OWNER, 100, reveal()).unwrap_err(),
+            ContractError::RevealTooEarly { allowed_at: 101 }
+        ));
+        assert!(matches!(
+            exec_at(&mut deps, OWNER, 201, reveal()).unwrap_err(),
+            ContractError::CommitExpired { expired_at: 200 }
+        ));
+        // the last block before the commit expires still reveals
+        let res = exec_at(&mut deps, OWNER, 200, reveal()).unwrap();
+        assert_eq!(res.messages[0].id, REVEAL_ISSUE_REPLY_ID);
+    }
+
+    #[test]
+    fn active_commit_is_only_replaced_once_expired() {
+        let mut deps = committed();
+        for height in [101, 200] {
+            assert!(matches!(
+                commit(&mut deps, height).unwrap_err(),
+                ContractError::CommitActive { expires_at: 200 }
+            ));
+        }
+
+        // the new commit starts the delays over
+        commit(&mut deps, 201).unwrap();
+        assert!(matches!(
+            exec_at(&mut deps, OWNER, 201, reveal()).unwrap_err(),
+            ContractError::RevealTooEarly { allowed_at: 202 }
+        ));
+        exec_at(&mut deps, OWNER, 202, reveal()).unwrap();
+    }
+
+    fn issued_event(denom: &str) -> Event {
+        Event::new(EVENT_ISSUED)
+            .add_attribute("denom", format!("\"{denom}\""))
+            .add_attribute("issuer", format!("\"{}\"", mock_env().contract.address))
+            .add_attribute("symbol", format!("\"{SYMBOL}\""))
+            .add_attribute("subunit", "\"uabc\"")
+            .add_attribute("precision", "6")
+            .add_attribute("initial_amount", "\"0\"")
+            .add_attribute("description", "\"\"")
+            .add_attribute("features", "[]")
+            .add_attribute("burn_rate", "\"0\"")
+            .add_attribute("send_commission_rate", "\"0\"")
+    }
+
+    #[test]
+    fn commit_and_reveal_issue_the_token() {
+        let mut deps = committed();
+        let res = exec_at(&mut deps, OWNER, 150, reveal()).unwrap();
+        let denom = build_denom("uabc", &mock_env().contract.address);
+        assert_eq!(res.messages.len(), 1);
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Custom(CoreumMsg::AssetFT(assetft::Msg::Issue {
+                symbol: SYMBOL.to_string(),
+                subunit: "uabc".to_string(),
+                precision: 6,
+                initial_amount: Uint128::zero(),
+                description: None,
+                features: Some(vec![]),
+                burn_rate: Some("0".to_string()),
+                send_commission_rate: Some("0".to_string()),
+            }))
+        );
+        // nothing else runs until the issue replied
+        assert!(matches!(
+            commit(&mut deps, 150).unwrap_err(),
+            ContractError::Guard(_)
+        ));
+
+        let reply_deps = DepsMut {
+            storage: &mut deps.storage,
+            api: &deps.api,
+            querier: QuerierWrapper::new(&deps.querier),
+        };
+        let res = reply(
+            reply_deps,
+            mock_env(),
+            Reply {
+                id: REVEAL_ISSUE_REPLY_ID,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: vec![issued_event(&denom)],
+                    data: None,
+                }),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            res.attributes,
+            vec![
+                attr("method", "reply_reveal_issue"),
+                attr("denom", denom),
+                attr("symbol", SYMBOL),
+            ]
+        );
+
+        // the revealed commit is consumed and the symbol may be committed again
+        assert!(matches!(
+            exec_at(&mut deps, OWNER, 151, reveal()).unwrap_err(),
+            ContractError::CommitNotFound {}
+        ));
+        commit(&mut deps, 151).unwrap();
+    }
+
+    fn burn_with_denom(denom: &str) -> CoreumResult<ContractError> {
+        let mut deps = setup();
+        DENOM.save(&mut deps.storage, &denom.to_string()).unwrap();
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked(CONTRACT);
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info(OWNER, &[]),
+            ExecuteMsg::Burn {
+                amount: 100,
+                denom: None,
+            },
+        )
+    }
+
+    #[test]
+    fn burn_only_accepts_the_contracts_own_token() {
+        let res = burn_with_denom(&build_denom("abc", &Addr::unchecked(CONTRACT))).unwrap();
+        assert_eq!(res.messages.len(), 1);
+
+        let foreign = "abc-core1qyqszqgpqyqszqgpqyqszqgpqyqszqgppae928";
+        assert!(matches!(
+            burn_with_denom(foreign).unwrap_err(),
+            ContractError::NotIssuedByContract { denom } if denom == foreign
+        ));
+        for denom in ["ucore", "abc", "abc-cosmos2contract"] {
+            assert!(matches!(
+                burn_with_denom(denom).unwrap_err(),
+                ContractError::InvalidDenom { .. }
+            ));
+        }
+    }
+
+    fn burn_supplied(denom: &str) -> CoreumResult<ContractError> {
+        let mut deps = setup();
+        DENOM
+            .save(
+                &mut deps.storage,
+                &build_denom("abc", &Addr::unchecked(CONTRACT)),
+            )
+            .unwrap();
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked(CONTRACT);
+        execute(
+            deps.as_mut(),
+            env,
+            mock_info(OWNER, &[]),
+            ExecuteMsg::Burn {
+                amount: 100,
+                denom: Some(denom.to_string()),
+            },
+        )
+    }
+
+    #[test]
+    fn burn_checks_the_supplied_denom() {
+        // another token the contract issued, e.g. by a reveal
+        let revealed = build_denom("uabc", &Addr::unchecked(CONTRACT));
+        let res = burn_supplied(&revealed).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Custom(CoreumMsg::AssetFT(assetft::Msg::Burn {
+                coin: coin(100, &revealed),
+            }))
+        );
+
+        let foreign = "abc-core1qyqszqgpqyqszqgpqyqszqgpqyqszqgppae928";
+        assert!(matches!(
+            burn_supplied(foreign).unwrap_err(),
+            ContractError::NotIssuedByContract { denom } if denom == foreign
+        ));
+        for denom in [
+            "ucore",
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2",
+        ] {
+            assert!(matches!(
+                burn_supplied(denom).unwrap_err(),
+                ContractError::InvalidDenom { denom: d } if d == denom
+            ));
+        }
+    }
+
+    const RECIPIENT: &str = "recipient";
+
+    fn whitelisting_token() -> Token {
+        Token {
+            denom: build_denom("abc", &Addr::unchecked(CONTRACT)),
+            issuer: CONTRACT.to_string(),
+            symbol: "ABC".to_string(),
+            subunit: "abc".to_string(),
+            precision: 6,
+            description: None,
+            features: Some(vec![TokenFeature::Whitelisting.into()]),
+            burn_rate: "0".to_string(),
+            send_commission_rate: "0".to_string(),
+            version: 1,
+        }
+    }
+
+    // the contract manages the whitelisting token and holds contract_balance of it
+    fn whitelisting_deps(contract_balance: u128, limit: u128) -> TestDeps {
+        let token = whitelisting_token();
+        let registry = CoreumMockQuerier::new()
+            .with_token(token.clone())
+            .with_balance(CONTRACT, coin(contract_balance, &token.denom))
+            .with_whitelisted(RECIPIENT, coin(limit, &token.denom));
+        let mut deps = mock_dependencies(registry);
+        initialize_owner(&mut deps.storage, &deps.api, Some(OWNER)).unwrap();
+        DENOM.save(&mut deps.storage, &token.denom).unwrap();
+        deps.querier
+            .update_balance(CONTRACT, vec![coin(contract_balance, &token.denom)]);
+        deps
+    }
+
+    fn contract_env() -> Env {
+        let mut env = mock_env();
+        env.contract.address = Addr::unchecked(CONTRACT);
+        env
+    }
+
+    fn send_with_limit(
+        msg: ExecuteMsg,
+        contract_balance: u128,
+        limit: u128,
+    ) -> CoreumResult<ContractError> {
+        let mut deps = whitelisting_deps(contract_balance, limit);
+        execute(deps.as_mut(), contract_env(), mock_info(OWNER, &[]), msg)
+    }
+
+    fn send_to_recipient(amount: u128) -> ExecuteMsg {
+        ExecuteMsg::SendTo {
+            account: RECIPIENT.to_string(),
+            amount,
+        }
+    }
+
+    #[test]
+    fn send_to_checks_the_transfer() {
+        let res = send_with_limit(send_to_recipient(100), 100, 100).unwrap();
+        assert_eq!(
+            res.messages[0].msg,
+            CosmosMsg::Bank(cosmwasm_std::BankMsg::Send {
+                to_address: RECIPIENT.to_string(),
+                amount: vec![coin(100, whitelisting_token().denom)],
+            })
+        );
+
+        assert!(matches!(
+            send_with_limit(send_to_recipient(100), 100, 60).unwrap_err(),
+            ContractError::WhitelistLimitExceeded { account, excess }
+                if account == RECIPIENT && excess.u128() == 40
+        ));
+        assert!(matches!(
+            send_with_limit(send_to_recipient(100), 70, 100).unwrap_err(),
+            ContractError::InsufficientBalance { account, shortfall }
+                if account == CONTRACT && shortfall.u128() == 30
+        ));
+    }
+
+    #[test]
+    fn mint_and_send_checks_the_recipient_only() {
+        let mint_and_send = ExecuteMsg::MintAndSend {
+            account: RECIPIENT.to_string(),
+            amount: 100,
+        };
+        // the minted amount is not in the contract balance yet
+        assert_eq!(
+            send_with_limit(mint_and_send.clone(), 0, 100)
+                .unwrap()
+                .messages
+                .len(),
+            2
+        );
+        assert!(matches!(
+            send_with_limit(mint_and_send, 0, 99).unwrap_err(),
+            ContractError::WhitelistLimitExceeded { excess, .. } if excess.u128() == 1
+        ));
+    }
+
+    #[test]
+    fn exhaustive_dispatch() {
+        let execute_cases = exhaustive_cases!(ExecuteMsg {
+            Mint { amount: 100 } => Outcome::Ok,
+            Burn {
+                amount: 100,
+                denom: None,
+            } => Outcome::Ok,
+            Freeze {
+                account: RECIPIENT.to_string(),
+                amount: 100,
+            } => Outcome::Ok,
+            Unfreeze {
+                account: RECIPIENT.to_string(),
+                amount: 100,
+            } => Outcome::Ok,
+            SetFrozen {
+                account: RECIPIENT.to_string(),
+                amount: 100,
+            } => Outcome::Ok,
+            GloballyFreeze {} => Outcome::Ok,
+            GloballyUnfreeze {} => Outcome::Ok,
+            SetWhitelistedLimit {
+                account: RECIPIENT.to_string(),
+                amount: 100,
+            } => Outcome::Ok,
+            MintAndSend {
+                account: RECIPIENT.to_string(),
+                amount: 100,
+            } => Outcome::Ok,
+            SendTo {
+                account: RECIPIENT.to_string(),
+                amount: 100,
+            } => Outcome::Ok,
+            UpgradeTokenV1 { ibc_enabled: true } => Outcome::Ok,
+            CommitIssue {
+                hash: commit_hash(OWNER),
+            } => Outcome::Ok,
+            // nothing was committed on the fresh deps of the case
+            RevealIssue {
+                symbol: SYMBOL.to_string(),
+                subunit: "uabc".to_string(),
+                precision: 6,
+                salt: Binary::from(SALT),
+            } => Outcome::Err(|err| matches!(err, ContractError::CommitNotFound {})),
+            SplitSend {
+                amount: 100,
+                recipients: vec![SplitRecipient {
+                    address: RECIPIENT.to_string(),
+                    weight: 1,
+                }],
+            } => Outcome::Ok,
+        });
+        run_cases(execute_cases, |msg| {
+            let mut deps = whitelisting_deps(1000, 1000);
+            execute(deps.as_mut(), contract_env(), mock_info(OWNER, &[]), msg)
+        });
+
+        let query_cases = exhaustive_cases!(QueryMsg {
+            Params {} => Outcome::Ok,
+            Token {} => Outcome::Ok,
+            Tokens {
+                issuer: CONTRACT.to_string(),
+            } => Outcome::Ok,
+            Balance {
+                account: CONTRACT.to_string(),
+            } => Outcome::Ok,
+            FrozenBalances {
+                account: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            FrozenBalance {
+                account: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            WhitelistedBalances {
+                account: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            WhitelistedBalance {
+                account: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            Balances {
+                account: CONTRACT.to_string(),
+            } => Outcome::Ok,
+            SimulateSend {
+                amount: Uint128::new(100),
+            } => Outcome::Ok,
+            NetReceiveAmount {
+                spendable: Uint128::new(100),
+            } => Outcome::Ok,
+            #[cfg(feature = "debug")]
+            RawRange {
+                start: None,
+                end: None,
+                limit: 10,
+            } => Outcome::Ok,
+        });
+        run_cases(query_cases, |msg| {
+            let deps = whitelisting_deps(1000, 1000);
+            query(deps.as_ref(), contract_env(), msg)
+        });
+    }
+}
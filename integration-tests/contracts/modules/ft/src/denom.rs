@@ -0,0 +1,58 @@
+pub use common::denom::{build_denom, classify_denom, issuer_of, DenomKind};
+use cosmwasm_std::Addr;
+
+use crate::error::ContractError;
+
+/// Splits a smart token denom into its subunit and issuer, the inverse of build_denom.
+pub fn parse_denom(denom: &str) -> Result<(String, Addr), ContractError> {
+    match classify_denom(denom) {
+        DenomKind::SmartToken { subunit, issuer } => Ok((subunit, issuer)),
+        _ => Err(ContractError::InvalidDenom {
+            denom: denom.to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISSUER: &str = "core1qyqszqgpqyqszqgpqyqszqgpqyqszqgppae928";
+    const HASH: &str = "27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+    #[test]
+    fn build_and_parse() {
+        let cases = [
+            ("abc", "abc"),
+            ("ABC", "abc"),
+            ("uAbc1", "uabc1"),
+            (&"z".repeat(51) as &str, &"z".repeat(51) as &str),
+        ];
+
+        for (subunit, expected) in cases {
+            let denom = build_denom(subunit, &Addr::unchecked(ISSUER));
+            assert_eq!(denom, format!("{expected}-{ISSUER}"));
+            assert_eq!(
+                parse_denom(&denom).unwrap(),
+                (expected.to_string(), Addr::unchecked(ISSUER))
+            );
+            assert_eq!(issuer_of(&denom), Some(Addr::unchecked(ISSUER)));
+        }
+    }
+
+    #[test]
+    fn parse_rejects_other_kinds() {
+        for denom in [
+            "ucore".to_string(),
+            format!("ibc/{HASH}"),
+            "abc-cosmos2contract".to_string(),
+            "abc".to_string(),
+        ] {
+            assert!(
+                matches!(parse_denom(&denom), Err(ContractError::InvalidDenom { denom: d }) if d == denom),
+                "{denom}"
+            );
+            assert_eq!(issuer_of(&denom), None, "{denom}");
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::str::FromStr;
+
+use common::any_msg::AnyMsg;
+use common::protos::AssetFTTx::{
+    self, MsgBurn, MsgFreeze, MsgGloballyFreeze, MsgGloballyUnfreeze, MsgIssue, MsgMint,
+    MsgSetWhitelistedLimit, MsgUnfreeze, MsgUpgradeTokenV1,
+};
+use coreum_wasm_sdk::assetft;
+use coreum_wasm_sdk::core::CoreumMsg;
+use cosmwasm_std::{Coin, CosmosMsg, Decimal, Env, Storage};
+use protobuf::{EnumOrUnknown, MessageField};
+
+use crate::error::ContractError;
+use crate::msg::MsgEncoding;
+use crate::state::ENCODING;
+
+/// Wraps an assetft message in the encoding chosen at instantiation. Both encodings must leave
+/// the chain in the same state, which is what contracts instantiated with each are compared on.
+/// With the audit feature custom messages failing the encoding audit are not sent.
+pub fn asset_msg(
+    storage: &dyn Storage,
+    env: &Env,
+    msg: assetft::Msg,
+) -> Result<CosmosMsg<CoreumMsg>, ContractError> {
+    match ENCODING.may_load(storage)?.unwrap_or_default() {
+        MsgEncoding::Custom => {
+            let msg = CoreumMsg::AssetFT(msg);
+            #[cfg(feature = "audit")]
+            crate::audit::audit_custom_msg(&msg)?;
+            Ok(CosmosMsg::Custom(msg))
+        }
+        MsgEncoding::Stargate => stargate_msg(env, msg),
+    }
+}
+
+// stargate_msg encodes the custom message as its coreum.asset.ft.v1 counterpart, the contract is
+// the issuer or sender like the custom message handler makes it.
+fn stargate_msg(env: &Env, msg: assetft::Msg) -> Result<CosmosMsg<CoreumMsg>, ContractError> {
+    let sender = env.contract.address.to_string();
+    let msg = match msg {
+        assetft::Msg::Issue {
+            symbol,
+            subunit,
+            precision,
+            initial_amount,
+            description,
+            features,
+            burn_rate,
+            send_commission_rate,
+        } => {
+            let mut issue = MsgIssue::new();
+            issue.issuer = sender;
+            issue.symbol = symbol;
+            issue.subunit = subunit;
+            issue.precision = precision;
+            issue.initial_amount = initial_amount.to_string();
+            issue.description = description.unwrap_or_default();
+            issue.features = features
+                .unwrap_or_default()
+                .into_iter()
+                .map(|feature| EnumOrUnknown::from_i32(feature as i32))
+                .collect();
+            issue.burn_rate = proto_dec(burn_rate)?;
+            issue.send_commission_rate = proto_dec(send_commission_rate)?;
+            issue.to_stargate()?
+        }
+        assetft::Msg::Mint { coin } => {
+            let mut mint = MsgMint::new();
+            mint.sender = sender;
+            mint.coin = proto_coin(coin);
+            mint.to_stargate()?
+        }
+        assetft::Msg::Burn { coin } => {
+            let mut burn = MsgBurn::new();
+            burn.sender = sender;
+            burn.coin = proto_coin(coin);
+            burn.to_stargate()?
+        }
+        assetft::Msg::Freeze { account, coin } => {
+            let mut freeze = MsgFreeze::new();
+            freeze.sender = sender;
+            freeze.account = account;
+            freeze.coin = proto_coin(coin);
+            freeze.to_stargate()?
+        }
+        assetft::Msg::Unfreeze { account, coin } => {
+            let mut unfreeze = MsgUnfreeze::new();
+            unfreeze.sender = sender;
+            unfreeze.account = account;
+            unfreeze.coin = proto_coin(coin);
+            unfreeze.to_stargate()?
+        }
+        assetft::Msg::GloballyFreeze { denom } => {
+            let mut freeze = MsgGloballyFreeze::new();
+            freeze.sender = sender;
+            freeze.denom = denom;
+            freeze.to_stargate()?
+        }
+        assetft::Msg::GloballyUnfreeze { denom } => {
+            let mut unfreeze = MsgGloballyUnfreeze::new();
+            unfreeze.sender = sender;
+            unfreeze.denom = denom;
+            unfreeze.to_stargate()?
+        }
+        assetft::Msg::SetWhitelistedLimit { account, coin } => {
+            let mut limit = MsgSetWhitelistedLimit::new();
+            limit.sender = sender;
+            limit.account = account;
+            limit.coin = proto_coin(coin);
+            limit.to_stargate()?
+        }
+        assetft::Msg::UpgradeTokenV1 { denom, ibc_enabled } => {
+            let mut upgrade = MsgUpgradeTokenV1::new();
+            upgrade.sender = sender;
+            upgrade.denom = denom;
+            upgrade.ibc_enabled = ibc_enabled;
+            upgrade.to_stargate()?
+        }
+    };
+    Ok(msg)
+}
+
+fn proto_coin(coin: Coin) -> MessageField<AssetFTTx::Coin> {
+    let mut proto_coin = AssetFTTx::Coin::new();
+    proto_coin.denom = coin.denom;
+    proto_coin.amount = coin.amount.to_string();
+    MessageField::some(proto_coin)
+}
+
+// sdk.Dec is encoded as its integer representation with 18 decimal places, the same as the
+// atomics of a Decimal. A missing rate is zero, an empty string would not decode.
+fn proto_dec(rate: Option<String>) -> Result<String, ContractError> {
+    let rate = match rate {
+        Some(rate) => Decimal::from_str(&rate)?,
+        None => Decimal::zero(),
+    };
+    Ok(rate.atomics().to_string())
+}
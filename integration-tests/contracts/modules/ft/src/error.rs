@@ -1,4 +1,6 @@
-use cosmwasm_std::StdError;
+use common::guard::GuardError;
+use common::split::SplitError;
+use cosmwasm_std::{Coin, StdError, Uint128};
 use cw_ownable::OwnershipError;
 use thiserror::Error;
 
@@ -9,4 +11,67 @@ pub enum ContractError {
 
     #[error(transparent)]
     Ownership(#[from] OwnershipError),
+
+    #[error(transparent)]
+    Protobuf(#[from] protobuf::Error),
+
+    #[error("Unsupported message version {version}, latest is {latest}")]
+    UnsupportedVersion { version: u8, latest: u8 },
+
+    #[error("Missing field {field}")]
+    MissingField { field: String },
+
+    #[error("Unknown token feature {feature}")]
+    UnknownFeature { feature: String },
+
+    #[error("Invalid issue {field}: {reason}")]
+    InvalidIssue { field: String, reason: String },
+
+    #[error("Event {event_type} not found")]
+    MissingEvent { event_type: String },
+
+    #[error("Invalid {event_type} event: {reason}")]
+    InvalidEvent { event_type: String, reason: String },
+
+    #[error("Commit is still active until height {expires_at}")]
+    CommitActive { expires_at: u64 },
+
+    #[error("No commit matches the revealed issue")]
+    CommitNotFound {},
+
+    #[error("Reveal is too early, allowed from height {allowed_at}")]
+    RevealTooEarly { allowed_at: u64 },
+
+    #[error("Commit expired at height {expired_at}")]
+    CommitExpired { expired_at: u64 },
+
+    #[error("Denom {denom} is not a smart token denom")]
+    InvalidDenom { denom: String },
+
+    #[error("Denom {denom} is not issued by the contract")]
+    NotIssuedByContract { denom: String },
+
+    #[error("Issue fee of {required} exceeds the available {available}")]
+    InsufficientIssueFee { required: Coin, available: Uint128 },
+
+    #[error("Balance of {account} is short by {shortfall}")]
+    InsufficientBalance { account: String, shortfall: Uint128 },
+
+    #[error("Unfrozen balance of {account} is short by {shortfall}")]
+    FrozenAmountExceeded { account: String, shortfall: Uint128 },
+
+    #[error("Whitelisted limit of {account} would be exceeded by {excess}")]
+    WhitelistLimitExceeded { account: String, excess: Uint128 },
+
+    #[error(transparent)]
+    Guard(#[from] GuardError),
+
+    #[error(transparent)]
+    Split(#[from] SplitError),
+
+    #[error("Unknown reply id: {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("Custom message encoding drift at {path}: {reason}")]
+    EncodingDrift { path: String, reason: String },
 }
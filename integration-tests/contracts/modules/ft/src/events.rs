@@ -0,0 +1,114 @@
+use cosmwasm_std::{from_json, Event, Uint128};
+use serde::de::DeserializeOwned;
+
+use crate::error::ContractError;
+use crate::feature::TokenFeature;
+
+pub const EVENT_ISSUED: &str = "coreum.asset.ft.v1.EventIssued";
+pub const EVENT_FROZEN_AMOUNT_CHANGED: &str = "coreum.asset.ft.v1.EventFrozenAmountChanged";
+pub const EVENT_WHITELISTED_AMOUNT_CHANGED: &str =
+    "coreum.asset.ft.v1.EventWhitelistedAmountChanged";
+
+// Typed events are emitted with every attribute value encoded as JSON, so strings arrive quoted
+// ("\"denom\""), uint32 fields as plain numbers and sdk.Int/sdk.Dec fields as quoted strings.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventIssued {
+    pub denom: String,
+    pub issuer: String,
+    pub symbol: String,
+    pub subunit: String,
+    pub precision: u32,
+    pub initial_amount: Uint128,
+    pub description: String,
+    pub features: Vec<TokenFeature>,
+    pub burn_rate: String,
+    pub send_commission_rate: String,
+}
+
+impl TryFrom<&Event> for EventIssued {
+    type Error = ContractError;
+
+    fn try_from(event: &Event) -> Result<Self, ContractError> {
+        ensure_type(event, EVENT_ISSUED)?;
+        Ok(Self {
+            denom: attribute(event, "denom")?,
+            issuer: attribute(event, "issuer")?,
+            symbol: attribute(event, "symbol")?,
+            subunit: attribute(event, "subunit")?,
+            precision: attribute(event, "precision")?,
+            initial_amount: attribute(event, "initial_amount")?,
+            description: attribute(event, "description")?,
+            features: attribute(event, "features")?,
+            burn_rate: attribute(event, "burn_rate")?,
+            send_commission_rate: attribute(event, "send_commission_rate")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventAmountChanged {
+    pub account: String,
+    pub denom: String,
+    pub previous_amount: Uint128,
+    pub current_amount: Uint128,
+}
+
+impl EventAmountChanged {
+    // the frozen and whitelisted amount events share the same layout
+    fn parse(event: &Event, event_type: &str) -> Result<Self, ContractError> {
+        ensure_type(event, event_type)?;
+        Ok(Self {
+            account: attribute(event, "account")?,
+            denom: attribute(event, "denom")?,
+            previous_amount: attribute(event, "previous_amount")?,
+            current_amount: attribute(event, "current_amount")?,
+        })
+    }
+
+    pub fn frozen(event: &Event) -> Result<Self, ContractError> {
+        Self::parse(event, EVENT_FROZEN_AMOUNT_CHANGED)
+    }
+
+    pub fn whitelisted(event: &Event) -> Result<Self, ContractError> {
+        Self::parse(event, EVENT_WHITELISTED_AMOUNT_CHANGED)
+    }
+}
+
+/// Finds the first event of the given type.
+pub fn find_event<'a>(events: &'a [Event], event_type: &str) -> Result<&'a Event, ContractError> {
+    events
+        .iter()
+        .find(|event| event.ty == event_type)
+        .ok_or_else(|| ContractError::MissingEvent {
+            event_type: event_type.to_string(),
+        })
+}
+
+fn ensure_type(event: &Event, event_type: &str) -> Result<(), ContractError> {
+    if event.ty != event_type {
+        return Err(ContractError::InvalidEvent {
+            event_type: event_type.to_string(),
+            reason: format!("unexpected event type {}", event.ty),
+        });
+    }
+    Ok(())
+}
+
+// attribute decodes the JSON encoded value of an attribute, stripping the quoting of strings.
+fn attribute<T: DeserializeOwned>(event: &Event, key: &str) -> Result<T, ContractError> {
+    let value = event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.as_str())
+        .ok_or_else(|| ContractError::InvalidEvent {
+            event_type: event.ty.clone(),
+            reason: format!("missing attribute {}", key),
+        })?;
+
+    from_json(value.as_bytes()).map_err(|err| ContractError::InvalidEvent {
+        event_type: event.ty.clone(),
+        reason: format!("invalid attribute {}: {}", key, err),
+    })
+}
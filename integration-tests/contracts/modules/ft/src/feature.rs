@@ -0,0 +1,115 @@
+use std::fmt;
+use std::str::FromStr;
+
+use cosmwasm_schema::schemars::gen::SchemaGenerator;
+use cosmwasm_schema::schemars::schema::Schema;
+use cosmwasm_schema::schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+
+// Feature enum of x/asset/ft. Messages carry features by number, typed events by name, so both are
+// accepted when decoding while encoding always produces the number.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(try_from = "FeatureRepr", into = "u32")]
+pub enum TokenFeature {
+    Minting,
+    Burning,
+    Freezing,
+    Whitelisting,
+    Ibc,
+}
+
+impl TokenFeature {
+    pub const ALL: [TokenFeature; 5] = [
+        TokenFeature::Minting,
+        TokenFeature::Burning,
+        TokenFeature::Freezing,
+        TokenFeature::Whitelisting,
+        TokenFeature::Ibc,
+    ];
+
+    /// Name of the feature as used by the chain in typed events and CLI flags.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TokenFeature::Minting => "minting",
+            TokenFeature::Burning => "burning",
+            TokenFeature::Freezing => "freezing",
+            TokenFeature::Whitelisting => "whitelisting",
+            TokenFeature::Ibc => "ibc",
+        }
+    }
+}
+
+impl From<TokenFeature> for u32 {
+    fn from(feature: TokenFeature) -> u32 {
+        match feature {
+            TokenFeature::Minting => 0,
+            TokenFeature::Burning => 1,
+            TokenFeature::Freezing => 2,
+            TokenFeature::Whitelisting => 3,
+            TokenFeature::Ibc => 4,
+        }
+    }
+}
+
+impl TryFrom<u32> for TokenFeature {
+    type Error = ContractError;
+
+    fn try_from(feature: u32) -> Result<Self, ContractError> {
+        TokenFeature::ALL
+            .into_iter()
+            .find(|known| u32::from(*known) == feature)
+            .ok_or(ContractError::UnknownFeature {
+                feature: feature.to_string(),
+            })
+    }
+}
+
+impl FromStr for TokenFeature {
+    type Err = ContractError;
+
+    fn from_str(feature: &str) -> Result<Self, ContractError> {
+        TokenFeature::ALL
+            .into_iter()
+            .find(|known| known.name() == feature)
+            .ok_or(ContractError::UnknownFeature {
+                feature: feature.to_string(),
+            })
+    }
+}
+
+// the schema describes the encoded form, the feature number
+impl JsonSchema for TokenFeature {
+    fn schema_name() -> String {
+        "TokenFeature".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        u32::json_schema(gen)
+    }
+}
+
+impl fmt::Display for TokenFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum FeatureRepr {
+    Number(u32),
+    Name(String),
+}
+
+impl TryFrom<FeatureRepr> for TokenFeature {
+    type Error = ContractError;
+
+    fn try_from(repr: FeatureRepr) -> Result<Self, ContractError> {
+        match repr {
+            FeatureRepr::Number(feature) => feature.try_into(),
+            FeatureRepr::Name(feature) => feature.parse(),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use coreum_wasm_sdk::assetft::{
+    FrozenBalanceResponse, Query, Token, TokenResponse, WhitelistedBalanceResponse,
+};
+use coreum_wasm_sdk::core::CoreumQueries;
+use cosmwasm_std::{Deps, Env, Uint128};
+
+use crate::error::ContractError;
+use crate::feature::TokenFeature;
+use crate::msg::{AssetFTParams, AssetFTParamsResponse};
+
+/// Checks that a send of the smart token will go through the bank balance, freezing and
+/// whitelisting rules, so a failing send surfaces as a named error instead of reverting the whole
+/// response.
+pub fn ensure_transferable(
+    deps: Deps<CoreumQueries>,
+    from: &str,
+    to: &str,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let token = query_token(deps, denom)?;
+    check_sendable(deps, &token, from, amount)?;
+    check_receivable(deps, &token, to, amount)
+}
+
+/// Checks the recipient side of ensure_transferable only, for sends whose sender balance is
+/// created within the same response.
+pub fn ensure_receivable(
+    deps: Deps<CoreumQueries>,
+    to: &str,
+    denom: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let token = query_token(deps, denom)?;
+    check_receivable(deps, &token, to, amount)
+}
+
+/// Queries the params of x/asset/ft including the token upgrade params the bindings leave out.
+pub fn query_asset_ft_params(deps: Deps<CoreumQueries>) -> Result<AssetFTParams, ContractError> {
+    let res: AssetFTParamsResponse = deps
+        .querier
+        .query(&CoreumQueries::AssetFT(Query::Params {}).into())?;
+    Ok(res.params)
+}
+
+/// Checks that the contract holds the issue fee, which the chain burns from the issuer, so a
+/// short balance fails with the missing amount instead of the error of the issue message.
+pub fn ensure_issue_fee(deps: Deps<CoreumQueries>, env: &Env) -> Result<(), ContractError> {
+    let required = query_asset_ft_params(deps)?.issue_fee;
+    let available = deps
+        .querier
+        .query_balance(&env.contract.address, &required.denom)?
+        .amount;
+    if available < required.amount {
+        return Err(ContractError::InsufficientIssueFee {
+            required,
+            available,
+        });
+    }
+    Ok(())
+}
+
+fn check_sendable(
+    deps: Deps<CoreumQueries>,
+    token: &Token,
+    from: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    let balance = deps.querier.query_balance(from, &token.denom)?.amount;
+    if balance < amount {
+        return Err(ContractError::InsufficientBalance {
+            account: from.to_string(),
+            shortfall: amount - balance,
+        });
+    }
+
+    // the issuer is not subject to freezing
+    if !has_feature(token, TokenFeature::Freezing) || from == token.issuer {
+        return Ok(());
+    }
+    let res: FrozenBalanceResponse = deps.querier.query(
+        &CoreumQueries::AssetFT(Query::FrozenBalance {
+            account: from.to_string(),
+            denom: token.denom.clone(),
+        })
+        .into(),
+    )?;
+    let spendable = balance.saturating_sub(res.balance.amount);
+    if spendable < amount {
+        return Err(ContractError::FrozenAmountExceeded {
+            account: from.to_string(),
+            shortfall: amount - spendable,
+        });
+    }
+
+    Ok(())
+}
+
+fn check_receivable(
+    deps: Deps<CoreumQueries>,
+    token: &Token,
+    to: &str,
+    amount: Uint128,
+) -> Result<(), ContractError> {
+    // the issuer is not subject to whitelisting
+    if !has_feature(token, TokenFeature::Whitelisting) || to == token.issuer {
+        return Ok(());
+    }
+
+    let balance = deps.querier.query_balance(to, &token.denom)?.amount;
+    let res: WhitelistedBalanceResponse = deps.querier.query(
+        &CoreumQueries::AssetFT(Query::WhitelistedBalance {
+            account: to.to_string(),
+            denom: token.denom.clone(),
+        })
+        .into(),
+    )?;
+    let after = balance + amount;
+    if after > res.balance.amount {
+        return Err(ContractError::WhitelistLimitExceeded {
+            account: to.to_string(),
+            excess: after - res.balance.amount,
+        });
+    }
+
+    Ok(())
+}
+
+fn query_token(deps: Deps<CoreumQueries>, denom: &str) -> Result<Token, ContractError> {
+    let res: TokenResponse = deps.querier.query(
+        &CoreumQueries::AssetFT(Query::Token {
+            denom: denom.to_string(),
+        })
+        .into(),
+    )?;
+    Ok(res.token)
+}
+
+/// Decodes the features list of an assetft Token, which the bindings leave as plain numbers.
+pub fn token_features(token: &Token) -> Result<Vec<TokenFeature>, ContractError> {
+    token
+        .features
+        .iter()
+        .flatten()
+        .map(|feature| TokenFeature::try_from(*feature))
+        .collect()
+}
+
+fn has_feature(token: &Token, feature: TokenFeature) -> bool {
+    token
+        .features
+        .as_ref()
+        .is_some_and(|features| features.contains(&feature.into()))
+}
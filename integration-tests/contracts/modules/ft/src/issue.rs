@@ -0,0 +1,181 @@
+use coreum_wasm_sdk::assetft;
+use cosmwasm_std::{Decimal, Uint128};
+
+use crate::error::ContractError;
+use crate::feature::TokenFeature;
+
+// Limits of MsgIssue.ValidateBasic in x/asset/ft, keep them in sync with the chain.
+pub const MAX_PRECISION: u32 = 20;
+pub const MAX_DESCRIPTION_LENGTH: usize = 200;
+pub const MAX_RATE_DECIMAL_PLACES: u32 = 4;
+
+// lowercased symbols and subunits the chain refuses to issue
+const RESERVED: [&str; 7] = [
+    "udevcore",
+    "devcore",
+    "utestcore",
+    "testcore",
+    "ucore",
+    "core",
+    "ibc",
+];
+
+/// Builds an assetft issue message checking it against the chain's rules first, so an invalid
+/// issuance fails in the contract instead of in the dispatched message.
+pub struct IssueBuilder {
+    symbol: String,
+    subunit: String,
+    precision: u32,
+    initial_amount: Uint128,
+    description: Option<String>,
+    features: Vec<TokenFeature>,
+    burn_rate: Option<Decimal>,
+    send_commission_rate: Option<Decimal>,
+}
+
+impl IssueBuilder {
+    pub fn new(symbol: String, subunit: String, precision: u32) -> Self {
+        Self {
+            symbol,
+            subunit,
+            precision,
+            initial_amount: Uint128::zero(),
+            description: None,
+            features: vec![],
+            burn_rate: None,
+            send_commission_rate: None,
+        }
+    }
+
+    pub fn initial_amount(mut self, initial_amount: Uint128) -> Self {
+        self.initial_amount = initial_amount;
+        self
+    }
+
+    pub fn description(mut self, description: String) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    pub fn features(mut self, features: Vec<TokenFeature>) -> Self {
+        self.features = features;
+        self
+    }
+
+    pub fn burn_rate(mut self, burn_rate: Decimal) -> Self {
+        self.burn_rate = Some(burn_rate);
+        self
+    }
+
+    pub fn send_commission_rate(mut self, send_commission_rate: Decimal) -> Self {
+        self.send_commission_rate = Some(send_commission_rate);
+        self
+    }
+
+    pub fn build(self) -> Result<assetft::Msg, ContractError> {
+        validate_symbol(&self.symbol)?;
+        validate_subunit(&self.subunit)?;
+        if self.precision == 0 || self.precision > MAX_PRECISION {
+            return Err(invalid(
+                "precision",
+                format!("must be between 1 and {MAX_PRECISION}"),
+            ));
+        }
+        if let Some(description) = &self.description {
+            if description.len() > MAX_DESCRIPTION_LENGTH {
+                return Err(invalid(
+                    "description",
+                    format!("must not be longer than {MAX_DESCRIPTION_LENGTH} bytes"),
+                ));
+            }
+        }
+        validate_features(&self.features)?;
+        if let Some(rate) = self.burn_rate {
+            validate_rate("burn_rate", rate)?;
+        }
+        if let Some(rate) = self.send_commission_rate {
+            validate_rate("send_commission_rate", rate)?;
+        }
+
+        Ok(assetft::Msg::Issue {
+            symbol: self.symbol,
+            subunit: self.subunit,
+            precision: self.precision,
+            initial_amount: self.initial_amount,
+            description: self.description,
+            features: Some(self.features.into_iter().map(u32::from).collect()),
+            // the chain decodes the rates into sdk.Dec, which rejects null, so unset rates are zero
+            burn_rate: Some(self.burn_rate.unwrap_or_default().to_string()),
+            send_commission_rate: Some(self.send_commission_rate.unwrap_or_default().to_string()),
+        })
+    }
+}
+
+// symbols match ^[a-zA-Z][a-zA-Z0-9-]{2,127}$
+fn validate_symbol(symbol: &str) -> Result<(), ContractError> {
+    ensure_not_reserved("symbol", symbol)?;
+
+    let mut chars = symbol.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && (3..=128).contains(&symbol.len());
+    if !valid {
+        return Err(invalid(
+            "symbol",
+            "must match ^[a-zA-Z][a-zA-Z0-9-]{2,127}$",
+        ));
+    }
+    Ok(())
+}
+
+// subunits match ^[a-z][a-z0-9]{0,50}$
+fn validate_subunit(subunit: &str) -> Result<(), ContractError> {
+    ensure_not_reserved("subunit", subunit)?;
+
+    let mut chars = subunit.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && subunit.len() <= 51;
+    if !valid {
+        return Err(invalid("subunit", "must match ^[a-z][a-z0-9]{0,50}$"));
+    }
+    Ok(())
+}
+
+fn ensure_not_reserved(field: &str, value: &str) -> Result<(), ContractError> {
+    if RESERVED.contains(&value.to_lowercase().as_str()) {
+        return Err(invalid(field, format!("{value} is reserved")));
+    }
+    Ok(())
+}
+
+fn validate_features(features: &[TokenFeature]) -> Result<(), ContractError> {
+    for (i, feature) in features.iter().enumerate() {
+        if features[..i].contains(feature) {
+            return Err(invalid("features", format!("duplicate feature {feature}")));
+        }
+    }
+    Ok(())
+}
+
+// rates lie in [0, 1] with at most four decimal places
+fn validate_rate(field: &str, rate: Decimal) -> Result<(), ContractError> {
+    if rate > Decimal::one() {
+        return Err(invalid(field, "must not exceed 1"));
+    }
+    let step = Uint128::from(10u128.pow(Decimal::DECIMAL_PLACES - MAX_RATE_DECIMAL_PLACES));
+    if !(rate.atomics() % step).is_zero() {
+        return Err(invalid(
+            field,
+            format!("must not have more than {MAX_RATE_DECIMAL_PLACES} decimal places"),
+        ));
+    }
+    Ok(())
+}
+
+fn invalid(field: &str, reason: impl Into<String>) -> ContractError {
+    ContractError::InvalidIssue {
+        field: field.to_string(),
+        reason: reason.into(),
+    }
+}
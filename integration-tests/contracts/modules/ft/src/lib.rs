@@ -1,4 +1,19 @@
+#[cfg(feature = "audit")]
+pub mod audit;
+pub mod balances;
+pub mod chain_error;
 pub mod contract;
+pub mod denom;
+pub mod encoding;
 pub mod error;
+pub mod events;
+pub mod feature;
+pub mod helpers;
+pub mod issue;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
 pub mod msg;
+#[cfg(feature = "multitest")]
+pub mod multitest;
+pub mod rates;
 pub mod state;
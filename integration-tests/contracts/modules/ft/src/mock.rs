@@ -0,0 +1,608 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::{Deref, DerefMut};
+
+use coreum_wasm_sdk::assetft::{self, Token};
+use coreum_wasm_sdk::assetnft::{self, Class};
+use coreum_wasm_sdk::core::CoreumQueries;
+use coreum_wasm_sdk::pagination::{PageRequest, PageResponse};
+use cosmwasm_std::testing::{MockApi, MockQuerier, MockStorage};
+use cosmwasm_std::{
+    coin, from_json, to_json_binary, Binary, Coin, ContractResult, OwnedDeps, Querier,
+    QuerierResult, SystemResult, Uint128,
+};
+use serde::Serialize;
+
+use crate::balances::{AssetFTBalancesQuery, BalancesQuery, BalancesRequest};
+use crate::msg::{AccountBalance, AccountBalancesResponse, AssetFTParams, AssetFTParamsResponse};
+
+/// In-memory registry of assetft tokens and assetnft classes answering the CoreumQueries custom
+/// queries, so unit tests of the contract run without a chain. The registry is filled first and
+/// then registered as the custom handler of a MockQuerier, see mock_dependencies.
+#[derive(Clone, Debug)]
+pub struct CoreumMockQuerier {
+    ft_params: AssetFTParams,
+    tokens: BTreeMap<String, Token>,
+    // amounts keyed by (account, denom)
+    balances: BTreeMap<(String, String), Uint128>,
+    frozen: BTreeMap<(String, String), Uint128>,
+    whitelisted: BTreeMap<(String, String), Uint128>,
+    nft_params: assetnft::Params,
+    classes: BTreeMap<String, Class>,
+    // nfts keyed by (class_id, id)
+    frozen_nfts: BTreeSet<(String, String)>,
+    whitelisted_nfts: BTreeMap<(String, String), BTreeSet<String>>,
+}
+
+impl Default for CoreumMockQuerier {
+    fn default() -> Self {
+        Self {
+            ft_params: AssetFTParams::with_issue_fee(coin(10_000_000, "udevcore")),
+            tokens: BTreeMap::new(),
+            balances: BTreeMap::new(),
+            frozen: BTreeMap::new(),
+            whitelisted: BTreeMap::new(),
+            nft_params: assetnft::Params {
+                mint_fee: coin(0, "udevcore"),
+            },
+            classes: BTreeMap::new(),
+            frozen_nfts: BTreeSet::new(),
+            whitelisted_nfts: BTreeMap::new(),
+        }
+    }
+}
+
+impl CoreumMockQuerier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_ft_params(mut self, params: AssetFTParams) -> Self {
+        self.ft_params = params;
+        self
+    }
+
+    pub fn with_token(mut self, token: Token) -> Self {
+        self.tokens.insert(token.denom.clone(), token);
+        self
+    }
+
+    pub fn with_balance(mut self, account: &str, balance: Coin) -> Self {
+        self.balances
+            .insert((account.to_string(), balance.denom), balance.amount);
+        self
+    }
+
+    pub fn with_frozen(mut self, account: &str, frozen: Coin) -> Self {
+        self.frozen
+            .insert((account.to_string(), frozen.denom), frozen.amount);
+        self
+    }
+
+    pub fn with_whitelisted(mut self, account: &str, whitelisted: Coin) -> Self {
+        self.whitelisted
+            .insert((account.to_string(), whitelisted.denom), whitelisted.amount);
+        self
+    }
+
+    pub fn with_nft_params(mut self, params: assetnft::Params) -> Self {
+        self.nft_params = params;
+        self
+    }
+
+    pub fn with_class(mut self, class: Class) -> Self {
+        self.classes.insert(class.id.clone(), class);
+        self
+    }
+
+    pub fn with_frozen_nft(mut self, class_id: &str, id: &str) -> Self {
+        self.frozen_nfts
+            .insert((class_id.to_string(), id.to_string()));
+        self
+    }
+
+    pub fn with_whitelisted_nft(mut self, class_id: &str, id: &str, account: &str) -> Self {
+        self.whitelisted_nfts
+            .entry((class_id.to_string(), id.to_string()))
+            .or_default()
+            .insert(account.to_string());
+        self
+    }
+
+    /// Answers a custom query the way the chain's wasm bindings do. The NFT module queries are not
+    /// part of the registry and fail as unsupported.
+    pub fn handle(&self, query: &CoreumQueries) -> QuerierResult {
+        let response = match query {
+            CoreumQueries::AssetFT(query) => self.handle_ft(query),
+            CoreumQueries::AssetNFT(query) => self.handle_nft(query),
+            CoreumQueries::NFT(_) => Err("nft queries are not supported by the mock".to_string()),
+        };
+        SystemResult::Ok(ContractResult::from(response))
+    }
+
+    fn handle_ft(&self, query: &assetft::Query) -> Result<Binary, String> {
+        match query {
+            assetft::Query::Params {} => encode(&AssetFTParamsResponse {
+                params: self.ft_params.clone(),
+            }),
+            assetft::Query::Tokens { pagination, issuer } => {
+                let tokens = self
+                    .tokens
+                    .values()
+                    .filter(|token| &token.issuer == issuer)
+                    .cloned()
+                    .collect();
+                let (tokens, pagination) = paginate(tokens, pagination);
+                encode(&assetft::TokensResponse { pagination, tokens })
+            }
+            assetft::Query::Token { denom } => encode(&assetft::TokenResponse {
+                token: self.token(denom)?.clone(),
+            }),
+            assetft::Query::Balance { account, denom } => {
+                let key = (account.clone(), denom.clone());
+                encode(&assetft::BalanceResponse {
+                    balance: amount(&self.balances, &key).to_string(),
+                    whitelisted: amount(&self.whitelisted, &key).to_string(),
+                    frozen: amount(&self.frozen, &key).to_string(),
+                    locked: "0".to_string(),
+                })
+            }
+            assetft::Query::FrozenBalances {
+                pagination,
+                account,
+            } => {
+                let (balances, pagination) =
+                    paginate(account_coins(&self.frozen, account), pagination);
+                encode(&assetft::FrozenBalancesResponse {
+                    pagination,
+                    balances,
+                })
+            }
+            assetft::Query::FrozenBalance { account, denom } => {
+                let key = (account.clone(), denom.clone());
+                encode(&assetft::FrozenBalanceResponse {
+                    balance: coin(amount(&self.frozen, &key).u128(), denom),
+                })
+            }
+            assetft::Query::WhitelistedBalances {
+                pagination,
+                account,
+            } => {
+                let (balances, pagination) =
+                    paginate(account_coins(&self.whitelisted, account), pagination);
+                encode(&assetft::WhitelistedBalancesResponse {
+                    pagination,
+                    balances,
+                })
+            }
+            assetft::Query::WhitelistedBalance { account, denom } => {
+                let key = (account.clone(), denom.clone());
+                encode(&assetft::WhitelistedBalanceResponse {
+                    balance: coin(amount(&self.whitelisted, &key).u128(), denom),
+                })
+            }
+        }
+    }
+
+    /// Answers the Balances query, which is not part of CoreumQueries, see CoreumQuerier. Nothing
+    /// is locked by vesting in the registry.
+    pub fn handle_balances(&self, request: &BalancesRequest) -> QuerierResult {
+        let BalancesRequest::Custom(BalancesQuery::AssetFT(AssetFTBalancesQuery::Balances {
+            account,
+            pagination,
+        })) = request;
+        let balances = account_coins(&self.balances, account)
+            .into_iter()
+            .map(|balance| {
+                let key = (account.clone(), balance.denom.clone());
+                let frozen = amount(&self.frozen, &key);
+                AccountBalance {
+                    spendable: balance.amount.saturating_sub(frozen),
+                    frozen,
+                    whitelisted: amount(&self.whitelisted, &key),
+                    locked: Uint128::zero(),
+                    denom: balance.denom,
+                    balance: balance.amount,
+                }
+            })
+            .collect();
+        let (balances, pagination) = paginate(balances, pagination);
+        let response = encode(&AccountBalancesResponse {
+            pagination,
+            balances,
+        });
+        SystemResult::Ok(ContractResult::from(response))
+    }
+
+    fn handle_nft(&self, query: &assetnft::Query) -> Result<Binary, String> {
+        match query {
+            assetnft::Query::Params {} => encode(&assetnft::ParamsResponse {
+                params: self.nft_params.clone(),
+            }),
+            assetnft::Query::Class { id } => encode(&assetnft::ClassResponse {
+                class: self.class(id)?.clone(),
+            }),
+            assetnft::Query::Classes { pagination, issuer } => {
+                let classes = self
+                    .classes
+                    .values()
+                    .filter(|class| &class.issuer == issuer)
+                    .cloned()
+                    .collect();
+                let (classes, pagination) = paginate(classes, pagination);
+                encode(&assetnft::ClassesResponse {
+                    pagination,
+                    classes,
+                })
+            }
+            assetnft::Query::Frozen { id, class_id } => {
+                self.class(class_id)?;
+                encode(&assetnft::FrozenResponse {
+                    frozen: self.frozen_nfts.contains(&(class_id.clone(), id.clone())),
+                })
+            }
+            assetnft::Query::Whitelisted {
+                id,
+                class_id,
+                account,
+            } => {
+                self.class(class_id)?;
+                encode(&assetnft::WhitelistedResponse {
+                    whitelisted: self
+                        .whitelisted_nfts
+                        .get(&(class_id.clone(), id.clone()))
+                        .is_some_and(|accounts| accounts.contains(account)),
+                })
+            }
+            assetnft::Query::WhitelistedAccountsForNFT {
+                pagination,
+                id,
+                class_id,
+            } => {
+                self.class(class_id)?;
+                let accounts = self
+                    .whitelisted_nfts
+                    .get(&(class_id.clone(), id.clone()))
+                    .map(|accounts| accounts.iter().cloned().collect())
+                    .unwrap_or_default();
+                let (accounts, pagination) = paginate(accounts, pagination);
+                encode(&assetnft::WhitelistedAccountsForNFTResponse {
+                    pagination,
+                    accounts,
+                })
+            }
+        }
+    }
+
+    fn token(&self, denom: &str) -> Result<&Token, String> {
+        self.tokens
+            .get(denom)
+            .ok_or_else(|| format!("token with denom {denom} not found"))
+    }
+
+    fn class(&self, id: &str) -> Result<&Class, String> {
+        self.classes
+            .get(id)
+            .ok_or_else(|| format!("class with id {id} not found"))
+    }
+}
+
+/// MockQuerier answering the CoreumQueries custom queries from the registry. Requests the
+/// CoreumQueries type can't carry, like the Balances query, are taken before they reach the
+/// MockQuerier, which would reject them as unparsable.
+pub struct CoreumQuerier {
+    base: MockQuerier<CoreumQueries>,
+    registry: CoreumMockQuerier,
+}
+
+impl CoreumQuerier {
+    pub fn new(registry: CoreumMockQuerier) -> Self {
+        let handler = registry.clone();
+        Self {
+            base: MockQuerier::new(&[]).with_custom_handler(move |query| handler.handle(query)),
+            registry,
+        }
+    }
+}
+
+impl Querier for CoreumQuerier {
+    fn raw_query(&self, bin_request: &[u8]) -> QuerierResult {
+        match from_json::<BalancesRequest>(bin_request) {
+            Ok(request) => self.registry.handle_balances(&request),
+            Err(_) => self.base.raw_query(bin_request),
+        }
+    }
+}
+
+impl Deref for CoreumQuerier {
+    type Target = MockQuerier<CoreumQueries>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for CoreumQuerier {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+/// Mock dependencies whose querier answers the CoreumQueries custom queries from the registry.
+pub fn mock_dependencies(
+    registry: CoreumMockQuerier,
+) -> OwnedDeps<MockStorage, MockApi, CoreumQuerier, CoreumQueries> {
+    OwnedDeps {
+        storage: MockStorage::default(),
+        api: MockApi::default(),
+        querier: CoreumQuerier::new(registry),
+        custom_query_type: Default::default(),
+    }
+}
+
+fn encode(response: &impl Serialize) -> Result<Binary, String> {
+    to_json_binary(response).map_err(|err| err.to_string())
+}
+
+fn amount(amounts: &BTreeMap<(String, String), Uint128>, key: &(String, String)) -> Uint128 {
+    amounts.get(key).copied().unwrap_or_default()
+}
+
+fn account_coins(amounts: &BTreeMap<(String, String), Uint128>, account: &str) -> Vec<Coin> {
+    amounts
+        .iter()
+        .filter(|((holder, _), _)| holder == account)
+        .map(|((_, denom), amount)| coin(amount.u128(), denom))
+        .collect()
+}
+
+// paginate applies the offset, limit and reverse of the request. Pages are only addressed by
+// offset, so the response never carries a next key.
+pub(crate) fn paginate<T>(
+    mut items: Vec<T>,
+    pagination: &Option<PageRequest>,
+) -> (Vec<T>, PageResponse) {
+    let request = pagination.clone().unwrap_or_default();
+    let total = items.len() as u64;
+    if request.reverse.unwrap_or(false) {
+        items.reverse();
+    }
+    let offset = request.offset.unwrap_or(0) as usize;
+    let limit = request.limit.map_or(usize::MAX, |limit| limit as usize);
+    let items = items.into_iter().skip(offset).take(limit).collect();
+    let response = PageResponse {
+        next_key: None,
+        total: request.count_total.unwrap_or(false).then_some(total),
+    };
+    (items, response)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{Deps, QuerierWrapper};
+
+    use super::*;
+    use crate::balances::query_account_balances;
+
+    const ISSUER: &str = "issuer";
+    const HOLDER: &str = "holder";
+
+    fn token(subunit: &str, issuer: &str) -> Token {
+        Token {
+            denom: format!("{subunit}-{issuer}"),
+            issuer: issuer.to_string(),
+            symbol: subunit.to_uppercase(),
+            subunit: subunit.to_string(),
+            precision: 6,
+            description: None,
+            features: Some(vec![]),
+            burn_rate: "0".to_string(),
+            send_commission_rate: "0".to_string(),
+            version: 1,
+        }
+    }
+
+    fn query<T: serde::de::DeserializeOwned>(
+        deps: &OwnedDeps<MockStorage, MockApi, CoreumQuerier, CoreumQueries>,
+        query: assetft::Query,
+    ) -> cosmwasm_std::StdResult<T> {
+        QuerierWrapper::<CoreumQueries>::new(&deps.querier)
+            .query(&CoreumQueries::AssetFT(query).into())
+    }
+
+    #[test]
+    fn tokens_are_filtered_by_issuer_and_paginated() {
+        let deps = mock_dependencies(
+            CoreumMockQuerier::new()
+                .with_token(token("aaa", ISSUER))
+                .with_token(token("bbb", ISSUER))
+                .with_token(token("ccc", ISSUER))
+                .with_token(token("ddd", "other")),
+        );
+
+        let res: assetft::TokensResponse = query(
+            &deps,
+            assetft::Query::Tokens {
+                pagination: None,
+                issuer: ISSUER.to_string(),
+            },
+        )
+        .unwrap();
+        let subunits: Vec<_> = res.tokens.iter().map(|t| t.subunit.as_str()).collect();
+        assert_eq!(subunits, vec!["aaa", "bbb", "ccc"]);
+        assert_eq!(res.pagination.total, None);
+
+        let res: assetft::TokensResponse = query(
+            &deps,
+            assetft::Query::Tokens {
+                pagination: Some(PageRequest {
+                    key: None,
+                    offset: Some(1),
+                    limit: Some(1),
+                    count_total: Some(true),
+                    reverse: Some(true),
+                }),
+                issuer: ISSUER.to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.tokens, vec![token("bbb", ISSUER)]);
+        assert_eq!(res.pagination.next_key, None);
+        assert_eq!(res.pagination.total, Some(3));
+    }
+
+    #[test]
+    fn balances_default_to_zero() {
+        let denom = token("abc", ISSUER).denom;
+        let deps = mock_dependencies(
+            CoreumMockQuerier::new()
+                .with_token(token("abc", ISSUER))
+                .with_balance(HOLDER, coin(100, &denom))
+                .with_frozen(HOLDER, coin(30, &denom)),
+        );
+
+        let res: assetft::BalanceResponse = query(
+            &deps,
+            assetft::Query::Balance {
+                account: HOLDER.to_string(),
+                denom: denom.clone(),
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            (res.balance, res.frozen, res.whitelisted, res.locked),
+            ("100".into(), "30".into(), "0".into(), "0".into())
+        );
+
+        let res: assetft::WhitelistedBalanceResponse = query(
+            &deps,
+            assetft::Query::WhitelistedBalance {
+                account: "stranger".to_string(),
+                denom: denom.clone(),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.balance, coin(0, &denom));
+    }
+
+    #[test]
+    fn unknown_tokens_fail() {
+        let deps = mock_dependencies(CoreumMockQuerier::new());
+        let err = query::<assetft::TokenResponse>(
+            &deps,
+            assetft::Query::Token {
+                denom: "abc-issuer".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("token with denom abc-issuer not found"));
+    }
+
+    #[test]
+    fn params_are_configurable() {
+        let deps = mock_dependencies(CoreumMockQuerier::new());
+        let res: AssetFTParamsResponse = query(&deps, assetft::Query::Params {}).unwrap();
+        assert_eq!(res.params.issue_fee, coin(10_000_000, "udevcore"));
+
+        let deps = mock_dependencies(
+            CoreumMockQuerier::new()
+                .with_ft_params(AssetFTParams::with_issue_fee(coin(5, "ucore"))),
+        );
+        let res: AssetFTParamsResponse = query(&deps, assetft::Query::Params {}).unwrap();
+        assert_eq!(res.params.issue_fee, coin(5, "ucore"));
+    }
+
+    #[test]
+    fn account_balances_are_answered_besides_the_custom_queries() {
+        let abc = token("abc", ISSUER).denom;
+        let xyz = token("xyz", ISSUER).denom;
+        let deps = mock_dependencies(
+            CoreumMockQuerier::new()
+                .with_balance(HOLDER, coin(100, &abc))
+                .with_frozen(HOLDER, coin(150, &abc))
+                .with_balance(HOLDER, coin(7, &xyz))
+                .with_whitelisted(HOLDER, coin(10, &xyz))
+                .with_balance("other", coin(1, &abc)),
+        );
+        let deps = Deps {
+            storage: &deps.storage,
+            api: &deps.api,
+            querier: QuerierWrapper::new(&deps.querier),
+        };
+
+        let res = query_account_balances(deps, HOLDER, None).unwrap();
+        assert_eq!(
+            res.balances,
+            vec![
+                AccountBalance {
+                    denom: abc,
+                    balance: Uint128::new(100),
+                    // frozen above the balance leaves nothing spendable
+                    spendable: Uint128::zero(),
+                    frozen: Uint128::new(150),
+                    whitelisted: Uint128::zero(),
+                    locked: Uint128::zero(),
+                },
+                AccountBalance {
+                    denom: xyz,
+                    balance: Uint128::new(7),
+                    spendable: Uint128::new(7),
+                    frozen: Uint128::zero(),
+                    whitelisted: Uint128::new(10),
+                    locked: Uint128::zero(),
+                },
+            ]
+        );
+        assert!(query_account_balances(deps, "nobody", None)
+            .unwrap()
+            .balances
+            .is_empty());
+    }
+
+    #[test]
+    fn nft_queries_need_a_registered_class() {
+        let class = Class {
+            id: "punk-issuer".to_string(),
+            issuer: ISSUER.to_string(),
+            name: "punk".to_string(),
+            symbol: "punk".to_string(),
+            description: None,
+            uri: None,
+            uri_hash: None,
+            data: None,
+            features: None,
+            royalty_rate: None,
+        };
+        let deps = mock_dependencies(
+            CoreumMockQuerier::new()
+                .with_class(class)
+                .with_frozen_nft("punk-issuer", "1")
+                .with_whitelisted_nft("punk-issuer", "2", HOLDER),
+        );
+        let wrapper = QuerierWrapper::<CoreumQueries>::new(&deps.querier);
+        let nft = |query| CoreumQueries::AssetNFT(query).into();
+
+        let res: assetnft::FrozenResponse = wrapper
+            .query(&nft(assetnft::Query::Frozen {
+                id: "1".to_string(),
+                class_id: "punk-issuer".to_string(),
+            }))
+            .unwrap();
+        assert!(res.frozen);
+        let res: assetnft::WhitelistedResponse = wrapper
+            .query(&nft(assetnft::Query::Whitelisted {
+                id: "2".to_string(),
+                class_id: "punk-issuer".to_string(),
+                account: HOLDER.to_string(),
+            }))
+            .unwrap();
+        assert!(res.whitelisted);
+        assert!(wrapper
+            .query::<assetnft::FrozenResponse>(&nft(assetnft::Query::Frozen {
+                id: "1".to_string(),
+                class_id: "other".to_string(),
+            }))
+            .is_err());
+    }
+}
@@ -1,40 +1,341 @@
+pub use common::issue_result::IssueResult;
+pub use common::raw_range::{RawEntry, RawRangeResponse};
+pub use common::split::SplitRecipient;
+use coreum_wasm_sdk::pagination::PageResponse;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Uint128;
+use cosmwasm_schema::schemars::JsonSchema;
+use cosmwasm_std::{Binary, Coin, Uint128};
+use serde::{Deserialize, Serialize};
 
+use crate::error::ContractError;
+use crate::feature::TokenFeature;
+
+// latest InstantiateMsg layout, payloads without a version are treated as version 1
+pub const INSTANTIATE_MSG_VERSION: u8 = 2;
+
+// precision of version 1 payloads, which may omit it
+pub const DEFAULT_PRECISION: u32 = 6;
+
+// How the contract sends its assetft messages. Custom goes through the CoreumMsg bindings,
+// Stargate encodes the coreum.asset.ft.v1 protos itself; both must end in the same state.
+#[cw_serde]
+#[derive(Default)]
+pub enum MsgEncoding {
+    #[default]
+    Custom,
+    Stargate,
+}
+
+// Wire format accepted by instantiate. Version 1 payloads may omit everything but the symbol,
+// subunit and initial amount, version 2 payloads must carry the precision.
 #[cw_serde]
 pub struct InstantiateMsg {
+    pub version: Option<u8>,
+    pub symbol: String,
+    pub subunit: String,
+    pub precision: Option<u32>,
+    pub initial_amount: Uint128,
+    pub description: Option<String>,
+    pub features: Option<Vec<TokenFeature>>,
+    pub burn_rate: Option<String>,
+    pub send_commission_rate: Option<String>,
+    pub encoding: Option<MsgEncoding>,
+}
+
+// InstantiateMsg with the version specific defaults applied
+#[cw_serde]
+pub struct InstantiateMsgV2 {
     pub symbol: String,
     pub subunit: String,
     pub precision: u32,
     pub initial_amount: Uint128,
     pub description: Option<String>,
-    pub features: Option<Vec<u32>>,
+    pub features: Vec<TokenFeature>,
     pub burn_rate: Option<String>,
     pub send_commission_rate: Option<String>,
+    pub encoding: MsgEncoding,
+}
+
+impl InstantiateMsgV2 {
+    /// Applies the defaults of version 1 payloads: a precision of 6, no features and
+    /// custom messages.
+    pub fn from_legacy(msg: InstantiateMsg) -> Self {
+        Self {
+            symbol: msg.symbol,
+            subunit: msg.subunit,
+            precision: msg.precision.unwrap_or(DEFAULT_PRECISION),
+            initial_amount: msg.initial_amount,
+            description: msg.description,
+            features: msg.features.unwrap_or_default(),
+            burn_rate: msg.burn_rate,
+            send_commission_rate: msg.send_commission_rate,
+            encoding: msg.encoding.unwrap_or_default(),
+        }
+    }
+}
+
+impl TryFrom<InstantiateMsg> for InstantiateMsgV2 {
+    type Error = ContractError;
+
+    fn try_from(msg: InstantiateMsg) -> Result<Self, ContractError> {
+        match msg.version.unwrap_or(1) {
+            1 => Ok(Self::from_legacy(msg)),
+            2 => {
+                if msg.precision.is_none() {
+                    return Err(ContractError::MissingField {
+                        field: "precision".to_string(),
+                    });
+                }
+                Ok(Self::from_legacy(msg))
+            }
+            version => Err(ContractError::UnsupportedVersion {
+                version,
+                latest: INSTANTIATE_MSG_VERSION,
+            }),
+        }
+    }
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
-    Mint { amount: u128 },
-    Burn { amount: u128 },
-    Freeze { account: String, amount: u128 },
-    Unfreeze { account: String, amount: u128 },
+    Mint {
+        amount: u128,
+    },
+    // burns the token of the contract unless another denom is given, which the contract must
+    // have issued too
+    Burn {
+        amount: u128,
+        denom: Option<String>,
+    },
+    Freeze {
+        account: String,
+        amount: u128,
+    },
+    Unfreeze {
+        account: String,
+        amount: u128,
+    },
+    // sets the frozen amount of the account instead of changing it
+    SetFrozen {
+        account: String,
+        amount: u128,
+    },
     GloballyFreeze {},
     GloballyUnfreeze {},
-    SetWhitelistedLimit { account: String, amount: u128 },
+    SetWhitelistedLimit {
+        account: String,
+        amount: u128,
+    },
     // custom message we use to show the submission of multiple messages
-    MintAndSend { account: String, amount: u128 },
-    UpgradeTokenV1 { ibc_enabled: bool },
+    MintAndSend {
+        account: String,
+        amount: u128,
+    },
+    // sends the amount from the contract balance to the account
+    SendTo {
+        account: String,
+        amount: u128,
+    },
+    UpgradeTokenV1 {
+        ibc_enabled: bool,
+    },
+    // owner only, reserves a symbol without revealing it, hash is sha256(symbol || salt || sender)
+    CommitIssue {
+        hash: Binary,
+    },
+    // issues the committed token, allowed between the minimum and maximum delay after the commit
+    RevealIssue {
+        symbol: String,
+        subunit: String,
+        precision: u32,
+        salt: Binary,
+    },
+    // sends the amount from the contract balance, divided proportionally to the weights
+    SplitSend {
+        amount: u128,
+        recipients: Vec<SplitRecipient>,
+    },
 }
 
 #[cw_serde]
 pub enum QueryMsg {
     Params {},
     Token {},
-    Tokens { issuer: String },
-    Balance { account: String },
-    FrozenBalances { account: String },
-    FrozenBalance { account: String },
-    WhitelistedBalances { account: String },
-    WhitelistedBalance { account: String },
+    Tokens {
+        issuer: String,
+    },
+    Balance {
+        account: String,
+    },
+    FrozenBalances {
+        account: String,
+    },
+    FrozenBalance {
+        account: String,
+    },
+    WhitelistedBalances {
+        account: String,
+    },
+    WhitelistedBalance {
+        account: String,
+    },
+    // amounts of every denom held by the account, not just the token of the contract
+    Balances {
+        account: String,
+    },
+    // fees of a send of the token between two accounts, as predicted from its rates
+    SimulateSend {
+        amount: Uint128,
+    },
+    // largest amount a send of the token delivers when the sender can spend at most spendable
+    NetReceiveAmount {
+        spendable: Uint128,
+    },
+    // dumps raw storage entries, only compiled into test builds
+    #[cfg(feature = "debug")]
+    RawRange {
+        start: Option<Binary>,
+        end: Option<Binary>,
+        limit: u32,
+    },
+}
+
+// Params of x/asset/ft as the chain answers the Params query, the bindings only decode the issue
+// fee. The decision timeout is an RFC 3339 time and the grace period is in nanoseconds, which is
+// how Go encodes time.Time and time.Duration. Unknown fields are accepted, unlike with cw_serde,
+// so params added by the chain don't break the query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[schemars(crate = "cosmwasm_schema::schemars")]
+pub struct AssetFTParams {
+    pub issue_fee: Coin,
+    pub token_upgrade_decision_timeout: String,
+    pub token_upgrade_grace_period: u64,
+}
+
+impl AssetFTParams {
+    /// Params with the issue fee and the defaults of the chain for the token upgrade: no decision
+    /// timeout set and a grace period of a week.
+    pub fn with_issue_fee(issue_fee: Coin) -> Self {
+        Self {
+            issue_fee,
+            token_upgrade_decision_timeout: "0001-01-01T00:00:00Z".to_string(),
+            token_upgrade_grace_period: 7 * 24 * 60 * 60 * 1_000_000_000,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[schemars(crate = "cosmwasm_schema::schemars")]
+pub struct AssetFTParamsResponse {
+    pub params: AssetFTParams,
+}
+
+// Amounts of a denom on an account as the Balances query answers them. Spendable is what a send
+// can take, the balance less the larger of the frozen and the vesting locked amount.
+#[cw_serde]
+pub struct AccountBalance {
+    pub denom: String,
+    pub balance: Uint128,
+    pub spendable: Uint128,
+    pub frozen: Uint128,
+    pub whitelisted: Uint128,
+    pub locked: Uint128,
+}
+
+#[cw_serde]
+pub struct AccountBalancesResponse {
+    pub pagination: PageResponse,
+    pub balances: Vec<AccountBalance>,
+}
+
+#[cw_serde]
+pub struct SimulateSendResponse {
+    pub burn_amount: Uint128,
+    pub commission_amount: Uint128,
+    pub total_spent: Uint128,
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, to_json_vec};
+
+    use super::*;
+
+    const V1: &str = include_str!("../testdata/instantiate_msg_v1.json");
+    const V2: &str = include_str!("../testdata/instantiate_msg_v2.json");
+
+    // parses the fixture and checks it survives a serialization round trip unchanged
+    fn round_trip(fixture: &str) -> InstantiateMsg {
+        let msg: InstantiateMsg = from_json(fixture.trim().as_bytes()).unwrap();
+        let encoded = to_json_vec(&msg).unwrap();
+        assert_eq!(from_json::<InstantiateMsg>(&encoded).unwrap(), msg);
+        msg
+    }
+
+    #[test]
+    fn v1_payload_gets_the_defaults() {
+        let msg = round_trip(V1);
+        assert_eq!(msg.version, None);
+        assert_eq!(
+            InstantiateMsgV2::try_from(msg).unwrap(),
+            InstantiateMsgV2 {
+                symbol: "ABC".to_string(),
+                subunit: "uabc".to_string(),
+                precision: DEFAULT_PRECISION,
+                initial_amount: Uint128::new(1000),
+                description: None,
+                features: vec![],
+                burn_rate: None,
+                send_commission_rate: None,
+                encoding: MsgEncoding::Custom,
+            }
+        );
+    }
+
+    #[test]
+    fn v2_payload_is_kept() {
+        let msg = round_trip(V2);
+        assert_eq!(
+            InstantiateMsgV2::try_from(msg).unwrap(),
+            InstantiateMsgV2 {
+                symbol: "ABC".to_string(),
+                subunit: "uabc".to_string(),
+                precision: 8,
+                initial_amount: Uint128::new(1000),
+                description: Some("abc token".to_string()),
+                features: vec![TokenFeature::Minting, TokenFeature::Freezing],
+                burn_rate: Some("0.1".to_string()),
+                send_commission_rate: Some("0.05".to_string()),
+                encoding: MsgEncoding::Stargate,
+            }
+        );
+    }
+
+    #[test]
+    fn v2_payload_needs_the_precision() {
+        let msg = InstantiateMsg {
+            version: Some(2),
+            ..round_trip(V1)
+        };
+        let err = InstantiateMsgV2::try_from(msg).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::MissingField { field } if field == "precision"
+        ));
+    }
+
+    #[test]
+    fn unknown_versions_are_rejected() {
+        for version in [0, INSTANTIATE_MSG_VERSION + 1] {
+            let msg = InstantiateMsg {
+                version: Some(version),
+                ..round_trip(V1)
+            };
+            let err = InstantiateMsgV2::try_from(msg).unwrap_err();
+            assert!(matches!(
+                err,
+                ContractError::UnsupportedVersion { version: found, latest: 2 } if found == version
+            ));
+        }
+    }
 }
@@ -0,0 +1,947 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Result as AnyResult};
+use coreum_wasm_sdk::assetft::{self, Token};
+use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries};
+use cosmwasm_schema::schemars::JsonSchema;
+use cosmwasm_std::{
+    coin, from_json, to_json_binary, to_json_vec, Addr, Api, BalanceResponse, BankMsg, BankQuery,
+    Binary, BlockInfo, Coin, CosmosMsg, CustomQuery, Decimal, Empty, Event, Order, Querier,
+    QuerierWrapper, QueryRequest, Storage, Uint128, WasmQuery,
+};
+use cw_multi_test::{AppResponse, Bank, BankKeeper, BankSudo, CosmosRouter, Module, SudoMsg};
+use cw_storage_plus::Map;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::events::{EVENT_FROZEN_AMOUNT_CHANGED, EVENT_ISSUED, EVENT_WHITELISTED_AMOUNT_CHANGED};
+use crate::feature::TokenFeature;
+use crate::mock::paginate;
+use crate::msg::{AssetFTParams, AssetFTParamsResponse};
+use crate::rates::{compute_burn_amount, compute_commission};
+
+// The assetft state lives in the app storage next to the other modules, so CoreumBankKeeper can
+// apply the token rules to the sends it executes. Amounts are keyed by (account, denom).
+const TOKENS: Map<&str, Token> = Map::new("coreum_assetft_tokens");
+const GLOBALLY_FROZEN: Map<&str, bool> = Map::new("coreum_assetft_globally_frozen");
+const FROZEN: Map<(&str, &str), Uint128> = Map::new("coreum_assetft_frozen");
+const WHITELISTED: Map<(&str, &str), Uint128> = Map::new("coreum_assetft_whitelisted");
+
+// version of the tokens issued by the module, UpgradeTokenV1 only applies to older ones
+const TOKEN_VERSION: u32 = 1;
+
+/// Custom module of a cw-multi-test App simulating the assetft messages and queries of the
+/// Coreum bindings. Burn rate, send commission, freezing and whitelisting are applied to bank
+/// sends, which needs CoreumBankKeeper registered as the bank module of the same App.
+/// The Balances query is not answered, the App only routes the queries of the CoreumQueries type.
+pub struct CoreumModule {
+    params: AssetFTParams,
+}
+
+impl Default for CoreumModule {
+    fn default() -> Self {
+        Self {
+            params: AssetFTParams::with_issue_fee(coin(0, "udevcore")),
+        }
+    }
+}
+
+impl CoreumModule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the fee burnt from the issuer on every issuance, nothing is charged by default.
+    pub fn with_issue_fee(mut self, issue_fee: Coin) -> Self {
+        self.params.issue_fee = issue_fee;
+        self
+    }
+
+    fn execute_ft<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: assetft::Msg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match msg {
+            assetft::Msg::Issue {
+                symbol,
+                subunit,
+                precision,
+                initial_amount,
+                description,
+                features,
+                burn_rate,
+                send_commission_rate,
+            } => {
+                let denom = format!("{subunit}-{sender}").to_lowercase();
+                if TOKENS.has(storage, &denom) {
+                    bail!("token with denom {denom} already exists: invalid input");
+                }
+                let token = Token {
+                    denom: denom.clone(),
+                    issuer: sender.to_string(),
+                    symbol,
+                    subunit,
+                    precision,
+                    description,
+                    features,
+                    burn_rate: sdk_dec(burn_rate)?,
+                    send_commission_rate: sdk_dec(send_commission_rate)?,
+                    version: TOKEN_VERSION,
+                };
+                TOKENS.save(storage, &denom, &token)?;
+
+                if !self.params.issue_fee.amount.is_zero() {
+                    router.execute(
+                        api,
+                        storage,
+                        block,
+                        sender.clone(),
+                        CosmosMsg::Bank(BankMsg::Burn {
+                            amount: vec![self.params.issue_fee.clone()],
+                        }),
+                    )?;
+                }
+                if !initial_amount.is_zero() {
+                    router.sudo(
+                        api,
+                        storage,
+                        block,
+                        SudoMsg::Bank(BankSudo::Mint {
+                            to_address: sender.to_string(),
+                            amount: vec![coin(initial_amount.u128(), &denom)],
+                        }),
+                    )?;
+                }
+
+                Ok(AppResponse {
+                    events: vec![issued_event(&token, initial_amount)?],
+                    data: None,
+                })
+            }
+            assetft::Msg::Mint { coin } => {
+                ensure_issuer_feature(storage, &sender, &coin.denom, TokenFeature::Minting)?;
+                router.sudo(
+                    api,
+                    storage,
+                    block,
+                    SudoMsg::Bank(BankSudo::Mint {
+                        to_address: sender.to_string(),
+                        amount: vec![coin],
+                    }),
+                )?;
+                Ok(AppResponse::default())
+            }
+            assetft::Msg::Burn { coin } => {
+                let token = load_token(storage, &coin.denom)?;
+                if token.issuer != sender.as_str() {
+                    ensure_feature(&token, TokenFeature::Burning)?;
+                    ensure_spendable(api, storage, router, block, &token, &sender, coin.amount)?;
+                }
+                router.execute(
+                    api,
+                    storage,
+                    block,
+                    sender,
+                    CosmosMsg::Bank(BankMsg::Burn { amount: vec![coin] }),
+                )
+            }
+            assetft::Msg::Freeze { account, coin } => {
+                let token =
+                    ensure_issuer_feature(storage, &sender, &coin.denom, TokenFeature::Freezing)?;
+                if token.issuer == account {
+                    bail!("issuer's balance can't be frozen: unauthorized");
+                }
+                let previous = amount(storage, FROZEN, &account, &coin.denom)?;
+                let current = previous.checked_add(coin.amount)?;
+                set_amount(storage, FROZEN, &account, &coin.denom, current)?;
+                amount_changed(
+                    EVENT_FROZEN_AMOUNT_CHANGED,
+                    &account,
+                    &coin.denom,
+                    previous,
+                    current,
+                )
+            }
+            assetft::Msg::Unfreeze { account, coin } => {
+                ensure_issuer_feature(storage, &sender, &coin.denom, TokenFeature::Freezing)?;
+                let previous = amount(storage, FROZEN, &account, &coin.denom)?;
+                if previous < coin.amount {
+                    bail!(
+                        "unfreeze request {coin} is greater than the available frozen balance \
+                         {previous}{}: insufficient funds",
+                        coin.denom
+                    );
+                }
+                let current = previous - coin.amount;
+                set_amount(storage, FROZEN, &account, &coin.denom, current)?;
+                amount_changed(
+                    EVENT_FROZEN_AMOUNT_CHANGED,
+                    &account,
+                    &coin.denom,
+                    previous,
+                    current,
+                )
+            }
+            assetft::Msg::GloballyFreeze { denom } => {
+                ensure_issuer_feature(storage, &sender, &denom, TokenFeature::Freezing)?;
+                GLOBALLY_FROZEN.save(storage, &denom, &true)?;
+                Ok(AppResponse::default())
+            }
+            assetft::Msg::GloballyUnfreeze { denom } => {
+                ensure_issuer_feature(storage, &sender, &denom, TokenFeature::Freezing)?;
+                GLOBALLY_FROZEN.remove(storage, &denom);
+                Ok(AppResponse::default())
+            }
+            assetft::Msg::SetWhitelistedLimit { account, coin } => {
+                let token = ensure_issuer_feature(
+                    storage,
+                    &sender,
+                    &coin.denom,
+                    TokenFeature::Whitelisting,
+                )?;
+                if token.issuer == account {
+                    bail!("issuer's balance can't be whitelisted: unauthorized");
+                }
+                let previous = amount(storage, WHITELISTED, &account, &coin.denom)?;
+                set_amount(storage, WHITELISTED, &account, &coin.denom, coin.amount)?;
+                amount_changed(
+                    EVENT_WHITELISTED_AMOUNT_CHANGED,
+                    &account,
+                    &coin.denom,
+                    previous,
+                    coin.amount,
+                )
+            }
+            assetft::Msg::UpgradeTokenV1 { denom, ibc_enabled } => {
+                let mut token = load_token(storage, &denom)?;
+                if token.issuer != sender.as_str() {
+                    bail!("only issuer can upgrade the denom: unauthorized");
+                }
+                if token.version >= TOKEN_VERSION {
+                    bail!("denom {denom} has been already upgraded to v1: unauthorized");
+                }
+                token.version = TOKEN_VERSION;
+                if ibc_enabled {
+                    token
+                        .features
+                        .get_or_insert_with(Vec::new)
+                        .push(TokenFeature::Ibc.into());
+                }
+                TOKENS.save(storage, &denom, &token)?;
+                Ok(AppResponse::default())
+            }
+        }
+    }
+
+    fn query_ft(
+        &self,
+        storage: &dyn Storage,
+        querier: &dyn Querier,
+        request: assetft::Query,
+    ) -> AnyResult<Binary> {
+        let res = match request {
+            assetft::Query::Params {} => to_json_binary(&AssetFTParamsResponse {
+                params: self.params.clone(),
+            })?,
+            assetft::Query::Tokens { pagination, issuer } => {
+                let tokens = TOKENS
+                    .range(storage, None, None, Order::Ascending)
+                    .map(|item| item.map(|(_, token)| token))
+                    .filter(|token| token.as_ref().map_or(true, |token| token.issuer == issuer))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let (tokens, pagination) = paginate(tokens, &pagination);
+                to_json_binary(&assetft::TokensResponse { pagination, tokens })?
+            }
+            assetft::Query::Token { denom } => to_json_binary(&assetft::TokenResponse {
+                token: load_token(storage, &denom)?,
+            })?,
+            assetft::Query::Balance { account, denom } => {
+                let balance =
+                    QuerierWrapper::<Empty>::new(querier).query_balance(&account, &denom)?;
+                to_json_binary(&assetft::BalanceResponse {
+                    balance: balance.amount.to_string(),
+                    whitelisted: amount(storage, WHITELISTED, &account, &denom)?.to_string(),
+                    frozen: amount(storage, FROZEN, &account, &denom)?.to_string(),
+                    locked: "0".to_string(),
+                })?
+            }
+            assetft::Query::FrozenBalances {
+                pagination,
+                account,
+            } => {
+                let (balances, pagination) =
+                    paginate(account_coins(storage, FROZEN, &account)?, &pagination);
+                to_json_binary(&assetft::FrozenBalancesResponse {
+                    pagination,
+                    balances,
+                })?
+            }
+            assetft::Query::FrozenBalance { account, denom } => {
+                let frozen = amount(storage, FROZEN, &account, &denom)?;
+                to_json_binary(&assetft::FrozenBalanceResponse {
+                    balance: coin(frozen.u128(), denom),
+                })?
+            }
+            assetft::Query::WhitelistedBalances {
+                pagination,
+                account,
+            } => {
+                let (balances, pagination) =
+                    paginate(account_coins(storage, WHITELISTED, &account)?, &pagination);
+                to_json_binary(&assetft::WhitelistedBalancesResponse {
+                    pagination,
+                    balances,
+                })?
+            }
+            assetft::Query::WhitelistedBalance { account, denom } => {
+                let whitelisted = amount(storage, WHITELISTED, &account, &denom)?;
+                to_json_binary(&assetft::WhitelistedBalanceResponse {
+                    balance: coin(whitelisted.u128(), denom),
+                })?
+            }
+        };
+        Ok(res)
+    }
+}
+
+impl Module for CoreumModule {
+    type ExecT = CoreumMsg;
+    type QueryT = CoreumQueries;
+    type SudoT = Empty;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: CoreumMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match msg {
+            CoreumMsg::AssetFT(msg) => self.execute_ft(api, storage, router, block, sender, msg),
+            msg => bail!("Unsupported Coreum message: {msg:?}"),
+        }
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        _api: &dyn Api,
+        _storage: &mut dyn Storage,
+        _router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        _block: &BlockInfo,
+        msg: Empty,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        bail!("Unsupported Coreum sudo message: {msg:?}")
+    }
+
+    fn query(
+        &self,
+        _api: &dyn Api,
+        storage: &dyn Storage,
+        querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: CoreumQueries,
+    ) -> AnyResult<Binary> {
+        match request {
+            CoreumQueries::AssetFT(request) => self.query_ft(storage, querier, request),
+            request => bail!("Unsupported Coreum query: {request:?}"),
+        }
+    }
+}
+
+/// Bank module applying the assetft rules of the tokens issued through CoreumModule to sends:
+/// global and account freezing, whitelisting limits, burn rate and send commission. Like on
+/// chain, sends from or to the issuer are exempt from all of them and sends from contracts from
+/// the rates.
+#[derive(Default)]
+pub struct CoreumBankKeeper {
+    inner: BankKeeper,
+}
+
+impl CoreumBankKeeper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // this is an "admin" function to let us adjust bank accounts in genesis
+    pub fn init_balance(
+        &self,
+        storage: &mut dyn Storage,
+        account: &Addr,
+        amount: Vec<Coin>,
+    ) -> AnyResult<()> {
+        self.inner.init_balance(storage, account, amount)
+    }
+}
+
+impl Bank for CoreumBankKeeper {}
+
+impl Module for CoreumBankKeeper {
+    type ExecT = BankMsg;
+    type QueryT = BankQuery;
+    type SudoT = BankSudo;
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        sender: Addr,
+        msg: BankMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        let BankMsg::Send {
+            to_address,
+            amount: coins,
+        } = msg
+        else {
+            return self.inner.execute(api, storage, router, block, sender, msg);
+        };
+
+        // the fees of every coin are charged after its amount was sent, so the checks of the
+        // recipient see the balance it ends up with
+        let mut events = vec![];
+        for coin in coins {
+            let Some(token) = TOKENS.may_load(storage, &coin.denom)? else {
+                let res = self.inner.execute(
+                    api,
+                    storage,
+                    router,
+                    block,
+                    sender.clone(),
+                    BankMsg::Send {
+                        to_address: to_address.clone(),
+                        amount: vec![coin],
+                    },
+                )?;
+                events.extend(res.events);
+                continue;
+            };
+
+            let from_issuer = token.issuer == sender.as_str();
+            let to_issuer = token.issuer == to_address;
+            let exempt =
+                from_issuer || to_issuer || is_contract(api, storage, router, block, &sender);
+            let (burn_amount, commission_amount) = if exempt {
+                (Uint128::zero(), Uint128::zero())
+            } else {
+                (
+                    compute_burn_amount(coin.amount, Decimal::from_str(&token.burn_rate)?),
+                    compute_commission(
+                        coin.amount,
+                        Decimal::from_str(&token.send_commission_rate)?,
+                    ),
+                )
+            };
+
+            if !from_issuer {
+                if GLOBALLY_FROZEN.has(storage, &token.denom) {
+                    bail!(
+                        "{} is globally frozen: token is globally frozen",
+                        token.denom
+                    );
+                }
+                let spent = coin.amount + burn_amount + commission_amount;
+                ensure_spendable(api, storage, router, block, &token, &sender, spent)?;
+            }
+            if !to_issuer && has_feature(&token, TokenFeature::Whitelisting) {
+                let balance = bank_balance(api, storage, router, block, &to_address, &token.denom)?;
+                let limit = amount(storage, WHITELISTED, &to_address, &token.denom)?;
+                if balance + coin.amount > limit {
+                    bail!(
+                        "balance whitelisted for {to_address} is not enough to receive {coin}, \
+                         current whitelisted balance: {limit}{}: whitelisted limit exceeded",
+                        token.denom
+                    );
+                }
+            }
+
+            let res = self.inner.execute(
+                api,
+                storage,
+                router,
+                block,
+                sender.clone(),
+                BankMsg::Send {
+                    to_address: to_address.clone(),
+                    amount: vec![coin.clone()],
+                },
+            )?;
+            events.extend(res.events);
+            if !burn_amount.is_zero() {
+                self.inner.execute(
+                    api,
+                    storage,
+                    router,
+                    block,
+                    sender.clone(),
+                    BankMsg::Burn {
+                        amount: vec![Coin::new(burn_amount.u128(), &token.denom)],
+                    },
+                )?;
+            }
+            if !commission_amount.is_zero() {
+                let res = self.inner.execute(
+                    api,
+                    storage,
+                    router,
+                    block,
+                    sender.clone(),
+                    BankMsg::Send {
+                        to_address: token.issuer.clone(),
+                        amount: vec![Coin::new(commission_amount.u128(), &token.denom)],
+                    },
+                )?;
+                events.extend(res.events);
+            }
+        }
+
+        Ok(AppResponse { events, data: None })
+    }
+
+    fn sudo<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        msg: BankSudo,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: std::fmt::Debug + Clone + PartialEq + JsonSchema + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        self.inner.sudo(api, storage, router, block, msg)
+    }
+
+    fn query(
+        &self,
+        api: &dyn Api,
+        storage: &dyn Storage,
+        querier: &dyn Querier,
+        block: &BlockInfo,
+        request: BankQuery,
+    ) -> AnyResult<Binary> {
+        self.inner.query(api, storage, querier, block, request)
+    }
+}
+
+fn load_token(storage: &dyn Storage, denom: &str) -> AnyResult<Token> {
+    match TOKENS.may_load(storage, denom)? {
+        Some(token) => Ok(token),
+        None => bail!("denom: {denom}: token not found"),
+    }
+}
+
+fn has_feature(token: &Token, feature: TokenFeature) -> bool {
+    token
+        .features
+        .as_ref()
+        .is_some_and(|features| features.contains(&feature.into()))
+}
+
+fn ensure_feature(token: &Token, feature: TokenFeature) -> AnyResult<()> {
+    if !has_feature(token, feature) {
+        bail!("feature {feature} is disabled: feature disabled");
+    }
+    Ok(())
+}
+
+// ensure_issuer_feature loads the token of an issuer only message and checks the sender may send it
+fn ensure_issuer_feature(
+    storage: &dyn Storage,
+    sender: &Addr,
+    denom: &str,
+    feature: TokenFeature,
+) -> AnyResult<Token> {
+    let token = load_token(storage, denom)?;
+    if token.issuer != sender.as_str() {
+        bail!(
+            "address {sender} is unauthorized to perform \"{feature}\" related operations: \
+             unauthorized"
+        );
+    }
+    ensure_feature(&token, feature)?;
+    Ok(token)
+}
+
+// ensure_spendable checks that spending the amount leaves the frozen part of the balance in place
+fn ensure_spendable<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    token: &Token,
+    account: &Addr,
+    spent: Uint128,
+) -> AnyResult<()>
+where
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    let balance = bank_balance(api, storage, router, block, account.as_str(), &token.denom)?;
+    let frozen = amount(storage, FROZEN, account.as_str(), &token.denom)?;
+    let available = balance.saturating_sub(frozen);
+    if available < spent {
+        bail!(
+            "{spent}{} is not available, available {available}{}: insufficient funds",
+            token.denom,
+            token.denom
+        );
+    }
+    Ok(())
+}
+
+fn bank_balance<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    address: &str,
+    denom: &str,
+) -> AnyResult<Uint128>
+where
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    let res: BalanceResponse = from_json(&router.query(
+        api,
+        storage,
+        block,
+        QueryRequest::Bank(BankQuery::Balance {
+            address: address.to_string(),
+            denom: denom.to_string(),
+        }),
+    )?)?;
+    Ok(res.amount.amount)
+}
+
+fn amount(
+    storage: &dyn Storage,
+    amounts: Map<(&str, &str), Uint128>,
+    account: &str,
+    denom: &str,
+) -> AnyResult<Uint128> {
+    Ok(amounts
+        .may_load(storage, (account, denom))?
+        .unwrap_or_default())
+}
+
+fn set_amount(
+    storage: &mut dyn Storage,
+    amounts: Map<(&str, &str), Uint128>,
+    account: &str,
+    denom: &str,
+    amount: Uint128,
+) -> AnyResult<()> {
+    if amount.is_zero() {
+        amounts.remove(storage, (account, denom));
+        return Ok(());
+    }
+    amounts.save(storage, (account, denom), &amount)?;
+    Ok(())
+}
+
+fn account_coins(
+    storage: &dyn Storage,
+    amounts: Map<(&str, &str), Uint128>,
+    account: &str,
+) -> AnyResult<Vec<Coin>> {
+    amounts
+        .prefix(account)
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, amount) = item?;
+            Ok(coin(amount.u128(), denom))
+        })
+        .collect()
+}
+
+// sdk_dec renders a rate like the chain stores sdk.Dec values, with all 18 decimal places
+fn sdk_dec(rate: Option<String>) -> AnyResult<String> {
+    let rate = match rate {
+        Some(rate) => Decimal::from_str(&rate)?,
+        None => Decimal::zero(),
+    };
+    let atomics = rate.atomics().u128();
+    let unit = 10u128.pow(Decimal::DECIMAL_PLACES);
+    Ok(format!("{}.{:018}", atomics / unit, atomics % unit))
+}
+
+// is_contract tells smart contracts apart from accounts, rates are not charged on their sends
+fn is_contract<ExecC, QueryC>(
+    api: &dyn Api,
+    storage: &dyn Storage,
+    router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+    block: &BlockInfo,
+    address: &Addr,
+) -> bool
+where
+    QueryC: CustomQuery + DeserializeOwned + 'static,
+{
+    let request = QueryRequest::Wasm(WasmQuery::ContractInfo {
+        contract_addr: address.to_string(),
+    });
+    router.query(api, storage, block, request).is_ok()
+}
+
+// typed events carry JSON encoded attribute values, see events.rs
+fn json(value: &impl Serialize) -> AnyResult<String> {
+    Ok(String::from_utf8(to_json_vec(value)?)?)
+}
+
+fn issued_event(token: &Token, initial_amount: Uint128) -> AnyResult<Event> {
+    Ok(Event::new(EVENT_ISSUED)
+        .add_attribute("denom", json(&token.denom)?)
+        .add_attribute("issuer", json(&token.issuer)?)
+        .add_attribute("symbol", json(&token.symbol)?)
+        .add_attribute("subunit", json(&token.subunit)?)
+        .add_attribute("precision", json(&token.precision)?)
+        .add_attribute("initial_amount", json(&initial_amount)?)
+        .add_attribute(
+            "description",
+            json(&token.description.clone().unwrap_or_default())?,
+        )
+        .add_attribute(
+            "features",
+            json(&token.features.clone().unwrap_or_default())?,
+        )
+        .add_attribute("burn_rate", json(&token.burn_rate)?)
+        .add_attribute("send_commission_rate", json(&token.send_commission_rate)?))
+}
+
+fn amount_changed(
+    event_type: &str,
+    account: &str,
+    denom: &str,
+    previous: Uint128,
+    current: Uint128,
+) -> AnyResult<AppResponse> {
+    let event = Event::new(event_type)
+        .add_attribute("account", json(&account)?)
+        .add_attribute("denom", json(&denom)?)
+        .add_attribute("previous_amount", json(&previous)?)
+        .add_attribute("current_amount", json(&current)?);
+    Ok(AppResponse {
+        events: vec![event],
+        data: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{MockApi, MockStorage};
+    use cosmwasm_std::{DepsMut, Env, Reply, Response};
+    use cw_multi_test::{App, BasicAppBuilder, ContractWrapper, Executor, WasmKeeper};
+
+    use super::*;
+    use crate::contract::{execute, instantiate, query, reply};
+    use crate::error::ContractError;
+    use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+    type CoreumApp = App<
+        CoreumBankKeeper,
+        MockApi,
+        MockStorage,
+        CoreumModule,
+        WasmKeeper<CoreumMsg, CoreumQueries>,
+    >;
+
+    const OWNER: &str = "owner";
+    const ALICE: &str = "alice";
+    const BOB: &str = "bob";
+
+    // the reply of the contract is generic over the querier, the App hands it the custom one
+    fn reply_custom(
+        deps: DepsMut<CoreumQueries>,
+        env: Env,
+        msg: Reply,
+    ) -> Result<Response<CoreumMsg>, ContractError> {
+        let deps = DepsMut {
+            storage: deps.storage,
+            api: deps.api,
+            querier: QuerierWrapper::new(&*deps.querier),
+        };
+        reply(deps, env, msg)
+    }
+
+    fn setup() -> (CoreumApp, Addr, String) {
+        let mut app: CoreumApp = BasicAppBuilder::<CoreumMsg, CoreumQueries>::new_custom()
+            .with_custom(CoreumModule::new())
+            .with_bank(CoreumBankKeeper::new())
+            .build(|_, _, _| {});
+        let code_id = app.store_code(Box::new(
+            ContractWrapper::new(execute, instantiate, query).with_reply(reply_custom),
+        ));
+        let contract = app
+            .instantiate_contract(
+                code_id,
+                Addr::unchecked(OWNER),
+                &InstantiateMsg {
+                    version: Some(2),
+                    symbol: "ABC".to_string(),
+                    subunit: "abc".to_string(),
+                    precision: Some(6),
+                    initial_amount: Uint128::new(1000),
+                    description: None,
+                    features: Some(vec![TokenFeature::Freezing, TokenFeature::Whitelisting]),
+                    burn_rate: Some("0.1".to_string()),
+                    send_commission_rate: Some("0.05".to_string()),
+                    encoding: None,
+                },
+                &[],
+                "ft",
+                None,
+            )
+            .unwrap();
+        let denom = format!("abc-{contract}");
+        (app, contract, denom)
+    }
+
+    fn balance(app: &CoreumApp, address: &str, denom: &str) -> u128 {
+        app.wrap()
+            .query_balance(address, denom)
+            .unwrap()
+            .amount
+            .u128()
+    }
+
+    fn exec(
+        app: &mut CoreumApp,
+        sender: &Addr,
+        contract: &Addr,
+        msg: &ExecuteMsg,
+    ) -> AnyResult<AppResponse> {
+        app.execute_contract(sender.clone(), contract.clone(), msg, &[])
+    }
+
+    fn set_whitelisted(app: &mut CoreumApp, contract: &Addr, account: &str, amount: u128) {
+        exec(
+            app,
+            &Addr::unchecked(OWNER),
+            contract,
+            &ExecuteMsg::SetWhitelistedLimit {
+                account: account.to_string(),
+                amount,
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn instantiation_issues_the_token() {
+        let (app, contract, denom) = setup();
+
+        let res: assetft::TokenResponse = app
+            .wrap()
+            .query_wasm_smart(&contract, &QueryMsg::Token {})
+            .unwrap();
+        assert_eq!(res.token.denom, denom);
+        assert_eq!(res.token.issuer, contract.as_str());
+        assert_eq!(res.token.burn_rate, "0.100000000000000000");
+        assert_eq!(res.token.send_commission_rate, "0.050000000000000000");
+        assert_eq!(balance(&app, contract.as_str(), &denom), 1000);
+    }
+
+    #[test]
+    fn sends_follow_the_whitelisting_limits() {
+        let (mut app, contract, denom) = setup();
+        let owner = Addr::unchecked(OWNER);
+        let send_to_alice = ExecuteMsg::SendTo {
+            account: ALICE.to_string(),
+            amount: 300,
+        };
+
+        // rejected by the contract before the bank keeper would revert the send
+        let err = exec(&mut app, &owner, &contract, &send_to_alice).unwrap_err();
+        assert!(err.root_cause().to_string().contains("Whitelisted limit"));
+
+        set_whitelisted(&mut app, &contract, ALICE, 300);
+        exec(&mut app, &owner, &contract, &send_to_alice).unwrap();
+        // sends of a contract are not charged the rates
+        assert_eq!(balance(&app, ALICE, &denom), 300);
+        assert_eq!(balance(&app, contract.as_str(), &denom), 700);
+
+        // the bank keeper applies the limit to sends between accounts too
+        let err = app
+            .send_tokens(
+                Addr::unchecked(ALICE),
+                Addr::unchecked(BOB),
+                &[coin(10, &denom)],
+            )
+            .unwrap_err();
+        assert!(err
+            .root_cause()
+            .to_string()
+            .contains("whitelisted limit exceeded"));
+    }
+
+    #[test]
+    fn sends_between_accounts_pay_the_rates_from_the_unfrozen_balance() {
+        let (mut app, contract, denom) = setup();
+        let owner = Addr::unchecked(OWNER);
+        set_whitelisted(&mut app, &contract, ALICE, 300);
+        set_whitelisted(&mut app, &contract, BOB, 1000);
+        exec(
+            &mut app,
+            &owner,
+            &contract,
+            &ExecuteMsg::SendTo {
+                account: ALICE.to_string(),
+                amount: 300,
+            },
+        )
+        .unwrap();
+        exec(
+            &mut app,
+            &owner,
+            &contract,
+            &ExecuteMsg::Freeze {
+                account: ALICE.to_string(),
+                amount: 200,
+            },
+        )
+        .unwrap();
+
+        // 90 plus 9 burnt and 4 of commission exceed the 100 unfrozen
+        let err = app
+            .send_tokens(
+                Addr::unchecked(ALICE),
+                Addr::unchecked(BOB),
+                &[coin(90, &denom)],
+            )
+            .unwrap_err();
+        assert!(err.root_cause().to_string().contains("insufficient funds"));
+
+        app.send_tokens(
+            Addr::unchecked(ALICE),
+            Addr::unchecked(BOB),
+            &[coin(80, &denom)],
+        )
+        .unwrap();
+        assert_eq!(balance(&app, BOB, &denom), 80);
+        // 8 burnt and 4 of commission sent to the issuer
+        assert_eq!(balance(&app, ALICE, &denom), 208);
+        assert_eq!(balance(&app, contract.as_str(), &denom), 704);
+    }
+}
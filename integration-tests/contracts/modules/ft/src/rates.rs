@@ -0,0 +1,63 @@
+use cosmwasm_std::{Decimal, Uint128, Uint256};
+
+// Burn rate and send commission of x/asset/ft, see ApplyRate in keeper/before_send.go. Both are
+// charged to the sender on top of the sent amount, each rounded up on its own, while the
+// recipient gets the sent amount. Sends from or to the issuer and sends from smart contracts are
+// exempt, which is for the caller to check.
+
+/// Amount burnt from the sender when sending the amount.
+pub fn compute_burn_amount(amount: Uint128, burn_rate: Decimal) -> Uint128 {
+    apply_rate(amount, burn_rate)
+}
+
+/// Amount the sender pays to the issuer as send commission when sending the amount.
+pub fn compute_commission(amount: Uint128, send_commission_rate: Decimal) -> Uint128 {
+    apply_rate(amount, send_commission_rate)
+}
+
+/// Amount leaving the sender's balance when sending the amount.
+pub fn total_spent_amount(
+    amount: Uint128,
+    burn_rate: Decimal,
+    send_commission_rate: Decimal,
+) -> Uint128 {
+    amount
+        + compute_burn_amount(amount, burn_rate)
+        + compute_commission(amount, send_commission_rate)
+}
+
+/// Largest amount a recipient can receive from a sender able to spend at most spendable, e.g.
+/// when the whole balance is sent.
+pub fn net_receive_amount(
+    spendable: Uint128,
+    burn_rate: Decimal,
+    send_commission_rate: Decimal,
+) -> Uint128 {
+    // dividing by 1 + rates is exact up to the two rounded up fees, so the estimate is off by at
+    // most two in either direction
+    let one = Uint256::from(Decimal::one().atomics());
+    let rates = Uint256::from(burn_rate.atomics()) + Uint256::from(send_commission_rate.atomics());
+    let estimate = Uint256::from(spendable) * one / (one + rates);
+    let mut amount = Uint128::try_from(estimate).unwrap_or(spendable);
+
+    let spent = |amount| total_spent_amount(amount, burn_rate, send_commission_rate);
+    while !amount.is_zero() && spent(amount) > spendable {
+        amount -= Uint128::one();
+    }
+    while amount < spendable && spent(amount + Uint128::one()) <= spendable {
+        amount += Uint128::one();
+    }
+    amount
+}
+
+// apply_rate is rate.MulInt(amount).Ceil().RoundInt() of the chain
+fn apply_rate(amount: Uint128, rate: Decimal) -> Uint128 {
+    if rate.is_zero() {
+        return Uint128::zero();
+    }
+    let one = Uint256::from(Decimal::one().atomics());
+    let scaled = Uint256::from(amount) * Uint256::from(rate.atomics());
+    let ceiled = (scaled + one - Uint256::one()) / one;
+    // rates above 1 are rejected by the chain, so the part never exceeds the amount
+    Uint128::try_from(ceiled).unwrap_or(Uint128::MAX)
+}
@@ -1,3 +1,19 @@
-use cw_storage_plus::Item;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Item, Map};
+
+use crate::msg::MsgEncoding;
 
 pub const DENOM: Item<String> = Item::new("state");
+
+// unset for contracts instantiated before the encoding could be chosen, which send custom messages
+pub const ENCODING: Item<MsgEncoding> = Item::new("encoding");
+
+// Symbol reservation made by CommitIssue, keyed by sha256(symbol || salt || sender)
+#[cw_serde]
+pub struct IssueCommit {
+    pub committer: Addr,
+    pub height: u64,
+}
+
+pub const ISSUE_COMMITS: Map<&[u8], IssueCommit> = Map::new("issue_commits");
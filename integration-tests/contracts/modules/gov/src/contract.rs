@@ -0,0 +1,195 @@
+use common::any_msg::AnyMsg;
+use common::protos::CosmosGov;
+use cosmwasm_std::{entry_point, Coin, Decimal, DepsMut, Env, MessageInfo, Response};
+use cw2::set_contract_version;
+use cw_ownable::{assert_owner, initialize_owner};
+use protobuf::well_known_types::any::Any;
+use protobuf::EnumOrUnknown;
+use CosmosGov::{MsgDeposit, MsgSubmitProposal, MsgVote, MsgVoteWeighted};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, ProposalMsg, VoteOption, WeightedVoteOption};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    initialize_owner(deps.storage, deps.api, Some(info.sender.as_ref()))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", info.sender))
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    match msg {
+        ExecuteMsg::SubmitProposal {
+            messages,
+            initial_deposit,
+            title,
+            summary,
+            metadata,
+        } => submit_proposal(env, messages, initial_deposit, title, summary, metadata),
+        ExecuteMsg::Vote {
+            proposal_id,
+            option,
+        } => vote(env, proposal_id, option),
+        ExecuteMsg::VoteWeighted {
+            proposal_id,
+            options,
+        } => vote_weighted(env, proposal_id, options),
+        ExecuteMsg::Deposit {
+            proposal_id,
+            amount,
+        } => deposit(env, proposal_id, amount),
+    }
+}
+
+// ********** Transactions **********
+
+fn submit_proposal(
+    env: Env,
+    messages: Vec<ProposalMsg>,
+    initial_deposit: Vec<Coin>,
+    title: String,
+    summary: String,
+    metadata: Option<String>,
+) -> Result<Response, ContractError> {
+    let mut proposal = MsgSubmitProposal::new();
+    proposal.messages = messages
+        .into_iter()
+        .map(|msg| Any {
+            type_url: msg.type_url,
+            value: msg.value.to_vec(),
+            ..Default::default()
+        })
+        .collect();
+    proposal.initial_deposit = proto_coins(&initial_deposit);
+    proposal.proposer = env.contract.address.to_string();
+    proposal.metadata = metadata.unwrap_or_default();
+    proposal.title = title.clone();
+    proposal.summary = summary;
+
+    Ok(Response::new()
+        .add_attribute("method", "submit_proposal")
+        .add_attribute("title", title)
+        .add_attribute("messages", proposal.messages.len().to_string())
+        .add_message(proposal.to_stargate()?))
+}
+
+fn vote(env: Env, proposal_id: u64, option: VoteOption) -> Result<Response, ContractError> {
+    let mut msg = MsgVote::new();
+    msg.proposal_id = proposal_id;
+    msg.voter = env.contract.address.to_string();
+    msg.option = proto_vote_option(option);
+
+    Ok(Response::new()
+        .add_attribute("method", "vote")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_message(msg.to_stargate()?))
+}
+
+fn vote_weighted(
+    env: Env,
+    proposal_id: u64,
+    options: Vec<WeightedVoteOption>,
+) -> Result<Response, ContractError> {
+    validate_weighted_options(&options)?;
+
+    let mut msg = MsgVoteWeighted::new();
+    msg.proposal_id = proposal_id;
+    msg.voter = env.contract.address.to_string();
+    msg.options = options
+        .iter()
+        .map(|option| CosmosGov::WeightedVoteOption {
+            option: proto_vote_option(option.option),
+            weight: option.weight.to_string(),
+            ..Default::default()
+        })
+        .collect();
+
+    Ok(Response::new()
+        .add_attribute("method", "vote_weighted")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_message(msg.to_stargate()?))
+}
+
+fn deposit(env: Env, proposal_id: u64, amount: Vec<Coin>) -> Result<Response, ContractError> {
+    if amount.is_empty() {
+        return Err(ContractError::EmptyDeposit {});
+    }
+
+    let mut msg = MsgDeposit::new();
+    msg.proposal_id = proposal_id;
+    msg.depositor = env.contract.address.to_string();
+    msg.amount = proto_coins(&amount);
+
+    Ok(Response::new()
+        .add_attribute("method", "deposit")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_message(msg.to_stargate()?))
+}
+
+// mirrors MsgVoteWeighted.ValidateBasic of x/gov
+fn validate_weighted_options(options: &[WeightedVoteOption]) -> Result<(), ContractError> {
+    let mut total = Decimal::zero();
+    for (i, option) in options.iter().enumerate() {
+        if option.weight.is_zero() {
+            return Err(invalid_weighted_vote("weights must be positive"));
+        }
+        if options[..i].iter().any(|seen| seen.option == option.option) {
+            return Err(invalid_weighted_vote("duplicate vote option"));
+        }
+        total += option.weight;
+    }
+    if total != Decimal::one() {
+        return Err(invalid_weighted_vote("weights must sum up to 1"));
+    }
+    Ok(())
+}
+
+fn invalid_weighted_vote(reason: &str) -> ContractError {
+    ContractError::InvalidWeightedVote {
+        reason: reason.to_string(),
+    }
+}
+
+fn proto_vote_option(option: VoteOption) -> EnumOrUnknown<CosmosGov::VoteOption> {
+    EnumOrUnknown::new(match option {
+        VoteOption::Yes => CosmosGov::VoteOption::VOTE_OPTION_YES,
+        VoteOption::Abstain => CosmosGov::VoteOption::VOTE_OPTION_ABSTAIN,
+        VoteOption::No => CosmosGov::VoteOption::VOTE_OPTION_NO,
+        VoteOption::NoWithVeto => CosmosGov::VoteOption::VOTE_OPTION_NO_WITH_VETO,
+    })
+}
+
+fn proto_coins(coins: &[Coin]) -> Vec<CosmosGov::Coin> {
+    coins
+        .iter()
+        .map(|coin| CosmosGov::Coin {
+            denom: coin.denom.clone(),
+            amount: coin.amount.to_string(),
+            ..Default::default()
+        })
+        .collect()
+}
@@ -0,0 +1,52 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, Coin, Decimal};
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+// the contract is the proposer, voter and depositor, deposits are paid from the funds it holds
+#[cw_serde]
+pub enum ExecuteMsg {
+    SubmitProposal {
+        messages: Vec<ProposalMsg>,
+        initial_deposit: Vec<Coin>,
+        title: String,
+        summary: String,
+        metadata: Option<String>,
+    },
+    Vote {
+        proposal_id: u64,
+        option: VoteOption,
+    },
+    // weights must be positive and sum up to 1
+    VoteWeighted {
+        proposal_id: u64,
+        options: Vec<WeightedVoteOption>,
+    },
+    Deposit {
+        proposal_id: u64,
+        amount: Vec<Coin>,
+    },
+}
+
+// proto encoded message executed by gov once the proposal passes
+#[cw_serde]
+pub struct ProposalMsg {
+    pub type_url: String,
+    pub value: Binary,
+}
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum VoteOption {
+    Yes,
+    Abstain,
+    No,
+    NoWithVeto,
+}
+
+#[cw_serde]
+pub struct WeightedVoteOption {
+    pub option: VoteOption,
+    pub weight: Decimal,
+}
@@ -0,0 +1,120 @@
+use coreum_wasm_sdk::assetft;
+use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries, CoreumResult};
+use cosmwasm_std::{
+    coin, entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdResult, Uint128,
+};
+use cw2::set_contract_version;
+
+use crate::error::ContractError;
+use crate::msg::{DenomResponse, ExecuteMsg, InstantiateMsg, PendingResponse, QueryMsg};
+use crate::state::{PendingMsg, DENOM, NEXT_ID, QUEUE};
+
+// version info for migration info, the name is shared by every version of the contract so the
+// migration recognizes it
+pub const CONTRACT_NAME: &str = "migration";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// feature 0 of x/asset/ft
+const FEATURE_MINTING: u32 = 0;
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> CoreumResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // the issue fee is paid from the funds sent with the instantiation
+    let issue_msg = CoreumMsg::AssetFT(assetft::Msg::Issue {
+        symbol: msg.symbol,
+        subunit: msg.subunit.clone(),
+        precision: 6,
+        initial_amount: Uint128::zero(),
+        description: None,
+        features: Some(vec![FEATURE_MINTING]),
+        burn_rate: None,
+        send_commission_rate: None,
+    });
+    let denom = format!("{}-{}", msg.subunit, env.contract.address).to_lowercase();
+
+    DENOM.save(deps.storage, &denom)?;
+    NEXT_ID.save(deps.storage, &0)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("denom", denom)
+        .add_message(issue_msg))
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<CoreumQueries>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> CoreumResult<ContractError> {
+    match msg {
+        ExecuteMsg::Enqueue { amount } => enqueue(deps, amount),
+        ExecuteMsg::Flush {} => flush(deps),
+    }
+}
+
+// ********** Transactions **********
+
+fn enqueue(deps: DepsMut<CoreumQueries>, amount: Uint128) -> CoreumResult<ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    let id = NEXT_ID.load(deps.storage)?;
+    NEXT_ID.save(deps.storage, &(id + 1))?;
+    QUEUE.save(deps.storage, id, &PendingMsg::Mint { amount })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "enqueue")
+        .add_attribute("id", id.to_string()))
+}
+
+fn flush(deps: DepsMut<CoreumQueries>) -> CoreumResult<ContractError> {
+    let denom = DENOM.load(deps.storage)?;
+    let pending = QUEUE
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut msgs = Vec::with_capacity(pending.len());
+    for (id, msg) in pending {
+        QUEUE.remove(deps.storage, id);
+        msgs.push(match msg {
+            PendingMsg::Mint { amount } => CoreumMsg::AssetFT(assetft::Msg::Mint {
+                coin: coin(amount.u128(), denom.clone()),
+            }),
+        });
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "flush")
+        .add_attribute("count", msgs.len().to_string())
+        .add_messages(msgs))
+}
+
+// ********** Queries **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<CoreumQueries>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Pending {} => to_binary(&PendingResponse {
+            count: QUEUE
+                .keys(deps.storage, None, None, Order::Ascending)
+                .count() as u64,
+        }),
+        QueryMsg::Denom {} => to_binary(&DenomResponse {
+            denom: DENOM.load(deps.storage)?,
+        }),
+    }
+}
@@ -0,0 +1,14 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+use cw_storage_plus::{Item, Map};
+
+// Layout of the queued messages in this version, stored as {"mint":{"amount":"100"}}. Version 2
+// of the contract stores full custom messages instead and migrates the entries left by this one.
+#[cw_serde]
+pub enum PendingMsg {
+    Mint { amount: Uint128 },
+}
+
+pub const DENOM: Item<String> = Item::new("denom");
+pub const NEXT_ID: Item<u64> = Item::new("next_id");
+pub const QUEUE: Map<u64, PendingMsg> = Map::new("queue");
@@ -0,0 +1,301 @@
+use coreum_wasm_sdk::assetft;
+use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries, CoreumResult};
+use cosmwasm_std::{
+    coin, entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response,
+    StdResult, Uint128,
+};
+use cw2::{get_contract_version, set_contract_version};
+
+use crate::error::ContractError;
+use crate::msg::{
+    DenomResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, PendingResponse, QueryMsg,
+};
+use crate::state::{DENOM, NEXT_ID, QUEUE};
+use crate::versioned::StoredMsg;
+
+// version info for migration info, the name is shared by every version of the contract so the
+// migration recognizes it
+pub const CONTRACT_NAME: &str = "migration";
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// feature 0 of x/asset/ft
+const FEATURE_MINTING: u32 = 0;
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> CoreumResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    // the issue fee is paid from the funds sent with the instantiation
+    let issue_msg = CoreumMsg::AssetFT(assetft::Msg::Issue {
+        symbol: msg.symbol,
+        subunit: msg.subunit.clone(),
+        precision: 6,
+        initial_amount: Uint128::zero(),
+        description: None,
+        features: Some(vec![FEATURE_MINTING]),
+        burn_rate: None,
+        send_commission_rate: None,
+    });
+    let denom = format!("{}-{}", msg.subunit, env.contract.address).to_lowercase();
+
+    DENOM.save(deps.storage, &denom)?;
+    NEXT_ID.save(deps.storage, &0)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("denom", denom)
+        .add_message(issue_msg))
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<CoreumQueries>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> CoreumResult<ContractError> {
+    match msg {
+        ExecuteMsg::Enqueue { amount } => enqueue(deps, amount),
+        ExecuteMsg::Flush {} => flush(deps),
+    }
+}
+
+// ********** Transactions **********
+
+fn enqueue(deps: DepsMut<CoreumQueries>, amount: Uint128) -> CoreumResult<ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::InvalidZeroAmount {});
+    }
+    let denom = DENOM.load(deps.storage)?;
+    let id = NEXT_ID.load(deps.storage)?;
+    NEXT_ID.save(deps.storage, &(id + 1))?;
+    let msg = CoreumMsg::AssetFT(assetft::Msg::Mint {
+        coin: coin(amount.u128(), denom),
+    });
+    QUEUE.save(deps.storage, id, &StoredMsg::latest(msg))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "enqueue")
+        .add_attribute("id", id.to_string()))
+}
+
+fn flush(deps: DepsMut<CoreumQueries>) -> CoreumResult<ContractError> {
+    let denom = DENOM.load(deps.storage)?;
+    let pending = QUEUE
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    // entries not migrated yet are still dispatched, in either layout
+    let mut msgs = Vec::with_capacity(pending.len());
+    for (id, msg) in pending {
+        QUEUE.remove(deps.storage, id);
+        msgs.push(msg.into_latest(&denom)?.msg);
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "flush")
+        .add_attribute("count", msgs.len().to_string())
+        .add_messages(msgs))
+}
+
+// ********** Migrate **********
+
+// migrate rewrites the queued messages of version 1 into the versioned layout.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(
+    deps: DepsMut<CoreumQueries>,
+    _env: Env,
+    _msg: MigrateMsg,
+) -> CoreumResult<ContractError> {
+    let previous = get_contract_version(deps.storage)?;
+    if previous.contract != CONTRACT_NAME {
+        return Err(ContractError::InvalidMigrationSource {
+            name: previous.contract,
+        });
+    }
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let denom = DENOM.load(deps.storage)?;
+    let legacy = QUEUE
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| item.as_ref().map_or(true, |(_, msg)| msg.is_legacy()))
+        .collect::<StdResult<Vec<_>>>()?;
+    let migrated = legacy.len();
+    for (id, msg) in legacy {
+        QUEUE.save(
+            deps.storage,
+            id,
+            &StoredMsg::Versioned(msg.into_latest(&denom)?),
+        )?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "migrate")
+        .add_attribute("from_version", previous.version)
+        .add_attribute("to_version", CONTRACT_VERSION)
+        .add_attribute("migrated", migrated.to_string()))
+}
+
+// ********** Queries **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<CoreumQueries>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Pending {} => to_binary(&query_pending(deps)?),
+        QueryMsg::Denom {} => to_binary(&DenomResponse {
+            denom: DENOM.load(deps.storage)?,
+        }),
+    }
+}
+
+fn query_pending(deps: Deps<CoreumQueries>) -> StdResult<PendingResponse> {
+    let mut res = PendingResponse {
+        count: 0,
+        legacy: 0,
+    };
+    for item in QUEUE.range(deps.storage, None, None, Order::Ascending) {
+        let (_, msg) = item?;
+        res.count += 1;
+        if msg.is_legacy() {
+            res.legacy += 1;
+        }
+    }
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+    use cosmwasm_std::{from_binary, CosmosMsg, OwnedDeps};
+
+    use super::*;
+
+    const CREATOR: &str = "creator";
+
+    type TestDeps = OwnedDeps<MockStorage, MockApi, MockQuerier<CoreumQueries>, CoreumQueries>;
+
+    fn deps() -> TestDeps {
+        OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier: MockQuerier::new(&[]),
+            custom_query_type: Default::default(),
+        }
+    }
+
+    // a contract instantiated by version 1 with mints of the amounts queued
+    fn v1_deps(amounts: &[u128]) -> TestDeps {
+        let mut deps = deps();
+        let info = mock_info(CREATOR, &[]);
+        let msg = migration_v1::msg::InstantiateMsg {
+            symbol: "MIG".to_string(),
+            subunit: "umig".to_string(),
+        };
+        migration_v1::contract::instantiate(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+        for amount in amounts {
+            let msg = migration_v1::msg::ExecuteMsg::Enqueue {
+                amount: Uint128::new(*amount),
+            };
+            migration_v1::contract::execute(deps.as_mut(), mock_env(), info.clone(), msg).unwrap();
+        }
+        deps
+    }
+
+    fn denom() -> String {
+        format!("umig-{}", mock_env().contract.address)
+    }
+
+    fn pending(deps: &TestDeps) -> PendingResponse {
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Pending {}).unwrap()).unwrap()
+    }
+
+    fn exec(deps: &mut TestDeps, msg: ExecuteMsg) -> CoreumResult<ContractError> {
+        execute(deps.as_mut(), mock_env(), mock_info(CREATOR, &[]), msg)
+    }
+
+    fn mint(amount: u128) -> CosmosMsg<CoreumMsg> {
+        CosmosMsg::Custom(CoreumMsg::AssetFT(assetft::Msg::Mint {
+            coin: coin(amount, denom()),
+        }))
+    }
+
+    #[test]
+    fn migrates_the_v1_queue() {
+        let mut deps = v1_deps(&[100, 200]);
+        assert_eq!(
+            pending(&deps),
+            PendingResponse {
+                count: 2,
+                legacy: 2
+            }
+        );
+
+        let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "migrated" && attr.value == "2"));
+        assert_eq!(
+            get_contract_version(&deps.storage).unwrap().version,
+            CONTRACT_VERSION
+        );
+        assert_eq!(
+            pending(&deps),
+            PendingResponse {
+                count: 2,
+                legacy: 0
+            }
+        );
+
+        // the migrated entries are flushed with the ones queued after the migration
+        exec(
+            &mut deps,
+            ExecuteMsg::Enqueue {
+                amount: Uint128::new(300),
+            },
+        )
+        .unwrap();
+        let res = exec(&mut deps, ExecuteMsg::Flush {}).unwrap();
+        let msgs: Vec<_> = res.messages.into_iter().map(|sub| sub.msg).collect();
+        assert_eq!(msgs, vec![mint(100), mint(200), mint(300)]);
+        assert_eq!(pending(&deps).count, 0);
+    }
+
+    #[test]
+    fn migrating_twice_finds_nothing_to_rewrite() {
+        let mut deps = v1_deps(&[100]);
+        migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        let res = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap();
+        assert!(res
+            .attributes
+            .iter()
+            .any(|attr| attr.key == "migrated" && attr.value == "0"));
+    }
+
+    #[test]
+    fn unmigrated_v1_entries_are_still_flushed() {
+        let mut deps = v1_deps(&[100]);
+        let res = exec(&mut deps, ExecuteMsg::Flush {}).unwrap();
+        assert_eq!(res.messages[0].msg, mint(100));
+    }
+
+    #[test]
+    fn migration_needs_the_same_contract() {
+        let mut deps = deps();
+        set_contract_version(&mut deps.storage, "other", "0.1.0").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), MigrateMsg {}).unwrap_err();
+        assert!(matches!(
+            err,
+            ContractError::InvalidMigrationSource { name } if name == "other"
+        ));
+    }
+}
@@ -0,0 +1,17 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Invalid zero amount")]
+    InvalidZeroAmount {},
+
+    #[error("Cannot migrate from contract {name}")]
+    InvalidMigrationSource { name: String },
+
+    #[error("Unsupported stored message version {version}, latest is {latest}")]
+    UnsupportedVersion { version: u8, latest: u8 },
+}
@@ -0,0 +1,37 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Uint128;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub symbol: String,
+    pub subunit: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // queues a mint of the amount to the contract
+    Enqueue { amount: Uint128 },
+    // dispatches the queued messages and clears the queue
+    Flush {},
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
+
+#[cw_serde]
+pub enum QueryMsg {
+    Pending {},
+    Denom {},
+}
+
+#[cw_serde]
+pub struct PendingResponse {
+    pub count: u64,
+    // entries still in the layout of version 1, none once the migration ran
+    pub legacy: u64,
+}
+
+#[cw_serde]
+pub struct DenomResponse {
+    pub denom: String,
+}
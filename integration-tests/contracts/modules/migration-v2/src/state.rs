@@ -0,0 +1,8 @@
+use cw_storage_plus::{Item, Map};
+
+use crate::versioned::StoredMsg;
+
+pub const DENOM: Item<String> = Item::new("denom");
+pub const NEXT_ID: Item<u64> = Item::new("next_id");
+// the namespace of version 1, whose entries are read in either layout
+pub const QUEUE: Map<u64, StoredMsg> = Map::new("queue");
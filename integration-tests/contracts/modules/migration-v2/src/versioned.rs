@@ -0,0 +1,68 @@
+use coreum_wasm_sdk::assetft;
+use coreum_wasm_sdk::core::CoreumMsg;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{coin, Uint128};
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+
+// latest layout of the stored messages
+pub const STORED_MSG_VERSION: u8 = 2;
+
+// Version 1 stored its own message shape, {"mint":{"amount":"100"}}, which only made sense with
+// the denom of the contract. Version 2 stores the custom message as the chain receives it,
+// tagged with the layout version, so a later change of the custom message JSON is told apart
+// from the entries written before it.
+
+/// Custom message stored with the version of its layout.
+#[cw_serde]
+pub struct VersionedMsg {
+    pub version: u8,
+    pub msg: CoreumMsg,
+}
+
+/// Stored message layout of version 1.
+#[cw_serde]
+pub enum LegacyMsg {
+    Mint { amount: Uint128 },
+}
+
+/// Any stored message, decoded with the latest layout first.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(untagged)]
+pub enum StoredMsg {
+    Versioned(VersionedMsg),
+    Legacy(LegacyMsg),
+}
+
+impl StoredMsg {
+    pub fn latest(msg: CoreumMsg) -> Self {
+        StoredMsg::Versioned(VersionedMsg {
+            version: STORED_MSG_VERSION,
+            msg,
+        })
+    }
+
+    pub fn is_legacy(&self) -> bool {
+        matches!(self, StoredMsg::Legacy(_))
+    }
+
+    /// Converts the message into the latest layout, legacy messages are bound to the denom.
+    pub fn into_latest(self, denom: &str) -> Result<VersionedMsg, ContractError> {
+        match self {
+            StoredMsg::Versioned(versioned) if versioned.version == STORED_MSG_VERSION => {
+                Ok(versioned)
+            }
+            StoredMsg::Versioned(versioned) => Err(ContractError::UnsupportedVersion {
+                version: versioned.version,
+                latest: STORED_MSG_VERSION,
+            }),
+            StoredMsg::Legacy(LegacyMsg::Mint { amount }) => Ok(VersionedMsg {
+                version: STORED_MSG_VERSION,
+                msg: CoreumMsg::AssetFT(assetft::Msg::Mint {
+                    coin: coin(amount.u128(), denom),
+                }),
+            }),
+        }
+    }
+}
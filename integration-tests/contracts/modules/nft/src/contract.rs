@@ -1,3 +1,5 @@
+use common::any_msg::AnyMsg;
+use common::protos::AssetNFTTx;
 use coreum_wasm_sdk::assetnft::{
     self, BurntNFTResponse, BurntNFTsInClassResponse, ClassResponse, ClassesResponse,
     FrozenResponse, ParamsResponse, WhitelistedAccountsForNFTResponse, WhitelistedResponse,
@@ -6,14 +8,17 @@ use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries, CoreumResult};
 use coreum_wasm_sdk::nft;
 use coreum_wasm_sdk::pagination::PageRequest;
 use cosmwasm_std::{
-    entry_point, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, QueryRequest, Response,
-    StdResult,
+    entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, QueryRequest, Reply,
+    Response, StdResult, SubMsgResult,
 };
 use cw2::set_contract_version;
 use cw_ownable::{assert_owner, initialize_owner};
+use AssetNFTTx::{MsgAddToClassWhitelist, MsgRemoveFromClassWhitelist};
 
 use crate::error::ContractError;
+use crate::events::{find_event, EventClassIssued, EventMint, EVENT_CLASS_ISSUED, EVENT_MINT};
 use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::reply::{reply_events, reply_on_success, ReplyId};
 use crate::state::CLASS_ID;
 // version info for migration info
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -49,7 +54,7 @@ pub fn instantiate(
     Ok(Response::new()
         .add_attribute("owner", info.sender)
         .add_attribute("class_id", class_id)
-        .add_message(issue_msg))
+        .add_submessage(reply_on_success(issue_msg, ReplyId::IssueClass)))
 }
 
 // ********** Execute **********
@@ -57,7 +62,7 @@ pub fn instantiate(
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
-    _env: Env,
+    env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> CoreumResult<ContractError> {
@@ -76,6 +81,12 @@ pub fn execute(
             remove_from_white_list(deps, info, id, account)
         }
         ExecuteMsg::Send { id, receiver } => send(deps, info, id, receiver),
+        ExecuteMsg::AddToClassWhitelist { account } => {
+            add_to_class_white_list(deps, env, info, account)
+        }
+        ExecuteMsg::RemoveFromClassWhitelist { account } => {
+            remove_from_class_white_list(deps, env, info, account)
+        }
     }
 }
 
@@ -104,7 +115,7 @@ fn mint(
         .add_attribute("method", "mint")
         .add_attribute("class_id", class_id)
         .add_attribute("id", id)
-        .add_message(msg))
+        .add_submessage(reply_on_success(msg, ReplyId::Mint)))
 }
 
 fn burn(deps: DepsMut, info: MessageInfo, id: String) -> CoreumResult<ContractError> {
@@ -199,6 +210,50 @@ fn remove_from_white_list(
         .add_message(msg))
 }
 
+// The class whitelist messages are not part of the custom message bindings so they are sent as
+// stargate messages with the contract as the sender.
+fn add_to_class_white_list(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let class_id = CLASS_ID.load(deps.storage)?;
+
+    let mut whitelist = MsgAddToClassWhitelist::new();
+    whitelist.sender = env.contract.address.to_string();
+    whitelist.class_id = class_id.clone();
+    whitelist.account = account.clone();
+
+    Ok(Response::new()
+        .add_attribute("method", "add_to_class_white_list")
+        .add_attribute("class_id", class_id)
+        .add_attribute("account", account)
+        .add_message(whitelist.to_stargate()?))
+}
+
+fn remove_from_class_white_list(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let class_id = CLASS_ID.load(deps.storage)?;
+
+    let mut whitelist = MsgRemoveFromClassWhitelist::new();
+    whitelist.sender = env.contract.address.to_string();
+    whitelist.class_id = class_id.clone();
+    whitelist.account = account.clone();
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_from_class_white_list")
+        .add_attribute("class_id", class_id)
+        .add_attribute("account", account)
+        .add_message(whitelist.to_stargate()?))
+}
+
 fn send(
     deps: DepsMut,
     info: MessageInfo,
@@ -221,28 +276,62 @@ fn send(
         .add_message(msg))
 }
 
+// ********** Reply **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> CoreumResult<ContractError> {
+    match ReplyId::try_from(msg.id)? {
+        ReplyId::IssueClass => reply_issue_class(deps, msg.result),
+        ReplyId::Mint => reply_mint(msg.result),
+    }
+}
+
+// the class id reported by the chain is the source of truth
+fn reply_issue_class(deps: DepsMut, result: SubMsgResult) -> CoreumResult<ContractError> {
+    let issued =
+        EventClassIssued::try_from(find_event(reply_events(&result)?, EVENT_CLASS_ISSUED)?)?;
+    CLASS_ID.save(deps.storage, &issued.id)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "reply_issue_class")
+        .add_attribute("class_id", issued.id)
+        .add_attribute("issuer", issued.issuer))
+}
+
+fn reply_mint(result: SubMsgResult) -> CoreumResult<ContractError> {
+    let minted = EventMint::try_from(find_event(reply_events(&result)?, EVENT_MINT)?)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "reply_mint")
+        .add_attribute("class_id", minted.class_id)
+        .add_attribute("id", minted.id)
+        .add_attribute("owner", minted.owner))
+}
+
 // ********** Queries **********
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps<CoreumQueries>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
-        QueryMsg::Params {} => to_binary(&query_params(deps)?),
-        QueryMsg::Class {} => to_binary(&query_class(deps)?),
-        QueryMsg::Classes { issuer } => to_binary(&query_classes(deps, issuer)?),
-        QueryMsg::Frozen { id } => to_binary(&query_frozen(deps, id)?),
-        QueryMsg::Whitelisted { id, account } => to_binary(&query_whitelisted(deps, id, account)?),
+        QueryMsg::Params {} => to_json_binary(&query_params(deps)?),
+        QueryMsg::Class {} => to_json_binary(&query_class(deps)?),
+        QueryMsg::Classes { issuer } => to_json_binary(&query_classes(deps, issuer)?),
+        QueryMsg::Frozen { id } => to_json_binary(&query_frozen(deps, id)?),
+        QueryMsg::Whitelisted { id, account } => {
+            to_json_binary(&query_whitelisted(deps, id, account)?)
+        }
         QueryMsg::WhitelistedAccountsForNft { id } => {
-            to_binary(&query_whitelisted_accounts_for_nft(deps, id)?)
+            to_json_binary(&query_whitelisted_accounts_for_nft(deps, id)?)
         }
-        QueryMsg::Balance { owner } => to_binary(&query_balance(deps, owner)?),
-        QueryMsg::Owner { id } => to_binary(&query_owner(deps, id)?),
-        QueryMsg::Supply {} => to_binary(&query_supply(deps)?),
-        QueryMsg::Nft { id } => to_binary(&query_nft(deps, id)?),
-        QueryMsg::Nfts { owner } => to_binary(&query_nfts(deps, owner)?),
-        QueryMsg::ClassNft {} => to_binary(&query_nft_class(deps)?),
-        QueryMsg::ClassesNft {} => to_binary(&query_nft_classes(deps)?),
-        QueryMsg::BurntNft { nft_id } => to_binary(&query_burnt_nft(deps, nft_id)?),
-        QueryMsg::BurntNftsInClass {} => to_binary(&query_burnt_nfts_in_class(deps)?),
+        QueryMsg::Balance { owner } => to_json_binary(&query_balance(deps, owner)?),
+        QueryMsg::Owner { id } => to_json_binary(&query_owner(deps, id)?),
+        QueryMsg::Supply {} => to_json_binary(&query_supply(deps)?),
+        QueryMsg::Nft { id } => to_json_binary(&query_nft(deps, id)?),
+        QueryMsg::Nfts { owner } => to_json_binary(&query_nfts(deps, owner)?),
+        QueryMsg::ClassNft {} => to_json_binary(&query_nft_class(deps)?),
+        QueryMsg::ClassesNft {} => to_json_binary(&query_nft_classes(deps)?),
+        QueryMsg::BurntNft { nft_id } => to_json_binary(&query_burnt_nft(deps, nft_id)?),
+        QueryMsg::BurntNftsInClass {} => to_json_binary(&query_burnt_nfts_in_class(deps)?),
     }
 }
 
@@ -523,3 +612,266 @@ fn query_nft_classes(deps: Deps<CoreumQueries>) -> StdResult<nft::ClassesRespons
     };
     Ok(res)
 }
+
+#[cfg(test)]
+mod tests {
+    use common::exhaustive_cases;
+    use common::test_exhaustive::{run_cases, Outcome};
+    use coreum_wasm_sdk::pagination::PageResponse;
+    use cosmwasm_schema::serde::Serialize;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{coin, ContractResult, OwnedDeps, QuerierResult, SystemResult};
+
+    use super::*;
+
+    const OWNER: &str = "owner";
+    const CLASS: &str = "abc-contract";
+    const RECIPIENT: &str = "recipient";
+    const NFT_ID: &str = "nft1";
+
+    fn page() -> PageResponse {
+        PageResponse {
+            next_key: None,
+            total: Some(1),
+        }
+    }
+
+    fn answer(response: &impl Serialize) -> QuerierResult {
+        SystemResult::Ok(ContractResult::Ok(to_json_binary(response).unwrap()))
+    }
+
+    fn asset_class() -> assetnft::Class {
+        assetnft::Class {
+            id: CLASS.to_string(),
+            issuer: "contract".to_string(),
+            name: "ABC".to_string(),
+            symbol: "abc".to_string(),
+            description: None,
+            uri: None,
+            uri_hash: None,
+            data: None,
+            features: Some(vec![]),
+            royalty_rate: Some("0".to_string()),
+        }
+    }
+
+    fn nft_class() -> nft::Class {
+        nft::Class {
+            id: CLASS.to_string(),
+            name: Some("ABC".to_string()),
+            symbol: Some("abc".to_string()),
+            description: None,
+            uri: None,
+            uri_hash: None,
+            data: None,
+        }
+    }
+
+    fn minted() -> nft::NFT {
+        nft::NFT {
+            class_id: CLASS.to_string(),
+            id: NFT_ID.to_string(),
+            uri: None,
+            uri_hash: None,
+            data: None,
+        }
+    }
+
+    // a chain holding the class with a single nft owned by the recipient, the classes queried for
+    // other ids fail like on the chain
+    fn handle(query: &CoreumQueries) -> QuerierResult {
+        let class_id = match query {
+            CoreumQueries::AssetNFT(
+                assetnft::Query::Class { id: class_id }
+                | assetnft::Query::Frozen { class_id, .. }
+                | assetnft::Query::Whitelisted { class_id, .. }
+                | assetnft::Query::WhitelistedAccountsForNFT { class_id, .. }
+                | assetnft::Query::BurntNFT { class_id, .. }
+                | assetnft::Query::BurntNFTsInClass { class_id, .. },
+            )
+            | CoreumQueries::NFT(
+                nft::Query::Balance { class_id, .. }
+                | nft::Query::Owner { class_id, .. }
+                | nft::Query::Supply { class_id }
+                | nft::Query::NFT { class_id, .. }
+                | nft::Query::NFTs {
+                    class_id: Some(class_id),
+                    ..
+                }
+                | nft::Query::Class { class_id },
+            ) => Some(class_id),
+            _ => None,
+        };
+        if class_id.is_some_and(|class_id| class_id != CLASS) {
+            return SystemResult::Ok(ContractResult::Err("class not found".to_string()));
+        }
+
+        match query {
+            CoreumQueries::AssetNFT(query) => match query {
+                assetnft::Query::Params {} => answer(&ParamsResponse {
+                    params: assetnft::Params {
+                        mint_fee: coin(0, "udevcore"),
+                    },
+                }),
+                assetnft::Query::Class { .. } => answer(&ClassResponse {
+                    class: asset_class(),
+                }),
+                assetnft::Query::Classes { .. } => answer(&ClassesResponse {
+                    pagination: page(),
+                    classes: vec![asset_class()],
+                }),
+                assetnft::Query::Frozen { .. } => answer(&FrozenResponse { frozen: false }),
+                assetnft::Query::Whitelisted { .. } => {
+                    answer(&WhitelistedResponse { whitelisted: true })
+                }
+                assetnft::Query::WhitelistedAccountsForNFT { .. } => {
+                    answer(&WhitelistedAccountsForNFTResponse {
+                        pagination: page(),
+                        accounts: vec![RECIPIENT.to_string()],
+                    })
+                }
+                assetnft::Query::BurntNFT { .. } => answer(&BurntNFTResponse { burnt: false }),
+                assetnft::Query::BurntNFTsInClass { .. } => answer(&BurntNFTsInClassResponse {
+                    pagination: page(),
+                    nft_ids: vec![],
+                }),
+            },
+            CoreumQueries::NFT(query) => match query {
+                nft::Query::Balance { .. } => answer(&nft::BalanceResponse { amount: 1 }),
+                nft::Query::Owner { .. } => answer(&nft::OwnerResponse {
+                    owner: RECIPIENT.to_string(),
+                }),
+                nft::Query::Supply { .. } => answer(&nft::SupplyResponse { amount: 1 }),
+                nft::Query::NFT { .. } => answer(&nft::NFTResponse { nft: minted() }),
+                nft::Query::NFTs { .. } => answer(&nft::NFTsResponse {
+                    nfts: vec![minted()],
+                    pagination: page(),
+                }),
+                nft::Query::Class { .. } => answer(&nft::ClassResponse { class: nft_class() }),
+                nft::Query::Classes { .. } => answer(&nft::ClassesResponse {
+                    classes: vec![nft_class()],
+                    pagination: page(),
+                }),
+            },
+            _ => SystemResult::Ok(ContractResult::Err("unsupported query".to_string())),
+        }
+    }
+
+    fn query_deps() -> OwnedDeps<MockStorage, MockApi, MockQuerier<CoreumQueries>, CoreumQueries> {
+        let mut deps = OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier: MockQuerier::new(&[]).with_custom_handler(handle),
+            custom_query_type: Default::default(),
+        };
+        CLASS_ID
+            .save(&mut deps.storage, &CLASS.to_string())
+            .unwrap();
+        deps
+    }
+
+    #[test]
+    fn exhaustive_dispatch() {
+        let execute_cases = exhaustive_cases!(ExecuteMsg {
+            Mint {
+                id: NFT_ID.to_string(),
+                uri: None,
+                uri_hash: None,
+                data: None,
+            } => Outcome::Ok,
+            Burn {
+                id: NFT_ID.to_string(),
+            } => Outcome::Ok,
+            Freeze {
+                id: NFT_ID.to_string(),
+            } => Outcome::Ok,
+            Unfreeze {
+                id: NFT_ID.to_string(),
+            } => Outcome::Ok,
+            AddToWhitelist {
+                id: NFT_ID.to_string(),
+                account: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            RemoveFromWhitelist {
+                id: NFT_ID.to_string(),
+                account: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            Send {
+                id: NFT_ID.to_string(),
+                receiver: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            AddToClassWhitelist {
+                account: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            RemoveFromClassWhitelist {
+                account: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+        });
+        run_cases(execute_cases, |msg| {
+            let mut deps = mock_dependencies();
+            initialize_owner(&mut deps.storage, &deps.api, Some(OWNER)).unwrap();
+            CLASS_ID
+                .save(&mut deps.storage, &CLASS.to_string())
+                .unwrap();
+            execute(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg)
+        });
+
+        let query_cases = exhaustive_cases!(QueryMsg {
+            Params {} => Outcome::Ok,
+            Class {} => Outcome::Ok,
+            Classes {
+                issuer: "contract".to_string(),
+            } => Outcome::Ok,
+            Frozen {
+                id: NFT_ID.to_string(),
+            } => Outcome::Ok,
+            Whitelisted {
+                id: NFT_ID.to_string(),
+                account: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            WhitelistedAccountsForNft {
+                id: NFT_ID.to_string(),
+            } => Outcome::Ok,
+            Balance {
+                owner: RECIPIENT.to_string(),
+            } => Outcome::Ok,
+            Owner {
+                id: NFT_ID.to_string(),
+            } => Outcome::Ok,
+            Supply {} => Outcome::Ok,
+            Nft {
+                id: NFT_ID.to_string(),
+            } => Outcome::Ok,
+            Nfts { owner: None } => Outcome::Ok,
+            ClassNft {} => Outcome::Ok,
+            ClassesNft {} => Outcome::Ok,
+            BurntNft {
+                nft_id: NFT_ID.to_string(),
+            } => Outcome::Ok,
+            BurntNftsInClass {} => Outcome::Ok,
+        });
+        run_cases(query_cases, |msg| {
+            let deps = query_deps();
+            query(deps.as_ref(), mock_env(), msg)
+        });
+    }
+
+    #[test]
+    fn owner_queries_go_to_the_class_of_the_contract() {
+        let mut deps = query_deps();
+        CLASS_ID
+            .save(&mut deps.storage, &"other-contract".to_string())
+            .unwrap();
+        let err = query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Owner {
+                id: NFT_ID.to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("class not found"));
+    }
+}
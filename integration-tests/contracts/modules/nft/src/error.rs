@@ -9,4 +9,19 @@ pub enum ContractError {
 
     #[error(transparent)]
     Ownership(#[from] OwnershipError),
+
+    #[error(transparent)]
+    Protobuf(#[from] protobuf::Error),
+
+    #[error("Unknown reply id {id}")]
+    UnknownReplyId { id: u64 },
+
+    #[error("Invalid reply: {reason}")]
+    InvalidReply { reason: String },
+
+    #[error("Event {event_type} not found")]
+    MissingEvent { event_type: String },
+
+    #[error("Invalid {event_type} event: {reason}")]
+    InvalidEvent { event_type: String, reason: String },
 }
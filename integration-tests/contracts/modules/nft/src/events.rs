@@ -0,0 +1,125 @@
+use cosmwasm_std::{from_json, Event};
+
+use crate::error::ContractError;
+
+pub const EVENT_CLASS_ISSUED: &str = "coreum.asset.nft.v1.EventClassIssued";
+pub const EVENT_FROZEN: &str = "coreum.asset.nft.v1.EventFrozen";
+pub const EVENT_UNFROZEN: &str = "coreum.asset.nft.v1.EventUnfrozen";
+// assetnft mints through the nft module, which reports the minted nft
+pub const EVENT_MINT: &str = "cosmos.nft.v1beta1.EventMint";
+
+// Typed events are emitted with every attribute value encoded as JSON, so strings arrive quoted
+// ("\"id\""). Only the string attributes are parsed, the rest is left to the queries.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventClassIssued {
+    pub id: String,
+    pub issuer: String,
+    pub symbol: String,
+    pub name: String,
+    pub description: String,
+    pub uri: String,
+    pub uri_hash: String,
+}
+
+impl TryFrom<&Event> for EventClassIssued {
+    type Error = ContractError;
+
+    fn try_from(event: &Event) -> Result<Self, ContractError> {
+        ensure_type(event, EVENT_CLASS_ISSUED)?;
+        Ok(Self {
+            id: attribute(event, "id")?,
+            issuer: attribute(event, "issuer")?,
+            symbol: attribute(event, "symbol")?,
+            name: attribute(event, "name")?,
+            description: attribute(event, "description")?,
+            uri: attribute(event, "uri")?,
+            uri_hash: attribute(event, "uri_hash")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventMint {
+    pub class_id: String,
+    pub id: String,
+    pub owner: String,
+}
+
+impl TryFrom<&Event> for EventMint {
+    type Error = ContractError;
+
+    fn try_from(event: &Event) -> Result<Self, ContractError> {
+        ensure_type(event, EVENT_MINT)?;
+        Ok(Self {
+            class_id: attribute(event, "class_id")?,
+            id: attribute(event, "id")?,
+            owner: attribute(event, "owner")?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventFrozenChanged {
+    pub class_id: String,
+    pub id: String,
+    pub owner: String,
+}
+
+impl EventFrozenChanged {
+    // the frozen and unfrozen events share the same layout
+    fn parse(event: &Event, event_type: &str) -> Result<Self, ContractError> {
+        ensure_type(event, event_type)?;
+        Ok(Self {
+            class_id: attribute(event, "class_id")?,
+            id: attribute(event, "id")?,
+            owner: attribute(event, "owner")?,
+        })
+    }
+
+    pub fn frozen(event: &Event) -> Result<Self, ContractError> {
+        Self::parse(event, EVENT_FROZEN)
+    }
+
+    pub fn unfrozen(event: &Event) -> Result<Self, ContractError> {
+        Self::parse(event, EVENT_UNFROZEN)
+    }
+}
+
+/// Finds the first event of the given type.
+pub fn find_event<'a>(events: &'a [Event], event_type: &str) -> Result<&'a Event, ContractError> {
+    events
+        .iter()
+        .find(|event| event.ty == event_type)
+        .ok_or_else(|| ContractError::MissingEvent {
+            event_type: event_type.to_string(),
+        })
+}
+
+fn ensure_type(event: &Event, event_type: &str) -> Result<(), ContractError> {
+    if event.ty != event_type {
+        return Err(ContractError::InvalidEvent {
+            event_type: event_type.to_string(),
+            reason: format!("unexpected event type {}", event.ty),
+        });
+    }
+    Ok(())
+}
+
+// attribute decodes the JSON encoded string value of an attribute, stripping its quoting.
+fn attribute(event: &Event, key: &str) -> Result<String, ContractError> {
+    let value = event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.as_str())
+        .ok_or_else(|| ContractError::InvalidEvent {
+            event_type: event.ty.clone(),
+            reason: format!("missing attribute {}", key),
+        })?;
+
+    from_json(value.as_bytes()).map_err(|err| ContractError::InvalidEvent {
+        event_type: event.ty.clone(),
+        reason: format!("invalid attribute {}: {}", key, err),
+    })
+}
@@ -1,4 +1,6 @@
 pub mod contract;
 pub mod error;
+pub mod events;
 pub mod msg;
+pub mod reply;
 pub mod state;
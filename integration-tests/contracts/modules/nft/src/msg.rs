@@ -9,6 +9,8 @@ pub struct InstantiateMsg {
     pub uri: Option<String>,
     pub uri_hash: Option<String>,
     pub data: Option<Binary>,
+    // class features of x/asset/nft: burning 0, freezing 1, whitelisting 2 and disable_sending 3,
+    // the last one makes the nfts soulbound, only the issuer is able to send them
     pub features: Option<Vec<u32>>,
     pub royalty_rate: Option<String>,
 }
@@ -42,6 +44,12 @@ pub enum ExecuteMsg {
         id: String,
         receiver: String,
     },
+    AddToClassWhitelist {
+        account: String,
+    },
+    RemoveFromClassWhitelist {
+        account: String,
+    },
 }
 
 #[cw_serde]
@@ -0,0 +1,54 @@
+use cosmwasm_std::{CosmosMsg, Event, SubMsg, SubMsgResponse, SubMsgResult};
+use protobuf::Message;
+
+use crate::error::ContractError;
+
+// The asset messages answer with an EmptyResponse, so what they created is only reported by their
+// typed events, which wasmd hands to the reply together with the data of the response. The events
+// are parsed by the events module.
+
+/// Reply ids of the submessages the contract dispatches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u64)]
+pub enum ReplyId {
+    IssueClass = 1,
+    Mint = 2,
+}
+
+impl TryFrom<u64> for ReplyId {
+    type Error = ContractError;
+
+    fn try_from(id: u64) -> Result<Self, ContractError> {
+        match id {
+            1 => Ok(ReplyId::IssueClass),
+            2 => Ok(ReplyId::Mint),
+            id => Err(ContractError::UnknownReplyId { id }),
+        }
+    }
+}
+
+/// Wraps a custom or stargate message into a submessage replying on success under the id.
+pub fn reply_on_success<T>(msg: impl Into<CosmosMsg<T>>, id: ReplyId) -> SubMsg<T> {
+    SubMsg::reply_on_success(msg, id as u64)
+}
+
+/// Returns the events of a successful reply.
+pub fn reply_events(result: &SubMsgResult) -> Result<&[Event], ContractError> {
+    Ok(&success(result)?.events)
+}
+
+/// Decodes the protobuf response of the message from the data of a reply. The data is empty for
+/// EmptyResponse, which decodes into the default message.
+pub fn parse_msg_response<M: Message>(result: &SubMsgResult) -> Result<M, ContractError> {
+    let data = success(result)?.data.clone().unwrap_or_default();
+    Ok(M::parse_from_bytes(data.as_slice())?)
+}
+
+fn success(result: &SubMsgResult) -> Result<&SubMsgResponse, ContractError> {
+    match result {
+        SubMsgResult::Ok(response) => Ok(response),
+        SubMsgResult::Err(err) => Err(ContractError::InvalidReply {
+            reason: err.clone(),
+        }),
+    }
+}
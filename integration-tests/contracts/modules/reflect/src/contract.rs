@@ -0,0 +1,109 @@
+use common::issue_result::parse_issue_result;
+use coreum_wasm_sdk::core::{CoreumMsg, CoreumResult};
+use cosmwasm_std::{
+    entry_point, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply,
+    Response, StdResult, SubMsg,
+};
+use cw2::set_contract_version;
+use cw_ownable::{assert_owner, initialize_owner};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::REPLIES;
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> CoreumResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    initialize_owner(deps.storage, deps.api, Some(info.sender.as_ref()))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", info.sender))
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> CoreumResult<ContractError> {
+    match msg {
+        ExecuteMsg::Dispatch { msgs } => dispatch(deps, info, msgs),
+        ExecuteMsg::DispatchSub { msg, reply_id } => dispatch_sub(deps, info, *msg, reply_id),
+    }
+}
+
+// ********** Transactions **********
+
+fn dispatch(
+    deps: DepsMut,
+    info: MessageInfo,
+    msgs: Vec<CosmosMsg<CoreumMsg>>,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    if msgs.is_empty() {
+        return Err(ContractError::NoMessages {});
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "dispatch")
+        .add_attribute("messages", msgs.len().to_string())
+        .add_messages(msgs))
+}
+
+fn dispatch_sub(
+    deps: DepsMut,
+    info: MessageInfo,
+    msg: CosmosMsg<CoreumMsg>,
+    reply_id: u64,
+) -> CoreumResult<ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "dispatch_sub")
+        .add_attribute("reply_id", reply_id.to_string())
+        .add_submessage(SubMsg::reply_always(msg, reply_id)))
+}
+
+// ********** Reply **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> CoreumResult<ContractError> {
+    // failed submessages are stored too, reply_always turns their error into a reply instead of
+    // reverting the transaction
+    REPLIES.save(deps.storage, msg.id, &msg)?;
+
+    let mut res = Response::new()
+        .add_attribute("method", "reply")
+        .add_attribute("reply_id", msg.id.to_string());
+    // issuances dispatched through the ft contract report their denom without a follow-up query
+    if let Ok(issued) = parse_issue_result(&msg) {
+        res = res
+            .add_attribute("denom", issued.denom)
+            .add_attribute("amount_minted", issued.amount_minted);
+    }
+    Ok(res)
+}
+
+// ********** Queries **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Reply { id } => to_json_binary(&REPLIES.load(deps.storage, id)?),
+    }
+}
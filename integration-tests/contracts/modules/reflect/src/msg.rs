@@ -0,0 +1,25 @@
+use coreum_wasm_sdk::core::CoreumMsg;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::CosmosMsg;
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // forwards the messages verbatim with the contract as the sender
+    Dispatch {
+        msgs: Vec<CosmosMsg<CoreumMsg>>,
+    },
+    // dispatches the message as a SubMsg and stores its reply under reply_id
+    DispatchSub {
+        msg: Box<CosmosMsg<CoreumMsg>>,
+        reply_id: u64,
+    },
+}
+
+#[cw_serde]
+pub enum QueryMsg {
+    // returns the stored cosmwasm_std::Reply
+    Reply { id: u64 },
+}
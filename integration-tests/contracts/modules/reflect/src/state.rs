@@ -0,0 +1,5 @@
+use cosmwasm_std::Reply;
+use cw_storage_plus::Map;
+
+// (reply_id) -> reply received for a DispatchSub message
+pub const REPLIES: Map<u64, Reply> = Map::new("replies");
@@ -0,0 +1,280 @@
+use common::any_msg::AnyMsg;
+use common::protos::{CosmosDistribution, CosmosStaking};
+use cosmwasm_std::{coin, entry_point, Coin, DepsMut, Env, MessageInfo, Response};
+use cw2::set_contract_version;
+use cw_ownable::{assert_owner, initialize_owner};
+use protobuf::MessageField;
+use CosmosDistribution::{MsgSetWithdrawAddress, MsgWithdrawDelegatorReward};
+use CosmosStaking::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg};
+use crate::rewards::{dec_coin_to_uint_floor, query_delegation_rewards};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    initialize_owner(deps.storage, deps.api, Some(info.sender.as_ref()))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", info.sender))
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+
+    match msg {
+        ExecuteMsg::Delegate { validator, amount } => delegate(env, validator, amount),
+        ExecuteMsg::Undelegate { validator, amount } => undelegate(env, validator, amount),
+        ExecuteMsg::Redelegate {
+            src_validator,
+            dst_validator,
+            amount,
+        } => redelegate(env, src_validator, dst_validator, amount),
+        ExecuteMsg::WithdrawRewards { validator } => withdraw_rewards(env, validator),
+        ExecuteMsg::CompoundRewards { validator } => compound_rewards(deps, env, validator),
+        ExecuteMsg::SetWithdrawAddress { address } => set_withdraw_address(deps, env, address),
+    }
+}
+
+// ********** Transactions **********
+
+// validator addresses use the valoper prefix, so they are left to the chain to validate
+
+fn delegate(env: Env, validator: String, amount: Coin) -> Result<Response, ContractError> {
+    let mut msg = MsgDelegate::new();
+    msg.delegator_address = env.contract.address.to_string();
+    msg.validator_address = validator.clone();
+    msg.amount = proto_coin(&amount)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "delegate")
+        .add_attribute("validator", validator)
+        .add_attribute("amount", amount.to_string())
+        .add_message(msg.to_stargate()?))
+}
+
+fn undelegate(env: Env, validator: String, amount: Coin) -> Result<Response, ContractError> {
+    let mut msg = MsgUndelegate::new();
+    msg.delegator_address = env.contract.address.to_string();
+    msg.validator_address = validator.clone();
+    msg.amount = proto_coin(&amount)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "undelegate")
+        .add_attribute("validator", validator)
+        .add_attribute("amount", amount.to_string())
+        .add_message(msg.to_stargate()?))
+}
+
+fn redelegate(
+    env: Env,
+    src_validator: String,
+    dst_validator: String,
+    amount: Coin,
+) -> Result<Response, ContractError> {
+    if src_validator == dst_validator {
+        return Err(ContractError::SameValidator {});
+    }
+
+    let mut msg = MsgBeginRedelegate::new();
+    msg.delegator_address = env.contract.address.to_string();
+    msg.validator_src_address = src_validator.clone();
+    msg.validator_dst_address = dst_validator.clone();
+    msg.amount = proto_coin(&amount)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "redelegate")
+        .add_attribute("src_validator", src_validator)
+        .add_attribute("dst_validator", dst_validator)
+        .add_attribute("amount", amount.to_string())
+        .add_message(msg.to_stargate()?))
+}
+
+fn withdraw_rewards(env: Env, validator: String) -> Result<Response, ContractError> {
+    let mut msg = MsgWithdrawDelegatorReward::new();
+    msg.delegator_address = env.contract.address.to_string();
+    msg.validator_address = validator.clone();
+
+    Ok(Response::new()
+        .add_attribute("method", "withdraw_rewards")
+        .add_attribute("validator", validator)
+        .add_message(msg.to_stargate()?))
+}
+
+fn compound_rewards(deps: DepsMut, env: Env, validator: String) -> Result<Response, ContractError> {
+    let bonded_denom = deps.querier.query_bonded_denom()?;
+    let rewards =
+        query_delegation_rewards(&deps.querier, env.contract.address.as_str(), &validator)?;
+    let mut amount = coin(0, bonded_denom.clone());
+    for reward in rewards.iter().filter(|reward| reward.denom == bonded_denom) {
+        amount = dec_coin_to_uint_floor(reward)?;
+    }
+
+    // the withdrawal runs first, so the rewards are in the balance once the delegation runs
+    let withdraw = withdraw_rewards(env.clone(), validator.clone())?;
+    let delegate = delegate(env, validator.clone(), amount.clone())?;
+
+    Ok(Response::new()
+        .add_attribute("method", "compound_rewards")
+        .add_attribute("validator", validator)
+        .add_attribute("amount", amount.to_string())
+        .add_submessages(withdraw.messages)
+        .add_submessages(delegate.messages))
+}
+
+fn set_withdraw_address(
+    deps: DepsMut,
+    env: Env,
+    address: String,
+) -> Result<Response, ContractError> {
+    let address = deps.api.addr_validate(&address)?;
+
+    let mut msg = MsgSetWithdrawAddress::new();
+    msg.delegator_address = env.contract.address.to_string();
+    msg.withdraw_address = address.to_string();
+
+    Ok(Response::new()
+        .add_attribute("method", "set_withdraw_address")
+        .add_attribute("address", address)
+        .add_message(msg.to_stargate()?))
+}
+
+fn proto_coin(coin: &Coin) -> Result<MessageField<CosmosStaking::Coin>, ContractError> {
+    if coin.amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+
+    Ok(MessageField::some(CosmosStaking::Coin {
+        denom: coin.denom.clone(),
+        amount: coin.amount.to_string(),
+        ..Default::default()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::protos::CosmosDistributionQuery::{
+        DecCoin, QueryDelegationRewardsRequest, QueryDelegationRewardsResponse,
+    };
+    use common::stargate_mock::StargateMockQuerier;
+    use cosmwasm_std::testing::{mock_env, mock_info, MockApi, MockQuerier, MockStorage};
+    use cosmwasm_std::{ContractResult, CosmosMsg, OwnedDeps};
+    use protobuf::Message;
+
+    use super::*;
+    use crate::rewards::DELEGATION_REWARDS_PATH;
+
+    const OWNER: &str = "owner";
+    const VALIDATOR: &str = "corevaloper1";
+
+    fn rewards_deps(
+        rewards: &'static [(&'static str, &'static str)],
+    ) -> OwnedDeps<MockStorage, MockApi, StargateMockQuerier> {
+        let mut base = MockQuerier::new(&[]);
+        base.update_staking("ucore", &[], &[]);
+        let querier =
+            StargateMockQuerier::new(base).with_handler(DELEGATION_REWARDS_PATH, move |data| {
+                let request = QueryDelegationRewardsRequest::parse_from_bytes(data).unwrap();
+                assert_eq!(
+                    request.delegator_address,
+                    mock_env().contract.address.as_str()
+                );
+                assert_eq!(request.validator_address, VALIDATOR);
+                let mut res = QueryDelegationRewardsResponse::new();
+                for (denom, amount) in rewards {
+                    let mut reward = DecCoin::new();
+                    reward.denom = denom.to_string();
+                    reward.amount = amount.to_string();
+                    res.rewards.push(reward);
+                }
+                ContractResult::Ok(res.write_to_bytes().unwrap().into())
+            });
+        let mut deps = OwnedDeps {
+            storage: MockStorage::default(),
+            api: MockApi::default(),
+            querier,
+            custom_query_type: Default::default(),
+        };
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(OWNER, &[]),
+            InstantiateMsg {},
+        )
+        .unwrap();
+        deps
+    }
+
+    fn compound(
+        deps: &mut OwnedDeps<MockStorage, MockApi, StargateMockQuerier>,
+    ) -> Result<Response, ContractError> {
+        let msg = ExecuteMsg::CompoundRewards {
+            validator: VALIDATOR.to_string(),
+        };
+        execute(deps.as_mut(), mock_env(), mock_info(OWNER, &[]), msg)
+    }
+
+    #[test]
+    fn compound_delegates_the_whole_units_of_the_bonded_denom() {
+        let mut deps = rewards_deps(&[
+            ("uabc", "7.000000000000000000"),
+            ("ucore", "2500000.750000000000000000"),
+        ]);
+        let res = compound(&mut deps).unwrap();
+
+        let msgs: Vec<_> = res
+            .messages
+            .into_iter()
+            .map(|sub| match sub.msg {
+                CosmosMsg::Stargate { type_url, value } => (type_url, value),
+                msg => panic!("unexpected message {msg:?}"),
+            })
+            .collect();
+        assert_eq!(msgs.len(), 2);
+        assert_eq!(
+            msgs[0].0,
+            "/cosmos.distribution.v1beta1.MsgWithdrawDelegatorReward"
+        );
+        assert_eq!(msgs[1].0, "/cosmos.staking.v1beta1.MsgDelegate");
+        let delegate = MsgDelegate::parse_from_bytes(&msgs[1].1).unwrap();
+        assert_eq!(delegate.validator_address, VALIDATOR);
+        assert_eq!(delegate.amount.denom, "ucore");
+        assert_eq!(delegate.amount.amount, "2500000");
+    }
+
+    #[test]
+    fn compound_needs_a_whole_unit() {
+        let mut deps = rewards_deps(&[("ucore", "0.999999999999999999")]);
+        assert!(matches!(
+            compound(&mut deps),
+            Err(ContractError::ZeroAmount {})
+        ));
+
+        let mut deps = rewards_deps(&[("uabc", "5.0")]);
+        assert!(matches!(
+            compound(&mut deps),
+            Err(ContractError::ZeroAmount {})
+        ));
+    }
+}
@@ -0,0 +1,24 @@
+use cosmwasm_std::StdError;
+use cw_ownable::OwnershipError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Ownership(#[from] OwnershipError),
+
+    #[error("{0}")]
+    Protobuf(#[from] protobuf::Error),
+
+    #[error("Amount must be greater than zero")]
+    ZeroAmount {},
+
+    #[error("Source and destination validators must differ")]
+    SameValidator {},
+
+    #[error("Invalid dec coin amount {amount}: {reason}")]
+    InvalidDecCoin { amount: String, reason: String },
+}
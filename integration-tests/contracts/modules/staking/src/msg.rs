@@ -0,0 +1,34 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Coin;
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+// the contract is the delegator in all the messages, staking the funds it holds
+#[cw_serde]
+pub enum ExecuteMsg {
+    Delegate {
+        validator: String,
+        amount: Coin,
+    },
+    Undelegate {
+        validator: String,
+        amount: Coin,
+    },
+    Redelegate {
+        src_validator: String,
+        dst_validator: String,
+        amount: Coin,
+    },
+    WithdrawRewards {
+        validator: String,
+    },
+    // withdraws the rewards and delegates the whole units of the bonded denom back
+    CompoundRewards {
+        validator: String,
+    },
+    // redirects the rewards of future withdrawals
+    SetWithdrawAddress {
+        address: String,
+    },
+}
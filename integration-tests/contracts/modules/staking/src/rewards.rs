@@ -0,0 +1,154 @@
+use common::protos::CosmosDistributionQuery::{
+    DecCoin, QueryDelegationRewardsRequest, QueryDelegationRewardsResponse,
+};
+use common::stargate::query_stargate;
+use cosmwasm_std::{Coin, QuerierWrapper, StdResult, Uint128};
+
+use crate::error::ContractError;
+
+pub const DELEGATION_REWARDS_PATH: &str = "/cosmos.distribution.v1beta1.Query/DelegationRewards";
+
+// fractional digits of the sdk Dec type
+const DEC_PRECISION: usize = 18;
+
+pub fn query_delegation_rewards(
+    querier: &QuerierWrapper,
+    delegator: &str,
+    validator: &str,
+) -> StdResult<Vec<DecCoin>> {
+    let mut request = QueryDelegationRewardsRequest::new();
+    request.delegator_address = delegator.to_string();
+    request.validator_address = validator.to_string();
+    let res: QueryDelegationRewardsResponse =
+        query_stargate(querier, DELEGATION_REWARDS_PATH, &request)?;
+    Ok(res.rewards)
+}
+
+/// Converts a reward to a coin of its whole units, the fraction is dropped since only whole
+/// units are paid out on withdrawal. The sdk marshals a Dec over protobuf as its atomics, the
+/// value shifted by 18 digits without a point, while the text form has the point and up to 18
+/// fractional digits, both are accepted.
+pub fn dec_coin_to_uint_floor(dec_coin: &DecCoin) -> Result<Coin, ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidDecCoin {
+        amount: dec_coin.amount.clone(),
+        reason: reason.to_string(),
+    };
+
+    let amount = dec_coin.amount.as_str();
+    if amount.is_empty() || !amount.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return Err(invalid("amount must be a decimal number"));
+    }
+    let whole = match amount.split_once('.') {
+        Some((whole, fraction)) => {
+            if whole.is_empty() || fraction.is_empty() || fraction.contains('.') {
+                return Err(invalid("amount must be a decimal number"));
+            }
+            if fraction.len() > DEC_PRECISION {
+                return Err(invalid("fraction has more than 18 digits"));
+            }
+            whole
+        }
+        None => {
+            amount
+                .split_at(amount.len().saturating_sub(DEC_PRECISION))
+                .0
+        }
+    };
+
+    // an empty whole part is below one unit
+    let amount = if whole.is_empty() {
+        Uint128::zero()
+    } else {
+        whole
+            .parse::<Uint128>()
+            .map_err(|_| invalid("amount overflows 128 bits"))?
+    };
+
+    Ok(Coin {
+        denom: dec_coin.denom.clone(),
+        amount,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn floor(amount: &str) -> Result<Uint128, ContractError> {
+        let mut dec_coin = DecCoin::new();
+        dec_coin.denom = "ucore".to_string();
+        dec_coin.amount = amount.to_string();
+        let coin = dec_coin_to_uint_floor(&dec_coin)?;
+        assert_eq!(coin.denom, "ucore");
+        Ok(coin.amount)
+    }
+
+    #[test]
+    fn converts_the_text_form() {
+        assert_eq!(
+            floor("1000000000000000000.000000000000000000").unwrap(),
+            Uint128::new(1_000_000_000_000_000_000)
+        );
+        assert_eq!(floor("0.000000000000000000").unwrap(), Uint128::zero());
+        assert_eq!(
+            floor("340282366920938463463374607431768211455.000000000000000000").unwrap(),
+            Uint128::MAX
+        );
+    }
+
+    #[test]
+    fn converts_the_atomics() {
+        assert_eq!(
+            floor("1000000000000000000000000000000000000").unwrap(),
+            Uint128::new(1_000_000_000_000_000_000)
+        );
+        assert_eq!(floor("1000000000000000000").unwrap(), Uint128::one());
+        assert_eq!(floor("0").unwrap(), Uint128::zero());
+    }
+
+    #[test]
+    fn accepts_fewer_than_18_fractional_digits() {
+        assert_eq!(floor("12.5").unwrap(), Uint128::new(12));
+        assert_eq!(floor("3.000001").unwrap(), Uint128::new(3));
+        assert_eq!(floor("7.0").unwrap(), Uint128::new(7));
+    }
+
+    #[test]
+    fn rounds_down() {
+        assert_eq!(floor("1.999999999999999999").unwrap(), Uint128::one());
+        assert_eq!(floor("0.999999999999999999").unwrap(), Uint128::zero());
+        assert_eq!(floor("1999999999999999999").unwrap(), Uint128::one());
+        assert_eq!(floor("999999999999999999").unwrap(), Uint128::zero());
+    }
+
+    #[test]
+    fn rejects_malformed_amounts() {
+        for amount in [
+            "",
+            "1.",
+            ".5",
+            "1.2.3",
+            "-1.0",
+            "1e18",
+            " 1.0",
+            "0.1234567890123456789",
+        ] {
+            assert!(
+                matches!(floor(amount), Err(ContractError::InvalidDecCoin { .. })),
+                "{amount:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_amounts_over_128_bits() {
+        assert!(matches!(
+            floor("340282366920938463463374607431768211456.000000000000000000"),
+            Err(ContractError::InvalidDecCoin { .. })
+        ));
+        assert!(matches!(
+            floor("340282366920938463463374607431768211456000000000000000000"),
+            Err(ContractError::InvalidDecCoin { .. })
+        ));
+    }
+}
@@ -0,0 +1,212 @@
+use common::any_msg::AnyMsg;
+use common::protos::AssetFTTx;
+use coreum_wasm_sdk::assetft;
+use coreum_wasm_sdk::core::{CoreumMsg, CoreumQueries, CoreumResult};
+use cosmwasm_std::{
+    coin, entry_point, to_json_binary, Addr, BankMsg, Binary, CosmosMsg, Decimal, Deps, DepsMut,
+    Env, MessageInfo, Order, Response, StdResult, Timestamp, Uint128,
+};
+use cw2::set_contract_version;
+use protobuf::MessageField;
+use AssetFTTx::MsgSetFrozen;
+
+use crate::error::ContractError;
+use crate::msg::{
+    AllocationResponse, ExecuteMsg, InstantiateMsg, QueryMsg, ScheduleResponse, Unlock,
+};
+use crate::state::{ALLOCATIONS, DENOM, SCHEDULE, START, UNLOCKED};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// feature 2 of x/asset/ft, the contract only needs to freeze
+const FEATURE_FREEZING: u32 = 2;
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> CoreumResult<ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    validate_schedule(&msg.schedule)?;
+    if msg.allocations.is_empty() {
+        return Err(ContractError::EmptyAllocations {});
+    }
+
+    let denom = format!("{}-{}", msg.subunit, env.contract.address).to_lowercase();
+    let mut total = Uint128::zero();
+    let mut send_msgs = Vec::with_capacity(msg.allocations.len());
+    let mut freeze_msgs = Vec::with_capacity(msg.allocations.len());
+    for allocation in msg.allocations {
+        let beneficiary = deps.api.addr_validate(&allocation.beneficiary)?;
+        if allocation.amount.is_zero() {
+            return Err(ContractError::InvalidZeroAmount {});
+        }
+        if ALLOCATIONS.has(deps.storage, &beneficiary) {
+            return Err(ContractError::DuplicateBeneficiary {
+                beneficiary: beneficiary.to_string(),
+            });
+        }
+        ALLOCATIONS.save(deps.storage, &beneficiary, &allocation.amount)?;
+        total += allocation.amount;
+
+        send_msgs.push(BankMsg::Send {
+            to_address: beneficiary.to_string(),
+            amount: vec![coin(allocation.amount.u128(), denom.clone())],
+        });
+        freeze_msgs.push(set_frozen_msg(
+            &env,
+            &beneficiary,
+            allocation.amount,
+            &denom,
+        )?);
+    }
+
+    // the issue fee is paid from the funds sent with the instantiation
+    let issue_msg = CoreumMsg::AssetFT(assetft::Msg::Issue {
+        symbol: msg.symbol,
+        subunit: msg.subunit,
+        precision: msg.precision,
+        initial_amount: total,
+        description: msg.description,
+        features: Some(vec![FEATURE_FREEZING]),
+        burn_rate: None,
+        send_commission_rate: None,
+    });
+
+    DENOM.save(deps.storage, &denom)?;
+    START.save(deps.storage, &env.block.time)?;
+    SCHEDULE.save(deps.storage, &msg.schedule)?;
+    UNLOCKED.save(deps.storage, &Decimal::zero())?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("denom", denom)
+        .add_attribute("total", total)
+        .add_message(issue_msg)
+        .add_messages(send_msgs)
+        .add_messages(freeze_msgs))
+}
+
+fn validate_schedule(schedule: &[Unlock]) -> Result<(), ContractError> {
+    let invalid = |reason: &str| ContractError::InvalidSchedule {
+        reason: reason.to_string(),
+    };
+    let Some(last) = schedule.last() else {
+        return Err(invalid("no unlocks"));
+    };
+    if last.unlocked != Decimal::one() {
+        return Err(invalid("the last unlock must release everything"));
+    }
+    if schedule[0].unlocked.is_zero() {
+        return Err(invalid("unlocks must release a portion"));
+    }
+    if schedule.windows(2).any(|pair| {
+        pair[0].after_seconds >= pair[1].after_seconds || pair[0].unlocked >= pair[1].unlocked
+    }) {
+        return Err(invalid("unlocks must be ordered by time and portion"));
+    }
+    Ok(())
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<CoreumQueries>,
+    env: Env,
+    _info: MessageInfo,
+    msg: ExecuteMsg,
+) -> CoreumResult<ContractError> {
+    match msg {
+        ExecuteMsg::Tick {} => tick(deps, env),
+    }
+}
+
+// ********** Transactions **********
+
+// tick doesn't fail when the schedule hasn't moved, so it can be sent at any interval.
+fn tick(deps: DepsMut<CoreumQueries>, env: Env) -> CoreumResult<ContractError> {
+    let unlocked = unlocked_at(
+        START.load(deps.storage)?,
+        &SCHEDULE.load(deps.storage)?,
+        env.block.time,
+    );
+    let response = Response::new()
+        .add_attribute("method", "tick")
+        .add_attribute("unlocked", unlocked.to_string());
+    if unlocked == UNLOCKED.load(deps.storage)? {
+        return Ok(response);
+    }
+    UNLOCKED.save(deps.storage, &unlocked)?;
+
+    let denom = DENOM.load(deps.storage)?;
+    let msgs = ALLOCATIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (beneficiary, amount) = item?;
+            set_frozen_msg(&env, &beneficiary, frozen_part(amount, unlocked), &denom)
+        })
+        .collect::<Result<Vec<_>, ContractError>>()?;
+
+    Ok(response.add_messages(msgs))
+}
+
+fn unlocked_at(start: Timestamp, schedule: &[Unlock], time: Timestamp) -> Decimal {
+    let elapsed = time.seconds().saturating_sub(start.seconds());
+    schedule
+        .iter()
+        .take_while(|unlock| unlock.after_seconds <= elapsed)
+        .last()
+        .map_or(Decimal::zero(), |unlock| unlock.unlocked)
+}
+
+// the unlocked part is rounded down, so the frozen one never falls behind the schedule
+fn frozen_part(amount: Uint128, unlocked: Decimal) -> Uint128 {
+    amount - amount * unlocked
+}
+
+// MsgSetFrozen is not part of the custom message bindings so it is sent as a stargate message
+// with the contract as the sender.
+fn set_frozen_msg(
+    env: &Env,
+    account: &Addr,
+    amount: Uint128,
+    denom: &str,
+) -> Result<CosmosMsg<CoreumMsg>, ContractError> {
+    let mut frozen_coin = AssetFTTx::Coin::new();
+    frozen_coin.denom = denom.to_string();
+    frozen_coin.amount = amount.to_string();
+    let mut set_frozen = MsgSetFrozen::new();
+    set_frozen.sender = env.contract.address.to_string();
+    set_frozen.account = account.to_string();
+    set_frozen.coin = MessageField::some(frozen_coin);
+    Ok(set_frozen.to_stargate()?)
+}
+
+// ********** Queries **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<CoreumQueries>, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Schedule {} => to_json_binary(&ScheduleResponse {
+            denom: DENOM.load(deps.storage)?,
+            start: START.load(deps.storage)?,
+            schedule: SCHEDULE.load(deps.storage)?,
+            unlocked: UNLOCKED.load(deps.storage)?,
+        }),
+        QueryMsg::Allocation { beneficiary } => {
+            let beneficiary = deps.api.addr_validate(&beneficiary)?;
+            let amount = ALLOCATIONS.load(deps.storage, &beneficiary)?;
+            to_json_binary(&AllocationResponse {
+                amount,
+                frozen: frozen_part(amount, UNLOCKED.load(deps.storage)?),
+            })
+        }
+    }
+}
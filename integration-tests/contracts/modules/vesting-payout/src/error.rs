@@ -0,0 +1,23 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Protobuf(#[from] protobuf::Error),
+
+    #[error("Invalid zero amount")]
+    InvalidZeroAmount {},
+
+    #[error("No allocations")]
+    EmptyAllocations {},
+
+    #[error("Beneficiary {beneficiary} is allocated twice")]
+    DuplicateBeneficiary { beneficiary: String },
+
+    #[error("Invalid schedule: {reason}")]
+    InvalidSchedule { reason: String },
+}
@@ -0,0 +1,55 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub symbol: String,
+    pub subunit: String,
+    pub precision: u32,
+    pub description: Option<String>,
+    // the whole supply, each allocation is sent to its beneficiary and frozen there
+    pub allocations: Vec<Allocation>,
+    pub schedule: Vec<Unlock>,
+}
+
+#[cw_serde]
+pub struct Allocation {
+    pub beneficiary: String,
+    pub amount: Uint128,
+}
+
+// Portion of every allocation unfrozen once the given seconds have passed since the
+// instantiation. Portions are cumulative, so both fields grow along the schedule and the last
+// portion is one.
+#[cw_serde]
+pub struct Unlock {
+    pub after_seconds: u64,
+    pub unlocked: Decimal,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // sets the frozen balances of the beneficiaries to the part of the schedule not reached yet,
+    // meant to be sent periodically by anyone
+    Tick {},
+}
+
+#[cw_serde]
+pub enum QueryMsg {
+    Schedule {},
+    Allocation { beneficiary: String },
+}
+
+#[cw_serde]
+pub struct ScheduleResponse {
+    pub denom: String,
+    pub start: Timestamp,
+    pub schedule: Vec<Unlock>,
+    pub unlocked: Decimal,
+}
+
+#[cw_serde]
+pub struct AllocationResponse {
+    pub amount: Uint128,
+    pub frozen: Uint128,
+}
@@ -0,0 +1,13 @@
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+
+use crate::msg::Unlock;
+
+// token issued by the contract, which makes it the only account allowed to freeze it
+pub const DENOM: Item<String> = Item::new("denom");
+// block time of the instantiation, the schedule offsets count from it
+pub const START: Item<Timestamp> = Item::new("start");
+pub const SCHEDULE: Item<Vec<Unlock>> = Item::new("schedule");
+// portion of every allocation unfrozen by the last tick
+pub const UNLOCKED: Item<Decimal> = Item::new("unlocked");
+pub const ALLOCATIONS: Map<&Addr, Uint128> = Map::new("allocations");
@@ -0,0 +1,92 @@
+use common::any_msg::AnyMsg;
+use common::protos::CosmosVesting;
+use cosmwasm_std::{entry_point, Coin, DepsMut, Env, MessageInfo, Response, Timestamp};
+use cw2::set_contract_version;
+use cw_ownable::{assert_owner, initialize_owner};
+use CosmosVesting::MsgCreateVestingAccount;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, VestingSchedule};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// ********** Instantiate **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    initialize_owner(deps.storage, deps.api, Some(info.sender.as_ref()))?;
+
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("owner", info.sender))
+}
+
+// ********** Execute **********
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::CreateVestingAccount {
+            recipient,
+            amount,
+            end_time,
+            schedule,
+        } => create_vesting_account(deps, env, info, recipient, amount, end_time, schedule),
+    }
+}
+
+// ********** Transactions **********
+
+fn create_vesting_account(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    amount: Vec<Coin>,
+    end_time: Timestamp,
+    schedule: VestingSchedule,
+) -> Result<Response, ContractError> {
+    assert_owner(deps.storage, &info.sender)?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+    if amount.is_empty() {
+        return Err(ContractError::EmptyAmount {});
+    }
+    if end_time <= env.block.time {
+        return Err(ContractError::EndTimeInPast {});
+    }
+
+    let delayed = matches!(schedule, VestingSchedule::Delayed);
+    let mut msg = MsgCreateVestingAccount::new();
+    msg.from_address = env.contract.address.to_string();
+    msg.to_address = recipient.to_string();
+    msg.amount = amount
+        .iter()
+        .map(|coin| CosmosVesting::Coin {
+            denom: coin.denom.clone(),
+            amount: coin.amount.to_string(),
+            ..Default::default()
+        })
+        .collect();
+    msg.end_time = end_time.seconds() as i64;
+    msg.delayed = delayed;
+
+    Ok(Response::new()
+        .add_attribute("method", "create_vesting_account")
+        .add_attribute("recipient", recipient)
+        .add_attribute("end_time", end_time.seconds().to_string())
+        .add_attribute("delayed", delayed.to_string())
+        .add_message(msg.to_stargate()?))
+}
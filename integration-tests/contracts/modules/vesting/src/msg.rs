@@ -0,0 +1,25 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Timestamp};
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    // pays the amount from the contract funds into a new vesting account, the chain rejects a
+    // recipient which already exists
+    CreateVestingAccount {
+        recipient: String,
+        amount: Vec<Coin>,
+        end_time: Timestamp,
+        schedule: VestingSchedule,
+    },
+}
+
+#[cw_serde]
+pub enum VestingSchedule {
+    // vests linearly from the current block time until the end time
+    Continuous,
+    // vests everything at the end time
+    Delayed,
+}
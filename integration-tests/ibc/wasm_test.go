@@ -23,7 +23,9 @@ import (
 	"github.com/CoreumFoundation/coreum-tools/pkg/retry"
 	integrationtests "github.com/CoreumFoundation/coreum/v3/integration-tests"
 	ibcwasm "github.com/CoreumFoundation/coreum/v3/integration-tests/contracts/ibc"
+	"github.com/CoreumFoundation/coreum/v3/pkg/client"
 	"github.com/CoreumFoundation/coreum/v3/testutil/integration"
+	assetfttypes "github.com/CoreumFoundation/coreum/v3/x/asset/ft/types"
 )
 
 type ibcTimeoutBlock struct {
@@ -43,10 +45,24 @@ type ibcTransferRequest struct {
 	Timeout   ibcTimeout `json:"timeout"`
 }
 
+//nolint:tagliatelle // wasm requirements
+type ibcTransferHop struct {
+	ChannelID string `json:"channel_id"`
+	ToAddress string `json:"to_address"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type ibcMultiHopTransferRequest struct {
+	Hops             []ibcTransferHop `json:"hops"`
+	Amount           sdk.Coin         `json:"amount"`
+	TimeoutInSeconds uint64           `json:"timeout_in_seconds"`
+}
+
 type ibcTransferMethod string
 
 const (
-	ibcTransferMethodTransfer ibcTransferMethod = "transfer"
+	ibcTransferMethodTransfer         ibcTransferMethod = "transfer"
+	ibcTransferMethodMultiHopTransfer ibcTransferMethod = "multi_hop_transfer"
 )
 
 type ibcCallChannelRequest struct {
@@ -64,6 +80,45 @@ const (
 	ibcCallMethodGetCount  ibcCallMethod = "get_count"
 )
 
+//nolint:tagliatelle // wasm requirements
+type ibcAssetChannelTransferRequest struct {
+	Channel  string `json:"channel"`
+	Receiver string `json:"receiver"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type ibcAssetChannelTransferVoucherRequest struct {
+	Channel  string      `json:"channel"`
+	Denom    string      `json:"denom"`
+	Amount   sdkmath.Int `json:"amount"`
+	Receiver string      `json:"receiver"`
+}
+
+type ibcAssetChannelEscrowRequest struct {
+	Channel string `json:"channel"`
+	Denom   string `json:"denom"`
+}
+
+type ibcAssetChannelVoucherRequest struct {
+	Denom   string `json:"denom"`
+	Address string `json:"address"`
+}
+
+type ibcAssetChannelAmountResponse struct {
+	Amount sdkmath.Int `json:"amount"`
+}
+
+type ibcAssetChannelMethod string
+
+const (
+	// tx.
+	ibcAssetChannelMethodTransfer        ibcAssetChannelMethod = "transfer"
+	ibcAssetChannelMethodTransferVoucher ibcAssetChannelMethod = "transfer_voucher"
+	// query.
+	ibcAssetChannelMethodEscrow  ibcAssetChannelMethod = "escrow"
+	ibcAssetChannelMethodVoucher ibcAssetChannelMethod = "voucher"
+)
+
 // TestIBCTransferFromSmartContract tests the IBCTransfer from the contract.
 func TestIBCTransferFromSmartContract(t *testing.T) {
 	t.Parallel()
@@ -157,6 +212,78 @@ func TestIBCTransferFromSmartContract(t *testing.T) {
 	requireT.NoError(osmosisChain.AwaitForBalance(ctx, t, osmosisRecipient, expectedOsmosisRecipientBalance))
 }
 
+// TestIBCMultiHopTransferFromSmartContract tests the transfer from the contract forwarded by the packet-forward
+// middleware of the intermediate chain.
+func TestIBCMultiHopTransferFromSmartContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chains := integrationtests.NewChainsTestingContext(t)
+	requireT := require.New(t)
+	coreumChain := chains.Coreum
+	osmosisChain := chains.Osmosis
+
+	osmosisToCoreumChannelID := osmosisChain.AwaitForIBCChannelID(ctx, t, ibctransfertypes.PortID, coreumChain.ChainSettings.ChainID)
+	coreumToOsmosisChannelID := coreumChain.AwaitForIBCChannelID(ctx, t, ibctransfertypes.PortID, osmosisChain.ChainSettings.ChainID)
+
+	coreumAdmin := coreumChain.GenAccount()
+	coreumRecipient := coreumChain.GenAccount()
+	osmosisIntermediary := osmosisChain.GenAccount()
+
+	coreumChain.Faucet.FundAccounts(ctx, t, integration.FundedAccount{
+		Address: coreumAdmin,
+		Amount:  coreumChain.NewCoin(sdkmath.NewInt(2000000)),
+	})
+	sendCoin := coreumChain.NewCoin(sdkmath.NewInt(1000))
+
+	// deploy the contract and fund it
+	initialPayload, err := json.Marshal(struct{}{})
+	requireT.NoError(err)
+	contractAddr, _, err := coreumChain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		coreumChain.TxFactory().WithSimulateAndExecute(true),
+		coreumAdmin,
+		ibcwasm.IBCTransferWASM,
+		integration.InstantiateConfig{
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    initialPayload,
+			Amount:     sendCoin,
+			Label:      "ibc_transfer",
+		},
+	)
+	requireT.NoError(err)
+
+	// send the coins to osmosis and forward them back to coreum, so the recipient receives the native denom
+	transferPayload, err := json.Marshal(map[ibcTransferMethod]ibcMultiHopTransferRequest{
+		ibcTransferMethodMultiHopTransfer: {
+			Hops: []ibcTransferHop{
+				{
+					ChannelID: coreumToOsmosisChannelID,
+					ToAddress: osmosisChain.MustConvertToBech32Address(osmosisIntermediary),
+				},
+				{
+					ChannelID: osmosisToCoreumChannelID,
+					ToAddress: coreumRecipient.String(),
+				},
+			},
+			Amount:           sendCoin,
+			TimeoutInSeconds: 120,
+		},
+	})
+	requireT.NoError(err)
+
+	_, err = coreumChain.Wasm.ExecuteWASMContract(
+		ctx,
+		coreumChain.TxFactory().WithSimulateAndExecute(true),
+		coreumAdmin,
+		contractAddr,
+		transferPayload,
+		sdk.Coin{},
+	)
+	requireT.NoError(err)
+
+	requireT.NoError(coreumChain.AwaitForBalance(ctx, t, coreumRecipient, sendCoin))
+}
+
 // TestIBCCallFromSmartContract tests the IBC contract calls.
 func TestIBCCallFromSmartContract(t *testing.T) {
 	// we don't enable the t.Parallel here since that test uses the config unseal hack because of the cosmos relayer
@@ -275,6 +402,241 @@ func TestIBCCallFromSmartContract(t *testing.T) {
 	awaitWasmCounterValue(ctx, t, osmosisChain, osmosisToCoreumChannelID, osmosisContractAddr, 2)
 }
 
+// TestIBCAssetChannelFromSmartContract tests the transfer of the assetft tokens over the channel owned by the contracts.
+func TestIBCAssetChannelFromSmartContract(t *testing.T) {
+	// we don't enable the t.Parallel here since that test uses the config unseal hack because of the cosmos relayer
+	// implementation
+	restoreSDKConfig := unsealSDKConfig()
+	defer restoreSDKConfig()
+
+	// channelIBCVersion is the version defined in the ibc.rs in the smart contract
+	const channelIBCVersion = "asset-channel-1"
+
+	ctx, chains := integrationtests.NewChainsTestingContext(t)
+	requireT := require.New(t)
+	coreumChain := chains.Coreum
+	osmosisChain := chains.Osmosis
+
+	coreumWasmClient := wasmtypes.NewQueryClient(coreumChain.ClientContext)
+	osmosisWasmClient := wasmtypes.NewQueryClient(osmosisChain.ClientContext)
+	coreumBankClient := banktypes.NewQueryClient(coreumChain.ClientContext)
+
+	coreumIssuer := coreumChain.GenAccount()
+	coreumRecipientBlocked := coreumChain.GenAccount()
+	coreumRecipientWhitelisted := coreumChain.GenAccount()
+	osmosisCaller := osmosisChain.GenAccount()
+
+	issueFee := coreumChain.QueryAssetFTParams(ctx, t).IssueFee.Amount
+	coreumChain.FundAccountWithOptions(ctx, t, coreumIssuer, integration.BalancesOptions{
+		Messages: []sdk.Msg{
+			&assetfttypes.MsgIssue{},
+			&assetfttypes.MsgSetWhitelistedLimit{},
+			&assetfttypes.MsgSetWhitelistedLimit{},
+		},
+		Amount: issueFee.Add(sdkmath.NewInt(2000000)),
+	})
+
+	osmosisChain.Faucet.FundAccounts(ctx, t, integration.FundedAccount{
+		Address: osmosisCaller,
+		Amount:  osmosisChain.NewCoin(sdkmath.NewInt(2000000)),
+	})
+
+	initialPayload, err := json.Marshal(struct{}{})
+	requireT.NoError(err)
+
+	coreumContractAddr, _, err := coreumChain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		coreumChain.TxFactory().WithSimulateAndExecute(true),
+		coreumIssuer,
+		ibcwasm.IBCAssetChannelWASM,
+		integration.InstantiateConfig{
+			Admin:      coreumIssuer,
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    initialPayload,
+			Label:      "ibc_asset_channel",
+		},
+	)
+	requireT.NoError(err)
+
+	osmosisContractAddr, _, err := osmosisChain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		osmosisChain.TxFactory().WithSimulateAndExecute(true),
+		osmosisCaller,
+		ibcwasm.IBCAssetChannelWASM,
+		integration.InstantiateConfig{
+			Admin:      osmosisCaller,
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    initialPayload,
+			Label:      "ibc_asset_channel",
+		},
+	)
+	requireT.NoError(err)
+
+	coreumContractInfoRes, err := coreumWasmClient.ContractInfo(ctx, &wasmtypes.QueryContractInfoRequest{
+		Address: coreumContractAddr,
+	})
+	requireT.NoError(err)
+	coreumIBCPort := coreumContractInfoRes.ContractInfo.IBCPortID
+	requireT.NotEmpty(coreumIBCPort)
+
+	osmosisContractInfoRes, err := osmosisWasmClient.ContractInfo(ctx, &wasmtypes.QueryContractInfoRequest{
+		Address: osmosisContractAddr,
+	})
+	requireT.NoError(err)
+	osmosisIBCPort := osmosisContractInfoRes.ContractInfo.IBCPortID
+	requireT.NotEmpty(osmosisIBCPort)
+
+	closerFunc := CreateIBCChannelsAndConnect(
+		ctx,
+		t,
+		coreumChain.Chain,
+		coreumIBCPort,
+		osmosisChain,
+		osmosisIBCPort,
+		channelIBCVersion,
+		ibcchanneltypes.UNORDERED,
+	)
+	defer closerFunc()
+
+	coreumToOsmosisChannelID := coreumChain.AwaitForIBCChannelID(ctx, t, coreumIBCPort, osmosisChain.ChainSettings.ChainID)
+	osmosisToCoreumChannelID := osmosisChain.AwaitForIBCChannelID(ctx, t, osmosisIBCPort, coreumChain.ChainSettings.ChainID)
+	t.Logf("Channels are ready coreum channel ID:%s, osmosis channel ID:%s", coreumToOsmosisChannelID, osmosisToCoreumChannelID)
+
+	// issue the token with the whitelisting, so the unescrow can be rejected by the asset module
+	issueMsg := &assetfttypes.MsgIssue{
+		Issuer:        coreumIssuer.String(),
+		Symbol:        "mysymbol",
+		Subunit:       "mysubunit",
+		Precision:     8,
+		InitialAmount: sdkmath.NewInt(1_000_000),
+		Features: []assetfttypes.Feature{
+			assetfttypes.Feature_whitelisting,
+		},
+	}
+	_, err = client.BroadcastTx(
+		ctx,
+		coreumChain.ClientContext.WithFromAddress(coreumIssuer),
+		coreumChain.TxFactory().WithGas(coreumChain.GasLimitByMsgs(issueMsg)),
+		issueMsg,
+	)
+	requireT.NoError(err)
+	denom := assetfttypes.BuildDenom(issueMsg.Subunit, coreumIssuer)
+	sendBackCoin := sdk.NewCoin(denom, sdkmath.NewInt(1000))
+	sendCoin := sdk.NewCoin(denom, sendBackCoin.Amount.MulRaw(2))
+
+	for _, whitelistMsg := range []*assetfttypes.MsgSetWhitelistedLimit{
+		{
+			Sender:  coreumIssuer.String(),
+			Account: coreumContractAddr,
+			Coin:    sendCoin,
+		},
+		{
+			Sender:  coreumIssuer.String(),
+			Account: coreumRecipientWhitelisted.String(),
+			Coin:    sendBackCoin,
+		},
+	} {
+		_, err = client.BroadcastTx(
+			ctx,
+			coreumChain.ClientContext.WithFromAddress(coreumIssuer),
+			coreumChain.TxFactory().WithGas(coreumChain.GasLimitByMsgs(whitelistMsg)),
+			whitelistMsg,
+		)
+		requireT.NoError(err)
+	}
+
+	// escrow the tokens on coreum and mint the vouchers on osmosis
+	transferPayload, err := json.Marshal(map[ibcAssetChannelMethod]ibcAssetChannelTransferRequest{
+		ibcAssetChannelMethodTransfer: {
+			Channel:  coreumToOsmosisChannelID,
+			Receiver: osmosisChain.MustConvertToBech32Address(osmosisCaller),
+		},
+	})
+	requireT.NoError(err)
+	_, err = coreumChain.Wasm.ExecuteWASMContract(
+		ctx,
+		coreumChain.TxFactory().WithSimulateAndExecute(true),
+		coreumIssuer,
+		coreumContractAddr,
+		transferPayload,
+		sendCoin,
+	)
+	requireT.NoError(err)
+
+	contractBalanceRes, err := coreumBankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+		Address: coreumContractAddr,
+		Denom:   denom,
+	})
+	requireT.NoError(err)
+	requireT.Equal(sendCoin.String(), contractBalanceRes.Balance.String())
+
+	voucherDenom := osmosisIBCPort + "/" + osmosisToCoreumChannelID + "/" + denom
+	osmosisCallerAddress := osmosisChain.MustConvertToBech32Address(osmosisCaller)
+	awaitIBCAssetChannelVoucherAmount(
+		ctx, t, osmosisChain, osmosisContractAddr, voucherDenom, osmosisCallerAddress, sendCoin.Amount,
+	)
+
+	// send the vouchers back to two accounts, one blocked, one whitelisted
+	for _, recipient := range []sdk.AccAddress{coreumRecipientWhitelisted, coreumRecipientBlocked} {
+		transferVoucherPayload, err := json.Marshal(
+			map[ibcAssetChannelMethod]ibcAssetChannelTransferVoucherRequest{
+				ibcAssetChannelMethodTransferVoucher: {
+					Channel:  osmosisToCoreumChannelID,
+					Denom:    voucherDenom,
+					Amount:   sendBackCoin.Amount,
+					Receiver: recipient.String(),
+				},
+			},
+		)
+		requireT.NoError(err)
+		_, err = osmosisChain.Wasm.ExecuteWASMContract(
+			ctx,
+			osmosisChain.TxFactory().WithSimulateAndExecute(true),
+			osmosisCaller,
+			osmosisContractAddr,
+			transferVoucherPayload,
+			sdk.Coin{},
+		)
+		requireT.NoError(err)
+	}
+
+	// transfer to whitelisted account is expected to succeed
+	requireT.NoError(coreumChain.AwaitForBalance(ctx, t, coreumRecipientWhitelisted, sendBackCoin))
+
+	// transfer to blocked account is expected to fail and the vouchers should be returned back
+	awaitIBCAssetChannelVoucherAmount(
+		ctx, t, osmosisChain, osmosisContractAddr, voucherDenom, osmosisCallerAddress, sendBackCoin.Amount,
+	)
+
+	balanceRes, err := coreumBankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+		Address: coreumRecipientBlocked.String(),
+		Denom:   denom,
+	})
+	requireT.NoError(err)
+	requireT.Equal(sdk.NewCoin(denom, sdk.ZeroInt()).String(), balanceRes.Balance.String())
+
+	// the escrow of the failed transfer is restored
+	escrowPayload, err := json.Marshal(map[ibcAssetChannelMethod]ibcAssetChannelEscrowRequest{
+		ibcAssetChannelMethodEscrow: {
+			Channel: coreumToOsmosisChannelID,
+			Denom:   denom,
+		},
+	})
+	requireT.NoError(err)
+	queryOut, err := coreumChain.Wasm.QueryWASMContract(ctx, coreumContractAddr, escrowPayload)
+	requireT.NoError(err)
+	var escrowRes ibcAssetChannelAmountResponse
+	requireT.NoError(json.Unmarshal(queryOut, &escrowRes))
+	requireT.Equal(sendBackCoin.Amount.String(), escrowRes.Amount.String())
+
+	contractBalanceRes, err = coreumBankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+		Address: coreumContractAddr,
+		Denom:   denom,
+	})
+	requireT.NoError(err)
+	requireT.Equal(sendBackCoin.String(), contractBalanceRes.Balance.String())
+}
+
 // executeWasmIncrement executes increment method on the contract which calls another contract and increments the counter.
 func executeWasmIncrement(
 	ctx context.Context,
@@ -338,6 +700,45 @@ func awaitWasmCounterValue(
 	t.Logf("Received expected count of %d.", expectedCount)
 }
 
+// awaitIBCAssetChannelVoucherAmount waits until the voucher balance on the asset channel contract reaches the
+// expectedAmount.
+func awaitIBCAssetChannelVoucherAmount(
+	ctx context.Context,
+	t *testing.T,
+	chain integration.Chain,
+	contractAddress, denom, address string,
+	expectedAmount sdkmath.Int,
+) {
+	t.Helper()
+
+	t.Logf("Awaiting for voucher amount:%s, chainID: %s, denom:%s", expectedAmount, chain.ChainSettings.ChainID, denom)
+
+	retryCtx, retryCancel := context.WithTimeout(ctx, time.Minute)
+	defer retryCancel()
+	require.NoError(t, retry.Do(retryCtx, time.Second, func() error {
+		voucherPayload, err := json.Marshal(map[ibcAssetChannelMethod]ibcAssetChannelVoucherRequest{
+			ibcAssetChannelMethodVoucher: {
+				Denom:   denom,
+				Address: address,
+			},
+		})
+		require.NoError(t, err)
+		queryVoucherOut, err := chain.Wasm.QueryWASMContract(retryCtx, contractAddress, voucherPayload)
+		require.NoError(t, err)
+		var queryVoucherRes ibcAssetChannelAmountResponse
+		err = json.Unmarshal(queryVoucherOut, &queryVoucherRes)
+		require.NoError(t, err)
+
+		if !queryVoucherRes.Amount.Equal(expectedAmount) {
+			return retry.Retryable(errors.Errorf("voucher amount is still not equal to expected, current:%s, expected:%s", queryVoucherRes.Amount, expectedAmount))
+		}
+
+		return nil
+	}))
+
+	t.Logf("Received expected voucher amount of %s.", expectedAmount)
+}
+
 func unsealSDKConfig() func() {
 	config := sdk.GetConfig()
 	// unseal the config
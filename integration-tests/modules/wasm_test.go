@@ -3,11 +3,15 @@
 package modules
 
 import (
+	"bytes"
 	"context"
+	"crypto/sha256"
 	_ "embed"
 	"encoding/base64"
+	"encoding/hex"
 	"encoding/json"
 	"math/rand"
+	"strconv"
 	"testing"
 	"time"
 
@@ -16,10 +20,15 @@ import (
 	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
 	sdk "github.com/cosmos/cosmos-sdk/types"
 	cosmoserrors "github.com/cosmos/cosmos-sdk/types/errors"
+	authtypes "github.com/cosmos/cosmos-sdk/x/auth/types"
 	authztypes "github.com/cosmos/cosmos-sdk/x/authz"
 	banktypes "github.com/cosmos/cosmos-sdk/x/bank/types"
+	distributiontypes "github.com/cosmos/cosmos-sdk/x/distribution/types"
+	"github.com/cosmos/cosmos-sdk/x/feegrant"
+	govtypes "github.com/cosmos/cosmos-sdk/x/gov/types"
 	govtypesv1 "github.com/cosmos/cosmos-sdk/x/gov/types/v1"
 	nfttypes "github.com/cosmos/cosmos-sdk/x/nft"
+	stakingtypes "github.com/cosmos/cosmos-sdk/x/staking/types"
 	"github.com/samber/lo"
 	"github.com/stretchr/testify/assert"
 	"github.com/stretchr/testify/require"
@@ -58,10 +67,52 @@ type authzTransferRequest struct {
 	Denom   string `json:"denom"`
 }
 
+type authzGenericAuthorization struct {
+	Msg string `json:"msg"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type authzStakeValidators struct {
+	AllowList []string `json:"allow_list,omitempty"`
+	DenyList  []string `json:"deny_list,omitempty"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type authzStakeAuthorization struct {
+	MaxTokens         *sdk.Coin             `json:"max_tokens"`
+	Validators        *authzStakeValidators `json:"validators"`
+	AuthorizationType string                `json:"authorization_type"`
+}
+
+type authzAuthorization struct {
+	Generic *authzGenericAuthorization `json:"generic,omitempty"`
+	Stake   *authzStakeAuthorization   `json:"stake,omitempty"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type authzGrantRequest struct {
+	Grantee          string             `json:"grantee"`
+	Authorization    authzAuthorization `json:"authorization"`
+	ExpiresInSeconds uint64             `json:"expires_in_seconds"`
+}
+
+type authzGrantInfo struct {
+	Granter    string  `json:"granter"`
+	Expiration *string `json:"expiration"`
+}
+
+type authzGrantsResponse struct {
+	Grants []authzGrantInfo `json:"grants"`
+}
+
 type authzMethod string
 
 const (
 	transfer authzMethod = "transfer"
+	// tx.
+	authzMethodGrant authzMethod = "grant"
+	// query.
+	authzMethodGrants authzMethod = "grants"
 )
 
 // fungible token wasm models
@@ -76,8 +127,17 @@ type issueFTRequest struct {
 	Features           []assetfttypes.Feature `json:"features"`
 	BurnRate           string                 `json:"burn_rate"`
 	SendCommissionRate string                 `json:"send_commission_rate"`
+	Encoding           ftEncoding             `json:"encoding,omitempty"`
 }
 
+// ftEncoding selects how the contract sends its assetft messages.
+type ftEncoding string
+
+const (
+	ftEncodingCustom   ftEncoding = "custom"
+	ftEncodingStargate ftEncoding = "stargate"
+)
+
 type amountBodyFTRequest struct {
 	Amount string `json:"amount"`
 }
@@ -116,6 +176,541 @@ const (
 	ftMethodWhitelistedBalance  ftMethod = "whitelisted_balance"
 	ftMethodFrozenBalances      ftMethod = "frozen_balances"
 	ftMethodWhitelistedBalances ftMethod = "whitelisted_balances"
+	ftMethodBalances            ftMethod = "balances"
+	ftMethodSimulateSend        ftMethod = "simulate_send"
+	ftMethodNetReceiveAmount    ftMethod = "net_receive_amount"
+)
+
+//nolint:tagliatelle
+type simulateSendFTResponse struct {
+	BurnAmount       sdkmath.Int `json:"burn_amount"`
+	CommissionAmount sdkmath.Int `json:"commission_amount"`
+	TotalSpent       sdkmath.Int `json:"total_spent"`
+}
+
+type accountBalanceFT struct {
+	Denom       string      `json:"denom"`
+	Balance     sdkmath.Int `json:"balance"`
+	Spendable   sdkmath.Int `json:"spendable"`
+	Frozen      sdkmath.Int `json:"frozen"`
+	Whitelisted sdkmath.Int `json:"whitelisted"`
+	Locked      sdkmath.Int `json:"locked"`
+}
+
+type accountBalancesFTResponse struct {
+	Balances []accountBalanceFT `json:"balances"`
+}
+
+// cw20 adapter wasm models
+
+type cw20AdapterInstantiateRequest struct {
+	Denom string `json:"denom"`
+}
+
+type cw20AdapterAmountRequest struct {
+	Amount string `json:"amount"`
+}
+
+type cw20AdapterTransferRequest struct {
+	Recipient string `json:"recipient"`
+	Amount    string `json:"amount"`
+}
+
+type cw20AdapterAllowanceRequest struct {
+	Spender string `json:"spender"`
+	Amount  string `json:"amount"`
+}
+
+type cw20AdapterTransferFromRequest struct {
+	Owner     string `json:"owner"`
+	Recipient string `json:"recipient"`
+	Amount    string `json:"amount"`
+}
+
+type cw20AdapterBalanceRequest struct {
+	Address string `json:"address"`
+}
+
+type cw20AdapterOwnerSpenderRequest struct {
+	Owner   string `json:"owner"`
+	Spender string `json:"spender"`
+}
+
+type cw20AdapterBalanceResponse struct {
+	Balance sdkmath.Int `json:"balance"`
+}
+
+type cw20AdapterAllowanceResponse struct {
+	Allowance sdkmath.Int `json:"allowance"`
+}
+
+//nolint:tagliatelle
+type cw20AdapterTokenInfoResponse struct {
+	Name        string      `json:"name"`
+	Symbol      string      `json:"symbol"`
+	Decimals    uint8       `json:"decimals"`
+	TotalSupply sdkmath.Int `json:"total_supply"`
+}
+
+type cw20AdapterMethod string
+
+const (
+	// tx.
+	cw20AdapterMethodDeposit           cw20AdapterMethod = "deposit"
+	cw20AdapterMethodWithdraw          cw20AdapterMethod = "withdraw"
+	cw20AdapterMethodTransfer          cw20AdapterMethod = "transfer"
+	cw20AdapterMethodBurn              cw20AdapterMethod = "burn"
+	cw20AdapterMethodIncreaseAllowance cw20AdapterMethod = "increase_allowance"
+	cw20AdapterMethodTransferFrom      cw20AdapterMethod = "transfer_from"
+	// query.
+	cw20AdapterMethodBalance   cw20AdapterMethod = "balance"
+	cw20AdapterMethodAllowance cw20AdapterMethod = "allowance"
+	cw20AdapterMethodTokenInfo cw20AdapterMethod = "token_info"
+)
+
+// cw721 adapter wasm models
+
+type cw721AdapterInstantiateRequest struct {
+	Name        string `json:"name"`
+	Symbol      string `json:"symbol"`
+	RoyaltyRate string `json:"royalty_rate,omitempty"`
+}
+
+//nolint:tagliatelle
+type cw721AdapterMintRequest struct {
+	TokenID  string `json:"token_id"`
+	Owner    string `json:"owner"`
+	TokenURI string `json:"token_uri"`
+}
+
+//nolint:tagliatelle
+type cw721AdapterApproveRequest struct {
+	Spender string `json:"spender"`
+	TokenID string `json:"token_id"`
+}
+
+//nolint:tagliatelle
+type cw721AdapterTransferRequest struct {
+	Recipient string `json:"recipient"`
+	TokenID   string `json:"token_id"`
+}
+
+//nolint:tagliatelle
+type cw721AdapterTokenIDRequest struct {
+	TokenID string `json:"token_id"`
+}
+
+type cw721AdapterOwnerRequest struct {
+	Owner string `json:"owner"`
+}
+
+type cw721AdapterApproval struct {
+	Spender string `json:"spender"`
+}
+
+type cw721AdapterOwnerOfResponse struct {
+	Owner     string                 `json:"owner"`
+	Approvals []cw721AdapterApproval `json:"approvals"`
+}
+
+//nolint:tagliatelle
+type cw721AdapterNftInfoResponse struct {
+	TokenURI string `json:"token_uri"`
+}
+
+type cw721AdapterTokensResponse struct {
+	Tokens []string `json:"tokens"`
+}
+
+type cw721AdapterNumTokensResponse struct {
+	Count uint64 `json:"count"`
+}
+
+//nolint:tagliatelle
+type cw721AdapterClassIDResponse struct {
+	ClassID string `json:"class_id"`
+}
+
+type cw721AdapterListRequest struct {
+	TokenID string   `json:"token_id"`
+	Price   sdk.Coin `json:"price"`
+}
+
+type cw721AdapterRoyaltyInfoRequest struct {
+	TokenID   string `json:"token_id"`
+	SalePrice string `json:"sale_price"`
+}
+
+type cw721AdapterRoyaltiesInfoResponse struct {
+	Address       string `json:"address"`
+	RoyaltyAmount string `json:"royalty_amount"`
+}
+
+type cw721AdapterListingResponse struct {
+	Seller string   `json:"seller"`
+	Price  sdk.Coin `json:"price"`
+}
+
+type cw721AdapterMethod string
+
+const (
+	// tx.
+	cw721AdapterMethodMint        cw721AdapterMethod = "mint"
+	cw721AdapterMethodApprove     cw721AdapterMethod = "approve"
+	cw721AdapterMethodTransferNft cw721AdapterMethod = "transfer_nft"
+	cw721AdapterMethodWithdraw    cw721AdapterMethod = "withdraw"
+	cw721AdapterMethodList        cw721AdapterMethod = "list"
+	cw721AdapterMethodBuy         cw721AdapterMethod = "buy"
+	// query.
+	cw721AdapterMethodOwnerOf     cw721AdapterMethod = "owner_of"
+	cw721AdapterMethodNftInfo     cw721AdapterMethod = "nft_info"
+	cw721AdapterMethodTokens      cw721AdapterMethod = "tokens"
+	cw721AdapterMethodNumTokens   cw721AdapterMethod = "num_tokens"
+	cw721AdapterMethodClassID     cw721AdapterMethod = "class_id"
+	cw721AdapterMethodRoyaltyInfo cw721AdapterMethod = "royalty_info"
+	cw721AdapterMethodListing     cw721AdapterMethod = "listing"
+)
+
+type escrowInstantiateRequest struct {
+	Symbol        string `json:"symbol"`
+	Subunit       string `json:"subunit"`
+	Precision     uint32 `json:"precision"`
+	InitialAmount string `json:"initial_amount"`
+}
+
+type escrowCreateRequest struct {
+	Beneficiary string `json:"beneficiary"`
+	Arbiter     string `json:"arbiter"`
+	Amount      string `json:"amount"`
+}
+
+type escrowIDRequest struct {
+	ID uint64 `json:"id"`
+}
+
+type escrowResponse struct {
+	ID          uint64 `json:"id"`
+	Holder      string `json:"holder"`
+	Beneficiary string `json:"beneficiary"`
+	Arbiter     string `json:"arbiter"`
+	Amount      string `json:"amount"`
+}
+
+type escrowsResponse struct {
+	Escrows []escrowResponse `json:"escrows"`
+}
+
+type escrowDenomResponse struct {
+	Denom string `json:"denom"`
+}
+
+type escrowMethod string
+
+const (
+	// tx.
+	escrowMethodCreateEscrow escrowMethod = "create_escrow"
+	escrowMethodRelease      escrowMethod = "release"
+	escrowMethodRefund       escrowMethod = "refund"
+	// query.
+	escrowMethodEscrows escrowMethod = "escrows"
+	escrowMethodDenom   escrowMethod = "denom"
+)
+
+type airdropInstantiateRequest struct {
+	Symbol     string `json:"symbol"`
+	Subunit    string `json:"subunit"`
+	Precision  uint32 `json:"precision"`
+	MerkleRoot string `json:"merkle_root"`
+}
+
+type airdropClaim struct {
+	Address string   `json:"address,omitempty"`
+	Amount  string   `json:"amount"`
+	Proof   []string `json:"proof"`
+}
+
+type airdropClaimBatchRequest struct {
+	Claims []airdropClaim `json:"claims"`
+}
+
+type airdropAddressRequest struct {
+	Address string `json:"address"`
+}
+
+type airdropConfigResponse struct {
+	Denom        string `json:"denom"`
+	MerkleRoot   string `json:"merkle_root"`
+	TotalClaimed string `json:"total_claimed"`
+}
+
+type airdropIsClaimedResponse struct {
+	IsClaimed bool `json:"is_claimed"`
+}
+
+type airdropMethod string
+
+const (
+	// tx.
+	airdropMethodClaim      airdropMethod = "claim"
+	airdropMethodClaimBatch airdropMethod = "claim_batch"
+	// query.
+	airdropMethodConfig    airdropMethod = "config"
+	airdropMethodIsClaimed airdropMethod = "is_claimed"
+)
+
+type vestingPayoutAllocation struct {
+	Beneficiary string `json:"beneficiary"`
+	Amount      string `json:"amount"`
+}
+
+type vestingPayoutUnlock struct {
+	AfterSeconds uint64 `json:"after_seconds"`
+	Unlocked     string `json:"unlocked"`
+}
+
+type vestingPayoutInstantiateRequest struct {
+	Symbol      string                    `json:"symbol"`
+	Subunit     string                    `json:"subunit"`
+	Precision   uint32                    `json:"precision"`
+	Allocations []vestingPayoutAllocation `json:"allocations"`
+	Schedule    []vestingPayoutUnlock     `json:"schedule"`
+}
+
+type vestingPayoutBeneficiaryRequest struct {
+	Beneficiary string `json:"beneficiary"`
+}
+
+type vestingPayoutScheduleResponse struct {
+	Denom    string                `json:"denom"`
+	Start    string                `json:"start"`
+	Schedule []vestingPayoutUnlock `json:"schedule"`
+	Unlocked string                `json:"unlocked"`
+}
+
+type vestingPayoutAllocationResponse struct {
+	Amount string `json:"amount"`
+	Frozen string `json:"frozen"`
+}
+
+type vestingPayoutMethod string
+
+const (
+	// tx.
+	vestingPayoutMethodTick vestingPayoutMethod = "tick"
+	// query.
+	vestingPayoutMethodSchedule   vestingPayoutMethod = "schedule"
+	vestingPayoutMethodAllocation vestingPayoutMethod = "allocation"
+)
+
+type migrationInstantiateRequest struct {
+	Symbol  string `json:"symbol"`
+	Subunit string `json:"subunit"`
+}
+
+type migrationEnqueueRequest struct {
+	Amount string `json:"amount"`
+}
+
+type migrationDenomResponse struct {
+	Denom string `json:"denom"`
+}
+
+type migrationPendingResponse struct {
+	Count  uint64 `json:"count"`
+	Legacy uint64 `json:"legacy"`
+}
+
+type migrationMethod string
+
+const (
+	// tx.
+	migrationMethodEnqueue migrationMethod = "enqueue"
+	migrationMethodFlush   migrationMethod = "flush"
+	// query.
+	migrationMethodPending migrationMethod = "pending"
+	migrationMethodDenom   migrationMethod = "denom"
+)
+
+// reflect models
+
+type reflectDispatchRequest struct {
+	Msgs []json.RawMessage `json:"msgs"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type reflectDispatchSubRequest struct {
+	Msg     json.RawMessage `json:"msg"`
+	ReplyID uint64          `json:"reply_id"`
+}
+
+type reflectReplyRequest struct {
+	ID uint64 `json:"id"`
+}
+
+type reflectSubMsgResponse struct {
+	Events []json.RawMessage `json:"events"`
+}
+
+type reflectSubMsgResult struct {
+	Ok    *reflectSubMsgResponse `json:"ok"`
+	Error string                 `json:"error"`
+}
+
+type reflectReplyResponse struct {
+	ID     uint64              `json:"id"`
+	Result reflectSubMsgResult `json:"result"`
+}
+
+type reflectMethod string
+
+const (
+	// tx.
+	reflectMethodDispatch    reflectMethod = "dispatch"
+	reflectMethodDispatchSub reflectMethod = "dispatch_sub"
+	// query.
+	reflectMethodReply reflectMethod = "reply"
+)
+
+// feegrant models
+
+//nolint:tagliatelle // wasm requirements
+type feegrantBasicAllowance struct {
+	SpendLimit []sdk.Coin `json:"spend_limit"`
+	Expiration *string    `json:"expiration"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type feegrantPeriodicAllowance struct {
+	SpendLimit       []sdk.Coin `json:"spend_limit"`
+	Expiration       *string    `json:"expiration"`
+	PeriodSeconds    uint64     `json:"period_seconds"`
+	PeriodSpendLimit []sdk.Coin `json:"period_spend_limit"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type feegrantAllowedMsgAllowance struct {
+	Allowance       feegrantAllowance `json:"allowance"`
+	AllowedMessages []string          `json:"allowed_messages"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type feegrantAllowance struct {
+	Basic      *feegrantBasicAllowance      `json:"basic,omitempty"`
+	Periodic   *feegrantPeriodicAllowance   `json:"periodic,omitempty"`
+	AllowedMsg *feegrantAllowedMsgAllowance `json:"allowed_msg,omitempty"`
+}
+
+type feegrantGrantRequest struct {
+	Grantee   string            `json:"grantee"`
+	Allowance feegrantAllowance `json:"allowance"`
+}
+
+type feegrantRevokeRequest struct {
+	Grantee string `json:"grantee"`
+}
+
+type feegrantMethod string
+
+const (
+	feegrantMethodGrantAllowance  feegrantMethod = "grant_allowance"
+	feegrantMethodRevokeAllowance feegrantMethod = "revoke_allowance"
+)
+
+// staking models
+
+type stakingDelegateRequest struct {
+	Validator string   `json:"validator"`
+	Amount    sdk.Coin `json:"amount"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type stakingRedelegateRequest struct {
+	SrcValidator string   `json:"src_validator"`
+	DstValidator string   `json:"dst_validator"`
+	Amount       sdk.Coin `json:"amount"`
+}
+
+type stakingWithdrawRewardsRequest struct {
+	Validator string `json:"validator"`
+}
+
+type stakingSetWithdrawAddressRequest struct {
+	Address string `json:"address"`
+}
+
+type stakingMethod string
+
+const (
+	stakingMethodDelegate           stakingMethod = "delegate"
+	stakingMethodUndelegate         stakingMethod = "undelegate"
+	stakingMethodRedelegate         stakingMethod = "redelegate"
+	stakingMethodWithdrawRewards    stakingMethod = "withdraw_rewards"
+	stakingMethodSetWithdrawAddress stakingMethod = "set_withdraw_address"
+)
+
+// gov models
+
+//nolint:tagliatelle // wasm requirements
+type govProposalMsg struct {
+	TypeURL string `json:"type_url"`
+	Value   []byte `json:"value"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type govSubmitProposalRequest struct {
+	Messages       []govProposalMsg `json:"messages"`
+	InitialDeposit []sdk.Coin       `json:"initial_deposit"`
+	Title          string           `json:"title"`
+	Summary        string           `json:"summary"`
+	Metadata       string           `json:"metadata,omitempty"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type govVoteRequest struct {
+	ProposalID uint64 `json:"proposal_id"`
+	Option     string `json:"option"`
+}
+
+type govWeightedVoteOption struct {
+	Option string `json:"option"`
+	Weight string `json:"weight"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type govVoteWeightedRequest struct {
+	ProposalID uint64                  `json:"proposal_id"`
+	Options    []govWeightedVoteOption `json:"options"`
+}
+
+//nolint:tagliatelle // wasm requirements
+type govDepositRequest struct {
+	ProposalID uint64     `json:"proposal_id"`
+	Amount     []sdk.Coin `json:"amount"`
+}
+
+type govMethod string
+
+const (
+	govMethodSubmitProposal govMethod = "submit_proposal"
+	govMethodVote           govMethod = "vote"
+	govMethodVoteWeighted   govMethod = "vote_weighted"
+	govMethodDeposit        govMethod = "deposit"
+)
+
+// vesting models
+
+//nolint:tagliatelle // wasm requirements
+type vestingCreateAccountRequest struct {
+	Recipient string     `json:"recipient"`
+	Amount    []sdk.Coin `json:"amount"`
+	EndTime   string     `json:"end_time"`
+	Schedule  string     `json:"schedule"`
+}
+
+type vestingMethod string
+
+const (
+	vestingMethodCreateVestingAccount vestingMethod = "create_vesting_account"
 )
 
 // TestWASMBankSendContract runs a contract deployment flow and tests that the contract is able to use Bank module
@@ -686,13 +1281,277 @@ func TestWASMAuthzContract(t *testing.T) {
 	requireT.Equal(sdk.NewCoins(chain.NewCoin(totalAmountToSend)).String(), receiverBalancesRes.Balances.String())
 }
 
-// TestWASMFungibleTokenInContract verifies that smart contract is able to execute all fungible token message and core queries.
-func TestWASMFungibleTokenInContract(t *testing.T) {
+// TestWASMAuthzContractTimeBoundedGrant verifies that the contract creates grants expiring relative to the block
+// time and reports the expiration of its own grants.
+func TestWASMAuthzContractTimeBoundedGrant(t *testing.T) {
 	t.Parallel()
 
 	ctx, chain := integrationtests.NewCoreumTestingContext(t)
 
-	admin := chain.GenAccount()
+	requireT := require.New(t)
+	granter := chain.GenAccount()
+	grantee := chain.GenAccount()
+
+	authzClient := authztypes.NewQueryClient(chain.ClientContext)
+
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(granter, chain.NewCoin(sdkmath.NewInt(5000000000))),
+	)
+
+	txf := chain.TxFactory().WithSimulateAndExecute(true)
+
+	initialPayload, err := json.Marshal(authz{
+		Granter: granter.String(),
+	})
+	requireT.NoError(err)
+
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		granter,
+		moduleswasm.AuthzWASM,
+		integration.InstantiateConfig{
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    initialPayload,
+			Label:      "authz",
+		},
+	)
+	requireT.NoError(err)
+
+	// let the contract grant on behalf of the granter
+	grantMsg, err := authztypes.NewMsgGrant(
+		granter,
+		sdk.MustAccAddressFromBech32(contractAddr),
+		authztypes.NewGenericAuthorization(sdk.MsgTypeURL(&authztypes.MsgGrant{})),
+		lo.ToPtr(time.Now().Add(time.Hour)),
+	)
+	requireT.NoError(err)
+	_, err = client.BroadcastTx(
+		ctx,
+		chain.ClientContext.WithFromAddress(granter),
+		chain.TxFactory().WithGas(chain.GasLimitByMsgs(grantMsg)),
+		grantMsg,
+	)
+	requireT.NoError(err)
+
+	execute := func(method authzMethod, payload any) error {
+		executePayload, err := json.Marshal(map[authzMethod]any{method: payload})
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, granter, contractAddr, executePayload, sdk.Coin{})
+		return err
+	}
+
+	// ********** Grant **********
+
+	grantReq := authzGrantRequest{
+		Grantee: grantee.String(),
+		Authorization: authzAuthorization{
+			Generic: &authzGenericAuthorization{
+				Msg: sdk.MsgTypeURL(&banktypes.MsgSend{}),
+			},
+		},
+		ExpiresInSeconds: 60,
+	}
+	requireT.NoError(execute(authzMethodGrant, grantReq))
+
+	granteeGrantsRes, err := authzClient.Grants(ctx, &authztypes.QueryGrantsRequest{
+		Granter: granter.String(),
+		Grantee: grantee.String(),
+	})
+	requireT.NoError(err)
+	requireT.Len(granteeGrantsRes.Grants, 1)
+	requireT.NotNil(granteeGrantsRes.Grants[0].Expiration)
+	requireT.True(granteeGrantsRes.Grants[0].Expiration.After(time.Now()))
+	requireT.True(granteeGrantsRes.Grants[0].Expiration.Before(time.Now().Add(2 * time.Minute)))
+
+	// the grant must expire after the block time
+	grantReq.ExpiresInSeconds = 0
+	requireT.ErrorContains(execute(authzMethodGrant, grantReq), "is not after the block time")
+
+	// ********** Grants **********
+
+	contractGrantsRes, err := authzClient.Grants(ctx, &authztypes.QueryGrantsRequest{
+		Granter: granter.String(),
+		Grantee: contractAddr,
+	})
+	requireT.NoError(err)
+	requireT.Len(contractGrantsRes.Grants, 1)
+
+	grantsPayload, err := json.Marshal(map[authzMethod]struct{}{authzMethodGrants: {}})
+	requireT.NoError(err)
+	queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, grantsPayload)
+	requireT.NoError(err)
+	var grantsRes authzGrantsResponse
+	requireT.NoError(json.Unmarshal(queryOut, &grantsRes))
+	requireT.Len(grantsRes.Grants, 1)
+	requireT.Equal(granter.String(), grantsRes.Grants[0].Granter)
+	requireT.NotNil(grantsRes.Grants[0].Expiration)
+	requireT.Equal(
+		strconv.FormatInt(contractGrantsRes.Grants[0].Expiration.UnixNano(), 10),
+		*grantsRes.Grants[0].Expiration,
+	)
+}
+
+// TestWASMAuthzContractStakeGrant verifies that the contract grants a delegate-only staking authorization restricted
+// to the allowed validators.
+func TestWASMAuthzContractStakeGrant(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	requireT := require.New(t)
+	granter := chain.GenAccount()
+	operator := chain.GenAccount()
+
+	authzClient := authztypes.NewQueryClient(chain.ClientContext)
+	stakingClient := stakingtypes.NewQueryClient(chain.ClientContext)
+
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(granter, chain.NewCoin(sdkmath.NewInt(5000000000))),
+	)
+
+	txf := chain.TxFactory().WithSimulateAndExecute(true)
+
+	validatorsRes, err := stakingClient.Validators(ctx, &stakingtypes.QueryValidatorsRequest{
+		Status: stakingtypes.Bonded.String(),
+	})
+	requireT.NoError(err)
+	requireT.GreaterOrEqual(len(validatorsRes.Validators), 2)
+	allowedValidator := validatorsRes.Validators[0].OperatorAddress
+	otherValidator := validatorsRes.Validators[1].OperatorAddress
+
+	initialPayload, err := json.Marshal(authz{
+		Granter: granter.String(),
+	})
+	requireT.NoError(err)
+
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		granter,
+		moduleswasm.AuthzWASM,
+		integration.InstantiateConfig{
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    initialPayload,
+			Label:      "authz",
+		},
+	)
+	requireT.NoError(err)
+
+	// let the contract grant on behalf of the granter
+	grantMsg, err := authztypes.NewMsgGrant(
+		granter,
+		sdk.MustAccAddressFromBech32(contractAddr),
+		authztypes.NewGenericAuthorization(sdk.MsgTypeURL(&authztypes.MsgGrant{})),
+		lo.ToPtr(time.Now().Add(time.Hour)),
+	)
+	requireT.NoError(err)
+	_, err = client.BroadcastTx(
+		ctx,
+		chain.ClientContext.WithFromAddress(granter),
+		chain.TxFactory().WithGas(chain.GasLimitByMsgs(grantMsg)),
+		grantMsg,
+	)
+	requireT.NoError(err)
+
+	// ********** Grant **********
+
+	maxTokens := chain.NewCoin(sdkmath.NewInt(1_000))
+	grantPayload, err := json.Marshal(map[authzMethod]authzGrantRequest{
+		authzMethodGrant: {
+			Grantee: operator.String(),
+			Authorization: authzAuthorization{
+				Stake: &authzStakeAuthorization{
+					MaxTokens: &maxTokens,
+					Validators: &authzStakeValidators{
+						AllowList: []string{allowedValidator},
+					},
+					AuthorizationType: "delegate",
+				},
+			},
+			ExpiresInSeconds: 600,
+		},
+	})
+	requireT.NoError(err)
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, granter, contractAddr, grantPayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	grantsRes, err := authzClient.Grants(ctx, &authztypes.QueryGrantsRequest{
+		Granter: granter.String(),
+		Grantee: operator.String(),
+	})
+	requireT.NoError(err)
+	requireT.Len(grantsRes.Grants, 1)
+	var stakeAuthorization stakingtypes.StakeAuthorization
+	chain.ClientContext.Codec().MustUnmarshal(grantsRes.Grants[0].Authorization.Value, &stakeAuthorization)
+	requireT.Equal(stakingtypes.AuthorizationType_AUTHORIZATION_TYPE_DELEGATE, stakeAuthorization.AuthorizationType)
+	requireT.Equal([]string{allowedValidator}, stakeAuthorization.GetAllowList().Address)
+	requireT.Equal(maxTokens.String(), stakeAuthorization.MaxTokens.String())
+
+	// ********** Exec **********
+
+	delegateAmount := chain.NewCoin(sdkmath.NewInt(400))
+	execMsgs := []sdk.Msg{
+		&stakingtypes.MsgDelegate{
+			DelegatorAddress: granter.String(),
+			ValidatorAddress: allowedValidator,
+			Amount:           delegateAmount,
+		},
+		&stakingtypes.MsgDelegate{
+			DelegatorAddress: granter.String(),
+			ValidatorAddress: otherValidator,
+			Amount:           delegateAmount,
+		},
+		&stakingtypes.MsgUndelegate{
+			DelegatorAddress: granter.String(),
+			ValidatorAddress: allowedValidator,
+			Amount:           delegateAmount,
+		},
+	}
+	execMsgsToFund := make([]sdk.Msg, 0, len(execMsgs))
+	for _, msg := range execMsgs {
+		execMsg := authztypes.NewMsgExec(operator, []sdk.Msg{msg})
+		execMsgsToFund = append(execMsgsToFund, &execMsg)
+	}
+	chain.FundAccountWithOptions(ctx, t, operator, integration.BalancesOptions{
+		Messages: execMsgsToFund,
+	})
+
+	broadcastExec := func(msg sdk.Msg) error {
+		execMsg := authztypes.NewMsgExec(operator, []sdk.Msg{msg})
+		_, err := client.BroadcastTx(
+			ctx,
+			chain.ClientContext.WithFromAddress(operator),
+			chain.TxFactory().WithGas(chain.GasLimitByMsgs(&execMsg)),
+			&execMsg,
+		)
+		return err
+	}
+
+	// delegation to the allowed validator is authorized
+	requireT.NoError(broadcastExec(execMsgs[0]))
+
+	delegationRes, err := stakingClient.Delegation(ctx, &stakingtypes.QueryDelegationRequest{
+		DelegatorAddr: granter.String(),
+		ValidatorAddr: allowedValidator,
+	})
+	requireT.NoError(err)
+	requireT.Equal(delegateAmount.String(), delegationRes.DelegationResponse.Balance.String())
+
+	// delegation to other validators is not
+	requireT.ErrorIs(broadcastExec(execMsgs[1]), cosmoserrors.ErrUnauthorized)
+
+	// and the grant is delegate-only
+	requireT.ErrorIs(broadcastExec(execMsgs[2]), authztypes.ErrNoAuthorizationFound)
+}
+
+// TestWASMFungibleTokenInContract verifies that smart contract is able to execute all fungible token message and core queries.
+func TestWASMFungibleTokenInContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
 	recipient1 := chain.GenAccount()
 	recipient2 := chain.GenAccount()
 
@@ -966,9 +1825,10 @@ func TestWASMFungibleTokenInContract(t *testing.T) {
 	requireT.NoError(err)
 	var wasmParamsRes assetfttypes.QueryParamsResponse
 	requireT.NoError(json.Unmarshal(queryOut, &wasmParamsRes))
-	requireT.Equal(
-		chain.QueryAssetFTParams(ctx, t).IssueFee, wasmParamsRes.Params.IssueFee,
-	)
+	ftParams := chain.QueryAssetFTParams(ctx, t)
+	requireT.Equal(ftParams.IssueFee, wasmParamsRes.Params.IssueFee)
+	requireT.True(ftParams.TokenUpgradeDecisionTimeout.Equal(wasmParamsRes.Params.TokenUpgradeDecisionTimeout))
+	requireT.Equal(ftParams.TokenUpgradeGracePeriod, wasmParamsRes.Params.TokenUpgradeGracePeriod)
 
 	// ********** Token **********
 
@@ -1081,12 +1941,36 @@ func TestWASMFungibleTokenInContract(t *testing.T) {
 	requireT.Equal(
 		sdk.NewCoin(denom, amountToWhitelist), wasmWhitelistedBalancesRes.Balances[0],
 	)
+
+	// ********** Balances **********
+
+	balancesPayload, err := json.Marshal(map[ftMethod]accountBodyFTRequest{
+		ftMethodBalances: {
+			Account: recipient2.String(),
+		},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, balancesPayload)
+	requireT.NoError(err)
+	var wasmBalancesRes accountBalancesFTResponse
+	requireT.NoError(json.Unmarshal(queryOut, &wasmBalancesRes))
+	var tokenBalance *accountBalanceFT
+	for i := range wasmBalancesRes.Balances {
+		if wasmBalancesRes.Balances[i].Denom == denom {
+			tokenBalance = &wasmBalancesRes.Balances[i]
+		}
+	}
+	requireT.NotNil(tokenBalance)
+	requireT.Equal(amountToWhitelist.String(), tokenBalance.Balance.String())
+	requireT.Equal(amountToWhitelist.String(), tokenBalance.Spendable.String())
+	requireT.Equal(sdkmath.ZeroInt().String(), tokenBalance.Frozen.String())
+	requireT.Equal(amountToWhitelist.String(), tokenBalance.Whitelisted.String())
+	requireT.Equal(sdkmath.ZeroInt().String(), tokenBalance.Locked.String())
 }
 
-// TestWASMNonFungibleTokenInContract verifies that smart contract is able to execute all non-fungible token message and core queries.
-//
-//nolint:nosnakecase
-func TestWASMNonFungibleTokenInContract(t *testing.T) {
+// TestWASMFungibleTokenEncodingsInContract verifies that the assetft messages sent by the contract as custom
+// messages and as stargate messages leave the chain in the same state.
+func TestWASMFungibleTokenEncodingsInContract(t *testing.T) {
 	t.Parallel()
 
 	ctx, chain := integrationtests.NewCoreumTestingContext(t)
@@ -1095,298 +1979,1498 @@ func TestWASMNonFungibleTokenInContract(t *testing.T) {
 	recipient := chain.GenAccount()
 
 	requireT := require.New(t)
+	issueFee := chain.QueryAssetFTParams(ctx, t).IssueFee
 	chain.Faucet.FundAccounts(ctx, t,
 		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
 	)
 
-	clientCtx := chain.ClientContext
 	txf := chain.TxFactory().
 		WithSimulateAndExecute(true)
-	assetNftClient := assetnfttypes.NewQueryClient(clientCtx)
-	nftClient := nfttypes.NewQueryClient(clientCtx)
+	bankClient := banktypes.NewQueryClient(chain.ClientContext)
+	ftClient := assetfttypes.NewQueryClient(chain.ClientContext)
+
+	type ftState struct {
+		Token            assetfttypes.Token
+		ContractBalance  sdkmath.Int
+		RecipientBalance sdkmath.Int
+		Frozen           sdkmath.Int
+		Whitelisted      sdkmath.Int
+	}
 
-	// ********** Issuance **********
+	runFlow := func(encoding ftEncoding) ftState {
+		issuanceReq := issueFTRequest{
+			Symbol:        "symbol",
+			Subunit:       "subunit",
+			Precision:     6,
+			InitialAmount: "10000",
+			Description:   "my wasm fungible token",
+			Features: []assetfttypes.Feature{
+				assetfttypes.Feature_minting,
+				assetfttypes.Feature_burning,
+				assetfttypes.Feature_freezing,
+				assetfttypes.Feature_whitelisting,
+			},
+			BurnRate:           "0.1",
+			SendCommissionRate: "0.2",
+			Encoding:           encoding,
+		}
+		payload, err := json.Marshal(issuanceReq)
+		requireT.NoError(err)
+
+		contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+			ctx,
+			txf,
+			admin,
+			moduleswasm.FTWASM,
+			integration.InstantiateConfig{
+				Amount:     issueFee,
+				AccessType: wasmtypes.AccessTypeUnspecified,
+				Payload:    payload,
+				Label:      "fungible_token_" + string(encoding),
+			},
+		)
+		requireT.NoError(err)
+		denom := assetfttypes.BuildDenom(issuanceReq.Subunit, sdk.MustAccAddressFromBech32(contractAddr))
+
+		execute := func(payload any) {
+			msg, err := json.Marshal(payload)
+			requireT.NoError(err)
+			_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, msg, sdk.Coin{})
+			requireT.NoError(err)
+		}
 
-	royaltyRate := sdk.MustNewDecFromStr("0.1")
-	data := make([]byte, 256)
-	for i := 0; i < 256; i++ {
-		data[i] = uint8(i)
+		execute(map[ftMethod]amountBodyFTRequest{ftMethodMint: {Amount: "500"}})
+		execute(map[ftMethod]amountBodyFTRequest{ftMethodBurn: {Amount: "100"}})
+		execute(map[ftMethod]accountAmountBodyFTRequest{
+			ftMethodFreeze: {Account: recipient.String(), Amount: "100"},
+		})
+		execute(map[ftMethod]accountAmountBodyFTRequest{
+			ftMethodUnfreeze: {Account: recipient.String(), Amount: "40"},
+		})
+		execute(map[ftMethod]struct{}{ftMethodGloballyFreeze: {}})
+		execute(map[ftMethod]struct{}{ftMethodGloballyUnfreeze: {}})
+		execute(map[ftMethod]accountAmountBodyFTRequest{
+			ftMethodSetWhitelistedLimit: {Account: recipient.String(), Amount: "300"},
+		})
+		execute(map[ftMethod]accountAmountBodyFTRequest{
+			ftMethodMintAndSend: {Account: recipient.String(), Amount: "200"},
+		})
+
+		tokenRes, err := ftClient.Token(ctx, &assetfttypes.QueryTokenRequest{Denom: denom})
+		requireT.NoError(err)
+		contractBalanceRes, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+			Address: contractAddr,
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+		recipientBalanceRes, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+			Address: recipient.String(),
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+		frozenRes, err := ftClient.FrozenBalance(ctx, &assetfttypes.QueryFrozenBalanceRequest{
+			Account: recipient.String(),
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+		whitelistedRes, err := ftClient.WhitelistedBalance(ctx, &assetfttypes.QueryWhitelistedBalanceRequest{
+			Account: recipient.String(),
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+
+		// the denom and the issuer depend on the contract address, everything else must match
+		token := tokenRes.Token
+		requireT.Equal(contractAddr, token.Issuer)
+		token.Denom = ""
+		token.Issuer = ""
+
+		return ftState{
+			Token:            token,
+			ContractBalance:  contractBalanceRes.Balance.Amount,
+			RecipientBalance: recipientBalanceRes.Balance.Amount,
+			Frozen:           frozenRes.Balance.Amount,
+			Whitelisted:      whitelistedRes.Balance.Amount,
+		}
 	}
-	encodedData := base64.StdEncoding.EncodeToString(data)
 
-	issueClassReq := moduleswasm.IssueNFTRequest{
-		Name:        "name",
-		Symbol:      "symbol",
-		Description: "description",
-		URI:         "https://my-nft-class-meta.invalid/1",
-		URIHash:     "hash",
-		Data:        encodedData,
-		Features: []assetnfttypes.ClassFeature{
-			assetnfttypes.ClassFeature_burning,
-			assetnfttypes.ClassFeature_freezing,
-			assetnfttypes.ClassFeature_whitelisting,
-			assetnfttypes.ClassFeature_disable_sending,
-		},
-		RoyaltyRate: royaltyRate.String(),
+	customState := runFlow(ftEncodingCustom)
+	stargateState := runFlow(ftEncodingStargate)
+
+	requireT.Equal(sdkmath.NewInt(10_400), customState.ContractBalance)
+	requireT.Equal(sdkmath.NewInt(200), customState.RecipientBalance)
+	requireT.Equal(customState, stargateState)
+}
+
+// TestWASMFungibleTokenRatesInContract verifies that the burn amount and send commission predicted by the contract
+// match what the chain charges for sends of random amounts.
+func TestWASMFungibleTokenRatesInContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	sender := chain.GenAccount()
+	recipient := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+	)
+
+	const sends = 10
+	sendMsgs := make([]sdk.Msg, 0, sends+1)
+	for i := 0; i < sends+1; i++ {
+		sendMsgs = append(sendMsgs, &banktypes.MsgSend{})
 	}
-	issuerNFTInstantiatePayload, err := json.Marshal(issueClassReq)
+	chain.FundAccountWithOptions(ctx, t, sender, integration.BalancesOptions{
+		Messages: sendMsgs,
+	})
+
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	bankClient := banktypes.NewQueryClient(chain.ClientContext)
+
+	// rates with four decimal places make the rounding up visible on most amounts
+	issuanceReq := issueFTRequest{
+		Symbol:             "symbol",
+		Subunit:            "subunit",
+		Precision:          6,
+		InitialAmount:      "0",
+		Features:           []assetfttypes.Feature{assetfttypes.Feature_minting},
+		BurnRate:           "0.1234",
+		SendCommissionRate: "0.0567",
+	}
+	payload, err := json.Marshal(issuanceReq)
 	requireT.NoError(err)
 
-	// instantiate new contract
 	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
 		ctx,
 		txf,
 		admin,
-		moduleswasm.NftWASM,
+		moduleswasm.FTWASM,
 		integration.InstantiateConfig{
+			Amount:     chain.QueryAssetFTParams(ctx, t).IssueFee,
 			AccessType: wasmtypes.AccessTypeUnspecified,
-			Payload:    issuerNFTInstantiatePayload,
-			Label:      "non_fungible_token",
+			Payload:    payload,
+			Label:      "fungible_token_rates",
 		},
 	)
 	requireT.NoError(err)
+	denom := assetfttypes.BuildDenom(issuanceReq.Subunit, sdk.MustAccAddressFromBech32(contractAddr))
 
-	classID := assetnfttypes.BuildClassID(issueClassReq.Symbol, sdk.MustAccAddressFromBech32(contractAddr))
-	classRes, err := assetNftClient.Class(ctx, &assetnfttypes.QueryClassRequest{Id: classID})
+	// sends of the contract are exempt from the rates, so the sender gets the tokens without fees
+	mintAndSendPayload, err := json.Marshal(map[ftMethod]accountAmountBodyFTRequest{
+		ftMethodMintAndSend: {
+			Account: sender.String(),
+			Amount:  "1000000000",
+		},
+	})
+	requireT.NoError(err)
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, mintAndSendPayload, sdk.Coin{})
 	requireT.NoError(err)
 
-	dataBytes, err := codectypes.NewAnyWithValue(&assetnfttypes.DataBytes{Data: data})
-	// we need to do this, otherwise assertion fails because some private fields are set differently
-	dataToCompare := &codectypes.Any{
-		TypeUrl: dataBytes.TypeUrl,
-		Value:   dataBytes.Value,
+	balance := func(address string) sdkmath.Int {
+		res, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+			Address: address,
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+		return res.Balance.Amount
+	}
+	send := func(amount sdkmath.Int) {
+		msg := &banktypes.MsgSend{
+			FromAddress: sender.String(),
+			ToAddress:   recipient.String(),
+			Amount:      sdk.NewCoins(sdk.NewCoin(denom, amount)),
+		}
+		_, err := client.BroadcastTx(
+			ctx,
+			chain.ClientContext.WithFromAddress(sender),
+			chain.TxFactory().WithGas(chain.GasLimitByMsgs(msg)),
+			msg,
+		)
+		requireT.NoError(err)
+	}
+
+	//nolint:gosec // the amounts only need to vary
+	rnd := rand.New(rand.NewSource(time.Now().UnixNano()))
+	for i := 0; i < sends; i++ {
+		amount := sdkmath.NewInt(rnd.Int63n(1_000_000) + 1)
+
+		simulatePayload, err := json.Marshal(map[ftMethod]amountBodyFTRequest{
+			ftMethodSimulateSend: {Amount: amount.String()},
+		})
+		requireT.NoError(err)
+		queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, simulatePayload)
+		requireT.NoError(err)
+		var simulated simulateSendFTResponse
+		requireT.NoError(json.Unmarshal(queryOut, &simulated))
+		requireT.Equal(
+			amount.Add(simulated.BurnAmount).Add(simulated.CommissionAmount).String(), simulated.TotalSpent.String(),
+		)
+
+		senderBefore := balance(sender.String())
+		issuerBefore := balance(contractAddr)
+		recipientBefore := balance(recipient.String())
+		send(amount)
+
+		spent := senderBefore.Sub(balance(sender.String()))
+		commission := balance(contractAddr).Sub(issuerBefore)
+		received := balance(recipient.String()).Sub(recipientBefore)
+		requireT.Equal(simulated.TotalSpent.String(), spent.String(), "amount %s", amount)
+		requireT.Equal(simulated.CommissionAmount.String(), commission.String(), "amount %s", amount)
+		requireT.Equal(amount.String(), received.String(), "amount %s", amount)
 	}
+
+	// the net receive amount of the whole balance can be sent and leaves less than the fees of one more token
+	spendable := balance(sender.String())
+	netPayload, err := json.Marshal(map[ftMethod]map[string]string{
+		ftMethodNetReceiveAmount: {"spendable": spendable.String()},
+	})
+	requireT.NoError(err)
+	queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, netPayload)
 	requireT.NoError(err)
+	var netAmount sdkmath.Int
+	requireT.NoError(json.Unmarshal(queryOut, &netAmount))
 
-	expectedClass := assetnfttypes.Class{
-		Id:          classID,
-		Issuer:      contractAddr,
-		Name:        issueClassReq.Name,
-		Symbol:      issueClassReq.Symbol,
-		Description: issueClassReq.Description,
-		URI:         issueClassReq.URI,
-		URIHash:     issueClassReq.URIHash,
-		Data:        dataToCompare,
-		Features:    issueClassReq.Features,
-		RoyaltyRate: royaltyRate,
-	}
-	requireT.Equal(
-		expectedClass, classRes.Class,
+	send(netAmount)
+	requireT.True(balance(sender.String()).LT(sdkmath.NewInt(3)), "remaining %s", balance(sender.String()))
+}
+
+// TestWASMCW20AdapterContract verifies that the cw20 adapter contract moves an assetft token through the cw20
+// interface and keeps the wrapped supply backed by the native tokens it holds.
+func TestWASMCW20AdapterContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	holder := chain.GenAccount()
+	spender := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+		integration.NewFundedAccount(holder, chain.NewCoin(sdkmath.NewInt(500000000))),
+		integration.NewFundedAccount(spender, chain.NewCoin(sdkmath.NewInt(500000000))),
 	)
 
-	// ********** Mint **********
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	bankClient := banktypes.NewQueryClient(chain.ClientContext)
 
-	mintNFTReq1 := moduleswasm.NftMintRequest{
-		ID:      "id-1",
-		URI:     "https://my-nft-meta.invalid/1",
-		URIHash: "hash",
-		Data:    encodedData,
+	// the burning feature lets the adapter burn the tokens it holds
+	msgIssue := &assetfttypes.MsgIssue{
+		Issuer:        admin.String(),
+		Symbol:        "ADAPTED",
+		Subunit:       "uadapted",
+		Precision:     6,
+		InitialAmount: sdkmath.NewInt(1_000_000),
+		Features:      []assetfttypes.Feature{assetfttypes.Feature_burning},
 	}
-	mintPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftMintRequest{
-		moduleswasm.NftMethodMint: mintNFTReq1,
-	})
+	_, err := client.BroadcastTx(
+		ctx,
+		chain.ClientContext.WithFromAddress(admin),
+		chain.TxFactory().WithGas(chain.GasLimitByMsgs(msgIssue)),
+		msgIssue,
+	)
 	requireT.NoError(err)
+	denom := assetfttypes.BuildDenom(msgIssue.Subunit, admin)
 
-	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, mintPayload, sdk.Coin{})
+	payload, err := json.Marshal(cw20AdapterInstantiateRequest{Denom: denom})
 	requireT.NoError(err)
-
-	nftResp, err := nftClient.NFT(ctx, &nfttypes.QueryNFTRequest{
-		ClassId: classID,
-		Id:      mintNFTReq1.ID,
-	})
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.CW20AdapterWASM,
+		integration.InstantiateConfig{
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    payload,
+			Label:      "cw20_adapter",
+		},
+	)
 	requireT.NoError(err)
 
-	expectedNFT1 := &nfttypes.NFT{
-		ClassId: classID,
-		Id:      mintNFTReq1.ID,
-		Uri:     mintNFTReq1.URI,
-		UriHash: mintNFTReq1.URIHash,
-		Data:    dataToCompare,
+	execute := func(sender sdk.AccAddress, msg any, funds sdk.Coin) {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, sender, contractAddr, payload, funds)
+		requireT.NoError(err)
+	}
+	query := func(msg any, res any) {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, payload)
+		requireT.NoError(err)
+		requireT.NoError(json.Unmarshal(queryOut, res))
+	}
+	cw20Balance := func(address sdk.AccAddress) string {
+		var res cw20AdapterBalanceResponse
+		query(map[cw20AdapterMethod]cw20AdapterBalanceRequest{
+			cw20AdapterMethodBalance: {Address: address.String()},
+		}, &res)
+		return res.Balance.String()
+	}
+	bankBalance := func(address string) string {
+		res, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+			Address: address,
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+		return res.Balance.Amount.String()
 	}
-	requireT.Equal(
-		expectedNFT1, nftResp.Nft,
-	)
 
-	// ********** Freeze **********
+	// ********** Deposit **********
 
-	freezePayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
-		moduleswasm.NftMethodFreeze: {
-			ID: mintNFTReq1.ID,
-		},
-	})
-	requireT.NoError(err)
+	execute(admin, map[cw20AdapterMethod]struct{}{cw20AdapterMethodDeposit: {}}, sdk.NewInt64Coin(denom, 1000))
+	requireT.Equal("1000", cw20Balance(admin))
+	requireT.Equal("1000", bankBalance(contractAddr))
 
-	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, freezePayload, sdk.Coin{})
-	requireT.NoError(err)
+	// ********** Transfer **********
 
-	assertNftFrozenRes, err := assetNftClient.Frozen(ctx, &assetnfttypes.QueryFrozenRequest{
-		Id:      mintNFTReq1.ID,
-		ClassId: classID,
-	})
-	requireT.NoError(err)
-	requireT.True(assertNftFrozenRes.Frozen)
+	execute(admin, map[cw20AdapterMethod]cw20AdapterTransferRequest{
+		cw20AdapterMethodTransfer: {Recipient: holder.String(), Amount: "400"},
+	}, sdk.Coin{})
+	requireT.Equal("600", cw20Balance(admin))
+	requireT.Equal("400", cw20Balance(holder))
+
+	// ********** Allowance **********
+
+	execute(holder, map[cw20AdapterMethod]cw20AdapterAllowanceRequest{
+		cw20AdapterMethodIncreaseAllowance: {Spender: spender.String(), Amount: "150"},
+	}, sdk.Coin{})
+	execute(spender, map[cw20AdapterMethod]cw20AdapterTransferFromRequest{
+		cw20AdapterMethodTransferFrom: {Owner: holder.String(), Recipient: spender.String(), Amount: "100"},
+	}, sdk.Coin{})
+	requireT.Equal("300", cw20Balance(holder))
+	requireT.Equal("100", cw20Balance(spender))
+
+	var allowanceRes cw20AdapterAllowanceResponse
+	query(map[cw20AdapterMethod]cw20AdapterOwnerSpenderRequest{
+		cw20AdapterMethodAllowance: {Owner: holder.String(), Spender: spender.String()},
+	}, &allowanceRes)
+	requireT.Equal("50", allowanceRes.Allowance.String())
+
+	// ********** Withdraw **********
+
+	execute(spender, map[cw20AdapterMethod]cw20AdapterAmountRequest{
+		cw20AdapterMethodWithdraw: {Amount: "100"},
+	}, sdk.Coin{})
+	requireT.Equal("0", cw20Balance(spender))
+	requireT.Equal("100", bankBalance(spender.String()))
 
-	// ********** Unfreeze **********
+	// ********** Burn **********
 
-	unfreezePayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
-		moduleswasm.NftMethodUnfreeze: {
-			ID: mintNFTReq1.ID,
-		},
-	})
-	requireT.NoError(err)
+	execute(admin, map[cw20AdapterMethod]cw20AdapterAmountRequest{
+		cw20AdapterMethodBurn: {Amount: "200"},
+	}, sdk.Coin{})
+	requireT.Equal("400", cw20Balance(admin))
 
-	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, unfreezePayload, sdk.Coin{})
+	supplyRes, err := bankClient.SupplyOf(ctx, &banktypes.QuerySupplyOfRequest{Denom: denom})
 	requireT.NoError(err)
+	requireT.Equal("999800", supplyRes.Amount.Amount.String())
 
-	assertNftFrozenRes, err = assetNftClient.Frozen(ctx, &assetnfttypes.QueryFrozenRequest{
-		Id:      mintNFTReq1.ID,
-		ClassId: classID,
-	})
-	requireT.NoError(err)
-	requireT.False(assertNftFrozenRes.Frozen)
+	// ********** TokenInfo **********
 
-	// ********** AddToWhitelist **********
+	var tokenInfoRes cw20AdapterTokenInfoResponse
+	query(map[cw20AdapterMethod]struct{}{cw20AdapterMethodTokenInfo: {}}, &tokenInfoRes)
+	requireT.Equal(msgIssue.Symbol, tokenInfoRes.Symbol)
+	requireT.Equal(uint8(msgIssue.Precision), tokenInfoRes.Decimals)
+	// the wrapped supply is what the contract holds after the withdrawal and the burn
+	requireT.Equal("700", tokenInfoRes.TotalSupply.String())
+	requireT.Equal("700", bankBalance(contractAddr))
+}
 
-	addToWhitelistPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithAccountRequest{
-		moduleswasm.NftMethodAddToWhitelist: {
-			ID:      mintNFTReq1.ID,
-			Account: recipient.String(),
-		},
-	})
-	requireT.NoError(err)
+// TestWASMCW721AdapterContract verifies that the cw721 adapter contract moves assetnft nfts through the cw721
+// interface and hands them out as native nfts on withdrawal.
+func TestWASMCW721AdapterContract(t *testing.T) {
+	t.Parallel()
 
-	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, addToWhitelistPayload, sdk.Coin{})
-	requireT.NoError(err)
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
 
-	assertNftWhitelistedRes, err := assetNftClient.Whitelisted(ctx, &assetnfttypes.QueryWhitelistedRequest{
-		Id:      mintNFTReq1.ID,
-		ClassId: classID,
-		Account: recipient.String(),
-	})
-	requireT.NoError(err)
-	requireT.True(assertNftWhitelistedRes.Whitelisted)
+	admin := chain.GenAccount()
+	holder := chain.GenAccount()
+	spender := chain.GenAccount()
+	recipient := chain.GenAccount()
 
-	// ********** RemoveFromWhitelist **********
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+		integration.NewFundedAccount(holder, chain.NewCoin(sdkmath.NewInt(500000000))),
+		integration.NewFundedAccount(spender, chain.NewCoin(sdkmath.NewInt(500000000))),
+		integration.NewFundedAccount(recipient, chain.NewCoin(sdkmath.NewInt(500000000))),
+	)
 
-	removeFromWhitelistPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithAccountRequest{
-		moduleswasm.NftMethodRemoveFromWhiteList: {
-			ID:      mintNFTReq1.ID,
-			Account: recipient.String(),
-		},
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	nftClient := nfttypes.NewQueryClient(chain.ClientContext)
+
+	payload, err := json.Marshal(cw721AdapterInstantiateRequest{
+		Name:   "adapted",
+		Symbol: "adapted",
 	})
 	requireT.NoError(err)
-
-	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, removeFromWhitelistPayload, sdk.Coin{})
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.CW721AdapterWASM,
+		integration.InstantiateConfig{
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    payload,
+			Label:      "cw721_adapter",
+		},
+	)
 	requireT.NoError(err)
 
-	assertNftWhitelistedRes, err = assetNftClient.Whitelisted(ctx, &assetnfttypes.QueryWhitelistedRequest{
-		Id:      mintNFTReq1.ID,
-		ClassId: classID,
-		Account: recipient.String(),
-	})
-	requireT.NoError(err)
-	requireT.False(assertNftWhitelistedRes.Whitelisted)
+	execute := func(sender sdk.AccAddress, msg any) {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, sender, contractAddr, payload, sdk.Coin{})
+		requireT.NoError(err)
+	}
+	query := func(msg any, res any) {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, payload)
+		requireT.NoError(err)
+		requireT.NoError(json.Unmarshal(queryOut, res))
+	}
+	ownerOf := func(tokenID string) cw721AdapterOwnerOfResponse {
+		var res cw721AdapterOwnerOfResponse
+		query(map[cw721AdapterMethod]cw721AdapterTokenIDRequest{
+			cw721AdapterMethodOwnerOf: {TokenID: tokenID},
+		}, &res)
+		return res
+	}
 
-	// ********** Burn **********
+	var classIDRes cw721AdapterClassIDResponse
+	query(map[cw721AdapterMethod]struct{}{cw721AdapterMethodClassID: {}}, &classIDRes)
+	classID := classIDRes.ClassID
 
-	burnPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
-		moduleswasm.NftMethodBurn: {
-			ID: mintNFTReq1.ID,
-		},
+	// ********** Mint **********
+
+	const tokenID = "id1"
+	execute(admin, map[cw721AdapterMethod]cw721AdapterMintRequest{
+		cw721AdapterMethodMint: {TokenID: tokenID, Owner: holder.String(), TokenURI: "https://my-nft-meta.invalid/1"},
 	})
-	requireT.NoError(err)
+	requireT.Equal(holder.String(), ownerOf(tokenID).Owner)
 
-	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, burnPayload, sdk.Coin{})
+	// the nft itself is held by the contract
+	ownerRes, err := nftClient.Owner(ctx, &nfttypes.QueryOwnerRequest{ClassId: classID, Id: tokenID})
 	requireT.NoError(err)
+	requireT.Equal(contractAddr, ownerRes.Owner)
 
-	_, err = nftClient.NFT(ctx, &nfttypes.QueryNFTRequest{
-		ClassId: classID,
-		Id:      mintNFTReq1.ID,
-	})
-	requireT.Error(err)
-	requireT.Contains(err.Error(), nfttypes.ErrNFTNotExists.Error()) // the nft wraps the errors with the `errors` so the client doesn't decode them as sdk errors.
+	var nftInfoRes cw721AdapterNftInfoResponse
+	query(map[cw721AdapterMethod]cw721AdapterTokenIDRequest{
+		cw721AdapterMethodNftInfo: {TokenID: tokenID},
+	}, &nftInfoRes)
+	requireT.Equal("https://my-nft-meta.invalid/1", nftInfoRes.TokenURI)
 
-	// ********** Send **********
+	// ********** Approve and TransferNft **********
 
-	// mint
-	mintNFTReq2 := mintNFTReq1
-	mintNFTReq2.ID = "id-2"
-	mint2Payload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftMintRequest{
-		moduleswasm.NftMethodMint: mintNFTReq2,
+	execute(holder, map[cw721AdapterMethod]cw721AdapterApproveRequest{
+		cw721AdapterMethodApprove: {Spender: spender.String(), TokenID: tokenID},
 	})
-	requireT.NoError(err)
-	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, mint2Payload, sdk.Coin{})
-	requireT.NoError(err)
+	requireT.Equal([]cw721AdapterApproval{{Spender: spender.String()}}, ownerOf(tokenID).Approvals)
 
-	// addToWhitelistPayload
-	addToWhitelistPayload, err = json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithAccountRequest{
-		moduleswasm.NftMethodAddToWhitelist: {
-			ID:      mintNFTReq2.ID,
-			Account: recipient.String(),
-		},
+	execute(spender, map[cw721AdapterMethod]cw721AdapterTransferRequest{
+		cw721AdapterMethodTransferNft: {Recipient: recipient.String(), TokenID: tokenID},
 	})
-	requireT.NoError(err)
+	owner := ownerOf(tokenID)
+	requireT.Equal(recipient.String(), owner.Owner)
+	requireT.Empty(owner.Approvals)
 
-	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, addToWhitelistPayload, sdk.Coin{})
-	requireT.NoError(err)
+	var tokensRes cw721AdapterTokensResponse
+	query(map[cw721AdapterMethod]cw721AdapterOwnerRequest{
+		cw721AdapterMethodTokens: {Owner: recipient.String()},
+	}, &tokensRes)
+	requireT.Equal([]string{tokenID}, tokensRes.Tokens)
 
-	// send
-	sendPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithReceiverRequest{
-		moduleswasm.NftMethodSend: {
-			ID:       mintNFTReq2.ID,
-			Receiver: recipient.String(),
-		},
+	// ********** Withdraw **********
+
+	execute(recipient, map[cw721AdapterMethod]cw721AdapterTokenIDRequest{
+		cw721AdapterMethodWithdraw: {TokenID: tokenID},
 	})
+	ownerRes, err = nftClient.Owner(ctx, &nfttypes.QueryOwnerRequest{ClassId: classID, Id: tokenID})
 	requireT.NoError(err)
-	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, sendPayload, sdk.Coin{})
-	requireT.NoError(err)
+	requireT.Equal(recipient.String(), ownerRes.Owner)
 
-	// ********** Query **********
+	var numTokensRes cw721AdapterNumTokensResponse
+	query(map[cw721AdapterMethod]struct{}{cw721AdapterMethodNumTokens: {}}, &numTokensRes)
+	requireT.Zero(numTokensRes.Count)
+}
 
-	// ********** Params **********
+// TestWASMEscrowContract verifies that the escrow contract keeps escrowed assetft tokens frozen on the holder's
+// account and releases them to the beneficiary through the holder's authz grant.
+func TestWASMEscrowContract(t *testing.T) {
+	t.Parallel()
 
-	paramsPayLoad, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
-		moduleswasm.NftMethodParams: {},
-	})
-	requireT.NoError(err)
-	queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, paramsPayLoad)
-	requireT.NoError(err)
-	var wasmParamsRes assetnfttypes.QueryParamsResponse
-	requireT.NoError(json.Unmarshal(queryOut, &wasmParamsRes))
-	requireT.Equal(
-		chain.QueryAssetNFTParams(ctx, t).MintFee, wasmParamsRes.Params.MintFee,
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	holder := chain.GenAccount()
+	beneficiary := chain.GenAccount()
+	arbiter := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+		integration.NewFundedAccount(holder, chain.NewCoin(sdkmath.NewInt(500000000))),
+		integration.NewFundedAccount(beneficiary, chain.NewCoin(sdkmath.NewInt(500000000))),
+		integration.NewFundedAccount(arbiter, chain.NewCoin(sdkmath.NewInt(500000000))),
 	)
 
-	// ********** Class **********
+	clientCtx := chain.ClientContext
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	bankClient := banktypes.NewQueryClient(clientCtx)
+	ftClient := assetfttypes.NewQueryClient(clientCtx)
 
-	classPayload, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
-		moduleswasm.NftMethodClass: {},
+	payload, err := json.Marshal(escrowInstantiateRequest{
+		Symbol:        "escrowed",
+		Subunit:       "uescrowed",
+		Precision:     6,
+		InitialAmount: "1000",
 	})
 	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, classPayload)
-	requireT.NoError(err)
-	var classQueryRes moduleswasm.AssetnftClassResponse
-	requireT.NoError(json.Unmarshal(queryOut, &classQueryRes))
-	requireT.Equal(
-		moduleswasm.AssetnftClass{
-			ID:          expectedClass.Id,
-			Issuer:      expectedClass.Issuer,
-			Name:        expectedClass.Name,
-			Symbol:      expectedClass.Symbol,
-			Description: expectedClass.Description,
-			URI:         expectedClass.URI,
-			URIHash:     expectedClass.URIHash,
-			Data:        encodedData,
-			Features:    expectedClass.Features,
-			RoyaltyRate: expectedClass.RoyaltyRate,
-		}, classQueryRes.Class,
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.EscrowWASM,
+		integration.InstantiateConfig{
+			Amount:     chain.QueryAssetFTParams(ctx, t).IssueFee,
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    payload,
+			Label:      "escrow",
+		},
 	)
+	requireT.NoError(err)
 
-	// ********** Classes **********
+	execute := func(sender sdk.AccAddress, msg any) error {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, sender, contractAddr, payload, sdk.Coin{})
+		return err
+	}
+	query := func(msg any, res any) {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, payload)
+		requireT.NoError(err)
+		requireT.NoError(json.Unmarshal(queryOut, res))
+	}
 
-	classesPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIssuerRequest{
-		moduleswasm.NftMethodClasses: {
-			Issuer: contractAddr,
+	var denomRes escrowDenomResponse
+	query(map[escrowMethod]struct{}{escrowMethodDenom: {}}, &denomRes)
+	denom := denomRes.Denom
+
+	balance := func(account sdk.AccAddress) string {
+		res, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{Address: account.String(), Denom: denom})
+		requireT.NoError(err)
+		return res.Balance.Amount.String()
+	}
+	frozenBalance := func(account sdk.AccAddress) string {
+		res, err := ftClient.FrozenBalance(ctx, &assetfttypes.QueryFrozenBalanceRequest{
+			Account: account.String(),
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+		return res.Balance.Amount.String()
+	}
+	requireT.Equal("1000", balance(admin))
+
+	sendMsg := &banktypes.MsgSend{
+		FromAddress: admin.String(),
+		ToAddress:   holder.String(),
+		Amount:      sdk.NewCoins(sdk.NewInt64Coin(denom, 500)),
+	}
+	_, err = client.BroadcastTx(ctx, clientCtx.WithFromAddress(admin), txf, sendMsg)
+	requireT.NoError(err)
+
+	// the holder lets the contract send the escrowed tokens on release
+	grantMsg, err := authztypes.NewMsgGrant(
+		holder,
+		sdk.MustAccAddressFromBech32(contractAddr),
+		banktypes.NewSendAuthorization(sdk.NewCoins(sdk.NewInt64Coin(denom, 500)), nil),
+		lo.ToPtr(time.Now().Add(time.Hour)),
+	)
+	requireT.NoError(err)
+	_, err = client.BroadcastTx(ctx, clientCtx.WithFromAddress(holder), txf, grantMsg)
+	requireT.NoError(err)
+
+	// ********** CreateEscrow **********
+
+	requireT.NoError(execute(holder, map[escrowMethod]escrowCreateRequest{
+		escrowMethodCreateEscrow: {Beneficiary: beneficiary.String(), Arbiter: arbiter.String(), Amount: "300"},
+	}))
+	requireT.Equal("300", frozenBalance(holder))
+
+	// escrowing more than the unfrozen part of the balance is rejected
+	requireT.Error(execute(holder, map[escrowMethod]escrowCreateRequest{
+		escrowMethodCreateEscrow: {Beneficiary: beneficiary.String(), Arbiter: arbiter.String(), Amount: "201"},
+	}))
+
+	// the frozen tokens can't be sent by the holder
+	sendMsg = &banktypes.MsgSend{
+		FromAddress: holder.String(),
+		ToAddress:   beneficiary.String(),
+		Amount:      sdk.NewCoins(sdk.NewInt64Coin(denom, 201)),
+	}
+	_, err = client.BroadcastTx(
+		ctx,
+		clientCtx.WithFromAddress(holder),
+		chain.TxFactory().WithGas(chain.GasLimitByMsgs(sendMsg)),
+		sendMsg,
+	)
+	requireT.True(cosmoserrors.ErrInsufficientFunds.Is(err))
+
+	var escrowsRes escrowsResponse
+	query(map[escrowMethod]struct{}{escrowMethodEscrows: {}}, &escrowsRes)
+	requireT.Equal([]escrowResponse{{
+		ID:          1,
+		Holder:      holder.String(),
+		Beneficiary: beneficiary.String(),
+		Arbiter:     arbiter.String(),
+		Amount:      "300",
+	}}, escrowsRes.Escrows)
+
+	// ********** Release **********
+
+	// only the arbiter releases
+	requireT.Error(execute(beneficiary, map[escrowMethod]escrowIDRequest{escrowMethodRelease: {ID: 1}}))
+	requireT.NoError(execute(arbiter, map[escrowMethod]escrowIDRequest{escrowMethodRelease: {ID: 1}}))
+	requireT.Equal("0", frozenBalance(holder))
+	requireT.Equal("200", balance(holder))
+	requireT.Equal("300", balance(beneficiary))
+
+	// ********** Refund **********
+
+	requireT.NoError(execute(holder, map[escrowMethod]escrowCreateRequest{
+		escrowMethodCreateEscrow: {Beneficiary: beneficiary.String(), Arbiter: arbiter.String(), Amount: "200"},
+	}))
+	requireT.Equal("200", frozenBalance(holder))
+	requireT.NoError(execute(beneficiary, map[escrowMethod]escrowIDRequest{escrowMethodRefund: {ID: 2}}))
+	requireT.Equal("0", frozenBalance(holder))
+	requireT.Equal("200", balance(holder))
+
+	query(map[escrowMethod]struct{}{escrowMethodEscrows: {}}, &escrowsRes)
+	requireT.Empty(escrowsRes.Escrows)
+}
+
+// TestWASMAirdropContract verifies that the airdrop contract mints assetft tokens to the claimants of a merkle tree,
+// one by one and in batches, raising their whitelisted limits along the way.
+func TestWASMAirdropContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	claimants := make([]sdk.AccAddress, 20)
+	for i := range claimants {
+		claimants[i] = chain.GenAccount()
+	}
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+		integration.NewFundedAccount(claimants[0], chain.NewCoin(sdkmath.NewInt(500000000))),
+	)
+
+	clientCtx := chain.ClientContext
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	bankClient := banktypes.NewQueryClient(clientCtx)
+	ftClient := assetfttypes.NewQueryClient(clientCtx)
+
+	amounts := make([]sdkmath.Int, len(claimants))
+	leaves := make([][]byte, len(claimants))
+	totalAmount := sdkmath.ZeroInt()
+	for i, claimant := range claimants {
+		amounts[i] = sdkmath.NewInt(int64(100 * (i + 1)))
+		totalAmount = totalAmount.Add(amounts[i])
+		leaf := sha256.Sum256([]byte(claimant.String() + amounts[i].String()))
+		leaves[i] = leaf[:]
+	}
+	root, proofs := buildMerkleTree(leaves)
+
+	payload, err := json.Marshal(airdropInstantiateRequest{
+		Symbol:     "airdropped",
+		Subunit:    "uairdropped",
+		Precision:  6,
+		MerkleRoot: hex.EncodeToString(root),
+	})
+	requireT.NoError(err)
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.AirdropWASM,
+		integration.InstantiateConfig{
+			Amount:     chain.QueryAssetFTParams(ctx, t).IssueFee,
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    payload,
+			Label:      "airdrop",
+		},
+	)
+	requireT.NoError(err)
+
+	execute := func(sender sdk.AccAddress, msg any) error {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, sender, contractAddr, payload, sdk.Coin{})
+		return err
+	}
+	query := func(msg any, res any) {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, payload)
+		requireT.NoError(err)
+		requireT.NoError(json.Unmarshal(queryOut, res))
+	}
+	isClaimed := func(account sdk.AccAddress) bool {
+		var res airdropIsClaimedResponse
+		query(map[airdropMethod]airdropAddressRequest{
+			airdropMethodIsClaimed: {Address: account.String()},
+		}, &res)
+		return res.IsClaimed
+	}
+
+	var configRes airdropConfigResponse
+	query(map[airdropMethod]struct{}{airdropMethodConfig: {}}, &configRes)
+	denom := configRes.Denom
+	requireT.Equal(hex.EncodeToString(root), configRes.MerkleRoot)
+
+	assertClaimed := func(i int) {
+		balanceRes, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+			Address: claimants[i].String(),
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+		requireT.Equal(amounts[i].String(), balanceRes.Balance.Amount.String())
+
+		whitelistedRes, err := ftClient.WhitelistedBalance(ctx, &assetfttypes.QueryWhitelistedBalanceRequest{
+			Account: claimants[i].String(),
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+		requireT.Equal(amounts[i].String(), whitelistedRes.Balance.Amount.String())
+		requireT.True(isClaimed(claimants[i]))
+	}
+
+	// ********** Claim **********
+
+	// the proof doesn't match another amount
+	requireT.Error(execute(claimants[0], map[airdropMethod]airdropClaim{
+		airdropMethodClaim: {Amount: amounts[0].AddRaw(1).String(), Proof: proofs[0]},
+	}))
+	requireT.False(isClaimed(claimants[0]))
+
+	claim := map[airdropMethod]airdropClaim{
+		airdropMethodClaim: {Amount: amounts[0].String(), Proof: proofs[0]},
+	}
+	requireT.NoError(execute(claimants[0], claim))
+	assertClaimed(0)
+
+	// claims are one-off
+	requireT.Error(execute(claimants[0], claim))
+
+	// ********** ClaimBatch **********
+
+	claims := make([]airdropClaim, 0, len(claimants)-1)
+	for i := 1; i < len(claimants); i++ {
+		claims = append(claims, airdropClaim{
+			Address: claimants[i].String(),
+			Amount:  amounts[i].String(),
+			Proof:   proofs[i],
+		})
+	}
+	// the claimant claimed already, so the whole batch fails
+	requireT.Error(execute(admin, map[airdropMethod]airdropClaimBatchRequest{
+		airdropMethodClaimBatch: {Claims: append(claims, airdropClaim{
+			Address: claimants[0].String(),
+			Amount:  amounts[0].String(),
+			Proof:   proofs[0],
+		})},
+	}))
+
+	requireT.NoError(execute(admin, map[airdropMethod]airdropClaimBatchRequest{
+		airdropMethodClaimBatch: {Claims: claims},
+	}))
+	for i := range claimants {
+		assertClaimed(i)
+	}
+
+	query(map[airdropMethod]struct{}{airdropMethodConfig: {}}, &configRes)
+	requireT.Equal(totalAmount.String(), configRes.TotalClaimed)
+	supplyRes, err := bankClient.SupplyOf(ctx, &banktypes.QuerySupplyOfRequest{Denom: denom})
+	requireT.NoError(err)
+	requireT.Equal(totalAmount.String(), supplyRes.Amount.Amount.String())
+}
+
+// TestWASMVestingPayoutContract verifies that the vesting payout contract hands out the whole supply frozen and
+// unfreezes it with MsgSetFrozen as the ticks reach the unlocks of the schedule.
+func TestWASMVestingPayoutContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	beneficiary1 := chain.GenAccount()
+	beneficiary2 := chain.GenAccount()
+	recipient := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+		integration.NewFundedAccount(beneficiary1, chain.NewCoin(sdkmath.NewInt(500000000))),
+		integration.NewFundedAccount(beneficiary2, chain.NewCoin(sdkmath.NewInt(500000000))),
+	)
+
+	clientCtx := chain.ClientContext
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	bankClient := banktypes.NewQueryClient(clientCtx)
+	ftClient := assetfttypes.NewQueryClient(clientCtx)
+
+	const unlockStep = 10 * time.Second
+	payload, err := json.Marshal(vestingPayoutInstantiateRequest{
+		Symbol:    "vested",
+		Subunit:   "uvested",
+		Precision: 6,
+		Allocations: []vestingPayoutAllocation{
+			{Beneficiary: beneficiary1.String(), Amount: "1000"},
+			{Beneficiary: beneficiary2.String(), Amount: "3000"},
+		},
+		Schedule: []vestingPayoutUnlock{
+			{AfterSeconds: uint64(unlockStep.Seconds()), Unlocked: "0.25"},
+			{AfterSeconds: uint64(2 * unlockStep.Seconds()), Unlocked: "1"},
+		},
+	})
+	requireT.NoError(err)
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.VestingPayoutWASM,
+		integration.InstantiateConfig{
+			Amount:     chain.QueryAssetFTParams(ctx, t).IssueFee,
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    payload,
+			Label:      "vesting_payout",
+		},
+	)
+	requireT.NoError(err)
+
+	tickPayload, err := json.Marshal(map[vestingPayoutMethod]struct{}{vestingPayoutMethodTick: {}})
+	requireT.NoError(err)
+	tick := func() {
+		// anyone triggers the schedule
+		_, err := chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, tickPayload, sdk.Coin{})
+		requireT.NoError(err)
+	}
+	query := func(msg any, res any) {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, payload)
+		requireT.NoError(err)
+		requireT.NoError(json.Unmarshal(queryOut, res))
+	}
+
+	var scheduleRes vestingPayoutScheduleResponse
+	query(map[vestingPayoutMethod]struct{}{vestingPayoutMethodSchedule: {}}, &scheduleRes)
+	denom := scheduleRes.Denom
+	requireT.Equal("0", scheduleRes.Unlocked)
+
+	assertFrozen := func(account sdk.AccAddress, balance, frozen string) {
+		balanceRes, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+			Address: account.String(),
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+		requireT.Equal(balance, balanceRes.Balance.Amount.String())
+
+		frozenRes, err := ftClient.FrozenBalance(ctx, &assetfttypes.QueryFrozenBalanceRequest{
+			Account: account.String(),
+			Denom:   denom,
+		})
+		requireT.NoError(err)
+		requireT.Equal(frozen, frozenRes.Balance.Amount.String())
+
+		var allocationRes vestingPayoutAllocationResponse
+		query(map[vestingPayoutMethod]vestingPayoutBeneficiaryRequest{
+			vestingPayoutMethodAllocation: {Beneficiary: account.String()},
+		}, &allocationRes)
+		requireT.Equal(frozen, allocationRes.Frozen)
+	}
+	send := func(sender sdk.AccAddress, amount int64) error {
+		sendMsg := &banktypes.MsgSend{
+			FromAddress: sender.String(),
+			ToAddress:   recipient.String(),
+			Amount:      sdk.NewCoins(sdk.NewInt64Coin(denom, amount)),
+		}
+		_, err := client.BroadcastTx(
+			ctx,
+			clientCtx.WithFromAddress(sender),
+			chain.TxFactory().WithGas(chain.GasLimitByMsgs(sendMsg)),
+			sendMsg,
+		)
+		return err
+	}
+
+	// ********** Fully frozen **********
+
+	assertFrozen(beneficiary1, "1000", "1000")
+	assertFrozen(beneficiary2, "3000", "3000")
+	requireT.True(cosmoserrors.ErrInsufficientFunds.Is(send(beneficiary1, 1)))
+
+	// a tick before the first unlock leaves everything frozen
+	tick()
+	assertFrozen(beneficiary1, "1000", "1000")
+
+	// ********** First unlock **********
+
+	time.Sleep(unlockStep + 2*time.Second)
+	tick()
+	query(map[vestingPayoutMethod]struct{}{vestingPayoutMethodSchedule: {}}, &scheduleRes)
+	requireT.Equal("0.25", scheduleRes.Unlocked)
+	assertFrozen(beneficiary1, "1000", "750")
+	assertFrozen(beneficiary2, "3000", "2250")
+
+	requireT.True(cosmoserrors.ErrInsufficientFunds.Is(send(beneficiary1, 251)))
+	requireT.NoError(send(beneficiary1, 250))
+
+	// ********** Last unlock **********
+
+	time.Sleep(unlockStep)
+	tick()
+	assertFrozen(beneficiary1, "750", "0")
+	assertFrozen(beneficiary2, "3000", "0")
+	requireT.NoError(send(beneficiary2, 3000))
+}
+
+// TestWASMCW721AdapterRoyalty verifies that sales through the cw721 adapter contract pay the royalty rate of the
+// assetnft class to the minter.
+func TestWASMCW721AdapterRoyalty(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	seller := chain.GenAccount()
+	buyer := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+		integration.NewFundedAccount(seller, chain.NewCoin(sdkmath.NewInt(500000000))),
+		integration.NewFundedAccount(buyer, chain.NewCoin(sdkmath.NewInt(500000000))),
+	)
+
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	bankClient := banktypes.NewQueryClient(chain.ClientContext)
+	assetNftClient := assetnfttypes.NewQueryClient(chain.ClientContext)
+
+	payload, err := json.Marshal(cw721AdapterInstantiateRequest{
+		Name:        "royalties",
+		Symbol:      "royalties",
+		RoyaltyRate: "0.1",
+	})
+	requireT.NoError(err)
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.CW721AdapterWASM,
+		integration.InstantiateConfig{
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    payload,
+			Label:      "cw721_adapter",
+		},
+	)
+	requireT.NoError(err)
+
+	execute := func(sender sdk.AccAddress, msg any, funds sdk.Coin) error {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, sender, contractAddr, payload, funds)
+		return err
+	}
+	query := func(msg any, res any) {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, payload)
+		requireT.NoError(err)
+		requireT.NoError(json.Unmarshal(queryOut, res))
+	}
+	balance := func(account sdk.AccAddress) sdkmath.Int {
+		res, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+			Address: account.String(),
+			Denom:   chain.ChainSettings.Denom,
+		})
+		requireT.NoError(err)
+		return res.Balance.Amount
+	}
+
+	var classIDRes cw721AdapterClassIDResponse
+	query(map[cw721AdapterMethod]struct{}{cw721AdapterMethodClassID: {}}, &classIDRes)
+	classRes, err := assetNftClient.Class(ctx, &assetnfttypes.QueryClassRequest{Id: classIDRes.ClassID})
+	requireT.NoError(err)
+	requireT.Equal(sdk.MustNewDecFromStr("0.1"), classRes.Class.RoyaltyRate)
+
+	const tokenID = "id1"
+	requireT.NoError(execute(admin, map[cw721AdapterMethod]cw721AdapterMintRequest{
+		cw721AdapterMethodMint: {TokenID: tokenID, Owner: seller.String()},
+	}, sdk.Coin{}))
+
+	// ********** RoyaltyInfo **********
+
+	var royaltyRes cw721AdapterRoyaltiesInfoResponse
+	query(map[cw721AdapterMethod]cw721AdapterRoyaltyInfoRequest{
+		cw721AdapterMethodRoyaltyInfo: {TokenID: tokenID, SalePrice: "1005"},
+	}, &royaltyRes)
+	// rounded down
+	requireT.Equal(cw721AdapterRoyaltiesInfoResponse{Address: admin.String(), RoyaltyAmount: "100"}, royaltyRes)
+
+	// ********** List and Buy **********
+
+	price := chain.NewCoin(sdkmath.NewInt(1000000))
+	// only the owner lists
+	requireT.Error(execute(buyer, map[cw721AdapterMethod]cw721AdapterListRequest{
+		cw721AdapterMethodList: {TokenID: tokenID, Price: price},
+	}, sdk.Coin{}))
+	requireT.NoError(execute(seller, map[cw721AdapterMethod]cw721AdapterListRequest{
+		cw721AdapterMethodList: {TokenID: tokenID, Price: price},
+	}, sdk.Coin{}))
+
+	var listingRes cw721AdapterListingResponse
+	query(map[cw721AdapterMethod]cw721AdapterTokenIDRequest{
+		cw721AdapterMethodListing: {TokenID: tokenID},
+	}, &listingRes)
+	requireT.Equal(cw721AdapterListingResponse{Seller: seller.String(), Price: price}, listingRes)
+
+	buyMsg := map[cw721AdapterMethod]cw721AdapterTokenIDRequest{
+		cw721AdapterMethodBuy: {TokenID: tokenID},
+	}
+	// the funds must match the price
+	requireT.Error(execute(buyer, buyMsg, chain.NewCoin(price.Amount.SubRaw(1))))
+
+	adminBalance := balance(admin)
+	sellerBalance := balance(seller)
+	requireT.NoError(execute(buyer, buyMsg, price))
+
+	var ownerRes cw721AdapterOwnerOfResponse
+	query(map[cw721AdapterMethod]cw721AdapterTokenIDRequest{
+		cw721AdapterMethodOwnerOf: {TokenID: tokenID},
+	}, &ownerRes)
+	requireT.Equal(buyer.String(), ownerRes.Owner)
+
+	requireT.Equal(adminBalance.Add(sdkmath.NewInt(100000)).String(), balance(admin).String())
+	requireT.Equal(sellerBalance.Add(sdkmath.NewInt(900000)).String(), balance(seller).String())
+
+	// the sale closes the listing
+	requireT.Error(execute(buyer, buyMsg, price))
+}
+
+// TestWASMContractMigrationWithVersionedMessages verifies that custom messages stored by a contract in the layout of
+// its first version are still dispatched after migrating it to a version storing them in another layout.
+func TestWASMContractMigrationWithVersionedMessages(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+	)
+
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	bankClient := banktypes.NewQueryClient(chain.ClientContext)
+
+	payload, err := json.Marshal(migrationInstantiateRequest{
+		Symbol:  "migrated",
+		Subunit: "umigrated",
+	})
+	requireT.NoError(err)
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.MigrationV1WASM,
+		integration.InstantiateConfig{
+			Admin:      admin,
+			Amount:     chain.QueryAssetFTParams(ctx, t).IssueFee,
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    payload,
+			Label:      "migration_v1",
+		},
+	)
+	requireT.NoError(err)
+
+	execute := func(msg any) {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, payload, sdk.Coin{})
+		requireT.NoError(err)
+	}
+	query := func(msg any, res any) {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, payload)
+		requireT.NoError(err)
+		requireT.NoError(json.Unmarshal(queryOut, res))
+	}
+	pending := func() migrationPendingResponse {
+		var res migrationPendingResponse
+		query(map[migrationMethod]struct{}{migrationMethodPending: {}}, &res)
+		return res
+	}
+
+	var denomRes migrationDenomResponse
+	query(map[migrationMethod]struct{}{migrationMethodDenom: {}}, &denomRes)
+
+	// ********** Version 1 **********
+
+	execute(map[migrationMethod]migrationEnqueueRequest{migrationMethodEnqueue: {Amount: "100"}})
+	execute(map[migrationMethod]migrationEnqueueRequest{migrationMethodEnqueue: {Amount: "200"}})
+	requireT.Equal(uint64(2), pending().Count)
+
+	// ********** Migration **********
+
+	newCodeID, err := chain.Wasm.DeployWASMContract(ctx, txf, admin, moduleswasm.MigrationV2WASM)
+	requireT.NoError(err)
+	requireT.NoError(chain.Wasm.MigrateWASMContract(ctx, txf, admin, contractAddr, newCodeID, []byte("{}")))
+
+	// the entries of version 1 are rewritten in the versioned layout
+	requireT.Equal(migrationPendingResponse{Count: 2, Legacy: 0}, pending())
+
+	// ********** Version 2 **********
+
+	execute(map[migrationMethod]migrationEnqueueRequest{migrationMethodEnqueue: {Amount: "300"}})
+	requireT.Equal(migrationPendingResponse{Count: 3, Legacy: 0}, pending())
+
+	execute(map[migrationMethod]struct{}{migrationMethodFlush: {}})
+	requireT.Equal(migrationPendingResponse{Count: 0, Legacy: 0}, pending())
+
+	balanceRes, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+		Address: contractAddr,
+		Denom:   denomRes.Denom,
+	})
+	requireT.NoError(err)
+	requireT.Equal("600", balanceRes.Balance.Amount.String())
+}
+
+// TestWASMNonFungibleTokenInContract verifies that smart contract is able to execute all non-fungible token message and core queries.
+//
+//nolint:nosnakecase
+func TestWASMNonFungibleTokenInContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	recipient := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+	)
+
+	clientCtx := chain.ClientContext
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	assetNftClient := assetnfttypes.NewQueryClient(clientCtx)
+	nftClient := nfttypes.NewQueryClient(clientCtx)
+
+	// ********** Issuance **********
+
+	royaltyRate := sdk.MustNewDecFromStr("0.1")
+	data := make([]byte, 256)
+	for i := 0; i < 256; i++ {
+		data[i] = uint8(i)
+	}
+	encodedData := base64.StdEncoding.EncodeToString(data)
+
+	issueClassReq := moduleswasm.IssueNFTRequest{
+		Name:        "name",
+		Symbol:      "symbol",
+		Description: "description",
+		URI:         "https://my-nft-class-meta.invalid/1",
+		URIHash:     "hash",
+		Data:        encodedData,
+		Features: []assetnfttypes.ClassFeature{
+			assetnfttypes.ClassFeature_burning,
+			assetnfttypes.ClassFeature_freezing,
+			assetnfttypes.ClassFeature_whitelisting,
+			assetnfttypes.ClassFeature_disable_sending,
+		},
+		RoyaltyRate: royaltyRate.String(),
+	}
+	issuerNFTInstantiatePayload, err := json.Marshal(issueClassReq)
+	requireT.NoError(err)
+
+	// instantiate new contract
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.NftWASM,
+		integration.InstantiateConfig{
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    issuerNFTInstantiatePayload,
+			Label:      "non_fungible_token",
+		},
+	)
+	requireT.NoError(err)
+
+	classID := assetnfttypes.BuildClassID(issueClassReq.Symbol, sdk.MustAccAddressFromBech32(contractAddr))
+	classRes, err := assetNftClient.Class(ctx, &assetnfttypes.QueryClassRequest{Id: classID})
+	requireT.NoError(err)
+
+	dataBytes, err := codectypes.NewAnyWithValue(&assetnfttypes.DataBytes{Data: data})
+	// we need to do this, otherwise assertion fails because some private fields are set differently
+	dataToCompare := &codectypes.Any{
+		TypeUrl: dataBytes.TypeUrl,
+		Value:   dataBytes.Value,
+	}
+	requireT.NoError(err)
+
+	expectedClass := assetnfttypes.Class{
+		Id:          classID,
+		Issuer:      contractAddr,
+		Name:        issueClassReq.Name,
+		Symbol:      issueClassReq.Symbol,
+		Description: issueClassReq.Description,
+		URI:         issueClassReq.URI,
+		URIHash:     issueClassReq.URIHash,
+		Data:        dataToCompare,
+		Features:    issueClassReq.Features,
+		RoyaltyRate: royaltyRate,
+	}
+	requireT.Equal(
+		expectedClass, classRes.Class,
+	)
+
+	// ********** Mint **********
+
+	mintNFTReq1 := moduleswasm.NftMintRequest{
+		ID:      "id-1",
+		URI:     "https://my-nft-meta.invalid/1",
+		URIHash: "hash",
+		Data:    encodedData,
+	}
+	mintPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftMintRequest{
+		moduleswasm.NftMethodMint: mintNFTReq1,
+	})
+	requireT.NoError(err)
+
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, mintPayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	nftResp, err := nftClient.NFT(ctx, &nfttypes.QueryNFTRequest{
+		ClassId: classID,
+		Id:      mintNFTReq1.ID,
+	})
+	requireT.NoError(err)
+
+	expectedNFT1 := &nfttypes.NFT{
+		ClassId: classID,
+		Id:      mintNFTReq1.ID,
+		Uri:     mintNFTReq1.URI,
+		UriHash: mintNFTReq1.URIHash,
+		Data:    dataToCompare,
+	}
+	requireT.Equal(
+		expectedNFT1, nftResp.Nft,
+	)
+
+	// ********** Freeze **********
+
+	freezePayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
+		moduleswasm.NftMethodFreeze: {
+			ID: mintNFTReq1.ID,
+		},
+	})
+	requireT.NoError(err)
+
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, freezePayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	assertNftFrozenRes, err := assetNftClient.Frozen(ctx, &assetnfttypes.QueryFrozenRequest{
+		Id:      mintNFTReq1.ID,
+		ClassId: classID,
+	})
+	requireT.NoError(err)
+	requireT.True(assertNftFrozenRes.Frozen)
+
+	// ********** Unfreeze **********
+
+	unfreezePayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
+		moduleswasm.NftMethodUnfreeze: {
+			ID: mintNFTReq1.ID,
+		},
+	})
+	requireT.NoError(err)
+
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, unfreezePayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	assertNftFrozenRes, err = assetNftClient.Frozen(ctx, &assetnfttypes.QueryFrozenRequest{
+		Id:      mintNFTReq1.ID,
+		ClassId: classID,
+	})
+	requireT.NoError(err)
+	requireT.False(assertNftFrozenRes.Frozen)
+
+	// ********** AddToWhitelist **********
+
+	addToWhitelistPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithAccountRequest{
+		moduleswasm.NftMethodAddToWhitelist: {
+			ID:      mintNFTReq1.ID,
+			Account: recipient.String(),
+		},
+	})
+	requireT.NoError(err)
+
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, addToWhitelistPayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	assertNftWhitelistedRes, err := assetNftClient.Whitelisted(ctx, &assetnfttypes.QueryWhitelistedRequest{
+		Id:      mintNFTReq1.ID,
+		ClassId: classID,
+		Account: recipient.String(),
+	})
+	requireT.NoError(err)
+	requireT.True(assertNftWhitelistedRes.Whitelisted)
+
+	// ********** RemoveFromWhitelist **********
+
+	removeFromWhitelistPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithAccountRequest{
+		moduleswasm.NftMethodRemoveFromWhiteList: {
+			ID:      mintNFTReq1.ID,
+			Account: recipient.String(),
+		},
+	})
+	requireT.NoError(err)
+
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, removeFromWhitelistPayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	assertNftWhitelistedRes, err = assetNftClient.Whitelisted(ctx, &assetnfttypes.QueryWhitelistedRequest{
+		Id:      mintNFTReq1.ID,
+		ClassId: classID,
+		Account: recipient.String(),
+	})
+	requireT.NoError(err)
+	requireT.False(assertNftWhitelistedRes.Whitelisted)
+
+	// ********** Burn **********
+
+	burnPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
+		moduleswasm.NftMethodBurn: {
+			ID: mintNFTReq1.ID,
+		},
+	})
+	requireT.NoError(err)
+
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, burnPayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	_, err = nftClient.NFT(ctx, &nfttypes.QueryNFTRequest{
+		ClassId: classID,
+		Id:      mintNFTReq1.ID,
+	})
+	requireT.Error(err)
+	requireT.Contains(err.Error(), nfttypes.ErrNFTNotExists.Error()) // the nft wraps the errors with the `errors` so the client doesn't decode them as sdk errors.
+
+	// ********** Send **********
+
+	// mint
+	mintNFTReq2 := mintNFTReq1
+	mintNFTReq2.ID = "id-2"
+	mint2Payload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftMintRequest{
+		moduleswasm.NftMethodMint: mintNFTReq2,
+	})
+	requireT.NoError(err)
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, mint2Payload, sdk.Coin{})
+	requireT.NoError(err)
+
+	// addToWhitelistPayload
+	addToWhitelistPayload, err = json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithAccountRequest{
+		moduleswasm.NftMethodAddToWhitelist: {
+			ID:      mintNFTReq2.ID,
+			Account: recipient.String(),
+		},
+	})
+	requireT.NoError(err)
+
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, addToWhitelistPayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	// send
+	sendPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithReceiverRequest{
+		moduleswasm.NftMethodSend: {
+			ID:       mintNFTReq2.ID,
+			Receiver: recipient.String(),
+		},
+	})
+	requireT.NoError(err)
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, sendPayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	// ********** Query **********
+
+	// ********** Params **********
+
+	paramsPayLoad, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
+		moduleswasm.NftMethodParams: {},
+	})
+	requireT.NoError(err)
+	queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, paramsPayLoad)
+	requireT.NoError(err)
+	var wasmParamsRes assetnfttypes.QueryParamsResponse
+	requireT.NoError(json.Unmarshal(queryOut, &wasmParamsRes))
+	requireT.Equal(
+		chain.QueryAssetNFTParams(ctx, t).MintFee, wasmParamsRes.Params.MintFee,
+	)
+
+	// ********** Class **********
+
+	classPayload, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
+		moduleswasm.NftMethodClass: {},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, classPayload)
+	requireT.NoError(err)
+	var classQueryRes moduleswasm.AssetnftClassResponse
+	requireT.NoError(json.Unmarshal(queryOut, &classQueryRes))
+	requireT.Equal(
+		moduleswasm.AssetnftClass{
+			ID:          expectedClass.Id,
+			Issuer:      expectedClass.Issuer,
+			Name:        expectedClass.Name,
+			Symbol:      expectedClass.Symbol,
+			Description: expectedClass.Description,
+			URI:         expectedClass.URI,
+			URIHash:     expectedClass.URIHash,
+			Data:        encodedData,
+			Features:    expectedClass.Features,
+			RoyaltyRate: expectedClass.RoyaltyRate,
+		}, classQueryRes.Class,
+	)
+
+	// ********** Classes **********
+
+	classesPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIssuerRequest{
+		moduleswasm.NftMethodClasses: {
+			Issuer: contractAddr,
 		},
 	})
 	requireT.NoError(err)
@@ -1422,302 +3506,1071 @@ func TestWASMNonFungibleTokenInContract(t *testing.T) {
 
 	// ********** Frozen **********
 
-	freezePayload, err = json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
-		moduleswasm.NftMethodFreeze: {
-			ID: mintNFTReq2.ID,
+	freezePayload, err = json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
+		moduleswasm.NftMethodFreeze: {
+			ID: mintNFTReq2.ID,
+		},
+	})
+	requireT.NoError(err)
+
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, freezePayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	frozenPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
+		moduleswasm.NftMethodFrozen: {
+			ID: mintNFTReq2.ID,
+		},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, frozenPayload)
+	requireT.NoError(err)
+	var frozenQueryRes assetnfttypes.QueryFrozenResponse
+	requireT.NoError(json.Unmarshal(queryOut, &frozenQueryRes))
+	requireT.True(frozenQueryRes.Frozen)
+
+	// ********** Whitelisted **********
+
+	whitelistedPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithAccountRequest{
+		moduleswasm.NftMethodWhitelisted: {
+			ID:      mintNFTReq2.ID,
+			Account: recipient.String(),
+		},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, whitelistedPayload)
+	requireT.NoError(err)
+	var whitelistedQueryRes assetnfttypes.QueryWhitelistedResponse
+	requireT.NoError(json.Unmarshal(queryOut, &whitelistedQueryRes))
+	requireT.True(whitelistedQueryRes.Whitelisted)
+
+	// ********** WhitelistedAccountsforNFT **********
+
+	whitelistedAccountsForNFTPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
+		moduleswasm.NftMethodWhitelistedAccountsForNft: {
+			ID: mintNFTReq2.ID,
+		},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, whitelistedAccountsForNFTPayload)
+	requireT.NoError(err)
+	var whitelistedAccountsForNFTQueryRes assetnfttypes.QueryWhitelistedAccountsForNFTResponse
+	requireT.NoError(json.Unmarshal(queryOut, &whitelistedAccountsForNFTQueryRes))
+	requireT.Equal(whitelistedAccountsForNFTQueryRes.Accounts[0], recipient.String())
+
+	// ********** BurntNFT **********
+
+	burntNFTPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.BurntNftIDRequest{
+		moduleswasm.NftMethodBurntNft: {
+			NftID: "id-1",
+		},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, burntNFTPayload)
+	requireT.NoError(err)
+	var burntNFTQueryRes assetnfttypes.QueryBurntNFTResponse
+	requireT.NoError(json.Unmarshal(queryOut, &burntNFTQueryRes))
+	requireT.Equal(burntNFTQueryRes.Burnt, true)
+
+	// ********** BurntNFTsInClass **********
+
+	burntNFTsInClassPayload, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
+		moduleswasm.NftMethodBurntNftInClass: {},
+	})
+
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, burntNFTsInClassPayload)
+	requireT.NoError(err)
+	var burntNFTsInClassQueryRes assetnfttypes.QueryBurntNFTsInClassResponse
+	requireT.NoError(json.Unmarshal(queryOut, &burntNFTsInClassQueryRes))
+	requireT.Equal(burntNFTsInClassQueryRes.NftIds, []string{"id-1"})
+
+	// ********** Balance **********
+
+	balancePayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftOwnerRequest{
+		moduleswasm.NftMethodBalance: {
+			Owner: recipient.String(),
+		},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, balancePayload)
+	requireT.NoError(err)
+	var balanceQueryRes nfttypes.QueryBalanceResponse
+	requireT.NoError(json.Unmarshal(queryOut, &balanceQueryRes))
+	requireT.Equal(uint64(1), balanceQueryRes.Amount)
+
+	// ********** Owner **********
+
+	ownerPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
+		moduleswasm.NftMethodOwner: {
+			ID: mintNFTReq2.ID,
+		},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, ownerPayload)
+	requireT.NoError(err)
+	var ownerQueryRes nfttypes.QueryOwnerResponse
+	requireT.NoError(json.Unmarshal(queryOut, &ownerQueryRes))
+	requireT.Equal(recipient.String(), ownerQueryRes.Owner)
+
+	// ********** Supply **********
+
+	supplyPayload, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
+		moduleswasm.NftMethodSupply: {},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, supplyPayload)
+	requireT.NoError(err)
+	var supplyQueryRes nfttypes.QuerySupplyResponse
+	requireT.NoError(json.Unmarshal(queryOut, &supplyQueryRes))
+	requireT.Equal(uint64(1), supplyQueryRes.Amount)
+
+	// ********** NFT **********
+
+	nftPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
+		moduleswasm.NftMethodNFT: {
+			ID: mintNFTReq2.ID,
+		},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, nftPayload)
+	requireT.NoError(err)
+	var nftQueryRes moduleswasm.NftRes
+	requireT.NoError(json.Unmarshal(queryOut, &nftQueryRes))
+
+	requireT.Equal(
+		moduleswasm.NftItem{
+			ClassID: classID,
+			ID:      mintNFTReq2.ID,
+			URI:     mintNFTReq2.URI,
+			URIHash: mintNFTReq2.URIHash,
+			Data:    encodedData,
+		}, nftQueryRes.NFT,
+	)
+
+	// ********** NFTs **********
+
+	nftsPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftOwnerRequest{
+		moduleswasm.NftMethodNFTs: {
+			Owner: recipient.String(),
+		},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, nftsPayload)
+	requireT.NoError(err)
+	var nftsQueryRes moduleswasm.NftsRes
+	requireT.NoError(json.Unmarshal(queryOut, &nftsQueryRes))
+
+	requireT.Equal(
+		moduleswasm.NftItem{
+			ClassID: classID,
+			ID:      mintNFTReq2.ID,
+			URI:     mintNFTReq2.URI,
+			URIHash: mintNFTReq2.URIHash,
+			Data:    encodedData,
+		}, nftsQueryRes.NFTs[0],
+	)
+
+	// ********** Class **********
+
+	nftClassPayload, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
+		moduleswasm.NftMethodClassNFT: {},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, nftClassPayload)
+	requireT.NoError(err)
+	var nftClassQueryRes moduleswasm.NftClassResponse
+	requireT.NoError(json.Unmarshal(queryOut, &nftClassQueryRes))
+
+	requireT.Equal(
+		moduleswasm.NftClass{
+			ID:          expectedClass.Id,
+			Name:        expectedClass.Name,
+			Symbol:      expectedClass.Symbol,
+			Description: expectedClass.Description,
+			URI:         expectedClass.URI,
+			URIHash:     expectedClass.URIHash,
+			Data:        encodedData,
+		}, nftClassQueryRes.Class,
+	)
+
+	// ********** Classes **********
+
+	nftClassesPayload, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
+		moduleswasm.NftMethodClassesNFT: {},
+	})
+	requireT.NoError(err)
+	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, nftClassesPayload)
+	requireT.NoError(err)
+	var nftClassesQueryRes moduleswasm.NftClassesResponse
+	requireT.NoError(json.Unmarshal(queryOut, &nftClassesQueryRes))
+
+	requireT.Contains(nftClassesQueryRes.Classes, moduleswasm.NftClass{
+		ID:          expectedClass.Id,
+		Name:        expectedClass.Name,
+		Symbol:      expectedClass.Symbol,
+		Description: expectedClass.Description,
+		URI:         expectedClass.URI,
+		URIHash:     expectedClass.URIHash,
+		Data:        encodedData,
+	})
+}
+
+// TestWASMSoulboundNFTInContract verifies that nfts of a class issued by the contract with sending disabled stay with
+// the account they were sent to by the issuer, while the owner is still able to burn them.
+func TestWASMSoulboundNFTInContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	recipient := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+		integration.NewFundedAccount(recipient, chain.NewCoin(sdkmath.NewInt(500000000))),
+	)
+
+	clientCtx := chain.ClientContext
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	nftClient := nfttypes.NewQueryClient(clientCtx)
+
+	issueClassReq := moduleswasm.IssueNFTRequest{
+		Name:   "credential",
+		Symbol: "credential",
+		Features: []assetnfttypes.ClassFeature{
+			assetnfttypes.ClassFeature_burning,
+			assetnfttypes.ClassFeature_disable_sending,
+		},
+		RoyaltyRate: "0",
+	}
+	issuerNFTInstantiatePayload, err := json.Marshal(issueClassReq)
+	requireT.NoError(err)
+
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.NftWASM,
+		integration.InstantiateConfig{
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    issuerNFTInstantiatePayload,
+			Label:      "soulbound_non_fungible_token",
+		},
+	)
+	requireT.NoError(err)
+	classID := assetnfttypes.BuildClassID(issueClassReq.Symbol, sdk.MustAccAddressFromBech32(contractAddr))
+
+	// ********** Mint and Send **********
+
+	const nftID = "id-1"
+	mintPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftMintRequest{
+		moduleswasm.NftMethodMint: {ID: nftID},
+	})
+	requireT.NoError(err)
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, mintPayload, sdk.Coin{})
+	requireT.NoError(err)
+
+	// the issuer is the only account allowed to send
+	sendPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithReceiverRequest{
+		moduleswasm.NftMethodSend: {
+			ID:       nftID,
+			Receiver: recipient.String(),
 		},
 	})
 	requireT.NoError(err)
+	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, sendPayload, sdk.Coin{})
+	requireT.NoError(err)
 
-	_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, freezePayload, sdk.Coin{})
+	ownerRes, err := nftClient.Owner(ctx, &nfttypes.QueryOwnerRequest{ClassId: classID, Id: nftID})
 	requireT.NoError(err)
+	requireT.Equal(recipient.String(), ownerRes.Owner)
 
-	frozenPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
-		moduleswasm.NftMethodFrozen: {
-			ID: mintNFTReq2.ID,
+	// ********** Transfer **********
+
+	for _, receiver := range []string{chain.GenAccount().String(), contractAddr} {
+		sendMsg := &nfttypes.MsgSend{
+			Sender:   recipient.String(),
+			ClassId:  classID,
+			Id:       nftID,
+			Receiver: receiver,
+		}
+		_, err = client.BroadcastTx(
+			ctx,
+			clientCtx.WithFromAddress(recipient),
+			chain.TxFactory().WithGas(chain.GasLimitByMsgs(sendMsg)),
+			sendMsg,
+		)
+		requireT.True(cosmoserrors.ErrUnauthorized.Is(err))
+	}
+
+	// ********** Burn **********
+
+	burnMsg := &assetnfttypes.MsgBurn{
+		Sender:  recipient.String(),
+		ClassID: classID,
+		ID:      nftID,
+	}
+	_, err = client.BroadcastTx(
+		ctx,
+		clientCtx.WithFromAddress(recipient),
+		chain.TxFactory().WithGas(chain.GasLimitByMsgs(burnMsg)),
+		burnMsg,
+	)
+	requireT.NoError(err)
+
+	supplyRes, err := nftClient.Supply(ctx, &nfttypes.QuerySupplyRequest{ClassId: classID})
+	requireT.NoError(err)
+	requireT.Zero(supplyRes.Amount)
+}
+
+// TestWASMBankSendContractWithMultipleFundsAttached tests sending multiple ft funds and core token to smart contract.
+// TODO: remove this test after this task is implemented. https://app.clickup.com/t/86857vqra
+func TestWASMBankSendContractWithMultipleFundsAttached(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	recipient := chain.GenAccount()
+	nativeDenom := chain.ChainSettings.Denom
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdk.NewInt(5000_000_000))),
+	)
+
+	// deployWASMContract and init contract with the initial coins amount
+	initialPayload, err := json.Marshal(struct{}{})
+	requireT.NoError(err)
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		chain.TxFactory().
+			WithSimulateAndExecute(true),
+		admin,
+		moduleswasm.BankSendWASM,
+		integration.InstantiateConfig{
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    initialPayload,
+			Amount:     chain.NewCoin(sdk.NewInt(10000)),
+			Label:      "bank_send",
 		},
-	})
+	)
 	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, frozenPayload)
+
+	issueMsgs := make([]sdk.Msg, 0)
+	coinsToSend := make([]sdk.Coin, 0)
+	for i := 0; i < 20; i++ {
+		// Issue the new fungible token
+		msgIssue := &assetfttypes.MsgIssue{
+			Issuer:        admin.String(),
+			Symbol:        randStringWithLength(20),
+			Subunit:       randStringWithLength(20),
+			Precision:     6,
+			InitialAmount: sdk.NewInt(10000000000000),
+		}
+		denom := assetfttypes.BuildDenom(msgIssue.Subunit, admin)
+		coinsToSend = append(coinsToSend, sdk.NewInt64Coin(denom, 1_000_000))
+		issueMsgs = append(issueMsgs, msgIssue)
+	}
+	// issue tokens
+	_, err = client.BroadcastTx(
+		ctx,
+		chain.ClientContext.WithFromAddress(admin),
+		chain.TxFactory().WithGas(chain.GasLimitByMsgs(issueMsgs...)),
+		issueMsgs...,
+	)
 	requireT.NoError(err)
-	var frozenQueryRes assetnfttypes.QueryFrozenResponse
-	requireT.NoError(json.Unmarshal(queryOut, &frozenQueryRes))
-	requireT.True(frozenQueryRes.Frozen)
 
-	// ********** Whitelisted **********
+	// add additional native coins
+	coinsToSend = append(coinsToSend, chain.NewCoin(sdk.NewInt(10000)))
 
-	whitelistedPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDWithAccountRequest{
-		moduleswasm.NftMethodWhitelisted: {
-			ID:      mintNFTReq2.ID,
-			Account: recipient.String(),
+	// send coin from the contract to test wallet
+	withdrawPayload, err := json.Marshal(map[bankMethod]bankWithdrawRequest{
+		withdraw: {
+			Amount:    "5000",
+			Denom:     nativeDenom,
+			Recipient: recipient.String(),
 		},
 	})
 	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, whitelistedPayload)
+
+	executeMsg := &wasmtypes.MsgExecuteContract{
+		Sender:   admin.String(),
+		Contract: contractAddr,
+		Msg:      wasmtypes.RawContractMessage(withdrawPayload),
+		Funds:    sdk.NewCoins(coinsToSend...),
+	}
+	_, err = client.BroadcastTx(
+		ctx,
+		chain.ClientContext.WithFromAddress(admin),
+		chain.TxFactory().WithGasAdjustment(1.5).WithSimulateAndExecute(true),
+		executeMsg,
+	)
 	requireT.NoError(err)
-	var whitelistedQueryRes assetnfttypes.QueryWhitelistedResponse
-	requireT.NoError(json.Unmarshal(queryOut, &whitelistedQueryRes))
-	requireT.True(whitelistedQueryRes.Whitelisted)
+	waitCtx, cancel := context.WithTimeout(ctx, 10*time.Second)
+	t.Cleanup(cancel)
+	requireT.NoError(client.AwaitNextBlocks(waitCtx, chain.ClientContext, 2))
+}
 
-	// ********** WhitelistedAccountsforNFT **********
+// TestWASMReflectContract verifies that the reflect contract dispatches the messages of its owner and stores the
+// replies of its submessages, failed ones included.
+func TestWASMReflectContract(t *testing.T) {
+	t.Parallel()
 
-	whitelistedAccountsForNFTPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
-		moduleswasm.NftMethodWhitelistedAccountsForNft: {
-			ID: mintNFTReq2.ID,
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	recipient := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+	)
+
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	bankClient := banktypes.NewQueryClient(chain.ClientContext)
+
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.ReflectWASM,
+		integration.InstantiateConfig{
+			Amount:     chain.NewCoin(sdkmath.NewInt(1_000)),
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    json.RawMessage("{}"),
+			Label:      "reflect",
 		},
-	})
+	)
 	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, whitelistedAccountsForNFTPayload)
+
+	execute := func(sender sdk.AccAddress, msg any) error {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, sender, contractAddr, payload, sdk.Coin{})
+		return err
+	}
+	bankSend := func(amount int64) json.RawMessage {
+		msg, err := json.Marshal(map[string]any{
+			"bank": map[string]any{
+				"send": map[string]any{
+					"to_address": recipient.String(),
+					"amount":     []sdk.Coin{chain.NewCoin(sdkmath.NewInt(amount))},
+				},
+			},
+		})
+		requireT.NoError(err)
+		return msg
+	}
+	assertRecipientBalance := func(expected int64) {
+		balanceRes, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+			Address: recipient.String(),
+			Denom:   chain.ChainSettings.Denom,
+		})
+		requireT.NoError(err)
+		requireT.Equal(sdkmath.NewInt(expected).String(), balanceRes.Balance.Amount.String())
+	}
+	queryReply := func(id uint64) reflectReplyResponse {
+		payload, err := json.Marshal(map[reflectMethod]reflectReplyRequest{
+			reflectMethodReply: {ID: id},
+		})
+		requireT.NoError(err)
+		queryOut, err := chain.Wasm.QueryWASMContract(ctx, contractAddr, payload)
+		requireT.NoError(err)
+		var res reflectReplyResponse
+		requireT.NoError(json.Unmarshal(queryOut, &res))
+		return res
+	}
+
+	// ********** Dispatch **********
+
+	dispatch := map[reflectMethod]reflectDispatchRequest{
+		reflectMethodDispatch: {Msgs: []json.RawMessage{bankSend(400)}},
+	}
+	requireT.NoError(execute(admin, dispatch))
+	assertRecipientBalance(400)
+
+	// only the owner dispatches
+	requireT.Error(execute(recipient, dispatch))
+
+	// ********** DispatchSub **********
+
+	// the contract doesn't hold that much, the failure is stored instead of reverting the transaction
+	requireT.NoError(execute(admin, map[reflectMethod]reflectDispatchSubRequest{
+		reflectMethodDispatchSub: {Msg: bankSend(10_000), ReplyID: 1},
+	}))
+	assertRecipientBalance(400)
+	reply := queryReply(1)
+	requireT.EqualValues(1, reply.ID)
+	requireT.Nil(reply.Result.Ok)
+	requireT.NotEmpty(reply.Result.Error)
+
+	requireT.NoError(execute(admin, map[reflectMethod]reflectDispatchSubRequest{
+		reflectMethodDispatchSub: {Msg: bankSend(100), ReplyID: 2},
+	}))
+	assertRecipientBalance(500)
+	reply = queryReply(2)
+	requireT.EqualValues(2, reply.ID)
+	requireT.NotNil(reply.Result.Ok)
+	requireT.NotEmpty(reply.Result.Ok.Events)
+	requireT.Empty(reply.Result.Error)
+}
+
+// TestWASMFeegrantContract verifies that the feegrant contract grants and revokes the allowances paid from its
+// balance.
+func TestWASMFeegrantContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	grantee := chain.GenAccount()
+	recipient := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+		integration.NewFundedAccount(grantee, chain.NewCoin(sdkmath.NewInt(1))),
+	)
+
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	feegrantClient := feegrant.NewQueryClient(chain.ClientContext)
+
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.FeegrantWASM,
+		integration.InstantiateConfig{
+			Amount:     chain.NewCoin(sdkmath.NewInt(1_000_000)),
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    json.RawMessage("{}"),
+			Label:      "feegrant",
+		},
+	)
 	requireT.NoError(err)
-	var whitelistedAccountsForNFTQueryRes assetnfttypes.QueryWhitelistedAccountsForNFTResponse
-	requireT.NoError(json.Unmarshal(queryOut, &whitelistedAccountsForNFTQueryRes))
-	requireT.Equal(whitelistedAccountsForNFTQueryRes.Accounts[0], recipient.String())
 
-	// ********** BurntNFT **********
+	execute := func(sender sdk.AccAddress, msg any) error {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, sender, contractAddr, payload, sdk.Coin{})
+		return err
+	}
+	sendWithGrant := func() error {
+		sendMsg := &banktypes.MsgSend{
+			FromAddress: grantee.String(),
+			ToAddress:   recipient.String(),
+			Amount:      sdk.NewCoins(chain.NewCoin(sdkmath.NewInt(1))),
+		}
+		_, err := client.BroadcastTx(
+			ctx,
+			chain.ClientContext.WithFromAddress(grantee).WithFeeGranterAddress(sdk.MustAccAddressFromBech32(contractAddr)),
+			chain.TxFactory().WithGas(chain.GasLimitByMsgs(sendMsg)),
+			sendMsg,
+		)
+		return err
+	}
 
-	burntNFTPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.BurntNftIDRequest{
-		moduleswasm.NftMethodBurntNft: {
-			NftID: "id-1",
+	// ********** GrantAllowance **********
+
+	grant := map[feegrantMethod]feegrantGrantRequest{
+		feegrantMethodGrantAllowance: {
+			Grantee: grantee.String(),
+			Allowance: feegrantAllowance{
+				AllowedMsg: &feegrantAllowedMsgAllowance{
+					Allowance: feegrantAllowance{
+						Periodic: &feegrantPeriodicAllowance{
+							SpendLimit:       []sdk.Coin{chain.NewCoin(sdkmath.NewInt(1_000_000))},
+							PeriodSeconds:    3600,
+							PeriodSpendLimit: []sdk.Coin{chain.NewCoin(sdkmath.NewInt(500_000))},
+						},
+					},
+					AllowedMessages: []string{sdk.MsgTypeURL(&banktypes.MsgSend{})},
+				},
+			},
 		},
+	}
+	// only the owner grants
+	requireT.Error(execute(grantee, grant))
+	requireT.NoError(execute(admin, grant))
+
+	allowanceRes, err := feegrantClient.Allowance(ctx, &feegrant.QueryAllowanceRequest{
+		Granter: contractAddr,
+		Grantee: grantee.String(),
 	})
 	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, burntNFTPayload)
-	requireT.NoError(err)
-	var burntNFTQueryRes assetnfttypes.QueryBurntNFTResponse
-	requireT.NoError(json.Unmarshal(queryOut, &burntNFTQueryRes))
-	requireT.Equal(burntNFTQueryRes.Burnt, true)
+	requireT.Equal(
+		sdk.MsgTypeURL(&feegrant.AllowedMsgAllowance{}),
+		allowanceRes.Allowance.Allowance.TypeUrl,
+	)
+	var allowedMsgAllowance feegrant.AllowedMsgAllowance
+	chain.ClientContext.Codec().MustUnmarshal(allowanceRes.Allowance.Allowance.Value, &allowedMsgAllowance)
+	requireT.Equal([]string{sdk.MsgTypeURL(&banktypes.MsgSend{})}, allowedMsgAllowance.AllowedMessages)
+	requireT.Equal(sdk.MsgTypeURL(&feegrant.PeriodicAllowance{}), allowedMsgAllowance.Allowance.TypeUrl)
+	var periodicAllowance feegrant.PeriodicAllowance
+	chain.ClientContext.Codec().MustUnmarshal(allowedMsgAllowance.Allowance.Value, &periodicAllowance)
+	requireT.Equal(time.Hour, periodicAllowance.Period)
+	requireT.Equal(
+		sdk.NewCoins(chain.NewCoin(sdkmath.NewInt(500_000))).String(),
+		periodicAllowance.PeriodSpendLimit.String(),
+	)
 
-	// ********** BurntNFTsInClass **********
+	// the contract pays the fee of the grantee
+	requireT.NoError(sendWithGrant())
 
-	burntNFTsInClassPayload, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
-		moduleswasm.NftMethodBurntNftInClass: {},
+	// ********** RevokeAllowance **********
+
+	requireT.NoError(execute(admin, map[feegrantMethod]feegrantRevokeRequest{
+		feegrantMethodRevokeAllowance: {Grantee: grantee.String()},
+	}))
+
+	_, err = feegrantClient.Allowance(ctx, &feegrant.QueryAllowanceRequest{
+		Granter: contractAddr,
+		Grantee: grantee.String(),
 	})
+	requireT.Error(err)
+
+	err = sendWithGrant()
+	requireT.Error(err)
+	requireT.True(cosmoserrors.ErrNotFound.Is(err))
+}
+
+// TestWASMStakingContract verifies that the staking contract delegates, redelegates and undelegates the funds it
+// holds.
+func TestWASMStakingContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
 
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+	)
+
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	stakingClient := stakingtypes.NewQueryClient(chain.ClientContext)
+
+	validatorsRes, err := stakingClient.Validators(ctx, &stakingtypes.QueryValidatorsRequest{
+		Status: stakingtypes.Bonded.String(),
+	})
 	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, burntNFTsInClassPayload)
+	requireT.GreaterOrEqual(len(validatorsRes.Validators), 2)
+	srcValidator := validatorsRes.Validators[0].OperatorAddress
+	dstValidator := validatorsRes.Validators[1].OperatorAddress
+
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.StakingWASM,
+		integration.InstantiateConfig{
+			Amount:     chain.NewCoin(sdkmath.NewInt(1_000)),
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    json.RawMessage("{}"),
+			Label:      "staking",
+		},
+	)
 	requireT.NoError(err)
-	var burntNFTsInClassQueryRes assetnfttypes.QueryBurntNFTsInClassResponse
-	requireT.NoError(json.Unmarshal(queryOut, &burntNFTsInClassQueryRes))
-	requireT.Equal(burntNFTsInClassQueryRes.NftIds, []string{"id-1"})
 
-	// ********** Balance **********
+	execute := func(sender sdk.AccAddress, msg any) error {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, sender, contractAddr, payload, sdk.Coin{})
+		return err
+	}
+	assertDelegation := func(validator string, expected int64) {
+		delegationRes, err := stakingClient.Delegation(ctx, &stakingtypes.QueryDelegationRequest{
+			DelegatorAddr: contractAddr,
+			ValidatorAddr: validator,
+		})
+		requireT.NoError(err)
+		requireT.Equal(chain.NewCoin(sdkmath.NewInt(expected)).String(), delegationRes.DelegationResponse.Balance.String())
+	}
+
+	// ********** Delegate **********
+
+	delegate := map[stakingMethod]stakingDelegateRequest{
+		stakingMethodDelegate: {
+			Validator: srcValidator,
+			Amount:    chain.NewCoin(sdkmath.NewInt(1_000)),
+		},
+	}
+	// only the owner stakes the funds
+	requireT.Error(execute(chain.GenAccount(), delegate))
+	requireT.NoError(execute(admin, delegate))
+	assertDelegation(srcValidator, 1_000)
+
+	// ********** Redelegate **********
+
+	requireT.Error(execute(admin, map[stakingMethod]stakingRedelegateRequest{
+		stakingMethodRedelegate: {
+			SrcValidator: srcValidator,
+			DstValidator: srcValidator,
+			Amount:       chain.NewCoin(sdkmath.NewInt(400)),
+		},
+	}))
+	requireT.NoError(execute(admin, map[stakingMethod]stakingRedelegateRequest{
+		stakingMethodRedelegate: {
+			SrcValidator: srcValidator,
+			DstValidator: dstValidator,
+			Amount:       chain.NewCoin(sdkmath.NewInt(400)),
+		},
+	}))
+	assertDelegation(srcValidator, 600)
+	assertDelegation(dstValidator, 400)
+
+	// ********** Undelegate **********
 
-	balancePayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftOwnerRequest{
-		moduleswasm.NftMethodBalance: {
-			Owner: recipient.String(),
+	requireT.NoError(execute(admin, map[stakingMethod]stakingDelegateRequest{
+		stakingMethodUndelegate: {
+			Validator: srcValidator,
+			Amount:    chain.NewCoin(sdkmath.NewInt(100)),
 		},
+	}))
+	assertDelegation(srcValidator, 500)
+
+	unbondingRes, err := stakingClient.UnbondingDelegation(ctx, &stakingtypes.QueryUnbondingDelegationRequest{
+		DelegatorAddr: contractAddr,
+		ValidatorAddr: srcValidator,
 	})
 	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, balancePayload)
-	requireT.NoError(err)
-	var balanceQueryRes nfttypes.QueryBalanceResponse
-	requireT.NoError(json.Unmarshal(queryOut, &balanceQueryRes))
-	requireT.Equal(uint64(1), balanceQueryRes.Amount)
+	requireT.Len(unbondingRes.Unbond.Entries, 1)
+	requireT.Equal(sdkmath.NewInt(100).String(), unbondingRes.Unbond.Entries[0].Balance.String())
+}
 
-	// ********** Owner **********
+// TestWASMStakingContractRewards verifies that the staking contract redirects and withdraws the rewards of its
+// delegations.
+func TestWASMStakingContractRewards(t *testing.T) {
+	t.Parallel()
 
-	ownerPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
-		moduleswasm.NftMethodOwner: {
-			ID: mintNFTReq2.ID,
-		},
-	})
-	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, ownerPayload)
-	requireT.NoError(err)
-	var ownerQueryRes nfttypes.QueryOwnerResponse
-	requireT.NoError(json.Unmarshal(queryOut, &ownerQueryRes))
-	requireT.Equal(recipient.String(), ownerQueryRes.Owner)
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
 
-	// ********** Supply **********
+	admin := chain.GenAccount()
+	rewardRecipient := chain.GenAccount()
 
-	supplyPayload, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
-		moduleswasm.NftMethodSupply: {},
-	})
-	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, supplyPayload)
-	requireT.NoError(err)
-	var supplyQueryRes nfttypes.QuerySupplyResponse
-	requireT.NoError(json.Unmarshal(queryOut, &supplyQueryRes))
-	requireT.Equal(uint64(1), supplyQueryRes.Amount)
+	requireT := require.New(t)
+	// the amount of the delegation should be big enough to get at least some reward for the few blocks
+	amountToDelegate := sdkmath.NewInt(1_000_000_000)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(amountToDelegate.Add(sdkmath.NewInt(5000000000)))),
+	)
 
-	// ********** NFT **********
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	bankClient := banktypes.NewQueryClient(chain.ClientContext)
+	distributionClient := distributiontypes.NewQueryClient(chain.ClientContext)
+	stakingClient := stakingtypes.NewQueryClient(chain.ClientContext)
 
-	nftPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftIDRequest{
-		moduleswasm.NftMethodNFT: {
-			ID: mintNFTReq2.ID,
-		},
+	validatorsRes, err := stakingClient.Validators(ctx, &stakingtypes.QueryValidatorsRequest{
+		Status: stakingtypes.Bonded.String(),
 	})
 	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, nftPayload)
-	requireT.NoError(err)
-	var nftQueryRes moduleswasm.NftRes
-	requireT.NoError(json.Unmarshal(queryOut, &nftQueryRes))
+	requireT.NotEmpty(validatorsRes.Validators)
+	validator := validatorsRes.Validators[0].OperatorAddress
 
-	requireT.Equal(
-		moduleswasm.NftItem{
-			ClassID: classID,
-			ID:      mintNFTReq2.ID,
-			URI:     mintNFTReq2.URI,
-			URIHash: mintNFTReq2.URIHash,
-			Data:    encodedData,
-		}, nftQueryRes.NFT,
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
+		ctx,
+		txf,
+		admin,
+		moduleswasm.StakingWASM,
+		integration.InstantiateConfig{
+			Amount:     chain.NewCoin(amountToDelegate),
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    json.RawMessage("{}"),
+			Label:      "staking",
+		},
 	)
+	requireT.NoError(err)
 
-	// ********** NFTs **********
+	execute := func(msg any) error {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, admin, contractAddr, payload, sdk.Coin{})
+		return err
+	}
 
-	nftsPayload, err := json.Marshal(map[moduleswasm.NftMethod]moduleswasm.NftOwnerRequest{
-		moduleswasm.NftMethodNFTs: {
-			Owner: recipient.String(),
+	requireT.NoError(execute(map[stakingMethod]stakingDelegateRequest{
+		stakingMethodDelegate: {
+			Validator: validator,
+			Amount:    chain.NewCoin(amountToDelegate),
 		},
-	})
-	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, nftsPayload)
-	requireT.NoError(err)
-	var nftsQueryRes moduleswasm.NftsRes
-	requireT.NoError(json.Unmarshal(queryOut, &nftsQueryRes))
+	}))
 
-	requireT.Equal(
-		moduleswasm.NftItem{
-			ClassID: classID,
-			ID:      mintNFTReq2.ID,
-			URI:     mintNFTReq2.URI,
-			URIHash: mintNFTReq2.URIHash,
-			Data:    encodedData,
-		}, nftsQueryRes.NFTs[0],
-	)
+	// ********** SetWithdrawAddress **********
 
-	// ********** Class **********
+	requireT.NoError(execute(map[stakingMethod]stakingSetWithdrawAddressRequest{
+		stakingMethodSetWithdrawAddress: {Address: rewardRecipient.String()},
+	}))
 
-	nftClassPayload, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
-		moduleswasm.NftMethodClassNFT: {},
-	})
-	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, nftClassPayload)
+	withdrawAddressRes, err := distributionClient.DelegatorWithdrawAddress(
+		ctx,
+		&distributiontypes.QueryDelegatorWithdrawAddressRequest{DelegatorAddress: contractAddr},
+	)
 	requireT.NoError(err)
-	var nftClassQueryRes moduleswasm.NftClassResponse
-	requireT.NoError(json.Unmarshal(queryOut, &nftClassQueryRes))
+	requireT.Equal(rewardRecipient.String(), withdrawAddressRes.WithdrawAddress)
 
-	requireT.Equal(
-		moduleswasm.NftClass{
-			ID:          expectedClass.Id,
-			Name:        expectedClass.Name,
-			Symbol:      expectedClass.Symbol,
-			Description: expectedClass.Description,
-			URI:         expectedClass.URI,
-			URIHash:     expectedClass.URIHash,
-			Data:        encodedData,
-		}, nftClassQueryRes.Class,
-	)
+	// ********** WithdrawRewards **********
 
-	// ********** Classes **********
+	// await next 5 blocks
+	requireT.NoError(client.AwaitNextBlocks(ctx, chain.ClientContext, 5))
 
-	nftClassesPayload, err := json.Marshal(map[moduleswasm.NftMethod]struct{}{
-		moduleswasm.NftMethodClassesNFT: {},
-	})
-	requireT.NoError(err)
-	queryOut, err = chain.Wasm.QueryWASMContract(ctx, contractAddr, nftClassesPayload)
-	requireT.NoError(err)
-	var nftClassesQueryRes moduleswasm.NftClassesResponse
-	requireT.NoError(json.Unmarshal(queryOut, &nftClassesQueryRes))
+	requireT.NoError(execute(map[stakingMethod]stakingWithdrawRewardsRequest{
+		stakingMethodWithdrawRewards: {Validator: validator},
+	}))
 
-	requireT.Contains(nftClassesQueryRes.Classes, moduleswasm.NftClass{
-		ID:          expectedClass.Id,
-		Name:        expectedClass.Name,
-		Symbol:      expectedClass.Symbol,
-		Description: expectedClass.Description,
-		URI:         expectedClass.URI,
-		URIHash:     expectedClass.URIHash,
-		Data:        encodedData,
+	balanceRes, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+		Address: rewardRecipient.String(),
+		Denom:   chain.ChainSettings.Denom,
 	})
+	requireT.NoError(err)
+	requireT.True(balanceRes.Balance.IsPositive())
 }
 
-// TestWASMBankSendContractWithMultipleFundsAttached tests sending multiple ft funds and core token to smart contract.
-// TODO: remove this test after this task is implemented. https://app.clickup.com/t/86857vqra
-func TestWASMBankSendContractWithMultipleFundsAttached(t *testing.T) {
+// TestWASMGovContract verifies that the gov contract submits, votes for and deposits to proposals from the funds it
+// holds.
+func TestWASMGovContract(t *testing.T) {
 	t.Parallel()
 
 	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+	integration.SkipUnsafe(ctx, t)
 
 	admin := chain.GenAccount()
 	recipient := chain.GenAccount()
-	nativeDenom := chain.ChainSettings.Denom
 
 	requireT := require.New(t)
+
+	govParams, err := chain.Governance.QueryGovParams(ctx)
+	requireT.NoError(err)
+	minDeposit := govParams.MinDeposit[0]
+	extraDeposit := chain.NewCoin(sdkmath.NewInt(1_000))
+
 	chain.Faucet.FundAccounts(ctx, t,
-		integration.NewFundedAccount(admin, chain.NewCoin(sdk.NewInt(5000_000_000))),
+		integration.NewFundedAccount(admin, chain.NewCoin(minDeposit.Amount.Add(sdkmath.NewInt(5000000000)))),
 	)
 
-	// deployWASMContract and init contract with the initial coins amount
-	initialPayload, err := json.Marshal(struct{}{})
-	requireT.NoError(err)
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	govClient := govtypesv1.NewQueryClient(chain.ClientContext)
+
 	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
 		ctx,
-		chain.TxFactory().
-			WithSimulateAndExecute(true),
+		txf,
 		admin,
-		moduleswasm.BankSendWASM,
+		moduleswasm.GovWASM,
 		integration.InstantiateConfig{
+			Amount:     minDeposit.Add(extraDeposit),
 			AccessType: wasmtypes.AccessTypeUnspecified,
-			Payload:    initialPayload,
-			Amount:     chain.NewCoin(sdk.NewInt(10000)),
-			Label:      "bank_send",
+			Payload:    json.RawMessage("{}"),
+			Label:      "gov",
 		},
 	)
 	requireT.NoError(err)
 
-	issueMsgs := make([]sdk.Msg, 0)
-	coinsToSend := make([]sdk.Coin, 0)
-	for i := 0; i < 20; i++ {
-		// Issue the new fungible token
-		msgIssue := &assetfttypes.MsgIssue{
-			Issuer:        admin.String(),
-			Symbol:        randStringWithLength(20),
-			Subunit:       randStringWithLength(20),
-			Precision:     6,
-			InitialAmount: sdk.NewInt(10000000000000),
-		}
-		denom := assetfttypes.BuildDenom(msgIssue.Subunit, admin)
-		coinsToSend = append(coinsToSend, sdk.NewInt64Coin(denom, 1_000_000))
-		issueMsgs = append(issueMsgs, msgIssue)
+	execute := func(sender sdk.AccAddress, msg any) error {
+		payload, err := json.Marshal(msg)
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, sender, contractAddr, payload, sdk.Coin{})
+		return err
 	}
-	// issue tokens
-	_, err = client.BroadcastTx(
-		ctx,
-		chain.ClientContext.WithFromAddress(admin),
-		chain.TxFactory().WithGas(chain.GasLimitByMsgs(issueMsgs...)),
-		issueMsgs...,
-	)
+	assertVote := func(proposalID uint64, expected govtypesv1.WeightedVoteOptions) {
+		voteRes, err := govClient.Vote(ctx, &govtypesv1.QueryVoteRequest{
+			ProposalId: proposalID,
+			Voter:      contractAddr,
+		})
+		requireT.NoError(err)
+		requireT.Equal(expected, govtypesv1.WeightedVoteOptions(voteRes.Vote.Options))
+	}
+
+	// ********** SubmitProposal **********
+
+	proposalMsg := &banktypes.MsgSend{
+		FromAddress: authtypes.NewModuleAddress(govtypes.ModuleName).String(),
+		ToAddress:   recipient.String(),
+		Amount:      sdk.NewCoins(chain.NewCoin(sdkmath.NewInt(1))),
+	}
+	proposalMsgValue, err := chain.ClientContext.Codec().Marshal(proposalMsg)
+	requireT.NoError(err)
+
+	submit := map[govMethod]govSubmitProposalRequest{
+		govMethodSubmitProposal: {
+			Messages: []govProposalMsg{{
+				TypeURL: sdk.MsgTypeURL(proposalMsg),
+				Value:   proposalMsgValue,
+			}},
+			InitialDeposit: []sdk.Coin{minDeposit},
+			Title:          "Send from the community",
+			Summary:        "Proposed by the gov contract",
+		},
+	}
+	// only the owner proposes
+	requireT.Error(execute(recipient, submit))
+	requireT.NoError(execute(admin, submit))
+
+	proposalsRes, err := govClient.Proposals(ctx, &govtypesv1.QueryProposalsRequest{
+		Proposer: contractAddr,
+	})
 	requireT.NoError(err)
+	requireT.Len(proposalsRes.Proposals, 1)
+	proposal := proposalsRes.Proposals[0]
+	requireT.Equal(govtypesv1.StatusVotingPeriod, proposal.Status)
+	requireT.Equal("Send from the community", proposal.Title)
+	requireT.Len(proposal.Messages, 1)
+	requireT.Equal(sdk.MsgTypeURL(proposalMsg), proposal.Messages[0].TypeUrl)
+
+	// ********** Vote **********
+
+	requireT.NoError(execute(admin, map[govMethod]govVoteRequest{
+		govMethodVote: {ProposalID: proposal.Id, Option: "yes"},
+	}))
+	assertVote(proposal.Id, govtypesv1.WeightedVoteOptions{
+		&govtypesv1.WeightedVoteOption{
+			Option: govtypesv1.OptionYes,
+			Weight: sdk.OneDec().String(),
+		},
+	})
 
-	// add additional native coins
-	coinsToSend = append(coinsToSend, chain.NewCoin(sdk.NewInt(10000)))
+	// ********** VoteWeighted **********
 
-	// send coin from the contract to test wallet
-	withdrawPayload, err := json.Marshal(map[bankMethod]bankWithdrawRequest{
-		withdraw: {
-			Amount:    "5000",
-			Denom:     nativeDenom,
-			Recipient: recipient.String(),
+	// weights must sum up to 1
+	requireT.Error(execute(admin, map[govMethod]govVoteWeightedRequest{
+		govMethodVoteWeighted: {
+			ProposalID: proposal.Id,
+			Options: []govWeightedVoteOption{
+				{Option: "yes", Weight: "0.6"},
+				{Option: "no", Weight: "0.3"},
+			},
+		},
+	}))
+	requireT.NoError(execute(admin, map[govMethod]govVoteWeightedRequest{
+		govMethodVoteWeighted: {
+			ProposalID: proposal.Id,
+			Options: []govWeightedVoteOption{
+				{Option: "yes", Weight: "0.6"},
+				{Option: "no_with_veto", Weight: "0.4"},
+			},
+		},
+	}))
+	assertVote(proposal.Id, govtypesv1.WeightedVoteOptions{
+		&govtypesv1.WeightedVoteOption{
+			Option: govtypesv1.OptionYes,
+			Weight: sdk.MustNewDecFromStr("0.6").String(),
 		},
+		&govtypesv1.WeightedVoteOption{
+			Option: govtypesv1.OptionNoWithVeto,
+			Weight: sdk.MustNewDecFromStr("0.4").String(),
+		},
+	})
+
+	// ********** Deposit **********
+
+	requireT.NoError(execute(admin, map[govMethod]govDepositRequest{
+		govMethodDeposit: {ProposalID: proposal.Id, Amount: []sdk.Coin{extraDeposit}},
+	}))
+
+	depositRes, err := govClient.Deposit(ctx, &govtypesv1.QueryDepositRequest{
+		ProposalId: proposal.Id,
+		Depositor:  contractAddr,
 	})
 	requireT.NoError(err)
+	requireT.Equal(sdk.NewCoins(minDeposit.Add(extraDeposit)).String(), sdk.NewCoins(depositRes.Deposit.Amount...).String())
+}
 
-	executeMsg := &wasmtypes.MsgExecuteContract{
-		Sender:   admin.String(),
-		Contract: contractAddr,
-		Msg:      wasmtypes.RawContractMessage(withdrawPayload),
-		Funds:    sdk.NewCoins(coinsToSend...),
-	}
-	_, err = client.BroadcastTx(
+// TestWASMVestingContract verifies that the vesting contract creates continuous and delayed vesting accounts paid
+// from its balance.
+func TestWASMVestingContract(t *testing.T) {
+	t.Parallel()
+
+	ctx, chain := integrationtests.NewCoreumTestingContext(t)
+
+	admin := chain.GenAccount()
+	continuousRecipient := chain.GenAccount()
+	delayedRecipient := chain.GenAccount()
+
+	requireT := require.New(t)
+	chain.Faucet.FundAccounts(ctx, t,
+		integration.NewFundedAccount(admin, chain.NewCoin(sdkmath.NewInt(5000000000))),
+	)
+
+	txf := chain.TxFactory().
+		WithSimulateAndExecute(true)
+	authClient := authtypes.NewQueryClient(chain.ClientContext)
+	bankClient := banktypes.NewQueryClient(chain.ClientContext)
+
+	contractAddr, _, err := chain.Wasm.DeployAndInstantiateWASMContract(
 		ctx,
-		chain.ClientContext.WithFromAddress(admin),
-		chain.TxFactory().WithGasAdjustment(1.5).WithSimulateAndExecute(true),
-		executeMsg,
+		txf,
+		admin,
+		moduleswasm.VestingWASM,
+		integration.InstantiateConfig{
+			Amount:     chain.NewCoin(sdkmath.NewInt(2_000)),
+			AccessType: wasmtypes.AccessTypeUnspecified,
+			Payload:    json.RawMessage("{}"),
+			Label:      "vesting",
+		},
 	)
 	requireT.NoError(err)
-	waitCtx, cancel := context.WithTimeout(ctx, 10*time.Second)
-	t.Cleanup(cancel)
-	requireT.NoError(client.AwaitNextBlocks(waitCtx, chain.ClientContext, 2))
+
+	execute := func(sender sdk.AccAddress, req vestingCreateAccountRequest) error {
+		payload, err := json.Marshal(map[vestingMethod]vestingCreateAccountRequest{
+			vestingMethodCreateVestingAccount: req,
+		})
+		requireT.NoError(err)
+		_, err = chain.Wasm.ExecuteWASMContract(ctx, txf, sender, contractAddr, payload, sdk.Coin{})
+		return err
+	}
+	assertVestingAccount := func(account sdk.AccAddress, expectedTypeURL string) {
+		accountRes, err := authClient.Account(ctx, &authtypes.QueryAccountRequest{
+			Address: account.String(),
+		})
+		requireT.NoError(err)
+		requireT.Equal(expectedTypeURL, accountRes.Account.TypeUrl)
+
+		balanceRes, err := bankClient.Balance(ctx, &banktypes.QueryBalanceRequest{
+			Address: account.String(),
+			Denom:   chain.ChainSettings.Denom,
+		})
+		requireT.NoError(err)
+		requireT.Equal(chain.NewCoin(sdkmath.NewInt(1_000)).String(), balanceRes.Balance.String())
+	}
+
+	endTime := strconv.FormatInt(time.Now().Add(time.Hour).UnixNano(), 10)
+	continuous := vestingCreateAccountRequest{
+		Recipient: continuousRecipient.String(),
+		Amount:    []sdk.Coin{chain.NewCoin(sdkmath.NewInt(1_000))},
+		EndTime:   endTime,
+		Schedule:  "continuous",
+	}
+
+	// ********** CreateVestingAccount **********
+
+	// only the owner pays out the funds
+	requireT.Error(execute(continuousRecipient, continuous))
+
+	// the end time must be in the future
+	inPast := continuous
+	inPast.EndTime = strconv.FormatInt(time.Now().Add(-time.Hour).UnixNano(), 10)
+	requireT.Error(execute(admin, inPast))
+
+	requireT.NoError(execute(admin, continuous))
+	assertVestingAccount(continuousRecipient, "/cosmos.vesting.v1beta1.ContinuousVestingAccount")
+
+	// the chain rejects existing recipients
+	requireT.Error(execute(admin, continuous))
+
+	requireT.NoError(execute(admin, vestingCreateAccountRequest{
+		Recipient: delayedRecipient.String(),
+		Amount:    []sdk.Coin{chain.NewCoin(sdkmath.NewInt(1_000))},
+		EndTime:   endTime,
+		Schedule:  "delayed",
+	}))
+	assertVestingAccount(delayedRecipient, "/cosmos.vesting.v1beta1.DelayedVestingAccount")
 }
 
 func randStringWithLength(n int) string {
@@ -1728,3 +4581,40 @@ func randStringWithLength(n int) string {
 	}
 	return string(b)
 }
+
+// buildMerkleTree builds the tree the airdrop contract verifies claims against, hashing sibling nodes in ascending
+// byte order and carrying an unpaired node up a level, and returns its root with the hex encoded proof of every leaf.
+func buildMerkleTree(leaves [][]byte) ([]byte, [][]string) {
+	proofs := make([][]string, len(leaves))
+	// position of the node covering each leaf in the current level
+	indexes := make([]int, len(leaves))
+	for i := range indexes {
+		indexes[i] = i
+	}
+
+	level := leaves
+	for len(level) > 1 {
+		next := make([][]byte, 0, (len(level)+1)/2)
+		for i := 0; i < len(level); i += 2 {
+			if i+1 == len(level) {
+				next = append(next, level[i])
+				continue
+			}
+			first, second := level[i], level[i+1]
+			if bytes.Compare(first, second) > 0 {
+				first, second = second, first
+			}
+			hash := sha256.Sum256(append(append([]byte{}, first...), second...))
+			next = append(next, hash[:])
+		}
+		for leaf, index := range indexes {
+			if sibling := index ^ 1; sibling < len(level) {
+				proofs[leaf] = append(proofs[leaf], hex.EncodeToString(level[sibling]))
+			}
+			indexes[leaf] = index / 2
+		}
+		level = next
+	}
+
+	return level[0], proofs
+}
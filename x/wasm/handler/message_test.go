@@ -0,0 +1,205 @@
+package handler_test
+
+import (
+	"encoding/json"
+	"os"
+	"testing"
+
+	sdkmath "cosmossdk.io/math"
+	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+	nfttypes "github.com/cosmos/cosmos-sdk/x/nft"
+	"github.com/stretchr/testify/require"
+
+	"github.com/CoreumFoundation/coreum/v3/pkg/config"
+	"github.com/CoreumFoundation/coreum/v3/pkg/config/constant"
+	assetfttypes "github.com/CoreumFoundation/coreum/v3/x/asset/ft/types"
+	assetnfttypes "github.com/CoreumFoundation/coreum/v3/x/asset/nft/types"
+	"github.com/CoreumFoundation/coreum/v3/x/wasm/handler"
+)
+
+const (
+	issuer  = "devcore172rc5sz2uclpsy3vvx3y79ah5dk450z5ruq2r5"
+	account = "devcore1k3mke3gyf9apyd8vxveutgp9h4j2e80e05yfuq"
+	denom   = "abc-" + issuer
+	classID = "punk-" + issuer
+	nftID   = "punk1"
+)
+
+func TestMain(m *testing.M) {
+	n, err := config.NetworkConfigByChainID(constant.ChainIDDev)
+	if err != nil {
+		panic(err)
+	}
+	n.SetSDKConfig()
+	m.Run()
+}
+
+// TestCustomMessagesGolden decodes the custom messages generated by the ft contract with
+// `cargo run --features audit --bin gen-golden`, any change of the encoding on either side fails it.
+func TestCustomMessagesGolden(t *testing.T) {
+	sender := sdk.MustAccAddressFromBech32(issuer)
+	coin := sdk.NewCoin(denom, sdkmath.NewInt(1000))
+
+	testCases := map[string]sdk.Msg{
+		"assetft_issue": &assetfttypes.MsgIssue{
+			Issuer:        issuer,
+			Symbol:        "ABC",
+			Subunit:       "abc",
+			Precision:     6,
+			InitialAmount: sdkmath.NewIntWithDecimal(1, 21),
+			Description:   "ABC coin",
+			Features: []assetfttypes.Feature{
+				assetfttypes.Feature_minting,
+				assetfttypes.Feature_burning,
+				assetfttypes.Feature_freezing,
+				assetfttypes.Feature_whitelisting,
+			},
+			BurnRate:           sdk.MustNewDecFromStr("0.1"),
+			SendCommissionRate: sdk.MustNewDecFromStr("0.05"),
+		},
+		"assetft_mint": &assetfttypes.MsgMint{
+			Sender: issuer,
+			Coin:   coin,
+		},
+		"assetft_burn": &assetfttypes.MsgBurn{
+			Sender: issuer,
+			Coin:   coin,
+		},
+		"assetft_freeze": &assetfttypes.MsgFreeze{
+			Sender:  issuer,
+			Account: account,
+			Coin:    coin,
+		},
+		"assetft_unfreeze": &assetfttypes.MsgUnfreeze{
+			Sender:  issuer,
+			Account: account,
+			Coin:    coin,
+		},
+		"assetft_globally_freeze": &assetfttypes.MsgGloballyFreeze{
+			Sender: issuer,
+			Denom:  denom,
+		},
+		"assetft_globally_unfreeze": &assetfttypes.MsgGloballyUnfreeze{
+			Sender: issuer,
+			Denom:  denom,
+		},
+		"assetft_set_whitelisted_limit": &assetfttypes.MsgSetWhitelistedLimit{
+			Sender:  issuer,
+			Account: account,
+			Coin:    coin,
+		},
+		"assetft_upgrade_token_v1": &assetfttypes.MsgUpgradeTokenV1{
+			Sender:     issuer,
+			Denom:      denom,
+			IbcEnabled: true,
+		},
+		"assetnft_issue_class": &assetnfttypes.MsgIssueClass{
+			Issuer:      issuer,
+			Symbol:      "punk",
+			Name:        "Punks",
+			Description: "Punk class",
+			URI:         "https://punks.example/class",
+			URIHash:     "class-hash",
+			Data:        dataBytes(t, "class data"),
+			Features: []assetnfttypes.ClassFeature{
+				assetnfttypes.ClassFeature_burning,
+				assetnfttypes.ClassFeature_freezing,
+				assetnfttypes.ClassFeature_whitelisting,
+				assetnfttypes.ClassFeature_disable_sending,
+			},
+			RoyaltyRate: sdk.MustNewDecFromStr("0.1"),
+		},
+		"assetnft_mint": &assetnfttypes.MsgMint{
+			Sender:  issuer,
+			ClassID: classID,
+			ID:      nftID,
+			URI:     "https://punks.example/1",
+			URIHash: "nft-hash",
+			Data:    dataBytes(t, "nft data"),
+		},
+		"assetnft_burn": &assetnfttypes.MsgBurn{
+			Sender:  issuer,
+			ClassID: classID,
+			ID:      nftID,
+		},
+		"assetnft_freeze": &assetnfttypes.MsgFreeze{
+			Sender:  issuer,
+			ClassID: classID,
+			ID:      nftID,
+		},
+		"assetnft_unfreeze": &assetnfttypes.MsgUnfreeze{
+			Sender:  issuer,
+			ClassID: classID,
+			ID:      nftID,
+		},
+		"assetnft_add_to_whitelist": &assetnfttypes.MsgAddToWhitelist{
+			Sender:  issuer,
+			ClassID: classID,
+			ID:      nftID,
+			Account: account,
+		},
+		"assetnft_remove_from_whitelist": &assetnfttypes.MsgRemoveFromWhitelist{
+			Sender:  issuer,
+			ClassID: classID,
+			ID:      nftID,
+			Account: account,
+		},
+		"nft_send": &nfttypes.MsgSend{
+			ClassId:  classID,
+			Id:       nftID,
+			Sender:   issuer,
+			Receiver: account,
+		},
+	}
+
+	golden := readGolden(t)
+	require.Len(t, golden, len(testCases))
+
+	for name, expected := range testCases {
+		name := name
+		expected := expected
+		t.Run(name, func(t *testing.T) {
+			msg, ok := golden[name]
+			require.True(t, ok, "message is missing in the golden file")
+
+			msgs, err := handler.NewCoreumMsgHandler().Custom(sender, msg)
+			require.NoError(t, err)
+			require.Equal(t, []sdk.Msg{expected}, msgs)
+		})
+	}
+}
+
+// TestCustomMessageNullRate covers the encoding the audit of the ft contract rejects, rates left
+// to None are serialized as null which sdk.Dec can't decode.
+func TestCustomMessageNullRate(t *testing.T) {
+	golden := readGolden(t)
+
+	var msg map[string]map[string]map[string]json.RawMessage
+	require.NoError(t, json.Unmarshal(golden["assetft_issue"], &msg))
+	msg["AssetFT"]["Issue"]["burn_rate"] = json.RawMessage("null")
+	raw, err := json.Marshal(msg)
+	require.NoError(t, err)
+
+	_, err = handler.NewCoreumMsgHandler().Custom(sdk.MustAccAddressFromBech32(issuer), raw)
+	require.Error(t, err)
+}
+
+func readGolden(t *testing.T) map[string]json.RawMessage {
+	t.Helper()
+
+	data, err := os.ReadFile("testdata/custom_msgs.json")
+	require.NoError(t, err)
+
+	var golden map[string]json.RawMessage
+	require.NoError(t, json.Unmarshal(data, &golden))
+	return golden
+}
+
+func dataBytes(t *testing.T, data string) *codectypes.Any {
+	t.Helper()
+
+	dataValue, err := codectypes.NewAnyWithValue(&assetnfttypes.DataBytes{Data: []byte(data)})
+	require.NoError(t, err)
+	return dataValue
+}
@@ -5,9 +5,12 @@ import (
 	"encoding/base64"
 	"encoding/json"
 
+	sdkmath "cosmossdk.io/math"
 	wasmkeeper "github.com/CosmWasm/wasmd/x/wasm/keeper"
 	codectypes "github.com/cosmos/cosmos-sdk/codec/types"
 	sdk "github.com/cosmos/cosmos-sdk/types"
+	"github.com/cosmos/cosmos-sdk/types/query"
+	banktypes "github.com/cosmos/cosmos-sdk/x/bank/types"
 	nfttypes "github.com/cosmos/cosmos-sdk/x/nft"
 	"github.com/cosmos/gogoproto/proto"
 	"github.com/pkg/errors"
@@ -28,6 +31,29 @@ type assetFTQuery struct {
 	FrozenBalances      *assetfttypes.QueryFrozenBalancesRequest      `json:"FrozenBalances"`
 	WhitelistedBalance  *assetfttypes.QueryWhitelistedBalanceRequest  `json:"WhitelistedBalance"`
 	WhitelistedBalances *assetfttypes.QueryWhitelistedBalancesRequest `json:"WhitelistedBalances"`
+	Balances            *assetFTBalancesRequest                       `json:"Balances"`
+}
+
+// assetFTBalancesRequest is the request for the asset ft balances of all the denoms held by an account.
+type assetFTBalancesRequest struct {
+	Account    string             `json:"account"`
+	Pagination *query.PageRequest `json:"pagination"`
+}
+
+// assetFTAccountBalance is the balance of a denom on an account together with its asset ft amounts.
+type assetFTAccountBalance struct {
+	Denom       string      `json:"denom"`
+	Balance     sdkmath.Int `json:"balance"`
+	Spendable   sdkmath.Int `json:"spendable"`
+	Frozen      sdkmath.Int `json:"frozen"`
+	Whitelisted sdkmath.Int `json:"whitelisted"`
+	Locked      sdkmath.Int `json:"locked"`
+}
+
+// assetFTBalancesResponse is the response for the asset ft balances of an account.
+type assetFTBalancesResponse struct {
+	Pagination pageResponse            `json:"pagination"`
+	Balances   []assetFTAccountBalance `json:"balances"`
 }
 
 // assetNFTClass is the asset nft Class with string data.
@@ -149,6 +175,7 @@ type coreumQuery struct {
 
 // NewCoreumQueryHandler returns the coreum handler which handles queries from smart contracts.
 func NewCoreumQueryHandler(
+	bankQueryServer banktypes.QueryServer,
 	assetFTQueryServer assetfttypes.QueryServer,
 	assetNFTQueryServer assetnfttypes.QueryServer,
 	nftQueryServer nfttypes.QueryServer,
@@ -160,7 +187,9 @@ func NewCoreumQueryHandler(
 				return nil, errors.WithStack(err)
 			}
 
-			return processCoreumQuery(ctx, coreumQuery, assetFTQueryServer, assetNFTQueryServer, nftQueryServer)
+			return processCoreumQuery(
+				ctx, coreumQuery, bankQueryServer, assetFTQueryServer, assetNFTQueryServer, nftQueryServer,
+			)
 		},
 	}
 }
@@ -180,12 +209,13 @@ func convertStringToDataBytes(dataString string) (*codectypes.Any, error) {
 func processCoreumQuery(
 	ctx sdk.Context,
 	queries coreumQuery,
+	bankQueryServer banktypes.QueryServer,
 	assetFTQueryServer assetfttypes.QueryServer,
 	assetNFTQueryServer assetnfttypes.QueryServer,
 	nftQueryServer nfttypes.QueryServer,
 ) ([]byte, error) {
 	if queries.AssetFT != nil {
-		return processAssetFTQuery(ctx, queries.AssetFT, assetFTQueryServer)
+		return processAssetFTQuery(ctx, queries.AssetFT, bankQueryServer, assetFTQueryServer)
 	}
 	if queries.AssetNFT != nil {
 		return processAssetNFTQuery(ctx, queries.AssetNFT, assetNFTQueryServer)
@@ -197,7 +227,12 @@ func processCoreumQuery(
 	return nil, nil
 }
 
-func processAssetFTQuery(ctx sdk.Context, assetFTQuery *assetFTQuery, assetFTQueryServer assetfttypes.QueryServer) ([]byte, error) {
+func processAssetFTQuery(
+	ctx sdk.Context,
+	assetFTQuery *assetFTQuery,
+	bankQueryServer banktypes.QueryServer,
+	assetFTQueryServer assetfttypes.QueryServer,
+) ([]byte, error) {
 	if assetFTQuery.Params != nil {
 		return executeQuery(ctx, assetFTQuery.Params, func(ctx context.Context, req *assetfttypes.QueryParamsRequest) (*assetfttypes.QueryParamsResponse, error) {
 			return assetFTQueryServer.Params(ctx, req)
@@ -238,6 +273,11 @@ func processAssetFTQuery(ctx sdk.Context, assetFTQuery *assetFTQuery, assetFTQue
 			return assetFTQueryServer.WhitelistedBalances(ctx, req)
 		})
 	}
+	if assetFTQuery.Balances != nil {
+		return executeQuery(ctx, assetFTQuery.Balances, func(ctx context.Context, req *assetFTBalancesRequest) (*assetFTBalancesResponse, error) {
+			return queryAssetFTBalances(ctx, req, bankQueryServer, assetFTQueryServer)
+		})
+	}
 
 	return nil, nil
 }
@@ -498,6 +538,62 @@ func processNFTQuery(ctx sdk.Context, nftQuery *nftQuery, nftQueryServer nfttype
 	return nil, nil
 }
 
+// queryAssetFTBalances pages through the bank balances of the account and adds the asset ft amounts of each denom,
+// so contracts get all of them in one query.
+func queryAssetFTBalances(
+	ctx context.Context,
+	req *assetFTBalancesRequest,
+	bankQueryServer banktypes.QueryServer,
+	assetFTQueryServer assetfttypes.QueryServer,
+) (*assetFTBalancesResponse, error) {
+	balancesRes, err := bankQueryServer.AllBalances(ctx, &banktypes.QueryAllBalancesRequest{
+		Address:    req.Account,
+		Pagination: req.Pagination,
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	balances := make([]assetFTAccountBalance, 0, len(balancesRes.Balances))
+	for _, balance := range balancesRes.Balances {
+		balanceRes, err := assetFTQueryServer.Balance(ctx, &assetfttypes.QueryBalanceRequest{
+			Account: req.Account,
+			Denom:   balance.Denom,
+		})
+		if err != nil {
+			return nil, err
+		}
+
+		// the bank module keeps the locked coins and the asset ft module the frozen ones, a send has to pass both
+		spendable := balanceRes.Balance.Sub(sdkmath.MaxInt(balanceRes.Frozen, balanceRes.Locked))
+		if spendable.IsNegative() {
+			spendable = sdkmath.ZeroInt()
+		}
+
+		balances = append(balances, assetFTAccountBalance{
+			Denom:       balance.Denom,
+			Balance:     balanceRes.Balance,
+			Spendable:   spendable,
+			Frozen:      balanceRes.Frozen,
+			Whitelisted: balanceRes.Whitelisted,
+			Locked:      balanceRes.Locked,
+		})
+	}
+
+	var pagination pageResponse
+	if balancesRes.Pagination != nil {
+		pagination = pageResponse{
+			NextKey: balancesRes.Pagination.NextKey,
+			Total:   balancesRes.Pagination.Total,
+		}
+	}
+
+	return &assetFTBalancesResponse{
+		Pagination: pagination,
+		Balances:   balances,
+	}, nil
+}
+
 func executeQuery[T, K any](
 	ctx sdk.Context,
 	reqStruct T,
@@ -0,0 +1,138 @@
+package handler_test
+
+import (
+	"context"
+	"encoding/json"
+	"testing"
+
+	sdkmath "cosmossdk.io/math"
+	sdk "github.com/cosmos/cosmos-sdk/types"
+	"github.com/cosmos/cosmos-sdk/types/query"
+	banktypes "github.com/cosmos/cosmos-sdk/x/bank/types"
+	"github.com/stretchr/testify/require"
+
+	assetfttypes "github.com/CoreumFoundation/coreum/v3/x/asset/ft/types"
+	"github.com/CoreumFoundation/coreum/v3/x/wasm/handler"
+)
+
+// bankQueryServer serves the bank balances of the accounts.
+type bankQueryServer struct {
+	banktypes.QueryServer
+	balances map[string]sdk.Coins
+}
+
+func (s bankQueryServer) AllBalances(
+	_ context.Context,
+	req *banktypes.QueryAllBalancesRequest,
+) (*banktypes.QueryAllBalancesResponse, error) {
+	balances := s.balances[req.Address]
+	return &banktypes.QueryAllBalancesResponse{
+		Balances:   balances,
+		Pagination: &query.PageResponse{Total: uint64(len(balances))},
+	}, nil
+}
+
+// assetFTQueryServer serves the asset ft balances of the denoms held by the account.
+type assetFTQueryServer struct {
+	assetfttypes.QueryServer
+	balances map[string]*assetfttypes.QueryBalanceResponse
+}
+
+func (s assetFTQueryServer) Balance(
+	_ context.Context,
+	req *assetfttypes.QueryBalanceRequest,
+) (*assetfttypes.QueryBalanceResponse, error) {
+	return s.balances[req.Denom], nil
+}
+
+type accountBalance struct {
+	Denom       string      `json:"denom"`
+	Balance     sdkmath.Int `json:"balance"`
+	Spendable   sdkmath.Int `json:"spendable"`
+	Frozen      sdkmath.Int `json:"frozen"`
+	Whitelisted sdkmath.Int `json:"whitelisted"`
+	Locked      sdkmath.Int `json:"locked"`
+}
+
+type balancesResponse struct {
+	Balances   []accountBalance `json:"balances"`
+	Pagination struct {
+		Total uint64 `json:"total"`
+	} `json:"pagination"`
+}
+
+// TestAssetFTBalancesQuery checks the spendable amount of the Balances query is the balance minus the larger of the
+// frozen and locked amounts, never below zero.
+func TestAssetFTBalancesQuery(t *testing.T) {
+	const (
+		emptyAccount  = "devcore1qyqszqgpqyqszqgpqyqszqgpqyqszqgpnr58ym"
+		frozenDenom   = "frozen-" + issuer
+		lockedDenom   = "locked-" + issuer
+		clampedDenom  = "clamped-" + issuer
+		expectedTotal = 3
+	)
+
+	amounts := func(balance, frozen, locked int64) *assetfttypes.QueryBalanceResponse {
+		return &assetfttypes.QueryBalanceResponse{
+			Balance:     sdkmath.NewInt(balance),
+			Whitelisted: sdkmath.NewInt(balance * 2),
+			Frozen:      sdkmath.NewInt(frozen),
+			Locked:      sdkmath.NewInt(locked),
+		}
+	}
+	ftBalances := map[string]*assetfttypes.QueryBalanceResponse{
+		frozenDenom:  amounts(100, 60, 20),
+		lockedDenom:  amounts(100, 10, 70),
+		clampedDenom: amounts(50, 80, 30),
+	}
+	bankBalances := sdk.NewCoins()
+	for denom, balance := range ftBalances {
+		bankBalances = bankBalances.Add(sdk.NewCoin(denom, balance.Balance))
+	}
+
+	queryHandler := handler.NewCoreumQueryHandler(
+		bankQueryServer{balances: map[string]sdk.Coins{account: bankBalances}},
+		assetFTQueryServer{balances: ftBalances},
+		nil,
+		nil,
+	)
+	queryBalances := func(address string) balancesResponse {
+		t.Helper()
+
+		req, err := json.Marshal(map[string]any{
+			"AssetFT": map[string]any{
+				"Balances": map[string]any{"account": address},
+			},
+		})
+		require.NoError(t, err)
+		raw, err := queryHandler.Custom(sdk.Context{}.WithContext(context.Background()), req)
+		require.NoError(t, err)
+
+		var res balancesResponse
+		require.NoError(t, json.Unmarshal(raw, &res))
+		return res
+	}
+
+	res := queryBalances(account)
+	require.EqualValues(t, expectedTotal, res.Pagination.Total)
+	require.Len(t, res.Balances, expectedTotal)
+
+	expectedSpendable := map[string]int64{
+		frozenDenom:  40,
+		lockedDenom:  30,
+		clampedDenom: 0,
+	}
+	for _, balance := range res.Balances {
+		expected, ok := ftBalances[balance.Denom]
+		require.True(t, ok, balance.Denom)
+		require.Equal(t, expected.Balance.String(), balance.Balance.String(), balance.Denom)
+		require.Equal(t, expected.Frozen.String(), balance.Frozen.String(), balance.Denom)
+		require.Equal(t, expected.Whitelisted.String(), balance.Whitelisted.String(), balance.Denom)
+		require.Equal(t, expected.Locked.String(), balance.Locked.String(), balance.Denom)
+		require.Equal(t, sdkmath.NewInt(expectedSpendable[balance.Denom]).String(), balance.Spendable.String(), balance.Denom)
+	}
+
+	res = queryBalances(emptyAccount)
+	require.Zero(t, res.Pagination.Total)
+	require.Empty(t, res.Balances)
+}